@@ -1,5 +1,6 @@
 UPDATE gambling
 SET
-    coins = coins - $2
+    coins = coins - $3
 WHERE
-    id = $1;
\ No newline at end of file
+    guild_id = $1
+    AND id = $2;
\ No newline at end of file
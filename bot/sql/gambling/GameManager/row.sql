@@ -11,6 +11,7 @@ FROM
 LEFT JOIN
     levels AS l ON g.id = l.id
 LEFT JOIN
-    gambling_mine AS m ON g.id = m.id
+    gambling_mine AS m ON g.guild_id = m.guild_id AND g.id = m.id
 WHERE
-    g.id = $1;
\ No newline at end of file
+    g.guild_id = $1
+    AND g.id = $2;
\ No newline at end of file
@@ -0,0 +1,214 @@
+use std::sync::LazyLock;
+
+use endgame_analysis::{DestinyPerkManager, DestinyWeaponManager};
+use gambling::commands::craft::CraftManager;
+use gambling::commands::daily::DailyManager;
+use gambling::commands::dig::DigManager;
+use gambling::commands::gift::GiftManager;
+use gambling::commands::inventory::InventoryManager;
+use gambling::commands::leaderboard::LeaderboardManager;
+use gambling::commands::mine::MineManager;
+use gambling::commands::prestige::PrestigeManager;
+use gambling::commands::profile::ProfileManager;
+use gambling::commands::send::SendManager;
+use gambling::commands::shop::ShopManager;
+use gambling::commands::work::WorkManager;
+use gambling::{
+    EconomyManager, EffectsManager, GamblingManager, GameManager, GoalsManager, LedgerManager,
+    LottoManager, SessionManager, StaminaManager,
+};
+use levels::LevelsManager;
+use lfg::commands::{JoinedManager, SetupManager};
+use lfg::components::EditManager;
+use lfg::modals::create::GuildManager;
+use lfg::{PostManager, PostRow, Savable, TimezoneManager};
+use reaction_roles::ReactionRolesManager;
+use sqlx::{Database, Postgres, Sqlite};
+use suggestions::SuggestionsGuildManager;
+use temp_voice::{TempVoiceGuildManager, VoiceChannelManager};
+use ticket::TicketGuildManager;
+use ticket::ticket_manager::TicketManager;
+use zayden_core::{CooldownManager, CronJobManager, PermissionsManager, Registry};
+
+use crate::Error;
+use crate::cooldowns::CooldownTable;
+use crate::cron::CronJobTable;
+use crate::modules;
+use crate::modules::admin::PermissionsTable;
+use crate::modules::destiny2::endgame_analysis::{DestinyPerkTable, DestinyWeaponTable};
+use crate::modules::gambling::{
+    CraftTable, DailyTable, DigTable, EconomyTable, EffectsTable, GamblingTable, GameTable,
+    GiftTable, GoalsTable, InventoryTable, LeaderboardTable, LedgerTable, LottoTable, MineTable,
+    PrestigeTable, ProfileTable, SendTable, SessionTable, ShopTable, StaminaTable, WorkTable,
+};
+use crate::modules::levels::LevelsTable;
+use crate::modules::lfg::{PostTable, UsersTable};
+use crate::modules::reaction_roles::ReactionRolesTable;
+use crate::modules::temp_voice::VoiceChannelTable;
+use crate::modules::ticket::TicketTable;
+use crate::sqlx_lib::{GuildTable, ModulesManager};
+
+/// A database the bot can run on, naming the table that implements each
+/// manager trait for it.
+///
+/// Handlers are generic over the backend, so adding one only needs the
+/// manager implementations and its own migrations.
+pub trait Backend: Database + Sync {
+    type Cooldowns: CooldownManager<Self> + Send + Sync;
+    type CronJobs: CronJobManager<Self> + Send + Sync;
+    type Permissions: PermissionsManager<Self> + Send + Sync;
+    type Guilds: ModulesManager<Self>
+        + SuggestionsGuildManager<Self>
+        + TempVoiceGuildManager<Self>
+        + TicketGuildManager<Self>
+        + Send
+        + Sync;
+
+    type Craft: CraftManager<Self> + Send + Sync;
+    type Daily: DailyManager<Self> + Send + Sync;
+    type Dig: DigManager<Self> + Send + Sync;
+    type Economies: EconomyManager<Self> + Send + Sync;
+    type Effects: EffectsManager<Self> + Send + Sync;
+    type Gambling: GamblingManager<Self> + Send + Sync;
+    type Game: GameManager<Self> + Send + Sync;
+    type Gift: GiftManager<Self> + Send + Sync;
+    type Goals: GoalsManager<Self> + Send + Sync;
+    type Inventory: InventoryManager<Self> + Send + Sync;
+    type Leaderboard: LeaderboardManager<Self> + Send + Sync;
+    type Ledger: LedgerManager<Self> + Send + Sync;
+    type Lotto: LottoManager<Self> + Send + Sync;
+    type Mine: MineManager<Self> + Send + Sync;
+    type Prestige: PrestigeManager<Self> + Send + Sync;
+    type Profile: ProfileManager<Self> + Send + Sync;
+    type Sends: SendManager<Self> + Send + Sync;
+    type Sessions: SessionManager<Self> + Send + Sync;
+    type Shop: ShopManager<Self> + Send + Sync;
+    type Stamina: StaminaManager<Self> + Send + Sync;
+    type Work: WorkManager<Self> + Send + Sync;
+
+    type Levels: LevelsManager<Self> + Send + Sync;
+
+    type LfgPosts: PostManager<Self>
+        + JoinedManager<Self>
+        + EditManager<Self>
+        + SetupManager<Self>
+        + Savable<Self, PostRow>
+        + Send
+        + Sync;
+    type LfgGuilds: GuildManager<Self> + Send + Sync;
+    type LfgUsers: TimezoneManager<Self> + Send + Sync;
+
+    type ReactionRoles: ReactionRolesManager<Self> + Send + Sync;
+
+    type VoiceChannels: VoiceChannelManager<Self> + Send + Sync;
+
+    type Tickets: TicketManager<Self> + Send + Sync;
+
+    type DestinyWeapons: DestinyWeaponManager<Self> + Send + Sync;
+    type DestinyPerks: DestinyPerkManager<Self> + Send + Sync;
+
+    /// Every command, component and modal available on this backend.
+    fn registry() -> &'static Registry<Error, Self>;
+}
+
+static POSTGRES_REGISTRY: LazyLock<Registry<Error, Postgres>> =
+    LazyLock::new(modules::registry::<Postgres>);
+
+impl Backend for Postgres {
+    type Cooldowns = CooldownTable;
+    type CronJobs = CronJobTable;
+    type Permissions = PermissionsTable;
+    type Guilds = GuildTable;
+
+    type Craft = CraftTable;
+    type Daily = DailyTable;
+    type Dig = DigTable;
+    type Economies = EconomyTable;
+    type Effects = EffectsTable;
+    type Gambling = GamblingTable;
+    type Game = GameTable;
+    type Gift = GiftTable;
+    type Goals = GoalsTable;
+    type Inventory = InventoryTable;
+    type Leaderboard = LeaderboardTable;
+    type Ledger = LedgerTable;
+    type Lotto = LottoTable;
+    type Mine = MineTable;
+    type Prestige = PrestigeTable;
+    type Profile = ProfileTable;
+    type Sends = SendTable;
+    type Sessions = SessionTable;
+    type Shop = ShopTable;
+    type Stamina = StaminaTable;
+    type Work = WorkTable;
+
+    type Levels = LevelsTable;
+
+    type LfgPosts = PostTable;
+    type LfgGuilds = modules::lfg::GuildTable;
+    type LfgUsers = UsersTable;
+
+    type ReactionRoles = ReactionRolesTable;
+
+    type VoiceChannels = VoiceChannelTable;
+
+    type Tickets = TicketTable;
+
+    type DestinyWeapons = DestinyWeaponTable;
+    type DestinyPerks = DestinyPerkTable;
+
+    fn registry() -> &'static Registry<Error, Self> {
+        &POSTGRES_REGISTRY
+    }
+}
+
+static SQLITE_REGISTRY: LazyLock<Registry<Error, Sqlite>> =
+    LazyLock::new(modules::registry::<Sqlite>);
+
+impl Backend for Sqlite {
+    type Cooldowns = CooldownTable;
+    type CronJobs = CronJobTable;
+    type Permissions = PermissionsTable;
+    type Guilds = GuildTable;
+
+    type Craft = CraftTable;
+    type Daily = DailyTable;
+    type Dig = DigTable;
+    type Economies = EconomyTable;
+    type Effects = EffectsTable;
+    type Gambling = GamblingTable;
+    type Game = GameTable;
+    type Gift = GiftTable;
+    type Goals = GoalsTable;
+    type Inventory = InventoryTable;
+    type Leaderboard = LeaderboardTable;
+    type Ledger = LedgerTable;
+    type Lotto = LottoTable;
+    type Mine = MineTable;
+    type Prestige = PrestigeTable;
+    type Profile = ProfileTable;
+    type Sends = SendTable;
+    type Sessions = SessionTable;
+    type Shop = ShopTable;
+    type Stamina = StaminaTable;
+    type Work = WorkTable;
+
+    type Levels = LevelsTable;
+
+    type LfgPosts = PostTable;
+    type LfgGuilds = modules::lfg::GuildTable;
+    type LfgUsers = UsersTable;
+
+    type ReactionRoles = ReactionRolesTable;
+
+    type VoiceChannels = VoiceChannelTable;
+
+    type Tickets = TicketTable;
+
+    type DestinyWeapons = DestinyWeaponTable;
+    type DestinyPerks = DestinyPerkTable;
+
+    fn registry() -> &'static Registry<Error, Self> {
+        &SQLITE_REGISTRY
+    }
+}
@@ -0,0 +1,138 @@
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use serenity::all::{ChannelId, GuildId, MessageId};
+use zayden_core::{ConfigError, config};
+
+/// The layout of the config file. Sections owned by library crates are
+/// handed to them on load.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct File {
+    #[serde(default)]
+    emojis: EmojisConfig,
+    events: Option<EventsConfig>,
+    moderation: Option<ModerationConfig>,
+    metrics: Option<MetricsConfig>,
+    errors: Option<ErrorsConfig>,
+    #[serde(default)]
+    shutdown: ShutdownConfig,
+    gambling: gambling::Config,
+}
+
+/// The sections used by the bot's own modules.
+#[derive(Debug)]
+pub struct Config {
+    pub emojis: EmojisConfig,
+    pub events: Option<EventsConfig>,
+    // Read by the rules command, while the moderation module is disabled
+    #[allow(dead_code)]
+    pub moderation: Option<ModerationConfig>,
+    pub metrics: Option<MetricsConfig>,
+    pub errors: Option<ErrorsConfig>,
+    pub shutdown: ShutdownConfig,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmojisConfig {
+    /// PNGs uploaded as application emojis on startup.
+    pub dir: PathBuf,
+}
+
+impl Default for EmojisConfig {
+    fn default() -> Self {
+        Self {
+            dir: PathBuf::from("assets/emojis"),
+        }
+    }
+}
+
+/// The `/live` command is only registered with this section.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventsConfig {
+    pub guild: GuildId,
+    pub twitch_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+pub struct ModerationConfig {
+    pub rules_channel: ChannelId,
+    pub rules_message: MessageId,
+}
+
+/// `/metrics` and `/healthz` are only served with this section.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MetricsConfig {
+    pub addr: SocketAddr,
+}
+
+/// Unexpected errors are only reported to a channel with this section.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ErrorsConfig {
+    pub channel: ChannelId,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShutdownConfig {
+    /// How long running commands get to finish before the gateway is closed.
+    pub grace_period_secs: u64,
+}
+
+impl Default for ShutdownConfig {
+    fn default() -> Self {
+        Self {
+            grace_period_secs: 30,
+        }
+    }
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Loads the file at `CONFIG_PATH`, or `config.toml` by default.
+pub fn load() -> Result<(), ConfigError> {
+    load_from(config::path())
+}
+
+/// Loads the config file at `path`. Does nothing if a config is already
+/// loaded.
+pub fn load_from(path: impl AsRef<Path>) -> Result<(), ConfigError> {
+    if CONFIG.get().is_some() {
+        return Ok(());
+    }
+
+    let File {
+        emojis,
+        events,
+        moderation,
+        metrics,
+        errors,
+        shutdown,
+        gambling,
+    } = config::load(path)?;
+
+    gambling::config::init(gambling);
+
+    let _ = CONFIG.set(Config {
+        emojis,
+        events,
+        moderation,
+        metrics,
+        errors,
+        shutdown,
+    });
+
+    Ok(())
+}
+
+pub fn get() -> &'static Config {
+    CONFIG.get().expect("the config is loaded at startup")
+}
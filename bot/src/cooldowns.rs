@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use gambling::GAME_COOLDOWN;
+use sqlx::any::AnyQueryResult;
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
+use zayden_core::{Bucket, Cooldown, CooldownManager, CooldownRow, Cooldowns};
+
+use crate::modules::AI;
+
+/// Limits by command name, plus the shared ones checked by the modules
+/// themselves.
+pub fn limits() -> Cooldowns {
+    Cooldowns::new()
+        .limit(GAME_COOLDOWN, Cooldown::seconds(5))
+        .limit(AI, Cooldown::seconds(60).burst(5).bucket(Bucket::Channel))
+        .limit("work", Cooldown::seconds(60).burst(3))
+        .limit("dig", Cooldown::seconds(60).burst(5))
+        .limit("gift", Cooldown::seconds(600).persistent())
+}
+
+pub struct CooldownTable;
+
+#[async_trait]
+impl CooldownManager<Postgres> for CooldownTable {
+    async fn rows(pool: &PgPool) -> sqlx::Result<Vec<CooldownRow>> {
+        sqlx::query_as!(
+            CooldownRow,
+            "SELECT name, bucket_id, used_at FROM cooldowns"
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn replace(pool: &PgPool, rows: Vec<CooldownRow>) -> sqlx::Result<AnyQueryResult> {
+        let mut names = Vec::with_capacity(rows.len());
+        let mut bucket_ids = Vec::with_capacity(rows.len());
+        let mut used_at = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            names.push(row.name);
+            bucket_ids.push(row.bucket_id);
+            used_at.push(row.used_at);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("DELETE FROM cooldowns")
+            .execute(&mut *tx)
+            .await?;
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO cooldowns (name, bucket_id, used_at)
+            SELECT * FROM UNNEST($1::TEXT[], $2::BIGINT[], $3::TIMESTAMPTZ[])
+            "#,
+            &names,
+            &bucket_ids,
+            &used_at
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(result.into())
+    }
+}
+
+#[async_trait]
+impl CooldownManager<Sqlite> for CooldownTable {
+    async fn rows(pool: &SqlitePool) -> sqlx::Result<Vec<CooldownRow>> {
+        let rows = sqlx::query_as::<_, (String, i64, DateTime<Utc>)>(
+            "SELECT name, bucket_id, used_at FROM cooldowns",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(name, bucket_id, used_at)| CooldownRow {
+                name,
+                bucket_id,
+                used_at,
+            })
+            .collect())
+    }
+
+    async fn replace(pool: &SqlitePool, rows: Vec<CooldownRow>) -> sqlx::Result<AnyQueryResult> {
+        let mut tx = pool.begin().await?;
+
+        let mut result = sqlx::query("DELETE FROM cooldowns")
+            .execute(&mut *tx)
+            .await
+            .map(AnyQueryResult::from)?;
+
+        for row in rows {
+            let inserted =
+                sqlx::query("INSERT INTO cooldowns (name, bucket_id, used_at) VALUES ($1, $2, $3)")
+                    .bind(row.name)
+                    .bind(row.bucket_id)
+                    .bind(row.used_at)
+                    .execute(&mut *tx)
+                    .await?;
+
+            result.extend([AnyQueryResult::from(inserted)]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+}
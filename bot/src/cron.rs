@@ -1,93 +1,352 @@
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use futures::future;
 use serenity::all::Context;
-use sqlx::{PgPool, Postgres};
+use sqlx::any::AnyQueryResult;
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::time::Duration;
 use tokio::time::sleep;
-use zayden_core::{ActionFn, CronJobs};
+use zayden_core::{CronJob, CronJobManager, CronJobRow, CronJobs};
 
 use crate::Result;
+use crate::backend::Backend;
 
-pub async fn start_cron_jobs(ctx: Context, pool: PgPool) {
+const MAX_SLEEP: Duration = Duration::from_secs(60);
+
+pub struct CronJobTable;
+
+#[async_trait]
+impl CronJobManager<Postgres> for CronJobTable {
+    async fn rows(pool: &PgPool) -> sqlx::Result<Vec<CronJobRow>> {
+        sqlx::query_as!(
+            CronJobRow,
+            "SELECT id, schedule, catch_up, last_run FROM cron_jobs"
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn save(pool: &PgPool, row: CronJobRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            r#"
+            INSERT INTO cron_jobs (id, schedule, catch_up, last_run)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id, schedule) DO UPDATE
+            SET
+                catch_up = EXCLUDED.catch_up,
+                last_run = EXCLUDED.last_run;
+            "#,
+            row.id,
+            row.schedule,
+            row.catch_up,
+            row.last_run
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn delete(pool: &PgPool, id: &str) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!("DELETE FROM cron_jobs WHERE id = $1", id)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn delete_schedule(
+        pool: &PgPool,
+        id: &str,
+        schedule: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "DELETE FROM cron_jobs WHERE id = $1 AND schedule = $2",
+            id,
+            schedule
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl CronJobManager<Sqlite> for CronJobTable {
+    async fn rows(pool: &SqlitePool) -> sqlx::Result<Vec<CronJobRow>> {
+        let rows = sqlx::query_as::<_, (String, String, String, DateTime<Utc>)>(
+            "SELECT id, schedule, catch_up, last_run FROM cron_jobs",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, schedule, catch_up, last_run)| CronJobRow {
+                id,
+                schedule,
+                catch_up,
+                last_run,
+            })
+            .collect())
+    }
+
+    async fn save(pool: &SqlitePool, row: CronJobRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            r#"
+            INSERT INTO cron_jobs (id, schedule, catch_up, last_run)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (id, schedule) DO UPDATE
+            SET
+                catch_up = EXCLUDED.catch_up,
+                last_run = EXCLUDED.last_run;
+            "#,
+        )
+        .bind(row.id)
+        .bind(row.schedule)
+        .bind(row.catch_up)
+        .bind(row.last_run)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn delete(pool: &SqlitePool, id: &str) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM cron_jobs WHERE id = $1")
+            .bind(id)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn delete_schedule(
+        pool: &SqlitePool,
+        id: &str,
+        schedule: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM cron_jobs WHERE id = $1 AND schedule = $2")
+            .bind(id)
+            .bind(schedule)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+}
+
+/// Merges the jobs stored in the database with the ones registered at startup.
+///
+/// Jobs registered in `main` pick up their stored `last_run`, everything else
+/// is rebuilt from its row.
+pub async fn load_cron_jobs<Db: Backend>(ctx: &Context, pool: &Pool<Db>) -> Result<()> {
+    let rows = Db::CronJobs::rows(pool).await?;
+
+    let mut unknown = Vec::new();
+
+    let mut data = ctx.data.write().await;
+    let jobs = data.entry::<CronJobs<Db>>().or_insert(Vec::new());
+
+    for row in rows {
+        if let Some(job) = jobs.iter_mut().find(|job| job.matches(&row)) {
+            job.last_run = Some(row.last_run);
+            continue;
+        }
+
+        match restore_job::<Db>(&row) {
+            Some(job) => jobs.push(job),
+            None => unknown.push(row),
+        }
+    }
+
+    drop(data);
+
+    for row in unknown {
+        tracing::warn!(job = %row.id, "unknown cron job, removing it");
+        Db::CronJobs::delete_schedule(pool, &row.id, &row.schedule).await?;
+    }
+
+    Ok(())
+}
+
+fn restore_job<Db: Backend>(row: &CronJobRow) -> Option<CronJob<Db>> {
+    lfg::cron::restore_reminder::<Db, Db::LfgPosts>(row)
+}
+
+pub async fn start_cron_jobs<Db: Backend>(ctx: Context, pool: Pool<Db>) {
     if let Err(e) = _start_cron_jobs(ctx, pool).await {
-        eprintln!("Error starting cron jobs: {:?}", e);
+        tracing::error!(error = ?e, "failed to start cron jobs");
     }
 }
 
-async fn _start_cron_jobs(ctx: Context, pool: PgPool) -> Result<()> {
+async fn _start_cron_jobs<Db: Backend>(ctx: Context, pool: Pool<Db>) -> Result<()> {
+    catch_up(&ctx, &pool).await?;
+
     loop {
-        let pending_jobs = pending_jobs(&ctx).await;
+        let (target_wakeup_time, pending_jobs) = pending_jobs(&ctx, &pool).await?;
 
-        let sleep_duration = match pending_jobs.first() {
-            Some((target_wakeup_time, _)) => {
-                println!("Next Job: {:?}", target_wakeup_time);
+        let sleep_duration = match target_wakeup_time {
+            Some(target_wakeup_time) => {
+                tracing::debug!(at = %target_wakeup_time, "next cron job");
 
                 let now = Utc::now();
-                if *target_wakeup_time > now {
-                    (*target_wakeup_time - now)
+                if target_wakeup_time > now {
+                    (target_wakeup_time - now)
                         .to_std()
                         .unwrap_or(Duration::ZERO)
                 } else {
                     Duration::ZERO
                 }
             }
-            None => Duration::from_secs(60),
+            None => MAX_SLEEP,
         };
 
+        // Wake up regularly so jobs that were added, cancelled or rescheduled
+        // in the meantime are picked up.
+        if sleep_duration > MAX_SLEEP {
+            sleep(MAX_SLEEP).await;
+            continue;
+        }
+
         if sleep_duration > Duration::from_millis(50) {
             sleep(sleep_duration).await;
         }
 
-        if !pending_jobs.is_empty() {
-            let futures_iter = pending_jobs
-                .into_iter()
-                .map(|(_, action)| (action)(ctx.clone(), pool.clone()));
+        if let Some(run_time) = target_wakeup_time {
+            let mut due_jobs = Vec::with_capacity(pending_jobs.len());
+            for job in pending_jobs {
+                if CronJobs::<Db>::contains(&ctx, &job).await {
+                    due_jobs.push(job);
+                }
+            }
+
+            let futures_iter = due_jobs
+                .iter()
+                .map(|job| job.run(ctx.clone(), pool.clone()));
 
             future::join_all(futures_iter).await;
+
+            for job in due_jobs {
+                save_run(&ctx, &pool, job, run_time).await?;
+            }
         }
 
         sleep(Duration::from_secs(5)).await;
     }
 }
 
-async fn pending_jobs(ctx: &Context) -> Vec<(DateTime<Utc>, ActionFn<Postgres>)> {
-    let mut pending_jobs: Vec<(DateTime<Utc>, ActionFn<Postgres>)> = Vec::new();
+/// Runs the jobs that were due while the bot was offline, as allowed by each
+/// job's catch-up policy.
+async fn catch_up<Db: Backend>(ctx: &Context, pool: &Pool<Db>) -> Result<()> {
+    let now = Utc::now();
+
+    let jobs = {
+        let data = ctx.data.read().await;
+        data.get::<CronJobs<Db>>().cloned().unwrap_or(Vec::new())
+    };
+
+    // The reminders of an LFG post share an id, and only the latest one it
+    // missed is still worth sending
+    let mut latest = HashMap::new();
+    for job in &jobs {
+        if let Some(missed) = job.last_missed(now) {
+            latest
+                .entry(job.id.as_str())
+                .and_modify(|time: &mut DateTime<Utc>| *time = (*time).max(missed))
+                .or_insert(missed);
+        }
+    }
+
+    let futures_iter = jobs.iter().map(|job| {
+        let runs = match job.last_missed(now) {
+            Some(missed) if latest.get(job.id.as_str()) == Some(&missed) => job.missed_runs(now),
+            _ => 0,
+        };
+        let ctx = ctx.clone();
+        let pool = pool.clone();
+
+        async move {
+            if runs > 0 {
+                tracing::info!(job = %job.id, runs, "catching up missed runs");
+            }
+
+            for _ in 0..runs {
+                job.run(ctx.clone(), pool.clone()).await;
+            }
+        }
+    });
+
+    future::join_all(futures_iter).await;
+
+    for job in jobs {
+        save_run(ctx, pool, job, now).await?;
+    }
+
+    Ok(())
+}
+
+async fn save_run<Db: Backend>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    mut job: CronJob<Db>,
+    run_time: DateTime<Utc>,
+) -> Result<()> {
+    // The loop works out each job's next run from the copy in memory
+    CronJobs::<Db>::ran(ctx, &job, run_time).await;
+
+    if job.is_finished(run_time) {
+        Db::CronJobs::delete_schedule(pool, &job.id, job.source()).await?;
+    } else {
+        job.last_run = Some(run_time);
+        Db::CronJobs::save(pool, job.to_row()).await?;
+    }
+
+    Ok(())
+}
+
+async fn pending_jobs<Db: Backend>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+) -> Result<(Option<DateTime<Utc>>, Vec<CronJob<Db>>)> {
+    let mut pending_jobs: Vec<CronJob<Db>> = Vec::new();
     let mut earliest_time = None;
 
     let mut data = ctx.data.write().await;
-    let jobs = data
-        .remove::<CronJobs<Postgres>>()
+    let (jobs, finished): (Vec<_>, Vec<_>) = data
+        .remove::<CronJobs<Db>>()
         .unwrap_or(Vec::new())
         .into_iter()
-        .filter_map(|job| {
-            job.schedule
-                .upcoming(Utc)
-                .next()
-                .map(|run_time| (job, run_time))
+        .map(|job| {
+            // A run that came up while the loop was asleep is still due, so
+            // a job added or rescheduled in the meantime isn't dropped
+            let run_time = job.next_due();
+            (job, run_time)
         })
-        .collect::<Vec<_>>();
+        .partition(|(_, run_time)| run_time.is_some());
 
     for (job, run_time) in &jobs {
-        let run_time = *run_time;
+        let run_time = run_time.expect("Partitioned on upcoming run time");
 
         match earliest_time {
             Some(time) => match run_time.cmp(&time) {
                 Ordering::Less => {
                     earliest_time = Some(run_time);
-                    pending_jobs = vec![(run_time, job.action_fn.clone())]
+                    pending_jobs = vec![job.clone()]
                 }
-                Ordering::Equal => pending_jobs.push((run_time, job.action_fn.clone())),
+                Ordering::Equal => pending_jobs.push(job.clone()),
                 Ordering::Greater => {}
             },
             None => {
                 earliest_time = Some(run_time);
-                pending_jobs = vec![(run_time, job.action_fn.clone())];
+                pending_jobs = vec![job.clone()];
             }
         }
     }
 
-    data.insert::<CronJobs<Postgres>>(jobs.into_iter().map(|(job, _)| job).collect());
+    data.insert::<CronJobs<Db>>(jobs.into_iter().map(|(job, _)| job).collect());
+    drop(data);
+
+    for (job, _) in finished {
+        Db::CronJobs::delete_schedule(pool, &job.id, job.source()).await?;
+    }
 
-    pending_jobs
+    Ok((earliest_time, pending_jobs))
 }
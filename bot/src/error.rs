@@ -1,5 +1,6 @@
 use serenity::all::{DiscordJsonError, HttpError, StatusCode};
-use zayden_core::Error as ZaydenError;
+use zayden_core::i18n::DEFAULT_LOCALE;
+use zayden_core::{Error as ZaydenError, Localize, PermissionLevel, t};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -8,6 +9,13 @@ pub enum Error {
     MissingGuildId,
     NotInteractionAuthor,
     NegativeHours,
+    MissingPermission(PermissionLevel),
+    UnknownCommand(String),
+    ModuleDisabled(&'static str),
+    Cooldown(i64),
+    InvalidSchedule(String),
+
+    Core(ZaydenError),
 
     EndgameAnalysis(endgame_analysis::Error),
     Gambling(gambling::Error),
@@ -21,94 +29,121 @@ pub enum Error {
     Sqlx(sqlx::Error),
 }
 
-#[allow(clippy::print_in_format_impl)]
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Localize for Error {
+    fn localize(&self, locale: &str) -> String {
         match self {
-            Error::MissingGuildId => ZaydenError::MissingGuildId.fmt(f),
-            Error::NotInteractionAuthor => write!(f, "You are not the author of this interaction."),
-            Error::NegativeHours => write!(f, "Hours must be a positive number."),
-
-            Error::EndgameAnalysis(e) => e.fmt(f),
-            Error::Gambling(e) => e.fmt(f),
-            Error::Lfg(e) => e.fmt(f),
-            Error::ReactionRole(e) => e.fmt(f),
-            Error::Ticket(e) => e.fmt(f),
-            Error::Suggestions(e) => e.fmt(f),
-            Error::TempVoice(e) => e.fmt(f),
+            Error::MissingGuildId => ZaydenError::MissingGuildId.localize(locale),
+            Error::NotInteractionAuthor => ZaydenError::NotInteractionAuthor.localize(locale),
+            Error::NegativeHours => t!(locale, "bot.error.negative_hours"),
+            Error::MissingPermission(PermissionLevel::Owner) => {
+                t!(locale, "bot.error.owner_required")
+            }
+            Error::MissingPermission(level) => {
+                t!(locale, "bot.error.missing_permission", level = level)
+            }
+            Error::UnknownCommand(command) => {
+                t!(locale, "bot.error.unknown_command", command = command)
+            }
+            Error::ModuleDisabled(module) => {
+                t!(locale, "bot.error.module_disabled", module = module)
+            }
+            Error::Cooldown(timestamp) => ZaydenError::Cooldown(*timestamp).localize(locale),
+            Error::InvalidSchedule(schedule) => {
+                t!(locale, "bot.error.invalid_schedule", schedule = schedule)
+            }
+
+            Error::Core(e) => e.localize(locale),
+
+            Error::EndgameAnalysis(e) => e.to_string(),
+            Error::Gambling(e) => e.localize(locale),
+            Error::Lfg(e) => e.localize(locale),
+            Error::ReactionRole(e) => e.to_string(),
+            Error::Ticket(e) => e.to_string(),
+            Error::Suggestions(e) => e.to_string(),
+            Error::TempVoice(e) => e.to_string(),
 
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 10003, .. },
                     ..
                 },
-            ))) => ZaydenError::ChannelDeleted.fmt(f),
+            ))) => ZaydenError::ChannelDeleted.localize(locale),
 
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 10008, .. },
                     ..
                 },
-            ))) => write!(f, "Message was unexpectably deleted. Please try again."),
+            ))) => t!(locale, "core.error.message_deleted"),
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 10062, .. },
                     ..
                 },
-            ))) => ZaydenError::UnknownInteraction.fmt(f),
+            ))) => ZaydenError::UnknownInteraction.localize(locale),
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 50001, .. },
                     ..
                 },
-            ))) => write!(
-                f,
-                "I'm missing access perform that action. Please contact a server admin to resolve this."
-            ),
+            ))) => t!(locale, "core.error.missing_access"),
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 50013, .. },
                     ..
                 },
-            ))) => {
-                write!(
-                    f,
-                    "I'm missing permissions perform that action. Please contact a server admin to resolve this."
-                )
-            }
+            ))) => t!(locale, "core.error.missing_permissions"),
             Self::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     error: DiscordJsonError { code: 50083, .. },
                     ..
                 },
-            ))) => write!(f, "This thread has already been closed and archived."),
+            ))) => t!(locale, "core.error.thread_archived"),
             Error::Serenity(serenity::Error::Http(HttpError::UnsuccessfulRequest(
                 serenity::all::ErrorResponse {
                     status_code: StatusCode::INTERNAL_SERVER_ERROR | StatusCode::SERVICE_UNAVAILABLE,
                     ..
                 },
-            ))) => write!(
-                f,
-                "It looks like Discord is currently experiencing some server issues. Please try your request again shortly. If the problem persists, please contact OscarSix for more details."
-            ),
+            ))) => t!(locale, "core.error.discord_outage"),
             Error::Serenity(e) => unimplemented!("Unhandled Serenity error: {e:?}"),
 
-            Error::Sqlx(sqlx::Error::PoolTimedOut) => ZaydenError::PoolTimedOut.fmt(f),
+            Error::Sqlx(sqlx::Error::PoolTimedOut) => ZaydenError::PoolTimedOut.localize(locale),
             Error::Sqlx(sqlx::Error::ColumnDecode { index, source })
                 if source.is::<sqlx::error::UnexpectedNullError>() =>
             {
-                write!(
-                    f,
-                    "Unexpected null found at {index}, please contact OscarSix to resolve."
-                )
+                t!(locale, "core.error.unexpected_null", index = index)
             }
             Error::Sqlx(e) => unimplemented!("Unhandled SQLx error: {e:?}"),
         }
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.localize(DEFAULT_LOCALE))
+    }
+}
+
+impl Error {
+    /// Errors that point at a bug or outage rather than a user mistake.
+    pub fn is_internal(&self) -> bool {
+        matches!(self, Error::Serenity(_) | Error::Sqlx(_))
+    }
+}
+
 impl std::error::Error for Error {}
 
+impl From<ZaydenError> for Error {
+    fn from(value: ZaydenError) -> Self {
+        match value {
+            ZaydenError::MissingGuildId => Self::MissingGuildId,
+            ZaydenError::NotInteractionAuthor => Self::NotInteractionAuthor,
+            ZaydenError::Cooldown(timestamp) => Self::Cooldown(timestamp),
+            value => Self::Core(value),
+        }
+    }
+}
+
 impl From<endgame_analysis::Error> for Error {
     fn from(e: endgame_analysis::Error) -> Self {
         Error::EndgameAnalysis(e)
@@ -1,33 +1,36 @@
 use serenity::all::{Context, Guild};
-use sqlx::{PgPool, Postgres};
-use zayden_core::SlashCommand;
+use sqlx::Pool;
 use zayden_core::cache::GuildMembersCache;
 
+use crate::backend::Backend;
 use crate::modules;
-use crate::modules::events::live::Live;
-use crate::modules::lfg::{GuildTable, PostTable};
-use crate::{BRADSTER_GUILD, Result};
+use crate::{Result, config};
 
 use super::Handler;
 
-impl Handler {
-    pub async fn guild_create(ctx: &Context, guild: Guild, pool: &PgPool) -> Result<()> {
+impl<Db: Backend> Handler<Db> {
+    pub async fn guild_create(ctx: &Context, guild: Guild, pool: &Pool<Db>) -> Result<()> {
+        let disabled = modules::disabled_modules(ctx, pool, Some(guild.id)).await?;
+        let commands = Db::registry().register(ctx, guild.id, &disabled)?;
+
         let (_, _, _, commands) = tokio::join!(
-            lfg::events::guild_create::<Postgres, GuildTable, PostTable>(ctx, &guild, pool),
+            lfg::events::guild_create::<Db, Db::LfgGuilds, Db::LfgPosts, Db::CronJobs>(
+                ctx, &guild, pool
+            ),
             temp_voice::events::guild_create(ctx, &guild),
             GuildMembersCache::guild_create(ctx, &guild),
-            guild.set_commands(ctx, modules::global_register(ctx)),
+            guild.set_commands(ctx, commands),
         );
         commands?;
 
         if guild.id == 1222360995700150443 {
-            println!("Registered Zayden Guild")
-        } else if guild.id == BRADSTER_GUILD {
-            guild
-                .create_command(ctx, Live::register(ctx).unwrap())
-                .await?;
-
-            println!("Registered Bradster Guild");
+            tracing::info!("Registered Zayden Guild")
+        } else if config::get()
+            .events
+            .as_ref()
+            .is_some_and(|events| events.guild == guild.id)
+        {
+            tracing::info!("Registered events guild");
         }
 
         Ok(())
@@ -1,32 +1,40 @@
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
-use sqlx::PgPool;
-use zayden_core::Autocomplete;
+use sqlx::Pool;
+use zayden_core::{Localize, i18n, logging};
 
 use crate::Result;
+use crate::backend::Backend;
 use crate::handler::Handler;
-use crate::modules::destiny2::endgame_analysis::slash_commands::{TierList, Weapon};
-use crate::modules::lfg::Lfg;
+use crate::modules;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub async fn interaction_autocomplete(
         ctx: &Context,
         interaction: &CommandInteraction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
+        let span = logging::autocomplete_span(interaction);
         let option = interaction.data.autocomplete().unwrap();
 
-        let result = match interaction.data.name.as_str() {
-            "lfg" => Lfg::autocomplete(ctx, interaction, option, pool).await,
-            "weapon" => Weapon::autocomplete(ctx, interaction, option, pool).await,
-            "tierlist" => TierList::autocomplete(ctx, interaction, option, pool).await,
-            _ => {
-                println!("Unknown command: {}", interaction.data.name);
-                return Ok(());
-            }
+        let Some(route) = Db::registry().get_autocomplete(&interaction.data.name) else {
+            tracing::warn!(parent: &span, "unknown command");
+            return Ok(());
         };
 
+        if modules::check_enabled(ctx, pool, interaction.guild_id, route.module)
+            .await
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let result = logging::timed(&span, (route.run)(ctx, interaction, option, pool)).await;
+
         if let Err(e) = result {
-            let msg = e.to_string();
+            tracing::error!(parent: &span, error = ?e, "failed");
+
+            let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+            let msg = e.localize(locale);
 
             let _ = interaction.defer_ephemeral(ctx).await;
 
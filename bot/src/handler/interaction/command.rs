@@ -1,94 +1,73 @@
-use chrono::Utc;
 use serenity::all::{CommandInteraction, Context, EditInteractionResponse};
-use sqlx::PgPool;
-use zayden_core::{SlashCommand, get_option_str};
+use sqlx::Pool;
+use zayden_core::{
+    Cooldowns, Error as ZaydenError, ErrorReport, Localize, METRICS, PermissionLevel, Shutdown,
+    get_option_str, i18n, logging, permissions,
+};
 
-use crate::Result;
+use crate::backend::Backend;
 use crate::handler::Handler;
-use crate::modules::destiny2::endgame_analysis::slash_commands::{DimWishlist, TierList, Weapon};
-use crate::modules::destiny2::info::Perk;
-use crate::modules::events::live::Live;
-use crate::modules::gambling::{
-    Blackjack, Coinflip, Craft, Daily, Dig, Gift, Goals, HigherLower, Inventory, Leaderboard,
-    Lotto, Mine, Prestige, Profile, RockPaperScissors, Roll, Send, Shop, TicTacToe, Work,
-};
-use crate::modules::levels::{Levels, Rank, Xp};
-use crate::modules::lfg::Lfg;
-use crate::modules::misc::Random;
-use crate::modules::reaction_roles::ReactionRoleCommand;
-use crate::modules::suggestions::FetchSuggestions;
-use crate::modules::temp_voice::Voice;
-use crate::modules::ticket::slash_commands::{SupportCommand, TicketCommand};
-
-impl Handler {
+use crate::modules::{self, admin};
+use crate::{Error, Result};
+
+impl<Db: Backend> Handler<Db> {
     pub async fn interaction_command(
         ctx: &Context,
         interaction: &CommandInteraction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
         let options = interaction.data.options();
 
-        println!(
-            "[{}] {} ran command: {}{}",
-            Utc::now().format("%Y-%m-%d %H:%M:%S"),
-            interaction.user.name,
-            interaction.data.name,
-            get_option_str(&options)
-        );
-
-        let result = match interaction.data.name.as_str() {
-            // region Destiny 2
-            "weapon" => Weapon::run(ctx, interaction, options, pool),
-            "dimwishlist" => DimWishlist::run(ctx, interaction, options, pool),
-            "lfg" => Lfg::run(ctx, interaction, options, pool),
-            "tierlist" => TierList::run(ctx, interaction, options, pool),
-            "perk" => Perk::run(ctx, interaction, options, pool),
-            // endregion
-
-            // region gambling
-            "blackjack" => Blackjack::run(ctx, interaction, options, pool),
-            "coinflip" => Coinflip::run(ctx, interaction, options, pool),
-            "craft" => Craft::run(ctx, interaction, options, pool),
-            "daily" => Daily::run(ctx, interaction, options, pool),
-            "dig" => Dig::run(ctx, interaction, options, pool),
-            "inventory" => Inventory::run(ctx, interaction, options, pool),
-            "higherorlower" => HigherLower::run(ctx, interaction, options, pool),
-            "leaderboard" => Leaderboard::run(ctx, interaction, options, pool),
-            "lotto" => Lotto::run(ctx, interaction, options, pool),
-            "mine" => Mine::run(ctx, interaction, options, pool),
-            "profile" => Profile::run(ctx, interaction, options, pool),
-            "prestige" => Prestige::run(ctx, interaction, options, pool),
-            "rps" => RockPaperScissors::run(ctx, interaction, options, pool),
-            "roll" => Roll::run(ctx, interaction, options, pool),
-            "work" => Work::run(ctx, interaction, options, pool),
-            "gift" => Gift::run(ctx, interaction, options, pool),
-            "goals" => Goals::run(ctx, interaction, options, pool),
-            "send" => Send::run(ctx, interaction, options, pool),
-            "shop" => Shop::run(ctx, interaction, options, pool),
-            "tictactoe" => TicTacToe::run(ctx, interaction, options, pool),
-            // endregion
-            "levels" => Levels::run(ctx, interaction, options, pool),
-            "random" => Random::run(ctx, interaction, options, pool),
-            "fetch_suggestions" => FetchSuggestions::run(ctx, interaction, options, pool),
-            "live" => Live::run(ctx, interaction, options, pool),
-            "rank" => Rank::run(ctx, interaction, options, pool),
-            "xp" => Xp::run(ctx, interaction, options, pool),
-            "reaction_role" => ReactionRoleCommand::run(ctx, interaction, options, pool),
-            "voice" => Voice::run(ctx, interaction, options, pool),
-
-            // region: ticket
-            "ticket" => TicketCommand::run(ctx, interaction, options, pool),
-            "support" => SupportCommand::run(ctx, interaction, options, pool),
-            // endregion: ticket
-            _ => {
-                println!("Unknown command: {}", interaction.data.name);
-                return Ok(());
+        let span = logging::command_span(interaction, &options);
+        let option_str = get_option_str(&options);
+
+        let Some(route) = Db::registry().get_command(&interaction.data.name) else {
+            tracing::warn!(parent: &span, "unknown command");
+            return Ok(());
+        };
+
+        let labels = [interaction.data.name.as_str()];
+        METRICS.commands.with_label_values(&labels).inc();
+
+        let result = logging::timed(&span, async {
+            if Shutdown::started(ctx).await {
+                return Err(ZaydenError::ShuttingDown.into());
             }
-        }
+
+            modules::check_enabled(ctx, pool, interaction.guild_id, route.module).await?;
+
+            let level = permissions::command_level::<Db, Db::Permissions>(
+                pool,
+                interaction.guild_id,
+                &interaction.data.name,
+                PermissionLevel::Everyone,
+            )
+            .await?;
+            admin::require(pool, interaction, level).await?;
+
+            Cooldowns::check(ctx, &interaction.data.name, interaction).await?;
+            (route.run)(ctx, interaction, options, pool).await?;
+            Cooldowns::trigger(ctx, &interaction.data.name, interaction).await;
+
+            Ok::<(), Error>(())
+        })
         .await;
 
         if let Err(e) = result {
-            let msg = e.to_string();
+            tracing::error!(parent: &span, error = ?e, "failed");
+            METRICS.command_errors.with_label_values(&labels).inc();
+
+            if e.is_internal() {
+                let report = ErrorReport::new("InteractionCreate", &e)
+                    .interaction(format!("/{}", interaction.data.name), option_str)
+                    .guild(interaction.guild_id)
+                    .user(interaction.user.id);
+
+                Self::report_error(ctx, report).await;
+            }
+
+            let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+            let msg = e.localize(locale);
             let _ = interaction.defer_ephemeral(ctx).await;
 
             interaction
@@ -1,85 +1,45 @@
-use chrono::Utc;
 use serenity::all::{ComponentInteraction, Context, EditInteractionResponse};
-use sqlx::{PgPool, Postgres};
-use suggestions::Suggestions;
+use sqlx::Pool;
+use zayden_core::{ErrorReport, Localize, i18n, logging};
 
+use crate::Result;
+use crate::backend::Backend;
 use crate::handler::Handler;
-use crate::modules::lfg::PostTable;
-use crate::modules::ticket::Ticket;
-use crate::{Error, Result};
+use crate::modules;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub async fn interaction_component(
         ctx: &Context,
         interaction: &ComponentInteraction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        println!(
-            "[{}] {} ran component: {} - {}",
-            Utc::now().format("%Y-%m-%d %H:%M:%S"),
-            interaction.user.name,
-            interaction.data.custom_id,
-            interaction.message.id,
-        );
+        let span = logging::component_span(interaction);
 
-        let result = match interaction.data.custom_id.as_str() {
-            // region: Lfg
-            "lfg_join" => lfg::Components::join::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            "lfg_leave" => lfg::Components::leave::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            "lfg_alternative" => {
-                lfg::Components::alternative::<Postgres, PostTable>(ctx, interaction, pool)
-                    .await
-                    .map_err(Error::from)
-            }
-            "lfg_settings" => {
-                lfg::Components::settings::<Postgres, PostTable>(ctx, interaction, pool)
-                    .await
-                    .map_err(Error::from)
-            }
-
-            "lfg_edit" => lfg::Components::edit::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            "lfg_copy" => lfg::Components::copy::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            "lfg_kick" => lfg::Components::kick::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            "lfg_kick_menu" => {
-                lfg::KickComponent::run::<Postgres, PostTable>(ctx, interaction, pool)
-                    .await
-                    .map_err(Error::from)
-            }
-            "lfg_delete" => lfg::Components::delete::<Postgres, PostTable>(ctx, interaction, pool)
-                .await
-                .map_err(Error::from),
-            // "lfg_tags_add" => Lfg::tags_add(ctx, interaction).await,
-            // "lfg_tags_remove" => Lfg::tags_remove(ctx, interaction).await,
-            // endregion
-            "suggestions_accept" | "suggestions_added" | "accept" => {
-                Suggestions::components(ctx, interaction, true).await;
-                Ok(())
-            }
-            "suggestions_reject" | "reject" => {
-                Suggestions::components(ctx, interaction, false).await;
-                Ok(())
-            }
-
-            //region: Ticket
-            "ticket_create" | "support_ticket" => Ticket::ticket_create(ctx, interaction).await,
-            "support_close" => Ticket::support_close(ctx, interaction).await,
-            "support_faq" => Ticket::support_faq(ctx, interaction, pool).await,
-            //endregion: Ticket
-            _ => Ok(()),
+        // Components without a handler are awaited by collectors
+        let Some(route) = Db::registry().get_component(&interaction.data.custom_id) else {
+            return Ok(());
         };
 
+        let result = logging::timed(&span, async {
+            modules::check_enabled(ctx, pool, interaction.guild_id, route.module).await?;
+            (route.run)(ctx, interaction, pool).await
+        })
+        .await;
+
         if let Err(e) = result {
-            let msg = e.to_string();
+            tracing::error!(parent: &span, error = ?e, "failed");
+
+            if e.is_internal() {
+                let report = ErrorReport::new("InteractionCreate", &e)
+                    .interaction(interaction.data.custom_id.as_str(), "")
+                    .guild(interaction.guild_id)
+                    .user(interaction.user.id);
+
+                Self::report_error(ctx, report).await;
+            }
+
+            let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+            let msg = e.localize(locale);
 
             let _ = interaction.defer_ephemeral(ctx).await;
 
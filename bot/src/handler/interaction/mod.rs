@@ -1,5 +1,7 @@
+use chrono::Utc;
 use serenity::all::{Context, Interaction};
-use sqlx::PgPool;
+use sqlx::Pool;
+use zayden_core::METRICS;
 
 mod autocomplete;
 mod command;
@@ -7,26 +9,27 @@ mod component;
 mod modal;
 
 use crate::Result;
+use crate::backend::Backend;
 
 use super::Handler;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub async fn interaction_create(
         ctx: &Context,
         interaction: Interaction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
+        METRICS.last_interaction.set(Utc::now().timestamp());
+
         match &interaction {
-            Interaction::Command(command) => {
-                Handler::interaction_command(ctx, command, pool).await?
-            }
+            Interaction::Command(command) => Self::interaction_command(ctx, command, pool).await?,
             Interaction::Autocomplete(autocomplete) => {
-                Handler::interaction_autocomplete(ctx, autocomplete, pool).await?
+                Self::interaction_autocomplete(ctx, autocomplete, pool).await?
             }
             Interaction::Component(component) => {
-                Handler::interaction_component(ctx, component, pool).await?
+                Self::interaction_component(ctx, component, pool).await?
             }
-            Interaction::Modal(modal) => Handler::interaction_modal(ctx, modal, pool).await?,
+            Interaction::Modal(modal) => Self::interaction_modal(ctx, modal, pool).await?,
             _ => unimplemented!("Interaction not implemented: {:?}", interaction.kind()),
         };
 
@@ -1,68 +1,45 @@
-use chrono::Utc;
 use serenity::all::{Context, EditInteractionResponse, ModalInteraction};
-use sqlx::{PgPool, Postgres};
-use suggestions::Suggestions;
-use ticket::TicketModal;
+use sqlx::Pool;
+use zayden_core::{ErrorReport, Localize, i18n, logging};
 
+use crate::Result;
+use crate::backend::Backend;
 use crate::handler::Handler;
-use crate::modules::lfg::{PostTable, UsersTable};
-use crate::modules::ticket::TicketTable;
-use crate::sqlx_lib::GuildTable;
-use crate::{Error, Result, modules};
+use crate::modules;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub async fn interaction_modal(
         ctx: &Context,
         interaction: &ModalInteraction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        println!(
-            "[{}] {} ran modal: {}",
-            Utc::now().format("%Y-%m-%d %H:%M:%S"),
-            interaction.user.name,
-            interaction.data.custom_id
-        );
+        let span = logging::modal_span(interaction);
 
-        let result = match interaction.data.custom_id.as_str() {
-            // region LFG
-            "lfg_edit" => {
-                lfg::modals::Edit::run::<Postgres, PostTable, UsersTable>(ctx, interaction, pool)
-                    .await
-                    .map_err(Error::from)
-            }
-            custom_id if custom_id.starts_with("lfg_create") => lfg::modals::Create::run::<
-                Postgres,
-                modules::lfg::GuildTable,
-                PostTable,
-                UsersTable,
-            >(
-                ctx, interaction, pool
-            )
-            .await
-            .map_err(Error::from),
-            // endregion
-
-            // region Ticket
-            "create_ticket" => {
-                TicketModal::run::<Postgres, GuildTable, TicketTable>(ctx, interaction, pool)
-                    .await
-                    .map_err(Error::from)
-            }
-            // endregion
-            "suggestions_accept" => {
-                Suggestions::modal(ctx, interaction, true).await;
-                Ok(())
-            }
-            "suggestions_reject" => {
-                Suggestions::modal(ctx, interaction, false).await;
-                Ok(())
-            }
-
-            _ => unimplemented!("Modal not implemented: {}", interaction.data.custom_id),
+        // Modals without a handler are awaited by collectors
+        let Some(route) = Db::registry().get_modal(&interaction.data.custom_id) else {
+            return Ok(());
         };
 
+        let result = logging::timed(&span, async {
+            modules::check_enabled(ctx, pool, interaction.guild_id, route.module).await?;
+            (route.run)(ctx, interaction, &interaction.data.components, pool).await
+        })
+        .await;
+
         if let Err(e) = result {
-            let msg = e.to_string();
+            tracing::error!(parent: &span, error = ?e, "failed");
+
+            if e.is_internal() {
+                let report = ErrorReport::new("InteractionCreate", &e)
+                    .interaction(interaction.data.custom_id.as_str(), "")
+                    .guild(interaction.guild_id)
+                    .user(interaction.user.id);
+
+                Self::report_error(ctx, report).await;
+            }
+
+            let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+            let msg = e.localize(locale);
 
             let _ = interaction.defer_ephemeral(ctx).await;
 
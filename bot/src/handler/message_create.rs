@@ -1,31 +1,65 @@
 use futures::FutureExt;
+use gambling::{Economies, GameManager, Ledger};
 use serenity::all::{Context, Message};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::MessageCommand;
 
 use crate::Result;
+use crate::backend::Backend;
 use crate::handler::Handler;
 use crate::modules::ai::Ai;
-use crate::modules::gambling::GamblingTable;
-use crate::modules::levels::LevelsTable;
 use crate::modules::ticket::message_commands::support;
+use crate::modules::{self, GAMBLING, LEVELS, TICKET};
 
-impl Handler {
-    pub async fn message_create(ctx: &Context, msg: Message, pool: &PgPool) -> Result<()> {
+impl<Db: Backend> Handler<Db> {
+    pub async fn message_create(ctx: &Context, msg: Message, pool: &Pool<Db>) -> Result<()> {
         if msg.author.bot {
             return Ok(());
         }
 
-        let (new_level, ..) = tokio::try_join!(
-            levels::message_create::<Postgres, LevelsTable>(&msg, pool).map(Result::Ok),
-            Ai::run(ctx, &msg, pool),
-            support(ctx, &msg, pool),
-        )?;
+        let disabled = modules::disabled_modules(ctx, pool, msg.guild_id).await?;
+        let enabled = |module: &str| !disabled.iter().any(|name| name == module);
 
-        if let Some(level) = new_level {
-            GamblingTable::add_coins(pool, msg.author.id, level as i64 * 1000)
-                .await
-                .unwrap();
+        let levels = async {
+            if enabled(LEVELS) {
+                levels::message_create::<Db, Db::Levels>(&msg, pool).await
+            } else {
+                None
+            }
+        };
+
+        // The AI checks for itself whether it is enabled
+        let ai = Ai::run(ctx, &msg, pool);
+
+        let ticket = async {
+            if enabled(TICKET) {
+                support(ctx, &msg, pool).await
+            } else {
+                Ok(())
+            }
+        };
+
+        let (new_level, ..) = tokio::try_join!(levels.map(Result::Ok), ai, ticket)?;
+
+        if let Some(level) = new_level
+            && enabled(GAMBLING)
+        {
+            let reward = level as i64 * 1000;
+            let economy = Economies::get(ctx, msg.guild_id).await;
+
+            let mut tx = pool.begin().await?;
+
+            if let Some(balance) =
+                Db::Game::add_coins(&mut *tx, economy, msg.author.id, reward).await?
+            {
+                Ledger::new(economy, msg.author.id, "level_up")
+                    .reference(msg.id)
+                    .coins(balance - reward, balance)
+                    .record::<Db, Db::Ledger>(&mut *tx)
+                    .await?;
+            }
+
+            tx.commit().await?;
         }
 
         Ok(())
@@ -1,9 +1,13 @@
-use serenity::all::{Event, InteractionCreateEvent, RawEventHandler};
+use std::marker::PhantomData;
+
+use serenity::all::{Event, Interaction, RawEventHandler};
 use serenity::async_trait;
-use serenity::model::prelude::Interaction;
 use serenity::prelude::Context;
+use zayden_core::{ErrorReport, ErrorReports, METRICS, Shutdown, logging};
 
-use crate::sqlx_lib::PostgresPool;
+use crate::backend::Backend;
+use crate::config;
+use crate::sqlx_lib::DatabasePool;
 
 mod guild_create;
 mod interaction;
@@ -14,49 +18,74 @@ mod ready;
 mod thread_delete;
 mod voice_state_update;
 
-pub struct Handler;
+pub struct Handler<Db: Backend>(PhantomData<Db>);
+
+impl<Db: Backend> Handler<Db> {
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    pub async fn report_error(ctx: &Context, report: ErrorReport) {
+        let channel = config::get().errors.as_ref().map(|errors| errors.channel);
+        ErrorReports::report(ctx, channel, report).await;
+    }
+}
 
 #[async_trait]
-impl RawEventHandler for Handler {
+impl<Db: Backend> RawEventHandler for Handler<Db> {
     async fn raw_event(&self, ctx: Context, ev: Event) {
         let event_name = ev.name().unwrap_or(String::from("Unknown"));
-        let ev_command_name = match &ev {
-            Event::InteractionCreate(InteractionCreateEvent {
-                interaction: Interaction::Command(interaction),
-                ..
-            }) => interaction.data.name.clone(),
-            _ => String::new(),
+        let span = logging::event_span(&event_name);
+
+        tracing::debug!(parent: &span, event = ?ev, "received");
+
+        let pool = DatabasePool::<Db>::get(&ctx).await;
+        let _running = ctx.data.read().await.get::<Shutdown>().map(Shutdown::track);
+
+        let command = match &ev {
+            Event::InteractionCreate(event) => match &event.interaction {
+                Interaction::Command(command) | Interaction::Autocomplete(command) => {
+                    command.data.name.as_str()
+                }
+                _ => "",
+            },
+            _ => "",
         };
-        let ev_debug = format!("{:?}", ev);
 
-        let pool = PostgresPool::get(&ctx).await;
+        let timer = METRICS
+            .event_duration
+            .with_label_values(&[event_name.as_str(), command])
+            .start_timer();
 
-        let result = match ev {
-            Event::GuildCreate(event) => Self::guild_create(&ctx, event.guild, &pool).await,
-            Event::MessageCreate(event) => Self::message_create(&ctx, event.message, &pool).await,
-            Event::ReactionAdd(event) => Self::reaction_add(&ctx, event.reaction, &pool).await,
-            Event::ReactionRemove(event) => {
-                Self::reaction_remove(&ctx, event.reaction, &pool).await
-            }
-            Event::Ready(event) => Self::ready(&ctx, event.ready, &pool).await,
-            Event::VoiceStateUpdate(event) => {
-                Self::voice_state_update(&ctx, event.voice_state, &pool).await
+        let result = logging::timed(&span, async {
+            match ev {
+                Event::GuildCreate(event) => Self::guild_create(&ctx, event.guild, &pool).await,
+                Event::MessageCreate(event) => {
+                    Self::message_create(&ctx, event.message, &pool).await
+                }
+                Event::ReactionAdd(event) => Self::reaction_add(&ctx, event.reaction, &pool).await,
+                Event::ReactionRemove(event) => {
+                    Self::reaction_remove(&ctx, event.reaction, &pool).await
+                }
+                Event::Ready(event) => Self::ready(&ctx, event.ready, &pool).await,
+                Event::VoiceStateUpdate(event) => {
+                    Self::voice_state_update(&ctx, event.voice_state, &pool).await
+                }
+                Event::InteractionCreate(event) => {
+                    Self::interaction_create(&ctx, event.interaction, &pool).await
+                }
+                Event::ThreadDelete(event) => Self::thread_delete(&ctx, event.thread, &pool).await,
+                _ => Ok(()),
             }
-            Event::InteractionCreate(event) => {
-                Self::interaction_create(&ctx, event.interaction, &pool).await
-            }
-            Event::ThreadDelete(event) => Self::thread_delete(&ctx, event.thread, &pool).await,
-            _ => Ok(()),
-        };
+        })
+        .await;
+
+        timer.observe_duration();
 
         if let Err(e) = result {
-            let msg = if ev_command_name.is_empty() {
-                format!("Error handling {event_name}: {e:?}")
-            } else {
-                format!("Error handling {event_name} | {ev_command_name}: {:?}", e)
-            };
+            tracing::error!(parent: &span, event = %event_name, error = ?e, "failed to handle event");
 
-            eprintln!("\n{msg}\n{ev_debug}\n");
+            Self::report_error(&ctx, ErrorReport::new(event_name, &e)).await;
         }
     }
 }
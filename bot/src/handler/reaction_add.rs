@@ -1,21 +1,28 @@
 use serenity::all::{Context, Reaction};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use suggestions::Suggestions;
 
-use crate::modules::reaction_roles;
-use crate::sqlx_lib::GuildTable;
 use crate::Result;
+use crate::backend::Backend;
+use crate::modules::{self, REACTION_ROLES, SUGGESTIONS, reaction_roles};
 
 use super::Handler;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub(super) async fn reaction_add(
         ctx: &Context,
         reaction: Reaction,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        reaction_roles::reaction::reaction_add(ctx, &reaction).await?;
-        Suggestions::reaction::<Postgres, GuildTable>(ctx, &reaction, pool).await;
+        let disabled = modules::disabled_modules(ctx, pool, reaction.guild_id).await?;
+
+        if !disabled.iter().any(|name| name == REACTION_ROLES) {
+            reaction_roles::reaction::reaction_add::<Db>(ctx, &reaction).await?;
+        }
+
+        if !disabled.iter().any(|name| name == SUGGESTIONS) {
+            Suggestions::reaction::<Db, Db::Guilds>(ctx, &reaction, pool).await;
+        }
 
         Ok(())
     }
@@ -1,16 +1,26 @@
+use gambling::{Economies, Sessions};
 use serenity::all::{Context, OnlineStatus, Ready};
-use sqlx::PgPool;
+use sqlx::Pool;
+use zayden_core::Cooldowns;
 
 use crate::Result;
-use crate::cron::start_cron_jobs;
+use crate::backend::Backend;
+use crate::cron::{load_cron_jobs, start_cron_jobs};
 use crate::handler::Handler;
 
-impl Handler {
-    pub async fn ready(ctx: &Context, ready: Ready, pool: &PgPool) -> Result<()> {
-        println!("{} is connected!", ready.user.name);
+impl<Db: Backend> Handler<Db> {
+    pub async fn ready(ctx: &Context, ready: Ready, pool: &Pool<Db>) -> Result<()> {
+        tracing::info!("{} is connected!", ready.user.name);
 
         ctx.set_presence(None, OnlineStatus::Online);
 
+        load_cron_jobs(ctx, pool).await?;
+        Cooldowns::load::<Db, Db::Cooldowns>(ctx, pool).await?;
+        Economies::load::<Db, Db::Economies>(ctx, pool).await?;
+
+        // Games that timed out while the bot was offline
+        Sessions::refund_expired::<Db, Db::Game, Db::Sessions, Db::Ledger>(&ctx.http, pool).await?;
+
         let ctx = ctx.clone();
         let pool = pool.clone();
 
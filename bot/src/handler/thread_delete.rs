@@ -1,18 +1,18 @@
 use serenity::all::{Context, PartialGuildChannel};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 
 use crate::Result;
-use crate::modules::lfg::PostTable;
+use crate::backend::Backend;
 
 use super::Handler;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub async fn thread_delete(
         ctx: &Context,
         thread: PartialGuildChannel,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        lfg::events::thread_delete::<Postgres, PostTable>(ctx, &thread, pool).await;
+        lfg::events::thread_delete::<Db, Db::LfgPosts, Db::CronJobs>(ctx, &thread, pool).await;
 
         Ok(())
     }
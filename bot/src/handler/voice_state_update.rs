@@ -1,18 +1,23 @@
 use serenity::all::{Context, VoiceState};
-use sqlx::PgPool;
+use sqlx::Pool;
 
-use crate::modules::temp_voice;
 use crate::Result;
+use crate::backend::Backend;
+use crate::modules::{self, TEMP_VOICE, temp_voice};
 
 use super::Handler;
 
-impl Handler {
+impl<Db: Backend> Handler<Db> {
     pub(super) async fn voice_state_update(
         ctx: &Context,
         new: VoiceState,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        temp_voice::events::run(ctx, pool, &new).await?;
+        let disabled = modules::disabled_modules(ctx, pool, new.guild_id).await?;
+
+        if !disabled.iter().any(|name| name == TEMP_VOICE) {
+            temp_voice::events::run(ctx, pool, &new).await?;
+        }
 
         Ok(())
     }
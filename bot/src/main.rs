@@ -1,66 +1,138 @@
 use std::env;
+use std::time::Duration;
 
 use endgame_analysis::endgame_analysis::EndgameAnalysisSheet;
-use gambling::{Lotto, StaminaCron};
-use modules::destiny2::endgame_analysis::DestinyWeaponTable;
+use gambling::{Lotto, Sessions, StaminaCron};
 use modules::destiny2::endgame_analysis::database_manager::DestinyDatabaseManager;
-use serenity::all::{ClientBuilder, GatewayIntents, GuildId, UserId};
+use serenity::all::{ClientBuilder, GatewayIntents, Http};
 use serenity::prelude::TypeMap;
 
+use backend::Backend;
 pub use error::{Error, Result};
-use sqlx::Postgres;
-use sqlx_lib::PostgresPool;
-use zayden_core::CronJobs;
-
-use crate::modules::gambling::{LottoTable, StaminaTable};
+use sqlx::{Pool, Postgres, Sqlite};
+use sqlx_lib::DatabasePool;
+use zayden_core::{Cooldowns, CronJobs, Shutdown, emojis};
 
+mod backend;
+mod config;
+mod cooldowns;
 mod cron;
 mod error;
 mod handler;
+mod metrics;
 pub mod modules;
+mod shutdown;
 mod sqlx_lib;
-
-pub const SUPER_USERS: [UserId; 1] = [
-    UserId::new(211486447369322506), // oscarsix
-];
-pub const BRADSTER_GUILD: GuildId = GuildId::new(1255957182457974875);
+#[cfg(test)]
+mod tests;
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    if dotenvy::dotenv().is_err() {
-        println!(".env file not found. Please make sure enviroment variables are set.")
+    let dotenv = dotenvy::dotenv();
+
+    let log_format = env::var("LOG_FORMAT")
+        .ok()
+        .and_then(|format| format.parse().ok())
+        .unwrap_or_default();
+    zayden_core::logging::init(log_format);
+
+    if dotenv.is_err() {
+        tracing::warn!(".env file not found. Please make sure enviroment variables are set.")
     }
     // if cfg!(debug_assertions) {
     //     let _ = dotenvy::from_filename_override(".env.dev");
     // }
 
-    let pool = PostgresPool::new().await.unwrap();
+    if let Err(e) = config::load() {
+        tracing::error!("{e}");
+        std::process::exit(1);
+    }
 
-    if !cfg!(debug_assertions) {
-        DestinyDatabaseManager::update_dbs(&pool.pool)
+    let url = env::var("DATABASE_URL").expect("Expected DATABASE_URL in the environment");
+
+    // Self-hosted instances can run on a single SQLite file, which is
+    // migrated on startup. Postgres migrations are applied separately.
+    if url.starts_with("sqlite:") {
+        let pool = DatabasePool::<Sqlite>::connect().await.unwrap();
+        sqlx::migrate!("../migrations-sqlite")
+            .run(&pool)
             .await
             .unwrap();
-        EndgameAnalysisSheet::update::<Postgres, DestinyWeaponTable>(&pool.pool)
+
+        return run(pool).await;
+    }
+
+    let pool = DatabasePool::<Postgres>::connect().await.unwrap();
+
+    run(pool).await
+}
+
+async fn run<Db: Backend>(pool: Pool<Db>) -> Result<()> {
+    if !cfg!(debug_assertions) {
+        DestinyDatabaseManager::update_dbs(&pool).await.unwrap();
+        EndgameAnalysisSheet::update::<Db, Db::DestinyWeapons>(&pool)
             .await
             .unwrap();
     }
 
+    let shutdown = Shutdown::new();
+
     let mut type_map = TypeMap::new();
-    type_map.insert::<PostgresPool>(pool);
-    type_map.insert::<CronJobs<Postgres>>(vec![
-        Lotto::cron_job::<Postgres, LottoTable>(),
-        StaminaCron::cron_job::<Postgres, StaminaTable>(),
+    type_map.insert::<DatabasePool<Db>>(pool.clone());
+    type_map.insert::<Shutdown>(shutdown.clone());
+    type_map.insert::<Cooldowns>(cooldowns::limits());
+    type_map.insert::<CronJobs<Db>>(vec![
+        Lotto::cron_job::<Db, Db::Lotto, Db::Ledger>(),
+        StaminaCron::cron_job::<Db, Db::Stamina>(),
+        Sessions::cron_job::<Db, Db::Goals, Db::Effects, Db::Game, Db::Sessions, Db::Ledger>(),
+        Cooldowns::cron_job::<Db, Db::Cooldowns>(),
     ]);
 
     let token = &env::var("DISCORD_TOKEN").expect("Expected DISCORD_TOKEN in the environment");
 
-    let mut client = ClientBuilder::new(token, GatewayIntents::all())
+    // Emojis are resolved by name, so they have to be known before any
+    // command runs
+    let http = Http::new(token);
+    let app = http.get_current_application_info().await.unwrap();
+    http.set_application_id(app.id);
+
+    // The emoji ids in the config are used for any that didn't sync, and
+    // the check below still stops the bot if one is missing from both
+    if let Err(e) = emojis::sync(&http, &config::get().emojis.dir).await {
+        tracing::warn!("failed to sync the application emojis: {e}");
+    }
+
+    if let Err(e) = gambling::config::validate() {
+        tracing::error!("{e}");
+        std::process::exit(1);
+    }
+
+    tracing::info!(rtp = gambling::config::slots().rtp(), "loaded slot machine");
+
+    let mut client = ClientBuilder::new_with_http(http, GatewayIntents::all())
         .type_map(type_map)
-        .raw_event_handler(handler::Handler)
+        .raw_event_handler(handler::Handler::<Db>::new())
         .await
         .unwrap();
 
+    if let Some(metrics) = &config::get().metrics {
+        tokio::spawn(metrics::serve(
+            metrics.addr,
+            client.shard_manager.clone(),
+            pool.clone(),
+        ));
+    }
+
+    tokio::spawn(shutdown::on_signal(
+        shutdown,
+        client.shard_manager.clone(),
+        client.data.clone(),
+        pool.clone(),
+        Duration::from_secs(config::get().shutdown.grace_period_secs),
+    ));
+
     client.start().await.unwrap();
+    pool.close().await;
 
     Ok(())
 }
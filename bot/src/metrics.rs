@@ -0,0 +1,63 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use serenity::all::{ConnectionStage, ShardManager};
+use sqlx::Pool;
+use temp_voice::VoiceChannelManager;
+use zayden_core::{METRICS, metrics};
+
+use crate::backend::Backend;
+
+/// Serves `/metrics` and `/healthz`, logging why if the listener stops.
+pub async fn serve<Db: Backend>(addr: SocketAddr, shards: Arc<ShardManager>, pool: Pool<Db>) {
+    let scrape = move || {
+        let (shards, pool) = (shards.clone(), pool.clone());
+        async move { refresh(&shards, &pool).await }
+    };
+
+    if let Err(e) = metrics::serve(addr, scrape).await {
+        tracing::error!(error = ?e, "metrics server stopped");
+    }
+}
+
+/// Updates the gauges that are read on every scrape. The bot is healthy
+/// while all of its shards are connected to the gateway.
+async fn refresh<Db: Backend>(shards: &ShardManager, pool: &Pool<Db>) -> bool {
+    let healthy = {
+        let runners = shards.runners.lock().await;
+
+        for (id, runner) in runners.iter() {
+            METRICS
+                .gateway_latency
+                .with_label_values(&[&id.to_string()])
+                .set(runner.latency.unwrap_or_default().as_secs_f64());
+        }
+
+        !runners.is_empty()
+            && runners
+                .values()
+                .all(|runner| runner.stage == ConnectionStage::Connected)
+    };
+
+    let size = pool.size() as i64;
+    let idle = pool.num_idle() as i64;
+    METRICS
+        .db_connections
+        .with_label_values(&["active"])
+        .set(size - idle);
+    METRICS
+        .db_connections
+        .with_label_values(&["idle"])
+        .set(idle);
+    METRICS
+        .db_connections
+        .with_label_values(&["max"])
+        .set(pool.options().get_max_connections() as i64);
+
+    match Db::VoiceChannels::count(pool).await {
+        Ok(count) => METRICS.temp_voice_channels.set(count),
+        Err(e) => tracing::warn!(error = ?e, "failed to count temp voice channels"),
+    }
+
+    healthy
+}
@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, Permissions, ResolvedOption, ResolvedValue,
+};
+use sqlx::Pool;
+use zayden_core::{SlashCommand, parse_options};
+
+use crate::backend::Backend;
+use crate::modules::{self, DisabledModules, MODULES};
+use crate::sqlx_lib::ModulesManager;
+use crate::{Error, Result};
+
+pub struct Config;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Config {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        mut options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        interaction.defer_ephemeral(ctx).await.unwrap();
+
+        let command = options.remove(0);
+
+        let ResolvedValue::SubCommand(options) = command.value else {
+            unreachable!("Subcommand is required")
+        };
+        let mut options = parse_options(options);
+
+        let embed = match command.name {
+            "modules" => {
+                let module = match options.remove("module") {
+                    Some(ResolvedValue::String(module)) => Some(module),
+                    _ => None,
+                };
+                let enabled = match options.remove("enabled") {
+                    Some(ResolvedValue::Boolean(enabled)) => Some(enabled),
+                    _ => None,
+                };
+
+                let disabled = match (module, enabled) {
+                    (Some(module), Some(enabled)) => {
+                        let disabled =
+                            Db::Guilds::set_module(pool, guild_id, module, enabled).await?;
+                        DisabledModules::set(ctx, guild_id, disabled.clone()).await;
+
+                        guild_id
+                            .set_commands(ctx, Db::registry().register(ctx, guild_id, &disabled)?)
+                            .await?;
+
+                        disabled
+                    }
+                    _ => modules::disabled_modules(ctx, pool, Some(guild_id)).await?,
+                };
+
+                let description = MODULES
+                    .iter()
+                    .filter(|name| module.is_none_or(|module| module == **name))
+                    .map(|name| {
+                        let status = if disabled.iter().any(|module| module == name) {
+                            "Disabled"
+                        } else {
+                            "Enabled"
+                        };
+
+                        format!("`{name}` - {status}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                CreateEmbed::new().title("Modules").description(description)
+            }
+            _ => unreachable!("Subcommand is required"),
+        };
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        let module = MODULES.iter().fold(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "module",
+                "The module to configure",
+            ),
+            |option, module| option.add_string_choice(*module, *module),
+        );
+
+        let modules = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "modules",
+            "Enable or disable modules in this server",
+        )
+        .add_sub_option(module)
+        .add_sub_option(CreateCommandOption::new(
+            CommandOptionType::Boolean,
+            "enabled",
+            "Whether the module should be enabled",
+        ));
+
+        let cmd = CreateCommand::new("config")
+            .description("Configure the bot for this server")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .add_option(modules);
+
+        Ok(cmd)
+    }
+}
@@ -0,0 +1,162 @@
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use cron::Schedule;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, Permissions, ResolvedOption, ResolvedValue,
+};
+use sqlx::Pool;
+use zayden_core::{CronJobs, PermissionLevel, SlashCommand, parse_options};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+use super::require;
+
+const MAX_JOBS: usize = 25;
+
+pub struct Cron;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Cron {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        mut options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        require(pool, interaction, PermissionLevel::Owner).await?;
+
+        interaction.defer_ephemeral(ctx).await.unwrap();
+
+        let command = options.remove(0);
+
+        let ResolvedValue::SubCommand(options) = command.value else {
+            unreachable!("Subcommand is required")
+        };
+        let mut options = parse_options(options);
+
+        let embed = match command.name {
+            "list" => {
+                let pending = CronJobs::<Db>::pending(ctx).await;
+
+                let mut lines = pending
+                    .iter()
+                    .take(MAX_JOBS)
+                    .map(|(id, time)| format!("`{id}` - <t:{0}:f> (<t:{0}:R>)", time.timestamp()))
+                    .collect::<Vec<_>>();
+
+                if pending.len() > MAX_JOBS {
+                    lines.push(format!("... and {} more", pending.len() - MAX_JOBS));
+                }
+
+                if lines.is_empty() {
+                    lines.push(String::from("No pending jobs"));
+                }
+
+                CreateEmbed::new()
+                    .title(format!("Pending Jobs ({})", pending.len()))
+                    .description(lines.join("\n"))
+            }
+            "cancel" => {
+                let Some(ResolvedValue::String(id)) = options.remove("id") else {
+                    unreachable!("ID is required")
+                };
+
+                let cancelled = CronJobs::<Db>::cancel::<Db::CronJobs>(ctx, pool, id).await?;
+
+                CreateEmbed::new()
+                    .title("Cancel Job")
+                    .description(format!("Cancelled {cancelled} job(s) with id `{id}`"))
+            }
+            "reschedule" => {
+                let Some(ResolvedValue::String(id)) = options.remove("id") else {
+                    unreachable!("ID is required")
+                };
+                let Some(ResolvedValue::String(from)) = options.remove("schedule") else {
+                    unreachable!("Schedule is required")
+                };
+                let Some(ResolvedValue::String(to)) = options.remove("to") else {
+                    unreachable!("New schedule is required")
+                };
+
+                let schedule =
+                    Schedule::from_str(to).map_err(|_| Error::InvalidSchedule(to.to_string()))?;
+
+                let moved =
+                    CronJobs::<Db>::reschedule::<Db::CronJobs>(ctx, pool, id, from, schedule)
+                        .await?;
+
+                let desc = if moved {
+                    format!("Moved `{id}` from `{from}` to `{to}`")
+                } else {
+                    format!("No job with id `{id}` runs on `{from}`")
+                };
+
+                CreateEmbed::new().title("Reschedule Job").description(desc)
+            }
+            _ => unreachable!("Subcommand is required"),
+        };
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        let list = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "list",
+            "List the pending jobs and when they next run",
+        );
+
+        let cancel = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "cancel",
+            "Cancel every job with the given id",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "id", "The id of the job")
+                .required(true),
+        );
+
+        let reschedule = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "reschedule",
+            "Move one schedule of a job to another",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::String, "id", "The id of the job")
+                .required(true),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "schedule",
+                "The cron expression the job runs on now",
+            )
+            .required(true),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "to",
+                "The cron expression to run it on instead",
+            )
+            .required(true),
+        );
+
+        let cmd = CreateCommand::new("cron")
+            .description("Manage scheduled jobs")
+            .default_member_permissions(Permissions::ADMINISTRATOR)
+            .add_option(list)
+            .add_option(cancel)
+            .add_option(reschedule);
+
+        Ok(cmd)
+    }
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use serenity::all::{
+    CommandInteraction, Context, CreateCommand, CreateEmbed, EditInteractionResponse, Permissions,
+    ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{ErrorReports, PermissionLevel, SlashCommand};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+use super::require;
+
+const MAX_GROUPS: usize = 10;
+const PREVIEW_LEN: usize = 150;
+
+pub struct Errors;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Errors {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        _options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        require(pool, interaction, PermissionLevel::Owner).await?;
+
+        interaction.defer_ephemeral(ctx).await.unwrap();
+
+        let groups = ErrorReports::recent(ctx, MAX_GROUPS).await;
+
+        let description = if groups.is_empty() {
+            String::from("No errors since the last restart")
+        } else {
+            groups
+                .iter()
+                .map(|group| {
+                    let preview = group
+                        .report
+                        .error
+                        .chars()
+                        .take(PREVIEW_LEN)
+                        .collect::<String>();
+
+                    format!(
+                        "**{}** x{} - last <t:{}:R>\n```\n{preview}\n```",
+                        group.title(),
+                        group.count,
+                        group.last_seen.timestamp()
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let embed = CreateEmbed::new()
+            .title("Recent Errors")
+            .description(description);
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        let cmd = CreateCommand::new("errors")
+            .description("Show the most recent error groups")
+            .default_member_permissions(Permissions::ADMINISTRATOR);
+
+        Ok(cmd)
+    }
+}
@@ -0,0 +1,359 @@
+use async_trait::async_trait;
+use serenity::all::{CommandInteraction, GuildId, RoleId, UserId};
+use sqlx::any::AnyQueryResult;
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
+use zayden_core::permissions::{CommandPermissionRow, PermissionRoleRow, has_level};
+use zayden_core::{PermissionLevel, PermissionsManager, Registry};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+mod config;
+mod cron;
+mod errors;
+mod permissions;
+
+pub use config::Config;
+pub use cron::Cron;
+pub use errors::Errors;
+pub use permissions::PermissionsCommand;
+
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<Config>("config")
+        .command::<Cron>("cron")
+        .command::<Errors>("errors")
+        .command::<PermissionsCommand>("permissions")
+}
+
+/// Fails with [`Error::MissingPermission`] unless the interaction user has at
+/// least `level`.
+pub async fn require<Db: Backend>(
+    pool: &Pool<Db>,
+    interaction: &CommandInteraction,
+    level: PermissionLevel,
+) -> Result<()> {
+    let allowed = has_level::<Db, Db::Permissions>(
+        pool,
+        interaction.user.id,
+        interaction.member.as_deref(),
+        level,
+    )
+    .await?;
+
+    if !allowed {
+        return Err(Error::MissingPermission(level));
+    }
+
+    Ok(())
+}
+
+pub struct PermissionsTable;
+
+#[async_trait]
+impl PermissionsManager<Postgres> for PermissionsTable {
+    async fn owners(pool: &PgPool) -> sqlx::Result<Vec<UserId>> {
+        let owners = sqlx::query_scalar!("SELECT user_id FROM bot_owners")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(owners
+            .into_iter()
+            .map(|id| UserId::new(id as u64))
+            .collect())
+    }
+
+    async fn is_owner(pool: &PgPool, user: UserId) -> sqlx::Result<bool> {
+        let owner = sqlx::query_scalar!(
+            "SELECT EXISTS(SELECT 1 FROM bot_owners WHERE user_id = $1)",
+            user.get() as i64
+        )
+        .fetch_one(pool)
+        .await?;
+
+        Ok(owner.unwrap_or(false))
+    }
+
+    async fn add_owner(pool: &PgPool, user: UserId) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "INSERT INTO bot_owners (user_id) VALUES ($1) ON CONFLICT DO NOTHING",
+            user.get() as i64
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn remove_owner(pool: &PgPool, user: UserId) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "DELETE FROM bot_owners WHERE user_id = $1",
+            user.get() as i64
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn roles(pool: &PgPool, guild: GuildId) -> sqlx::Result<Vec<PermissionRoleRow>> {
+        sqlx::query_as!(
+            PermissionRoleRow,
+            "SELECT role_id, level FROM guild_permission_roles WHERE guild_id = $1",
+            guild.get() as i64
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn set_role(
+        pool: &PgPool,
+        guild: GuildId,
+        role: RoleId,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            r#"
+            INSERT INTO guild_permission_roles (guild_id, role_id, level)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, role_id) DO UPDATE
+            SET level = EXCLUDED.level;
+            "#,
+            guild.get() as i64,
+            role.get() as i64,
+            level.as_str()
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn remove_role(
+        pool: &PgPool,
+        guild: GuildId,
+        role: RoleId,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "DELETE FROM guild_permission_roles WHERE guild_id = $1 AND role_id = $2",
+            guild.get() as i64,
+            role.get() as i64
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn commands(pool: &PgPool, guild: GuildId) -> sqlx::Result<Vec<CommandPermissionRow>> {
+        sqlx::query_as!(
+            CommandPermissionRow,
+            "SELECT command, level FROM guild_command_permissions WHERE guild_id = $1",
+            guild.get() as i64
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn command(
+        pool: &PgPool,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<Option<CommandPermissionRow>> {
+        sqlx::query_as!(
+            CommandPermissionRow,
+            "SELECT command, level FROM guild_command_permissions WHERE guild_id = $1 AND command = $2",
+            guild.get() as i64,
+            command
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn set_command(
+        pool: &PgPool,
+        guild: GuildId,
+        command: &str,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            r#"
+            INSERT INTO guild_command_permissions (guild_id, command, level)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, command) DO UPDATE
+            SET level = EXCLUDED.level;
+            "#,
+            guild.get() as i64,
+            command,
+            level.as_str()
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn remove_command(
+        pool: &PgPool,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "DELETE FROM guild_command_permissions WHERE guild_id = $1 AND command = $2",
+            guild.get() as i64,
+            command
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl PermissionsManager<Sqlite> for PermissionsTable {
+    async fn owners(pool: &SqlitePool) -> sqlx::Result<Vec<UserId>> {
+        let owners = sqlx::query_scalar::<_, i64>("SELECT user_id FROM bot_owners")
+            .fetch_all(pool)
+            .await?;
+
+        Ok(owners
+            .into_iter()
+            .map(|id| UserId::new(id as u64))
+            .collect())
+    }
+
+    async fn is_owner(pool: &SqlitePool, user: UserId) -> sqlx::Result<bool> {
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM bot_owners WHERE user_id = $1)")
+            .bind(user.get() as i64)
+            .fetch_one(pool)
+            .await
+    }
+
+    async fn add_owner(pool: &SqlitePool, user: UserId) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("INSERT INTO bot_owners (user_id) VALUES ($1) ON CONFLICT DO NOTHING")
+            .bind(user.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn remove_owner(pool: &SqlitePool, user: UserId) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM bot_owners WHERE user_id = $1")
+            .bind(user.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn roles(pool: &SqlitePool, guild: GuildId) -> sqlx::Result<Vec<PermissionRoleRow>> {
+        let rows = sqlx::query_as::<_, (i64, String)>(
+            "SELECT role_id, level FROM guild_permission_roles WHERE guild_id = $1",
+        )
+        .bind(guild.get() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(role_id, level)| PermissionRoleRow { role_id, level })
+            .collect())
+    }
+
+    async fn set_role(
+        pool: &SqlitePool,
+        guild: GuildId,
+        role: RoleId,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            r#"
+            INSERT INTO guild_permission_roles (guild_id, role_id, level)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, role_id) DO UPDATE
+            SET level = EXCLUDED.level;
+            "#,
+        )
+        .bind(guild.get() as i64)
+        .bind(role.get() as i64)
+        .bind(level.as_str())
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn remove_role(
+        pool: &SqlitePool,
+        guild: GuildId,
+        role: RoleId,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM guild_permission_roles WHERE guild_id = $1 AND role_id = $2")
+            .bind(guild.get() as i64)
+            .bind(role.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn commands(
+        pool: &SqlitePool,
+        guild: GuildId,
+    ) -> sqlx::Result<Vec<CommandPermissionRow>> {
+        let rows = sqlx::query_as::<_, (String, String)>(
+            "SELECT command, level FROM guild_command_permissions WHERE guild_id = $1",
+        )
+        .bind(guild.get() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(command, level)| CommandPermissionRow { command, level })
+            .collect())
+    }
+
+    async fn command(
+        pool: &SqlitePool,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<Option<CommandPermissionRow>> {
+        let row = sqlx::query_as::<_, (String, String)>(
+            "SELECT command, level FROM guild_command_permissions WHERE guild_id = $1 AND command = $2",
+        )
+        .bind(guild.get() as i64)
+        .bind(command)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(|(command, level)| CommandPermissionRow { command, level }))
+    }
+
+    async fn set_command(
+        pool: &SqlitePool,
+        guild: GuildId,
+        command: &str,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            r#"
+            INSERT INTO guild_command_permissions (guild_id, command, level)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, command) DO UPDATE
+            SET level = EXCLUDED.level;
+            "#,
+        )
+        .bind(guild.get() as i64)
+        .bind(command)
+        .bind(level.as_str())
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn remove_command(
+        pool: &SqlitePool,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM guild_command_permissions WHERE guild_id = $1 AND command = $2")
+            .bind(guild.get() as i64)
+            .bind(command)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+}
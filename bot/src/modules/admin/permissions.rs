@@ -0,0 +1,249 @@
+use async_trait::async_trait;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, Mentionable, Permissions, ResolvedOption, ResolvedValue,
+};
+use sqlx::Pool;
+use zayden_core::{PermissionLevel, PermissionsManager, SlashCommand, parse_options};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+use super::require;
+
+pub struct PermissionsCommand;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for PermissionsCommand {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        mut options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let guild_id = interaction.guild_id.ok_or(Error::MissingGuildId)?;
+
+        interaction.defer_ephemeral(ctx).await.unwrap();
+
+        let group = options.remove(0);
+
+        let (subcommand, options) = match group.value {
+            ResolvedValue::SubCommandGroup(mut options) => {
+                let subcommand = options.remove(0);
+                let ResolvedValue::SubCommand(options) = subcommand.value else {
+                    unreachable!("Subcommand is required")
+                };
+                (subcommand.name, options)
+            }
+            ResolvedValue::SubCommand(options) => ("", options),
+            _ => unreachable!("Subcommand is required"),
+        };
+        let mut options = parse_options(options);
+
+        let level = match options.remove("level") {
+            Some(ResolvedValue::String(level)) => level.parse::<PermissionLevel>().ok(),
+            _ => None,
+        };
+
+        let description = match (group.name, subcommand) {
+            ("list", _) => {
+                require(pool, interaction, PermissionLevel::Admin).await?;
+
+                let owners = Db::Permissions::owners(pool)
+                    .await?
+                    .iter()
+                    .map(|user| user.mention().to_string())
+                    .collect::<Vec<_>>();
+
+                let roles = Db::Permissions::roles(pool, guild_id)
+                    .await?
+                    .iter()
+                    .map(|row| format!("{} - {}", row.role_id().mention(), row.level()))
+                    .collect::<Vec<_>>();
+
+                let commands = Db::Permissions::commands(pool, guild_id)
+                    .await?
+                    .iter()
+                    .map(|row| format!("`/{}` - {}", row.command, row.level()))
+                    .collect::<Vec<_>>();
+
+                [("Owners", owners), ("Roles", roles), ("Commands", commands)]
+                    .into_iter()
+                    .map(|(title, lines)| {
+                        let lines = if lines.is_empty() {
+                            String::from("None")
+                        } else {
+                            lines.join("\n")
+                        };
+                        format!("**{title}**\n{lines}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            }
+            ("owner", subcommand) => {
+                require(pool, interaction, PermissionLevel::Owner).await?;
+
+                let Some(ResolvedValue::User(user, _)) = options.remove("user") else {
+                    unreachable!("User is required")
+                };
+
+                if subcommand == "add" {
+                    Db::Permissions::add_owner(pool, user.id).await?;
+                    format!("{} is now a bot owner", user.mention())
+                } else {
+                    Db::Permissions::remove_owner(pool, user.id).await?;
+                    format!("{} is no longer a bot owner", user.mention())
+                }
+            }
+            ("role", subcommand) => {
+                require(pool, interaction, PermissionLevel::Admin).await?;
+
+                let Some(ResolvedValue::Role(role)) = options.remove("role") else {
+                    unreachable!("Role is required")
+                };
+
+                match level {
+                    Some(level) if subcommand == "set" => {
+                        Db::Permissions::set_role(pool, guild_id, role.id, level).await?;
+                        format!("{} now grants {level}", role.mention())
+                    }
+                    _ => {
+                        Db::Permissions::remove_role(pool, guild_id, role.id).await?;
+                        format!("{} no longer grants any permissions", role.mention())
+                    }
+                }
+            }
+            ("command", subcommand) => {
+                require(pool, interaction, PermissionLevel::Admin).await?;
+
+                let Some(ResolvedValue::String(command)) = options.remove("command") else {
+                    unreachable!("Command is required")
+                };
+                let command = command.trim_start_matches('/');
+
+                if Db::registry().get_command(command).is_none() {
+                    return Err(Error::UnknownCommand(command.to_string()));
+                }
+
+                match level {
+                    Some(level) if subcommand == "set" => {
+                        Db::Permissions::set_command(pool, guild_id, command, level).await?;
+                        format!("`/{command}` now requires {level}")
+                    }
+                    _ => {
+                        Db::Permissions::remove_command(pool, guild_id, command).await?;
+                        format!("`/{command}` no longer has an override")
+                    }
+                }
+            }
+            _ => unreachable!("Subcommand is required"),
+        };
+
+        let embed = CreateEmbed::new()
+            .title("Permissions")
+            .description(description);
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        let level =
+            CreateCommandOption::new(CommandOptionType::String, "level", "The permission level")
+                .required(true)
+                .add_string_choice("Moderator", PermissionLevel::Moderator.as_str())
+                .add_string_choice("Admin", PermissionLevel::Admin.as_str());
+
+        let user =
+            CreateCommandOption::new(CommandOptionType::User, "user", "The user").required(true);
+        let role =
+            CreateCommandOption::new(CommandOptionType::Role, "role", "The role").required(true);
+        let command =
+            CreateCommandOption::new(CommandOptionType::String, "command", "The command name")
+                .required(true);
+
+        let list = CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "list",
+            "List the bot owners, permission roles and command overrides",
+        );
+
+        let owner = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "owner",
+            "Manage the bot owners",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "add", "Add a bot owner")
+                .add_sub_option(user.clone()),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Remove a bot owner",
+            )
+            .add_sub_option(user),
+        );
+
+        let role_group = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "role",
+            "Manage the roles that grant permissions",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "set",
+                "Grant a permission level to a role",
+            )
+            .add_sub_option(role.clone())
+            .add_sub_option(level.clone()),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Stop a role from granting permissions",
+            )
+            .add_sub_option(role),
+        );
+
+        let command_group = CreateCommandOption::new(
+            CommandOptionType::SubCommandGroup,
+            "command",
+            "Manage the permission level required by commands",
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "set",
+                "Require a permission level for a command",
+            )
+            .add_sub_option(command.clone())
+            .add_sub_option(level),
+        )
+        .add_sub_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Remove a command's permission override",
+            )
+            .add_sub_option(command),
+        );
+
+        let cmd = CreateCommand::new("permissions")
+            .description("Manage bot permissions")
+            .default_member_permissions(Permissions::MANAGE_GUILD)
+            .add_option(list)
+            .add_option(owner)
+            .add_option(role_group)
+            .add_option(command_group);
+
+        Ok(cmd)
+    }
+}
@@ -2,9 +2,11 @@ use async_trait::async_trait;
 use openai_api_rust::chat::{ChatApi, ChatBody};
 use openai_api_rust::{Auth, OpenAI, Role};
 use serenity::all::{Context, Message};
-use sqlx::{PgPool, Postgres};
-use zayden_core::MessageCommand;
+use sqlx::Pool;
+use zayden_core::{Cooldowns, MessageCommand};
 
+use crate::backend::Backend;
+use crate::modules::{self, AI};
 use crate::{Error, Result};
 
 const PERSONALITY: &str = "[Word Limit: 100]
@@ -52,12 +54,27 @@ impl Ai {
 }
 
 #[async_trait]
-impl MessageCommand<Error, Postgres> for Ai {
-    async fn run(ctx: &Context, message: &Message, _pool: &PgPool) -> Result<()> {
+impl<Db: Backend> MessageCommand<Error, Db> for Ai {
+    async fn run(ctx: &Context, message: &Message, pool: &Pool<Db>) -> Result<()> {
         if message.mentions_me(ctx).await.map_or(true, |value| !value) {
             return Ok(());
         }
 
+        // Checked here rather than by the caller, as the module has no
+        // commands for the registry to leave out
+        if modules::disabled_modules(ctx, pool, message.guild_id)
+            .await?
+            .iter()
+            .any(|name| name == AI)
+        {
+            return Ok(());
+        }
+
+        if let Err(e) = Cooldowns::take(ctx, AI, message).await {
+            message.reply(ctx, e.to_string()).await?;
+            return Ok(());
+        }
+
         if message
             .referenced_message
             .as_ref()
@@ -103,7 +120,7 @@ impl MessageCommand<Error, Postgres> for Ai {
         let choice = match openai.chat_completion_create(&body) {
             Ok(mut completion) => completion.choices.pop().unwrap(),
             Err(e) => {
-                println!("{:?}", e);
+                tracing::error!(error = ?e, "failed to create chat completion");
                 return Ok(());
             }
         };
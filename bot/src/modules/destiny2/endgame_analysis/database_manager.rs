@@ -8,14 +8,16 @@ use bungie_api::{
     BungieClientBuilder, DestinyInventoryItemDefinition, DestinyInventoryItemManifest,
     DestinyPlugSetManifest, DestinySocketCategoryManifest, DestinySocketTypeManifest,
 };
-use sqlx::PgPool;
+use endgame_analysis::{DestinyPerk, DestinyPerkManager, DestinyWeapon, DestinyWeaponManager};
+use sqlx::Pool;
 
 use crate::Result;
+use crate::backend::Backend;
 
 pub struct DestinyDatabaseManager;
 
 impl DestinyDatabaseManager {
-    pub async fn update_dbs(pool: &PgPool) -> Result<()> {
+    pub async fn update_dbs<Db: Backend>(pool: &Pool<Db>) -> Result<()> {
         let api_key = env::var("BUNGIE_API_KEY").unwrap();
 
         let client = BungieClientBuilder::new(api_key).build().unwrap();
@@ -38,7 +40,7 @@ impl DestinyDatabaseManager {
             .await
             .unwrap();
 
-        DestinyDatabaseManager::update_weapon_db(
+        DestinyDatabaseManager::update_weapon_db::<Db>(
             pool,
             &item_manifest,
             &socket_type_manifest,
@@ -47,25 +49,18 @@ impl DestinyDatabaseManager {
         )
         .await?;
 
-        DestinyDatabaseManager::update_perk_db(pool, &item_manifest).await?;
+        DestinyDatabaseManager::update_perk_db::<Db>(pool, &item_manifest).await?;
 
         Ok(())
     }
 
-    async fn update_weapon_db(
-        pool: &PgPool,
+    async fn update_weapon_db<Db: Backend>(
+        pool: &Pool<Db>,
         item_manifest: &DestinyInventoryItemManifest,
         socket_type_manifest: &DestinySocketTypeManifest,
         socket_category_manifest: &DestinySocketCategoryManifest,
         plug_set_manifest: &DestinyPlugSetManifest,
     ) -> Result<()> {
-        let mut tx = pool.begin().await.unwrap();
-
-        sqlx::query!("DELETE FROM destiny_weapons")
-            .execute(&mut *tx)
-            .await
-            .unwrap();
-
         let valid_weapons = item_manifest
             .values()
             .filter(|item| match item {
@@ -96,6 +91,8 @@ impl DestinyDatabaseManager {
                 item
             });
 
+        let mut weapons = Vec::new();
+
         for weapon in valid_weapons {
             let perks = weapon
                 .sockets
@@ -148,63 +145,30 @@ impl DestinyDatabaseManager {
                 continue;
             }
 
-            let hash = weapon.hash as i64;
-            let name = weapon.display_properties.name.as_str();
-            let icon = weapon.display_properties.icon.as_deref();
-
-            let empty = Vec::new();
-
-            let column_1 = perks.first().unwrap();
-            let column_2 = perks.get(1).unwrap();
-            let perk_1 = perks.get(2).unwrap();
-            let perk_2 = perks.get(3).unwrap_or(&empty);
-
-            sqlx::query!(
-                r#"
-                INSERT INTO destiny_weapons (id, name, icon, column_1, column_2, perk_1, perk_2)
-                VALUES ($1, $2, $3, $4, $5, $6, $7)
-                "#,
-                hash,
-                name,
-                icon,
-                &column_1
-                    .into_iter()
-                    .map(|p| p.hash as i64)
-                    .collect::<Vec<_>>(),
-                &column_2
-                    .into_iter()
-                    .map(|p| p.hash as i64)
-                    .collect::<Vec<_>>(),
-                &perk_1
-                    .into_iter()
-                    .map(|p| p.hash as i64)
-                    .collect::<Vec<_>>(),
-                &perk_2
-                    .into_iter()
-                    .map(|p| p.hash as i64)
-                    .collect::<Vec<_>>(),
-            )
-            .execute(&mut *tx)
-            .await
-            .unwrap();
+            let hashes = |perks: &[&DestinyInventoryItemDefinition]| {
+                perks.iter().map(|p| p.hash as i64).collect::<Vec<_>>()
+            };
+
+            weapons.push(DestinyWeapon {
+                id: weapon.hash as i64,
+                icon: weapon.display_properties.icon.clone().unwrap_or_default(),
+                name: weapon.display_properties.name.clone(),
+                column_1: hashes(&perks[0]),
+                column_2: hashes(&perks[1]),
+                perk_1: hashes(&perks[2]),
+                perk_2: perks.get(3).map(|perks| hashes(perks)).unwrap_or_default(),
+            });
         }
 
-        tx.commit().await.unwrap();
+        Db::DestinyWeapons::replace(pool, &weapons).await?;
 
         Ok(())
     }
 
-    async fn update_perk_db(
-        pool: &PgPool,
+    async fn update_perk_db<Db: Backend>(
+        pool: &Pool<Db>,
         item_manifest: &DestinyInventoryItemManifest,
     ) -> Result<()> {
-        let mut tx = pool.begin().await.unwrap();
-
-        sqlx::query!("DELETE FROM destiny_perks")
-            .execute(&mut *tx)
-            .await
-            .unwrap();
-
         let valid_perks = item_manifest.values().filter(|item| match item {
             DestinyInventoryItemDefinition {
                 item_sub_type: DestinyItemSubType::Shader | DestinyItemSubType::Ornament,
@@ -234,27 +198,15 @@ impl DestinyDatabaseManager {
             _ => false,
         });
 
-        for perk in valid_perks {
-            let hash = perk.hash as i64;
-            let name = &perk.display_properties.name;
-            let description = &perk.display_properties.description;
-
-            sqlx::query!(
-                r#"
-                INSERT INTO destiny_perks (id, name, description)
-                VALUES ($1, $2, $3)
-                ON CONFLICT (id) DO NOTHING
-                "#,
-                hash,
-                name,
-                description
-            )
-            .execute(&mut *tx)
-            .await
-            .unwrap();
-        }
+        let perks = valid_perks
+            .map(|perk| DestinyPerk {
+                id: perk.hash as i64,
+                name: perk.display_properties.name.clone(),
+                description: perk.display_properties.description.clone(),
+            })
+            .collect::<Vec<_>>();
 
-        tx.commit().await.unwrap();
+        Db::DestinyPerks::replace(pool, &perks).await?;
 
         Ok(())
     }
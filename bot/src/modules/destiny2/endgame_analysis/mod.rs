@@ -3,7 +3,8 @@ pub mod slash_commands;
 
 use async_trait::async_trait;
 use endgame_analysis::{DestinyPerk, DestinyPerkManager, DestinyWeapon, DestinyWeaponManager};
-use sqlx::{PgPool, Postgres};
+use sqlx::types::Json;
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
 
 pub struct DestinyWeaponTable;
 
@@ -28,6 +29,108 @@ impl DestinyWeaponManager<Postgres> for DestinyWeaponTable {
         .fetch_all(pool)
         .await
     }
+
+    async fn replace(pool: &PgPool, weapons: &[DestinyWeapon]) -> sqlx::Result<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("DELETE FROM destiny_weapons")
+            .execute(&mut *tx)
+            .await?;
+
+        for weapon in weapons {
+            sqlx::query!(
+                r#"
+                INSERT INTO destiny_weapons (id, name, icon, column_1, column_2, perk_1, perk_2)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                "#,
+                weapon.id,
+                weapon.name,
+                weapon.icon,
+                &weapon.column_1,
+                &weapon.column_2,
+                &weapon.perk_1,
+                &weapon.perk_2,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+}
+
+type SqliteWeaponRow = (
+    i64,
+    String,
+    String,
+    Json<Vec<i64>>,
+    Json<Vec<i64>>,
+    Json<Vec<i64>>,
+    Json<Vec<i64>>,
+);
+
+fn sqlite_weapon(
+    (id, icon, name, column_1, column_2, perk_1, perk_2): SqliteWeaponRow,
+) -> DestinyWeapon {
+    DestinyWeapon {
+        id,
+        icon,
+        name,
+        column_1: column_1.0,
+        column_2: column_2.0,
+        perk_1: perk_1.0,
+        perk_2: perk_2.0,
+    }
+}
+
+#[async_trait]
+impl DestinyWeaponManager<Sqlite> for DestinyWeaponTable {
+    async fn get(pool: &SqlitePool, name: &str) -> sqlx::Result<DestinyWeapon> {
+        sqlx::query_as::<_, SqliteWeaponRow>(
+            "SELECT id, icon, name, column_1, column_2, perk_1, perk_2 FROM destiny_weapons WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_one(pool)
+        .await
+        .map(sqlite_weapon)
+    }
+
+    async fn get_by_prefix(pool: &SqlitePool, name: &str) -> sqlx::Result<Vec<DestinyWeapon>> {
+        let rows = sqlx::query_as::<_, SqliteWeaponRow>(
+            "SELECT id, icon, name, column_1, column_2, perk_1, perk_2 FROM destiny_weapons WHERE name LIKE $1 || '%'",
+        )
+        .bind(name)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(sqlite_weapon).collect())
+    }
+
+    async fn replace(pool: &SqlitePool, weapons: &[DestinyWeapon]) -> sqlx::Result<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM destiny_weapons")
+            .execute(&mut *tx)
+            .await?;
+
+        for weapon in weapons {
+            sqlx::query(
+                "INSERT INTO destiny_weapons (id, name, icon, column_1, column_2, perk_1, perk_2)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            )
+            .bind(weapon.id)
+            .bind(&weapon.name)
+            .bind(&weapon.icon)
+            .bind(Json(&weapon.column_1))
+            .bind(Json(&weapon.column_2))
+            .bind(Json(&weapon.perk_1))
+            .bind(Json(&weapon.perk_2))
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
 }
 
 pub struct DestinyPerkTable;
@@ -53,4 +156,72 @@ impl DestinyPerkManager<Postgres> for DestinyPerkTable {
         .fetch_all(pool)
         .await
     }
+
+    async fn replace(pool: &PgPool, perks: &[DestinyPerk]) -> sqlx::Result<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("DELETE FROM destiny_perks")
+            .execute(&mut *tx)
+            .await?;
+
+        for perk in perks {
+            sqlx::query!(
+                r#"
+                INSERT INTO destiny_perks (id, name, description)
+                VALUES ($1, $2, $3)
+                ON CONFLICT (id) DO NOTHING
+                "#,
+                perk.id,
+                perk.name,
+                perk.description
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
+}
+
+#[async_trait]
+impl DestinyPerkManager<Sqlite> for DestinyPerkTable {
+    async fn get(pool: &SqlitePool, name: &str) -> sqlx::Result<DestinyPerk> {
+        sqlx::query_as::<_, DestinyPerk>(
+            "SELECT id, name, description FROM destiny_perks WHERE name = $1",
+        )
+        .bind(name)
+        .fetch_one(pool)
+        .await
+    }
+
+    async fn get_all(pool: &SqlitePool, names: &[String]) -> sqlx::Result<Vec<DestinyPerk>> {
+        sqlx::query_as::<_, DestinyPerk>(
+            "SELECT id, name, description FROM destiny_perks WHERE name IN (SELECT value FROM json_each($1))",
+        )
+        .bind(Json(names))
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn replace(pool: &SqlitePool, perks: &[DestinyPerk]) -> sqlx::Result<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM destiny_perks")
+            .execute(&mut *tx)
+            .await?;
+
+        for perk in perks {
+            sqlx::query(
+                "INSERT INTO destiny_perks (id, name, description) VALUES ($1, $2, $3)
+                ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(perk.id)
+            .bind(&perk.name)
+            .bind(&perk.description)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await
+    }
 }
@@ -3,24 +3,23 @@ use endgame_analysis::{DimWishlistCommand, TierListCommand, WeaponCommand};
 use serenity::all::{
     AutocompleteOption, CommandInteraction, Context, CreateCommand, ResolvedOption,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::{Autocomplete, SlashCommand};
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::{DestinyPerkTable, DestinyWeaponTable};
-
 pub struct DimWishlist;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for DimWishlist {
+impl<Db: Backend> SlashCommand<Error, Db> for DimWishlist {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        DimWishlistCommand::run::<Postgres, DestinyWeaponTable, DestinyPerkTable>(
+        DimWishlistCommand::run::<Db, Db::DestinyWeapons, Db::DestinyPerks>(
             ctx,
             interaction,
             options,
@@ -39,15 +38,14 @@ impl SlashCommand<Error, Postgres> for DimWishlist {
 pub struct TierList;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for TierList {
+impl<Db: Backend> SlashCommand<Error, Db> for TierList {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        TierListCommand::run::<Postgres, DestinyWeaponTable>(ctx, interaction, options, pool)
-            .await?;
+        TierListCommand::run::<Db, Db::DestinyWeapons>(ctx, interaction, options, pool).await?;
 
         Ok(())
     }
@@ -58,20 +56,15 @@ impl SlashCommand<Error, Postgres> for TierList {
 }
 
 #[async_trait]
-impl Autocomplete<Error, Postgres> for TierList {
+impl<Db: Backend> Autocomplete<Error, Db> for TierList {
     async fn autocomplete(
         ctx: &Context,
         interaction: &CommandInteraction,
         option: AutocompleteOption<'_>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        TierListCommand::autocomplete::<Postgres, DestinyWeaponTable>(
-            ctx,
-            interaction,
-            option,
-            pool,
-        )
-        .await?;
+        TierListCommand::autocomplete::<Db, Db::DestinyWeapons>(ctx, interaction, option, pool)
+            .await?;
 
         Ok(())
     }
@@ -80,14 +73,14 @@ impl Autocomplete<Error, Postgres> for TierList {
 pub struct Weapon;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Weapon {
+impl<Db: Backend> SlashCommand<Error, Db> for Weapon {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        WeaponCommand::run::<Postgres, DestinyWeaponTable>(ctx, interaction, options, pool).await?;
+        WeaponCommand::run::<Db, Db::DestinyWeapons>(ctx, interaction, options, pool).await?;
 
         Ok(())
     }
@@ -98,14 +91,14 @@ impl SlashCommand<Error, Postgres> for Weapon {
 }
 
 #[async_trait]
-impl Autocomplete<Error, Postgres> for Weapon {
+impl<Db: Backend> Autocomplete<Error, Db> for Weapon {
     async fn autocomplete(
         ctx: &Context,
         interaction: &CommandInteraction,
         option: AutocompleteOption<'_>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        WeaponCommand::autocomplete::<Postgres, DestinyWeaponTable>(ctx, interaction, option, pool)
+        WeaponCommand::autocomplete::<Db, Db::DestinyWeapons>(ctx, interaction, option, pool)
             .await?;
 
         Ok(())
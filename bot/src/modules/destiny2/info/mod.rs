@@ -1,24 +1,25 @@
 use async_trait::async_trait;
-use endgame_analysis::DestinyPerk;
+use endgame_analysis::DestinyPerkManager;
 use serenity::all::{
     CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
     CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
     ResolvedOption, ResolvedValue,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct Perk;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Perk {
+impl<Db: Backend> SlashCommand<Error, Db> for Perk {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
         let ResolvedValue::String(perk) = options[0].value else {
             interaction
@@ -38,14 +39,7 @@ impl SlashCommand<Error, Postgres> for Perk {
 
         interaction.defer(ctx).await.unwrap();
 
-        let perk = match sqlx::query_as!(
-            DestinyPerk,
-            "SELECT * FROM destiny_perks WHERE name = $1 LIMIT 1",
-            perk
-        )
-        .fetch_one(pool)
-        .await
-        {
+        let perk = match Db::DestinyPerks::get(pool, perk).await {
             Ok(perk) => perk,
             Err(_) => {
                 interaction.edit_response(ctx, EditInteractionResponse::new().content("This command is still work in progress. Please make sure the perk is typed __exactly__ how it appears in game (including captalisation).")).await.unwrap();
@@ -1,16 +1,19 @@
 use endgame_analysis::slash_commands::{DimWishlist, TierList, Weapon};
 use info::Perk;
-use serenity::all::{Context, CreateCommand};
-use zayden_core::SlashCommand;
+use zayden_core::Registry;
+
+use crate::Error;
+use crate::backend::Backend;
 
 pub mod endgame_analysis;
 pub mod info;
 
-pub fn register(ctx: &Context) -> [CreateCommand; 4] {
-    [
-        DimWishlist::register(ctx).unwrap(),
-        Weapon::register(ctx).unwrap(),
-        TierList::register(ctx).unwrap(),
-        Perk::register(ctx).unwrap(),
-    ]
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<DimWishlist>("dimwishlist")
+        .command::<Weapon>("weapon")
+        .autocomplete::<Weapon>("weapon")
+        .command::<TierList>("tierlist")
+        .autocomplete::<TierList>("tierlist")
+        .command::<Perk>("perk")
 }
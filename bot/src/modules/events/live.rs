@@ -4,23 +4,28 @@ use serenity::all::{
     CommandInteraction, Context, CreateCommand, CreateScheduledEvent, EditInteractionResponse,
     Permissions, ResolvedOption, ScheduledEventType,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
-use crate::{Error, Result};
+use crate::backend::Backend;
+use crate::{Error, Result, config};
 
 pub struct Live;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Live {
+impl<Db: Backend> SlashCommand<Error, Db> for Live {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        _pool: &PgPool,
+        _pool: &Pool<Db>,
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let Some(events) = &config::get().events else {
+            unreachable!("live is only registered with an events config")
+        };
+
         let now = Utc::now();
 
         interaction
@@ -33,7 +38,7 @@ impl SlashCommand<Error, Postgres> for Live {
                     "Brad is LIVE",
                     now + Duration::minutes(1),
                 )
-                .location("https://www.twitch.tv/bradleythebradster")
+                .location(&events.twitch_url)
                 .end_time(now + Duration::hours(7)),
             )
             .await
@@ -1 +1,15 @@
+use zayden_core::Registry;
+
+use crate::backend::Backend;
+use crate::{Error, config};
+
 pub mod live;
+
+use live::Live;
+
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    match &config::get().events {
+        Some(events) => registry.guild_command::<Live>("live", events.guild),
+        None => registry,
+    }
+}
@@ -1,29 +1,33 @@
 use async_trait::async_trait;
-use gambling::Commands;
-use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
-use zayden_core::SlashCommand;
+use gambling::{Commands, Components};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateCommand, ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{Component, SlashCommand};
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::{EffectsTable, GamblingTable, GameTable, GoalsTable};
-
 pub struct Blackjack;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Blackjack {
+impl<Db: Backend> SlashCommand<Error, Db> for Blackjack {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::blackjack::<Postgres, GamblingTable, GoalsTable, EffectsTable, GameTable>(
-            ctx,
-            interaction,
-            options,
-            pool,
-        )
+        Commands::blackjack::<
+            Db,
+            Db::Gambling,
+            Db::Goals,
+            Db::Effects,
+            Db::Game,
+            Db::Sessions,
+            Db::Ledger,
+        >(ctx, interaction, options, pool)
         .await?;
 
         Ok(())
@@ -33,3 +37,21 @@ impl SlashCommand<Error, Postgres> for Blackjack {
         Ok(Commands::register_blackjack())
     }
 }
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for Blackjack {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        Components::blackjack::<
+            Db,
+            Db::Gambling,
+            Db::Goals,
+            Db::Effects,
+            Db::Game,
+            Db::Sessions,
+            Db::Ledger,
+        >(ctx, interaction, pool)
+        .await?;
+
+        Ok(())
+    }
+}
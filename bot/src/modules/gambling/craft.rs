@@ -1,27 +1,32 @@
 use async_trait::async_trait;
-use gambling::Commands;
 use gambling::commands::craft::{CraftManager, CraftRow};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres, any::AnyQueryResult};
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct CraftTable;
 
 #[async_trait]
 impl CraftManager<Postgres> for CraftTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<CraftRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<CraftRow>> {
         let id = id.into();
 
-        sqlx::query_as!(CraftRow, "SELECT id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production FROM gambling_mine WHERE id = $1", id.get() as i64).fetch_optional(pool).await
+        sqlx::query_as!(CraftRow, "SELECT id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production FROM gambling_mine WHERE guild_id = $1 AND id = $2", economy.get(), id.get() as i64).fetch_optional(pool).await
     }
 
-    async fn save(pool: &PgPool, row: CraftRow) -> sqlx::Result<AnyQueryResult> {
+    async fn save(pool: &PgPool, economy: Economy, row: CraftRow) -> sqlx::Result<AnyQueryResult> {
         sqlx::query!(
-            "INSERT INTO gambling_mine (id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling_mine (guild_id, id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coal = EXCLUDED.coal,
             iron = EXCLUDED.iron,
             gold = EXCLUDED.gold,
@@ -32,6 +37,7 @@ impl CraftManager<Postgres> for CraftTable {
             tech = EXCLUDED.tech,
             utility = EXCLUDED.utility,
             production = EXCLUDED.production;",
+            economy.get(),
             row.id,
             row.coal,
             row.iron,
@@ -50,17 +56,71 @@ impl CraftManager<Postgres> for CraftTable {
     }
 }
 
+#[async_trait]
+impl CraftManager<Sqlite> for CraftTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<CraftRow>> {
+        let id = id.into();
+
+        sqlx::query_as("SELECT id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production FROM gambling_mine WHERE guild_id = $1 AND id = $2")
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn save(
+        pool: &SqlitePool,
+        economy: Economy,
+        row: CraftRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO gambling_mine (guild_id, id, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coal = EXCLUDED.coal,
+            iron = EXCLUDED.iron,
+            gold = EXCLUDED.gold,
+            redstone = EXCLUDED.redstone,
+            lapis = EXCLUDED.lapis,
+            diamonds = EXCLUDED.diamonds,
+            emeralds = EXCLUDED.emeralds,
+            tech = EXCLUDED.tech,
+            utility = EXCLUDED.utility,
+            production = EXCLUDED.production;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coal)
+        .bind(row.iron)
+        .bind(row.gold)
+        .bind(row.redstone)
+        .bind(row.lapis)
+        .bind(row.diamonds)
+        .bind(row.emeralds)
+        .bind(row.tech)
+        .bind(row.utility)
+        .bind(row.production)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
 pub struct Craft;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Craft {
+impl<Db: Backend> SlashCommand<Error, Db> for Craft {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::craft::<Postgres, CraftTable>(ctx, interaction, options, pool).await?;
+        Commands::craft::<Db, Db::Craft>(ctx, interaction, options, pool).await?;
 
         Ok(())
     }
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use gambling::Commands;
+use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
+use sqlx::Pool;
+use zayden_core::SlashCommand;
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct Crash;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Crash {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        Commands::crash::<
+            Db,
+            Db::Gambling,
+            Db::Goals,
+            Db::Effects,
+            Db::Game,
+            Db::Sessions,
+            Db::Ledger,
+        >(ctx, interaction, options, pool)
+        .await?;
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        Ok(Commands::register_crash())
+    }
+}
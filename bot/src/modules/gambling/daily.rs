@@ -1,19 +1,26 @@
 use async_trait::async_trait;
 use gambling::{
-    Commands,
+    Commands, Economy,
     commands::daily::{DailyManager, DailyRow},
 };
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres, any::AnyQueryResult};
+use sqlx::{
+    PgConnection, PgPool, Pool, Postgres, Sqlite, SqliteConnection, SqlitePool, any::AnyQueryResult,
+};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct DailyTable;
 
 #[async_trait]
 impl DailyManager<Postgres> for DailyTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<DailyRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DailyRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -26,24 +33,77 @@ impl DailyManager<Postgres> for DailyTable {
                 COALESCE(m.prestige, 0) as prestige
 
                 FROM gambling g
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;",
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn save(pool: &PgPool, row: DailyRow) -> sqlx::Result<AnyQueryResult> {
+    async fn save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: DailyRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         sqlx::query!(
-            "INSERT INTO gambling (id, coins, daily)
-            VALUES ($1, $2, now())
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, daily)
+            VALUES ($1, $2, $3, now())
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, daily = EXCLUDED.daily;",
+            economy.get(),
             row.id,
             row.coins,
         )
-        .execute(pool)
+        .execute(conn)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl DailyManager<Sqlite> for DailyTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DailyRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT
+                g.id,
+                g.coins,
+                g.daily,
+
+                COALESCE(m.prestige, 0) as prestige
+
+                FROM gambling g
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: DailyRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, daily)
+            VALUES ($1, $2, $3, date('now'))
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, daily = EXCLUDED.daily;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .execute(conn)
         .await
         .map(AnyQueryResult::from)
     }
@@ -52,14 +112,14 @@ impl DailyManager<Postgres> for DailyTable {
 pub struct Daily;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Daily {
+impl<Db: Backend> SlashCommand<Error, Db> for Daily {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::daily::<Postgres, DailyTable>(ctx, interaction, pool).await?;
+        Commands::daily::<Db, Db::Daily, Db::Ledger>(ctx, interaction, pool).await?;
 
         Ok(())
     }
@@ -1,21 +1,23 @@
 use async_trait::async_trait;
-use gambling::Commands;
 use gambling::commands::dig::{DigManager, DigRow};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::any::AnyQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use sqlx::{Pool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::StaminaTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::GoalsTable;
-
 pub struct DigTable;
 
 #[async_trait]
 impl DigManager<Postgres> for DigTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<DigRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DigRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -41,37 +43,41 @@ impl DigManager<Postgres> for DigTable {
                 
             FROM gambling g
             LEFT JOIN levels l ON g.id = l.id
-            LEFT JOIN gambling_mine m ON g.id = m.id
-            WHERE g.id = $1;",
+            LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id
+            WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn save(pool: &PgPool, row: DigRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: DigRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems, stamina)
-            VALUES ($1, $2, $3, $4)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins,
             gems = EXCLUDED.gems,
             stamina = EXCLUDED.stamina;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
             row.stamina,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         let result2 = sqlx::query!(
-            "INSERT INTO gambling_mine (id, coal, iron, gold, redstone, lapis, diamonds, emeralds, mine_activity)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling_mine (guild_id, id, coal, iron, gold, redstone, lapis, diamonds, emeralds, mine_activity)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coal = EXCLUDED.coal,
             iron = EXCLUDED.iron,
             gold = EXCLUDED.gold,
@@ -80,6 +86,7 @@ impl DigManager<Postgres> for DigTable {
             diamonds = EXCLUDED.diamonds,
             emeralds = EXCLUDED.emeralds,
             mine_activity = EXCLUDED.mine_activity;",
+            economy.get(),
             row.id,
             row.coal,
             row.iron,
@@ -90,7 +97,7 @@ impl DigManager<Postgres> for DigTable {
             row.emeralds,
             row.mine_activity
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
@@ -102,17 +109,112 @@ impl DigManager<Postgres> for DigTable {
     }
 }
 
+#[async_trait]
+impl DigManager<Sqlite> for DigTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DigRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT
+                g.id,
+                g.coins,
+                g.gems,
+                g.stamina,
+
+                COALESCE(l.level, 0) AS level,
+
+                COALESCE(m.miners, 0) AS miners,
+                COALESCE(m.coal, 0) AS coal,
+                COALESCE(m.iron, 0) AS iron,
+                COALESCE(m.gold, 0) AS gold,
+                COALESCE(m.redstone, 0) AS redstone,
+                COALESCE(m.lapis, 0) AS lapis,
+                COALESCE(m.diamonds, 0) AS diamonds,
+                COALESCE(m.emeralds, 0) AS emeralds,
+                COALESCE(m.prestige, 0) AS prestige,
+                COALESCE(m.mine_activity, datetime('now')) AS mine_activity
+
+            FROM gambling g
+            LEFT JOIN levels l ON g.id = l.id
+            LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id
+            WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: DigRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins,
+            gems = EXCLUDED.gems,
+            stamina = EXCLUDED.stamina;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .bind(row.stamina)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result2 = sqlx::query(
+            "INSERT INTO gambling_mine (guild_id, id, coal, iron, gold, redstone, lapis, diamonds, emeralds, mine_activity)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coal = EXCLUDED.coal,
+            iron = EXCLUDED.iron,
+            gold = EXCLUDED.gold,
+            redstone = EXCLUDED.redstone,
+            lapis = EXCLUDED.lapis,
+            diamonds = EXCLUDED.diamonds,
+            emeralds = EXCLUDED.emeralds,
+            mine_activity = EXCLUDED.mine_activity;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coal)
+        .bind(row.iron)
+        .bind(row.gold)
+        .bind(row.redstone)
+        .bind(row.lapis)
+        .bind(row.diamonds)
+        .bind(row.emeralds)
+        .bind(row.mine_activity)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2]);
+
+        Ok(result)
+    }
+}
+
 pub struct Dig;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Dig {
+impl<Db: Backend> SlashCommand<Error, Db> for Dig {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::dig::<Postgres, StaminaTable, GoalsTable, DigTable>(ctx, interaction, pool)
+        Commands::dig::<Db, Db::Stamina, Db::Goals, Db::Dig, Db::Ledger>(ctx, interaction, pool)
             .await?;
         Ok(())
     }
@@ -0,0 +1,347 @@
+use async_trait::async_trait;
+use gambling::{Commands, EconomyManager};
+use serenity::all::{CommandInteraction, Context, CreateCommand, GuildId, ResolvedOption};
+use sqlx::any::AnyQueryResult;
+use sqlx::types::Json;
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
+use zayden_core::{PermissionLevel, SlashCommand};
+
+use crate::backend::Backend;
+use crate::modules::admin::require;
+use crate::{Error, Result};
+
+const MINE_COLUMNS: &str = "miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production, mine_activity";
+
+pub struct EconomyTable;
+
+#[async_trait]
+impl EconomyManager<Postgres> for EconomyTable {
+    async fn guilds(pool: &PgPool) -> sqlx::Result<Vec<i64>> {
+        sqlx::query_scalar!("SELECT guild_id FROM gambling_economies")
+            .fetch_all(pool)
+            .await
+    }
+
+    async fn enable(
+        pool: &PgPool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into();
+
+        sqlx::query!(
+            "INSERT INTO gambling_economies (guild_id) VALUES ($1) ON CONFLICT DO NOTHING",
+            guild_id.get() as i64
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn disable(
+        pool: &PgPool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into();
+
+        sqlx::query!(
+            "DELETE FROM gambling_economies WHERE guild_id = $1",
+            guild_id.get() as i64
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn import(
+        pool: &PgPool,
+        guild_id: impl Into<GuildId> + Send,
+        users: &[i64],
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into().get() as i64;
+
+        let mut tx = pool.begin().await?;
+
+        let users = sqlx::query_scalar!(
+            "SELECT id FROM gambling
+            WHERE guild_id = 0 AND id = ANY($2)
+            AND id NOT IN (SELECT id FROM gambling WHERE guild_id = $1)",
+            guild_id,
+            users
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut result = sqlx::query!(
+            "INSERT INTO gambling (guild_id, id, coins, daily, stamina, gift, gems)
+            SELECT $1, id, coins, daily, stamina, gift, gems FROM gambling
+            WHERE guild_id = 0 AND id = ANY($2)",
+            guild_id,
+            &users
+        )
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result2 = sqlx::query!(
+            "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+            SELECT $1, user_id, item_id, quantity FROM gambling_inventory
+            WHERE guild_id = 0 AND user_id = ANY($2)",
+            guild_id,
+            &users
+        )
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result3 = sqlx::query(&format!(
+            "INSERT INTO gambling_mine (guild_id, id, {MINE_COLUMNS})
+            SELECT $1, id, {MINE_COLUMNS} FROM gambling_mine
+            WHERE guild_id = 0 AND id = ANY($2)"
+        ))
+        .bind(guild_id)
+        .bind(&users)
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2, result3]);
+
+        // The copied balances open the guild's ledger
+        for currency in ["coins", "gems"] {
+            let recorded = sqlx::query(&format!(
+                "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, balance)
+                SELECT $1, id, {currency}, '{currency}', 'import', {currency} FROM gambling
+                WHERE guild_id = 0 AND id = ANY($2) AND {currency} <> 0"
+            ))
+            .bind(guild_id)
+            .bind(&users)
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([AnyQueryResult::from(recorded)]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    async fn reset(
+        pool: &PgPool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into().get() as i64;
+
+        let mut tx = pool.begin().await?;
+        let mut result = AnyQueryResult::default();
+
+        // The ledger is kept, with each balance closed by a reset entry
+        for currency in ["coins", "gems"] {
+            let recorded = sqlx::query(&format!(
+                "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, balance)
+                SELECT guild_id, id, -{currency}, '{currency}', 'reset', 0 FROM gambling
+                WHERE guild_id = $1 AND {currency} <> 0"
+            ))
+            .bind(guild_id)
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([AnyQueryResult::from(recorded)]);
+        }
+
+        // Children first, as the mines and inventories reference the balances
+        for table in [
+            "gambling_effects",
+            "gambling_goals",
+            "gambling_inventory",
+            "gambling_mine",
+            "gambling",
+        ] {
+            let deleted = sqlx::query(&format!("DELETE FROM {table} WHERE guild_id = $1"))
+                .bind(guild_id)
+                .execute(&mut *tx)
+                .await?;
+
+            result.extend([AnyQueryResult::from(deleted)]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl EconomyManager<Sqlite> for EconomyTable {
+    async fn guilds(pool: &SqlitePool) -> sqlx::Result<Vec<i64>> {
+        sqlx::query_scalar("SELECT guild_id FROM gambling_economies")
+            .fetch_all(pool)
+            .await
+    }
+
+    async fn enable(
+        pool: &SqlitePool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into();
+
+        sqlx::query("INSERT INTO gambling_economies (guild_id) VALUES ($1) ON CONFLICT DO NOTHING")
+            .bind(guild_id.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn disable(
+        pool: &SqlitePool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into();
+
+        sqlx::query("DELETE FROM gambling_economies WHERE guild_id = $1")
+            .bind(guild_id.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn import(
+        pool: &SqlitePool,
+        guild_id: impl Into<GuildId> + Send,
+        users: &[i64],
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into().get() as i64;
+
+        let mut tx = pool.begin().await?;
+
+        let users: Vec<i64> = sqlx::query_scalar(
+            "SELECT id FROM gambling
+            WHERE guild_id = 0 AND id IN (SELECT value FROM json_each($2))
+            AND id NOT IN (SELECT id FROM gambling WHERE guild_id = $1)",
+        )
+        .bind(guild_id)
+        .bind(Json(users))
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, daily, stamina, gift, gems)
+            SELECT $1, id, coins, daily, stamina, gift, gems FROM gambling
+            WHERE guild_id = 0 AND id IN (SELECT value FROM json_each($2))",
+        )
+        .bind(guild_id)
+        .bind(Json(&users))
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result2 = sqlx::query(
+            "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+            SELECT $1, user_id, item_id, quantity FROM gambling_inventory
+            WHERE guild_id = 0 AND user_id IN (SELECT value FROM json_each($2))",
+        )
+        .bind(guild_id)
+        .bind(Json(&users))
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result3 = sqlx::query(&format!(
+            "INSERT INTO gambling_mine (guild_id, id, {MINE_COLUMNS})
+            SELECT $1, id, {MINE_COLUMNS} FROM gambling_mine
+            WHERE guild_id = 0 AND id IN (SELECT value FROM json_each($2))"
+        ))
+        .bind(guild_id)
+        .bind(Json(&users))
+        .execute(&mut *tx)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2, result3]);
+
+        // The copied balances open the guild's ledger
+        for currency in ["coins", "gems"] {
+            let recorded = sqlx::query(&format!(
+                "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, balance)
+                SELECT $1, id, {currency}, '{currency}', 'import', {currency} FROM gambling
+                WHERE guild_id = 0 AND id IN (SELECT value FROM json_each($2)) AND {currency} <> 0"
+            ))
+            .bind(guild_id)
+            .bind(Json(&users))
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([AnyQueryResult::from(recorded)]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+
+    async fn reset(
+        pool: &SqlitePool,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let guild_id = guild_id.into().get() as i64;
+
+        let mut tx = pool.begin().await?;
+        let mut result = AnyQueryResult::default();
+
+        // The ledger is kept, with each balance closed by a reset entry
+        for currency in ["coins", "gems"] {
+            let recorded = sqlx::query(&format!(
+                "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, balance)
+                SELECT guild_id, id, -{currency}, '{currency}', 'reset', 0 FROM gambling
+                WHERE guild_id = $1 AND {currency} <> 0"
+            ))
+            .bind(guild_id)
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([AnyQueryResult::from(recorded)]);
+        }
+
+        // Children first, as the mines and inventories reference the balances
+        for table in [
+            "gambling_effects",
+            "gambling_goals",
+            "gambling_inventory",
+            "gambling_mine",
+            "gambling",
+        ] {
+            let deleted = sqlx::query(&format!("DELETE FROM {table} WHERE guild_id = $1"))
+                .bind(guild_id)
+                .execute(&mut *tx)
+                .await?;
+
+            result.extend([AnyQueryResult::from(deleted)]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+}
+
+pub struct Economy;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Economy {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        require(pool, interaction, PermissionLevel::Admin).await?;
+
+        Commands::economy::<Db, Db::Economies>(ctx, interaction, options, pool).await?;
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        Ok(Commands::register_economy())
+    }
+}
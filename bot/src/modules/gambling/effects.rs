@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use futures::TryStreamExt;
-use gambling::{EffectsManager, EffectsRow, shop::ShopItem};
+use gambling::{Economy, EffectsManager, EffectsRow, shop::ShopItem};
 use serenity::all::UserId;
+use sqlx::sqlite::SqliteQueryResult;
 use sqlx::{
-    PgConnection, Postgres,
+    PgConnection, Postgres, Sqlite, SqliteConnection,
     postgres::{PgQueryResult, types::PgInterval},
 };
 
@@ -15,13 +17,15 @@ pub struct EffectsTable;
 impl EffectsManager<Postgres> for EffectsTable {
     async fn get_effects(
         conn: &mut PgConnection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
     ) -> sqlx::Result<HashMap<String, i32>> {
         let user_id = user_id.into();
 
         sqlx::query_as!(
             EffectsRow,
-            "SELECT DISTINCT ON (item_id) id, item_id, expiry FROM gambling_effects WHERE user_id = $1",
+            "SELECT DISTINCT ON (item_id) id, item_id, expiry FROM gambling_effects WHERE guild_id = $1 AND user_id = $2",
+            economy.get(),
             user_id.get() as i64,
         )
         .fetch(conn).map_ok(|row| (row.item_id, row.id)).try_collect()
@@ -30,6 +34,7 @@ impl EffectsManager<Postgres> for EffectsTable {
 
     async fn get_effect(
         conn: &mut PgConnection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         effect: &str,
     ) -> sqlx::Result<Option<EffectsRow>> {
@@ -37,7 +42,8 @@ impl EffectsManager<Postgres> for EffectsTable {
 
         sqlx::query_as!(
             EffectsRow,
-            "SELECT DISTINCT ON (item_id) id, item_id, expiry FROM gambling_effects WHERE user_id = $1 AND item_id = $2",
+            "SELECT DISTINCT ON (item_id) id, item_id, expiry FROM gambling_effects WHERE guild_id = $1 AND user_id = $2 AND item_id = $3",
+            economy.get(),
             user_id.get() as i64,
             effect
         )
@@ -47,6 +53,7 @@ impl EffectsManager<Postgres> for EffectsTable {
 
     async fn add_effect(
         conn: &mut PgConnection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         item: &ShopItem<'_>,
     ) -> sqlx::Result<PgQueryResult> {
@@ -57,11 +64,12 @@ impl EffectsManager<Postgres> for EffectsTable {
             .map(|d| PgInterval::try_from(d).unwrap());
 
         sqlx::query!(
-            "INSERT INTO gambling_effects (user_id, item_id, expiry)
-            VALUES ($1, $2, NOW() + $3)
-            ON CONFLICT (user_id, item_id)
+            "INSERT INTO gambling_effects (guild_id, user_id, item_id, expiry)
+            VALUES ($1, $2, $3, NOW() + $4)
+            ON CONFLICT (guild_id, user_id, item_id)
             DO UPDATE SET
-                expiry = GREATEST(gambling_effects.expiry + $3, EXCLUDED.expiry)",
+                expiry = GREATEST(gambling_effects.expiry + $4, EXCLUDED.expiry)",
+            economy.get(),
             user_id.get() as i64,
             item.id,
             duration
@@ -79,3 +87,89 @@ impl EffectsManager<Postgres> for EffectsTable {
         .await
     }
 }
+
+#[async_trait]
+impl EffectsManager<Sqlite> for EffectsTable {
+    async fn get_effects(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        user_id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<HashMap<String, i32>> {
+        let user_id = user_id.into();
+
+        sqlx::query_as::<_, (i32, String)>(
+            "SELECT id, item_id FROM gambling_effects WHERE guild_id = $1 AND user_id = $2",
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .fetch(conn)
+        .map_ok(|(id, item_id)| (item_id, id))
+        .try_collect()
+        .await
+    }
+
+    async fn get_effect(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        user_id: impl Into<UserId> + Send,
+        effect: &str,
+    ) -> sqlx::Result<Option<EffectsRow>> {
+        let user_id = user_id.into();
+
+        let row = sqlx::query_as::<_, (i32, String, Option<NaiveDateTime>)>(
+            "SELECT id, item_id, expiry FROM gambling_effects WHERE guild_id = $1 AND user_id = $2 AND item_id = $3",
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .bind(effect)
+        .fetch_optional(conn)
+        .await?;
+
+        Ok(row.map(|(id, item_id, expiry)| EffectsRow {
+            id,
+            item_id,
+            expiry,
+        }))
+    }
+
+    async fn add_effect(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        user_id: impl Into<UserId> + Send,
+        item: &ShopItem<'_>,
+    ) -> sqlx::Result<SqliteQueryResult> {
+        let user_id = user_id.into();
+
+        // SQLite has no interval type, so the duration is passed as a
+        // datetime() modifier. A NULL modifier keeps the expiry NULL.
+        let duration = item
+            .effect_duration
+            .map(|d| format!("+{} seconds", d.as_secs()));
+
+        sqlx::query(
+            "INSERT INTO gambling_effects (guild_id, user_id, item_id, expiry)
+            VALUES ($1, $2, $3, datetime('now', $4))
+            ON CONFLICT (guild_id, user_id, item_id)
+            DO UPDATE SET
+                expiry = MAX(datetime(gambling_effects.expiry, $4), EXCLUDED.expiry)",
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .bind(item.id)
+        .bind(duration)
+        .execute(conn)
+        .await
+    }
+
+    async fn remove_effect(
+        conn: &mut SqliteConnection,
+        id: i32,
+    ) -> sqlx::Result<SqliteQueryResult> {
+        sqlx::query(
+            "DELETE FROM gambling_effects WHERE id = $1 AND (expiry <= datetime('now') OR expiry IS NULL)",
+        )
+        .bind(id)
+        .execute(conn)
+        .await
+    }
+}
@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use gambling::commands::gift::GiftManager;
-use gambling::{Commands, commands::gift::SenderRow};
+use gambling::{Commands, Economy, commands::gift::SenderRow};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres, any::AnyQueryResult};
+use sqlx::{
+    PgConnection, PgPool, Pool, Postgres, Sqlite, SqliteConnection, SqlitePool, any::AnyQueryResult,
+};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::GoalsTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct GiftTable;
@@ -14,6 +16,7 @@ pub struct GiftTable;
 impl GiftManager<Postgres> for GiftTable {
     async fn sender(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<SenderRow>> {
         let id = id.into();
@@ -32,8 +35,9 @@ impl GiftManager<Postgres> for GiftTable {
 
                 FROM gambling g
                 LEFT JOIN levels l ON g.id = l.id
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;",
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -41,35 +45,39 @@ impl GiftManager<Postgres> for GiftTable {
     }
 
     async fn add_coins(
-        pool: &PgPool,
+        conn: &mut PgConnection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult> {
+    ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
 
-        sqlx::query!(
-            "UPDATE gambling SET coins = coins + $2 WHERE id = $1",
+        sqlx::query_scalar!(
+            "UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins",
+            economy.get(),
             id.get() as i64,
             amount
         )
-        .execute(pool)
+        .fetch_optional(conn)
         .await
-        .map(AnyQueryResult::from)
     }
 
-    async fn save_sender(pool: &PgPool, row: SenderRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn save_sender(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: SenderRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems, gift)
-            VALUES ($1, $2, $3, now())
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems, gift)
+            VALUES ($1, $2, $3, $4, now())
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems, gift = EXCLUDED.gift;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
@@ -81,13 +89,95 @@ impl GiftManager<Postgres> for GiftTable {
             row.id,
             row.level,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         result.extend([result2]);
 
-        tx.commit().await?;
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl GiftManager<Sqlite> for GiftTable {
+    async fn sender(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<SenderRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT
+                g.id,
+                g.coins,
+                g.gems,
+                g.gift,
+
+                COALESCE(l.level, 0) AS level,
+
+                m.prestige
+
+                FROM gambling g
+                LEFT JOIN levels l ON g.id = l.id
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn add_coins(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar("UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins")
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .bind(amount)
+            .fetch_optional(conn)
+            .await
+    }
+
+    async fn save_sender(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: SenderRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems, gift)
+            VALUES ($1, $2, $3, $4, date('now'))
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems, gift = EXCLUDED.gift;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result2 = sqlx::query(
+            "INSERT INTO levels (id, level)
+            VALUES ($1, $2)
+            ON CONFLICT (id) DO UPDATE SET
+            level = EXCLUDED.level;",
+        )
+        .bind(row.id)
+        .bind(row.level)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2]);
 
         Ok(result)
     }
@@ -96,14 +186,15 @@ impl GiftManager<Postgres> for GiftTable {
 pub struct Gift;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Gift {
+impl<Db: Backend> SlashCommand<Error, Db> for Gift {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::gift::<Postgres, GoalsTable, GiftTable>(ctx, interaction, options, pool).await?;
+        Commands::gift::<Db, Db::Goals, Db::Gift, Db::Ledger>(ctx, interaction, options, pool)
+            .await?;
 
         Ok(())
     }
@@ -1,18 +1,23 @@
 use async_trait::async_trait;
 use chrono::NaiveDate;
 use gambling::commands::goals::GoalsRow;
-use gambling::{Commands, GamblingGoalsRow, GoalsManager};
+use gambling::{Commands, Economy, GamblingGoalsRow, GoalsManager};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct GoalsTable;
 
 #[async_trait]
 impl GoalsManager<Postgres> for GoalsTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<GoalsRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<GoalsRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -27,8 +32,9 @@ impl GoalsManager<Postgres> for GoalsTable {
 
                 FROM gambling g
                 LEFT JOIN levels l ON g.id = l.id
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;",
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -37,13 +43,15 @@ impl GoalsManager<Postgres> for GoalsTable {
 
     async fn full_rows(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
         let id = id.into();
 
         sqlx::query_as!(
             GamblingGoalsRow,
-            "SELECT user_id, goal_id, day, progress, target FROM gambling_goals WHERE user_id = $1",
+            "SELECT user_id, goal_id, day, progress, target FROM gambling_goals WHERE guild_id = $1 AND user_id = $2",
+            economy.get(),
             id.get() as i64
         )
         .fetch_all(pool)
@@ -52,6 +60,7 @@ impl GoalsManager<Postgres> for GoalsTable {
 
     async fn update(
         pool: &PgPool,
+        economy: Economy,
         rows: &[GamblingGoalsRow],
     ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
         let user_id = match rows.first() {
@@ -61,9 +70,13 @@ impl GoalsManager<Postgres> for GoalsTable {
 
         let mut tx = pool.begin().await?;
 
-        sqlx::query!("DELETE FROM gambling_goals WHERE user_id = $1", user_id)
-            .execute(&mut *tx)
-            .await?;
+        sqlx::query!(
+            "DELETE FROM gambling_goals WHERE guild_id = $1 AND user_id = $2",
+            economy.get(),
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
 
         let num_rows = rows.len();
         let mut user_ids: Vec<i64> = Vec::with_capacity(num_rows);
@@ -82,9 +95,10 @@ impl GoalsManager<Postgres> for GoalsTable {
 
         let rows = sqlx::query_as!(
             GamblingGoalsRow,
-            "INSERT INTO gambling_goals (user_id, goal_id, day, progress, target)
-            SELECT * FROM UNNEST($1::bigint[], $2::text[], $3::date[], $4::bigint[], $5::bigint[])
+            "INSERT INTO gambling_goals (guild_id, user_id, goal_id, day, progress, target)
+            SELECT $1, * FROM UNNEST($2::bigint[], $3::text[], $4::date[], $5::bigint[], $6::bigint[])
             RETURNING user_id, goal_id, day, progress, target;",
+            economy.get(),
             &user_ids,
             &goal_ids,
             &days,
@@ -100,17 +114,108 @@ impl GoalsManager<Postgres> for GoalsTable {
     }
 }
 
+#[async_trait]
+impl GoalsManager<Sqlite> for GoalsTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<GoalsRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT
+                g.coins,
+                g.gems,
+
+                COALESCE(l.level, 0) AS level,
+
+                m.prestige
+
+                FROM gambling g
+                LEFT JOIN levels l ON g.id = l.id
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn full_rows(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT user_id, goal_id, day, progress, target FROM gambling_goals WHERE guild_id = $1 AND user_id = $2",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn update(
+        pool: &SqlitePool,
+        economy: Economy,
+        rows: &[GamblingGoalsRow],
+    ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
+        let user_id = match rows.first() {
+            Some(row) => row.user_id,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::query("DELETE FROM gambling_goals WHERE guild_id = $1 AND user_id = $2")
+            .bind(economy.get())
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // No UNNEST in SQLite, so the goals are inserted one at a time inside
+        // the transaction.
+        let mut inserted = Vec::with_capacity(rows.len());
+
+        for row in rows {
+            let row = sqlx::query_as(
+                "INSERT INTO gambling_goals (guild_id, user_id, goal_id, day, progress, target)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING user_id, goal_id, day, progress, target;",
+            )
+            .bind(economy.get())
+            .bind(row.user_id)
+            .bind(&row.goal_id)
+            .bind(row.day)
+            .bind(row.progress)
+            .bind(row.target)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            inserted.push(row);
+        }
+
+        tx.commit().await?;
+
+        Ok(inserted)
+    }
+}
+
 pub struct Goals;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Goals {
+impl<Db: Backend> SlashCommand<Error, Db> for Goals {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::goals::<Postgres, GoalsTable>(ctx, interaction, pool).await?;
+        Commands::goals::<Db, Db::Goals>(ctx, interaction, pool).await?;
         Ok(())
     }
 
@@ -1,25 +1,26 @@
 use async_trait::async_trait;
-use gambling::Commands;
-use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
-use zayden_core::SlashCommand;
+use gambling::{Commands, Components};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateCommand, ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{Component, SlashCommand};
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::GameTable;
-use super::goals::GoalsTable;
-
 pub struct HigherLower;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for HigherLower {
+impl<Db: Backend> SlashCommand<Error, Db> for HigherLower {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::higher_lower::<Postgres, GoalsTable, GameTable>(ctx, interaction, pool).await?;
+        Commands::higher_lower::<Db, Db::Game, Db::Sessions, Db::Ledger>(ctx, interaction, pool)
+            .await?;
 
         Ok(())
     }
@@ -28,3 +29,17 @@ impl SlashCommand<Error, Postgres> for HigherLower {
         Ok(Commands::register_higher_lower())
     }
 }
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for HigherLower {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        Components::higher_lower::<Db, Db::Goals, Db::Game, Db::Sessions, Db::Ledger>(
+            ctx,
+            interaction,
+            pool,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
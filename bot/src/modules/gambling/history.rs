@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use gambling::{Commands, Economy, LedgerManager, LedgerRow, Transaction};
+use serenity::all::{
+    CommandInteraction, Context, CreateCommand, ResolvedOption, ResolvedValue, UserId,
+};
+use sqlx::any::AnyQueryResult;
+use sqlx::{PgConnection, PgPool, Pool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use zayden_core::{PermissionLevel, SlashCommand};
+
+use crate::backend::Backend;
+use crate::modules::admin::require;
+use crate::{Error, Result};
+
+pub struct LedgerTable;
+
+#[async_trait]
+impl LedgerManager<Postgres> for LedgerTable {
+    async fn insert(
+        conn: &mut PgConnection,
+        transaction: &Transaction,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, reference_id, balance)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+            transaction.economy.get(),
+            transaction.user_id,
+            transaction.amount,
+            transaction.currency,
+            transaction.source,
+            transaction.reference_id,
+            transaction.balance
+        )
+        .execute(conn)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn history(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        page_num: i64,
+        page_size: i64,
+    ) -> sqlx::Result<Vec<LedgerRow>> {
+        let id = id.into();
+        let offset = (page_num - 1) * page_size;
+
+        sqlx::query_as!(
+            LedgerRow,
+            "SELECT id, user_id, amount, currency, source, reference_id, balance, created_at
+            FROM gambling_ledger
+            WHERE guild_id = $1 AND user_id = $2
+            ORDER BY id DESC
+            LIMIT $3
+            OFFSET $4",
+            economy.get(),
+            id.get() as i64,
+            page_size,
+            offset
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn count(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<i64> {
+        let id = id.into();
+
+        sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM gambling_ledger WHERE guild_id = $1 AND user_id = $2"#,
+            economy.get(),
+            id.get() as i64
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
+
+#[async_trait]
+impl LedgerManager<Sqlite> for LedgerTable {
+    async fn insert(
+        conn: &mut SqliteConnection,
+        transaction: &Transaction,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO gambling_ledger (guild_id, user_id, amount, currency, source, reference_id, balance)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(transaction.economy.get())
+        .bind(transaction.user_id)
+        .bind(transaction.amount)
+        .bind(transaction.currency)
+        .bind(transaction.source)
+        .bind(transaction.reference_id)
+        .bind(transaction.balance)
+        .execute(conn)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn history(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        page_num: i64,
+        page_size: i64,
+    ) -> sqlx::Result<Vec<LedgerRow>> {
+        let id = id.into();
+        let offset = (page_num - 1) * page_size;
+
+        sqlx::query_as(
+            "SELECT id, user_id, amount, currency, source, reference_id, balance, created_at
+            FROM gambling_ledger
+            WHERE guild_id = $1 AND user_id = $2
+            ORDER BY id DESC
+            LIMIT $3
+            OFFSET $4",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .bind(page_size)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn count(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<i64> {
+        let id = id.into();
+
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM gambling_ledger WHERE guild_id = $1 AND user_id = $2",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_one(pool)
+        .await
+    }
+}
+
+pub struct History;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for History {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let other_user = options.iter().any(|option| {
+            matches!(option.value, ResolvedValue::User(user, _) if user.id != interaction.user.id)
+        });
+
+        if other_user {
+            require(pool, interaction, PermissionLevel::Moderator).await?;
+        }
+
+        Commands::history::<Db, Db::Ledger>(ctx, interaction, options, pool).await?;
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        Ok(Commands::register_history())
+    }
+}
@@ -1,12 +1,12 @@
 use async_trait::async_trait;
 use gambling::commands::inventory::{InventoryManager, InventoryRow};
-use gambling::{Commands, GamblingItem};
+use gambling::{Commands, Economy, GamblingItem};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::types::Json;
-use sqlx::{PgConnection, PgPool, Postgres};
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use sqlx::{Pool, types::Json};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::EffectsTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct InventoryTable;
@@ -15,6 +15,7 @@ pub struct InventoryTable;
 impl InventoryManager<Postgres> for InventoryTable {
     async fn row(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<InventoryRow>> {
         let id = id.into();
@@ -33,7 +34,7 @@ impl InventoryManager<Postgres> for InventoryTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>",
 
             COALESCE(m.tech, 0) AS "tech!",
@@ -47,7 +48,8 @@ impl InventoryManager<Postgres> for InventoryTable {
             COALESCE(m.diamonds, 0) AS "diamonds!",
             COALESCE(m.emeralds, 0) AS "emeralds!"
 
-            FROM gambling g LEFT JOIN gambling_mine m ON g.id = m.id WHERE g.id = $1;"#,
+            FROM gambling g LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -56,6 +58,7 @@ impl InventoryManager<Postgres> for InventoryTable {
 
     async fn edit_item_quantity(
         conn: &mut PgConnection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         item_id: &str,
         amount: i64,
@@ -66,15 +69,15 @@ impl InventoryManager<Postgres> for InventoryTable {
             r#"
         WITH updated_row AS (
             UPDATE gambling_inventory
-            SET quantity = quantity - $3
-            WHERE user_id = $1
-              AND item_id = $2
-              AND $3 <= gambling_inventory.quantity
+            SET quantity = quantity - $4
+            WHERE guild_id = $1 AND user_id = $2
+              AND item_id = $3
+              AND $4 <= gambling_inventory.quantity
             RETURNING quantity
         ),
         deleted_row AS (
             DELETE FROM gambling_inventory
-            WHERE user_id = $1 AND item_id = $2
+            WHERE guild_id = $1 AND user_id = $2 AND item_id = $3
             AND EXISTS (SELECT 1 FROM updated_row ur WHERE ur.quantity <= 0)
             RETURNING item_id
         )
@@ -83,6 +86,7 @@ impl InventoryManager<Postgres> for InventoryTable {
         FROM
             updated_row ur
         "#,
+            economy.get(),
             id.get() as i64,
             item_id,
             amount
@@ -92,23 +96,101 @@ impl InventoryManager<Postgres> for InventoryTable {
     }
 }
 
+#[async_trait]
+impl InventoryManager<Sqlite> for InventoryTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<InventoryRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"SELECT
+            g.coins,
+            g.gems,
+
+            (
+                SELECT json_group_array(
+                    json_object(
+                        'quantity', inv.quantity,
+                        'item_id', inv.item_id
+                    )
+                )
+                FROM gambling_inventory inv
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+            ) as inventory,
+
+            COALESCE(m.tech, 0) AS tech,
+            COALESCE(m.utility, 0) AS utility,
+            COALESCE(m.production, 0) AS production,
+            COALESCE(m.coal, 0) AS coal,
+            COALESCE(m.iron, 0) AS iron,
+            COALESCE(m.gold, 0) AS gold,
+            COALESCE(m.redstone, 0) AS redstone,
+            COALESCE(m.lapis, 0) AS lapis,
+            COALESCE(m.diamonds, 0) AS diamonds,
+            COALESCE(m.emeralds, 0) AS emeralds
+
+            FROM gambling g LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn edit_item_quantity(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        item_id: &str,
+        amount: i64,
+    ) -> sqlx::Result<i64> {
+        let id = id.into();
+
+        // SQLite doesn't allow writes inside a CTE, so the update and the
+        // cleanup of emptied stacks run as two statements.
+        let quantity: i64 = sqlx::query_scalar(
+            "UPDATE gambling_inventory
+            SET quantity = quantity - $4
+            WHERE guild_id = $1 AND user_id = $2
+              AND item_id = $3
+              AND $4 <= gambling_inventory.quantity
+            RETURNING quantity",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .bind(item_id)
+        .bind(amount)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if quantity <= 0 {
+            sqlx::query("DELETE FROM gambling_inventory WHERE guild_id = $1 AND user_id = $2 AND item_id = $3")
+                .bind(economy.get())
+                .bind(id.get() as i64)
+                .bind(item_id)
+                .execute(&mut *conn)
+                .await?;
+        }
+
+        Ok(quantity)
+    }
+}
+
 pub struct Inventory;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Inventory {
+impl<Db: Backend> SlashCommand<Error, Db> for Inventory {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::inventory::<Postgres, EffectsTable, InventoryTable>(
-            ctx,
-            interaction,
-            options,
-            pool,
-        )
-        .await?;
+        Commands::inventory::<Db, Db::Effects, Db::Inventory>(ctx, interaction, options, pool)
+            .await?;
 
         Ok(())
     }
@@ -1,15 +1,17 @@
 use async_trait::async_trait;
 use futures::TryStreamExt;
-use gambling::Commands;
 use gambling::commands::leaderboard::{
     CoinsRow, EggplantsRow, GemsRow, LeaderboardManager, LeaderboardRow, LottoTicketRow,
     NetworthRow,
 };
 use gambling::shop::{EGGPLANT, LOTTO_TICKET, WEAPON_CRATE};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres};
+use sqlx::types::Json;
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 const LIMIT: i64 = 10;
@@ -20,6 +22,7 @@ pub struct LeaderboardTable;
 impl LeaderboardManager<Postgres> for LeaderboardTable {
     async fn networth(
         pool: &PgPool,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>> {
@@ -32,24 +35,25 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
                 g.id,
                 (
                     g.coins +
-                    COALESCE(gi_eggplants.quantity, 0) * $3 +
-                    COALESCE(gi_crates.quantity, 0) * $5
+                    COALESCE(gi_eggplants.quantity, 0) * $4 +
+                    COALESCE(gi_crates.quantity, 0) * $6
                 ) AS networth
             FROM
                 gambling g
             LEFT JOIN
-                gambling_inventory gi_eggplants ON g.id = gi_eggplants.user_id
-                                            AND gi_eggplants.item_id = $2
+                gambling_inventory gi_eggplants ON g.guild_id = gi_eggplants.guild_id AND g.id = gi_eggplants.user_id
+                                            AND gi_eggplants.item_id = $3
             LEFT JOIN
-                gambling_inventory gi_crates ON g.id = gi_crates.user_id
-                                            AND gi_crates.item_id = $4
+                gambling_inventory gi_crates ON g.guild_id = gi_crates.guild_id AND g.id = gi_crates.user_id
+                                            AND gi_crates.item_id = $5
             WHERE
-                g.id = ANY($1)
+                g.guild_id = $1 AND g.id = ANY($2)
             ORDER BY
                 networth DESC
-            LIMIT $6
-            OFFSET $7
+            LIMIT $7
+            OFFSET $8
             "#,
+            economy.get(),
             users,
             EGGPLANT.id,
             EGGPLANT.coin_cost().unwrap_or_default(),
@@ -66,6 +70,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn networth_row_number(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>> {
         let user_id = id.into();
@@ -77,17 +82,19 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
                     g.id,
                     (
                         g.coins +
-                        COALESCE(gi_eggplants.quantity, 0) * $3 +
-                        COALESCE(gi_crates.quantity, 0) * $5
+                        COALESCE(gi_eggplants.quantity, 0) * $4 +
+                        COALESCE(gi_crates.quantity, 0) * $6
                     ) AS networth_value
                 FROM
                     gambling g
                 LEFT JOIN
-                    gambling_inventory gi_eggplants ON g.id = gi_eggplants.user_id
-                                                AND gi_eggplants.item_id = $2
+                    gambling_inventory gi_eggplants ON g.guild_id = gi_eggplants.guild_id AND g.id = gi_eggplants.user_id
+                                                AND gi_eggplants.item_id = $3
                 LEFT JOIN
-                    gambling_inventory gi_crates ON g.id = gi_crates.user_id
-                                                AND gi_crates.item_id = $4
+                    gambling_inventory gi_crates ON g.guild_id = gi_crates.guild_id AND g.id = gi_crates.user_id
+                                                AND gi_crates.item_id = $5
+                WHERE
+                    g.guild_id = $1
             ),
             ranked_users AS (
                 SELECT
@@ -98,8 +105,9 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             )
             SELECT rn
             FROM ranked_users
-            WHERE id = $1 -- User ID ($1)
+            WHERE id = $2 -- User ID ($2)
             "#,
+            economy.get(),
             user_id.get() as i64,
             EGGPLANT.id,
             EGGPLANT.coin_cost().unwrap_or_default(),
@@ -113,6 +121,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn coins(
         pool: &PgPool,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>> {
@@ -123,11 +132,12 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             r#"
             SELECT id, coins
             FROM gambling
-            WHERE id = ANY($1)
+            WHERE guild_id = $1 AND id = ANY($2)
             ORDER BY coins DESC
-            LIMIT $2
-            OFFSET $3
+            LIMIT $3
+            OFFSET $4
             "#,
+            economy.get(),
             users,
             LIMIT,
             offset
@@ -140,6 +150,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn coins_row_number(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>> {
         let user_id = id.into();
@@ -152,11 +163,14 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
                 ROW_NUMBER() OVER (ORDER BY coins DESC) as rn
             FROM
                 gambling
+            WHERE
+                guild_id = $1
         )
         SELECT rn
         FROM numbered_users
-        WHERE id = $1
+        WHERE id = $2
         "#,
+            economy.get(),
             user_id.get() as i64
         )
         .fetch_optional(pool)
@@ -166,6 +180,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn gems(
         pool: &PgPool,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>> {
@@ -176,11 +191,12 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             r#"
             SELECT id, gems
             FROM gambling
-            WHERE id = ANY($1)
+            WHERE guild_id = $1 AND id = ANY($2)
             ORDER BY gems DESC
-            LIMIT $2
-            OFFSET $3
+            LIMIT $3
+            OFFSET $4
             "#,
+            economy.get(),
             users,
             LIMIT,
             offset
@@ -193,6 +209,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn gems_row_number(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>> {
         let user_id = id.into();
@@ -205,11 +222,14 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
                 ROW_NUMBER() OVER (ORDER BY gems DESC) as rn
             FROM
                 gambling
+            WHERE
+                guild_id = $1
         )
         SELECT rn
         FROM numbered_users
-        WHERE id = $1
+        WHERE id = $2
         "#,
+            economy.get(),
             user_id.get() as i64
         )
         .fetch_optional(pool)
@@ -219,6 +239,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn eggplants(
         pool: &PgPool,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>> {
@@ -229,11 +250,12 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             r#"
             SELECT user_id, quantity
             FROM gambling_inventory
-            WHERE user_id = ANY($1) AND item_id = $2
+            WHERE guild_id = $1 AND user_id = ANY($2) AND item_id = $3
             ORDER BY quantity DESC
-            LIMIT $3
-            OFFSET $4
+            LIMIT $4
+            OFFSET $5
             "#,
+            economy.get(),
             users,
             EGGPLANT.id,
             LIMIT,
@@ -247,6 +269,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn eggplants_row_number(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
@@ -260,15 +283,17 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             FROM
                 gambling_inventory
             WHERE
-                item_id = $1
+                guild_id = $1
+                AND item_id = $2
         )
         SELECT
             row_num
         FROM
             RankedUsers
         WHERE
-            user_id = $2;
+            user_id = $3;
         "#,
+            economy.get(),
             EGGPLANT.id,
             id.get() as i64
         )
@@ -277,7 +302,11 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
         .map(|num| num.flatten())
     }
 
-    async fn lottotickets(pool: &PgPool, page_num: i64) -> sqlx::Result<Vec<LeaderboardRow>> {
+    async fn lottotickets(
+        pool: &PgPool,
+        economy: Economy,
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
         let offset = (page_num - 1) * LIMIT;
 
         sqlx::query_as!(
@@ -285,11 +314,12 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             r#"
             SELECT user_id, quantity
             FROM gambling_inventory
-            WHERE item_id = $1
+            WHERE guild_id = $1 AND item_id = $2
             ORDER BY quantity DESC
-            LIMIT $2
-            OFFSET $3
+            LIMIT $3
+            OFFSET $4
             "#,
+            economy.get(),
             LOTTO_TICKET.id,
             LIMIT,
             offset
@@ -302,6 +332,7 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
 
     async fn lottotickets_row_number(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
@@ -315,15 +346,17 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
             FROM
                 gambling_inventory
             WHERE
-                item_id = $1
+                guild_id = $1
+                AND item_id = $2
         )
         SELECT
             row_num
         FROM
             RankedUsers
         WHERE
-            user_id = $2;
+            user_id = $3;
         "#,
+            economy.get(),
             LOTTO_TICKET.id,
             id.get() as i64
         )
@@ -333,18 +366,355 @@ impl LeaderboardManager<Postgres> for LeaderboardTable {
     }
 }
 
+#[async_trait]
+impl LeaderboardManager<Sqlite> for LeaderboardTable {
+    async fn networth(
+        pool: &SqlitePool,
+        economy: Economy,
+        users: &[i64],
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page_num - 1) * LIMIT;
+
+        sqlx::query_as::<_, NetworthRow>(
+            r#"
+            SELECT
+                g.id,
+                (
+                    g.coins +
+                    COALESCE(gi_eggplants.quantity, 0) * $4 +
+                    COALESCE(gi_crates.quantity, 0) * $6
+                ) AS networth
+            FROM
+                gambling g
+            LEFT JOIN
+                gambling_inventory gi_eggplants ON g.guild_id = gi_eggplants.guild_id AND g.id = gi_eggplants.user_id
+                                            AND gi_eggplants.item_id = $3
+            LEFT JOIN
+                gambling_inventory gi_crates ON g.guild_id = gi_crates.guild_id AND g.id = gi_crates.user_id
+                                            AND gi_crates.item_id = $5
+            WHERE
+                g.guild_id = $1 AND g.id IN (SELECT value FROM json_each($2))
+            ORDER BY
+                networth DESC
+            LIMIT $7
+            OFFSET $8
+            "#,
+        )
+        .bind(economy.get())
+        .bind(Json(users))
+        .bind(EGGPLANT.id)
+        .bind(EGGPLANT.coin_cost().unwrap_or_default())
+        .bind(WEAPON_CRATE.id)
+        .bind(WEAPON_CRATE.coin_cost().unwrap_or_default())
+        .bind(LIMIT)
+        .bind(offset)
+        .fetch(pool)
+        .map_ok(LeaderboardRow::NetWorth)
+        .try_collect::<Vec<_>>()
+        .await
+    }
+
+    async fn networth_row_number(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let user_id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+            WITH user_networths AS (
+                SELECT
+                    g.id,
+                    (
+                        g.coins +
+                        COALESCE(gi_eggplants.quantity, 0) * $4 +
+                        COALESCE(gi_crates.quantity, 0) * $6
+                    ) AS networth_value
+                FROM
+                    gambling g
+                LEFT JOIN
+                    gambling_inventory gi_eggplants ON g.guild_id = gi_eggplants.guild_id AND g.id = gi_eggplants.user_id
+                                                AND gi_eggplants.item_id = $3
+                LEFT JOIN
+                    gambling_inventory gi_crates ON g.guild_id = gi_crates.guild_id AND g.id = gi_crates.user_id
+                                                AND gi_crates.item_id = $5
+                WHERE
+                    g.guild_id = $1
+            ),
+            ranked_users AS (
+                SELECT
+                    id,
+                    ROW_NUMBER() OVER (ORDER BY networth_value DESC) as rn
+                FROM
+                    user_networths
+            )
+            SELECT rn
+            FROM ranked_users
+            WHERE id = $2
+            "#,
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .bind(EGGPLANT.id)
+        .bind(EGGPLANT.coin_cost().unwrap_or_default())
+        .bind(WEAPON_CRATE.id)
+        .bind(WEAPON_CRATE.coin_cost().unwrap_or_default())
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn coins(
+        pool: &SqlitePool,
+        economy: Economy,
+        users: &[i64],
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page_num - 1) * LIMIT;
+
+        sqlx::query_as::<_, CoinsRow>(
+            r#"
+            SELECT id, coins
+            FROM gambling
+            WHERE guild_id = $1 AND id IN (SELECT value FROM json_each($2))
+            ORDER BY coins DESC
+            LIMIT $3
+            OFFSET $4
+            "#,
+        )
+        .bind(economy.get())
+        .bind(Json(users))
+        .bind(LIMIT)
+        .bind(offset)
+        .fetch(pool)
+        .map_ok(LeaderboardRow::Coins)
+        .try_collect::<Vec<_>>()
+        .await
+    }
+
+    async fn coins_row_number(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let user_id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+        WITH numbered_users AS (
+            SELECT
+                id,
+                ROW_NUMBER() OVER (ORDER BY coins DESC) as rn
+            FROM
+                gambling
+            WHERE
+                guild_id = $1
+        )
+        SELECT rn
+        FROM numbered_users
+        WHERE id = $2
+        "#,
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn gems(
+        pool: &SqlitePool,
+        economy: Economy,
+        users: &[i64],
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page_num - 1) * LIMIT;
+
+        sqlx::query_as::<_, GemsRow>(
+            r#"
+            SELECT id, gems
+            FROM gambling
+            WHERE guild_id = $1 AND id IN (SELECT value FROM json_each($2))
+            ORDER BY gems DESC
+            LIMIT $3
+            OFFSET $4
+            "#,
+        )
+        .bind(economy.get())
+        .bind(Json(users))
+        .bind(LIMIT)
+        .bind(offset)
+        .fetch(pool)
+        .map_ok(LeaderboardRow::Gems)
+        .try_collect::<Vec<_>>()
+        .await
+    }
+
+    async fn gems_row_number(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let user_id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+        WITH numbered_users AS (
+            SELECT
+                id,
+                ROW_NUMBER() OVER (ORDER BY gems DESC) as rn
+            FROM
+                gambling
+            WHERE
+                guild_id = $1
+        )
+        SELECT rn
+        FROM numbered_users
+        WHERE id = $2
+        "#,
+        )
+        .bind(economy.get())
+        .bind(user_id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn eggplants(
+        pool: &SqlitePool,
+        economy: Economy,
+        users: &[i64],
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page_num - 1) * LIMIT;
+
+        sqlx::query_as::<_, EggplantsRow>(
+            r#"
+            SELECT user_id, quantity
+            FROM gambling_inventory
+            WHERE guild_id = $1 AND user_id IN (SELECT value FROM json_each($2)) AND item_id = $3
+            ORDER BY quantity DESC
+            LIMIT $4
+            OFFSET $5
+            "#,
+        )
+        .bind(economy.get())
+        .bind(Json(users))
+        .bind(EGGPLANT.id)
+        .bind(LIMIT)
+        .bind(offset)
+        .fetch(pool)
+        .map_ok(LeaderboardRow::Eggplants)
+        .try_collect::<Vec<_>>()
+        .await
+    }
+
+    async fn eggplants_row_number(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+        WITH RankedUsers AS (
+            SELECT
+                user_id,
+                ROW_NUMBER() OVER (ORDER BY quantity DESC) as row_num
+            FROM
+                gambling_inventory
+            WHERE
+                guild_id = $1
+                AND item_id = $2
+        )
+        SELECT
+            row_num
+        FROM
+            RankedUsers
+        WHERE
+            user_id = $3;
+        "#,
+        )
+        .bind(economy.get())
+        .bind(EGGPLANT.id)
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn lottotickets(
+        pool: &SqlitePool,
+        economy: Economy,
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page_num - 1) * LIMIT;
+
+        sqlx::query_as::<_, LottoTicketRow>(
+            r#"
+            SELECT user_id, quantity
+            FROM gambling_inventory
+            WHERE guild_id = $1 AND item_id = $2
+            ORDER BY quantity DESC
+            LIMIT $3
+            OFFSET $4
+            "#,
+        )
+        .bind(economy.get())
+        .bind(LOTTO_TICKET.id)
+        .bind(LIMIT)
+        .bind(offset)
+        .fetch(pool)
+        .map_ok(LeaderboardRow::LottoTickets)
+        .try_collect::<Vec<_>>()
+        .await
+    }
+
+    async fn lottotickets_row_number(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+        WITH RankedUsers AS (
+            SELECT
+                user_id,
+                ROW_NUMBER() OVER (ORDER BY quantity DESC) as row_num
+            FROM
+                gambling_inventory
+            WHERE
+                guild_id = $1
+                AND item_id = $2
+        )
+        SELECT
+            row_num
+        FROM
+            RankedUsers
+        WHERE
+            user_id = $3;
+        "#,
+        )
+        .bind(economy.get())
+        .bind(LOTTO_TICKET.id)
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+}
+
 pub struct Leaderboard;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Leaderboard {
+impl<Db: Backend> SlashCommand<Error, Db> for Leaderboard {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::leaderboard::<Postgres, LeaderboardTable>(ctx, interaction, options, pool)
-            .await?;
+        Commands::leaderboard::<Db, Db::Leaderboard>(ctx, interaction, options, pool).await?;
 
         Ok(())
     }
@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 use bigdecimal::ToPrimitive;
 use gambling::shop::LOTTO_TICKET;
-use gambling::{Commands, LottoManager, LottoRow};
+use gambling::{Commands, Economy, LottoManager, LottoRow};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::any::AnyQueryResult;
-use sqlx::{PgConnection, PgPool, Postgres};
+use sqlx::{PgConnection, Postgres, Sqlite, SqliteConnection};
+use sqlx::{Pool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct LottoTable;
@@ -15,31 +16,35 @@ pub struct LottoTable;
 impl LottoManager<Postgres> for LottoTable {
     async fn row(
         conn: &mut PgConnection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<LottoRow>> {
         let id = id.into();
 
         sqlx::query_as!(
             LottoRow,
-            "SELECT g.id, g.coins, COALESCE(i.quantity, 0) AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.id = i.user_id AND i.item_id = $2 WHERE g.id = $1",
+            "SELECT g.id, g.coins, COALESCE(i.quantity, 0) AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.guild_id = i.guild_id AND g.id = i.user_id AND i.item_id = $3 WHERE g.guild_id = $1 AND g.id = $2",
+            economy.get(),
             id.get() as i64,
             LOTTO_TICKET.id
         ).fetch_optional(conn).await
     }
 
-    async fn rows(conn: &mut PgConnection) -> sqlx::Result<Vec<LottoRow>> {
+    async fn rows(conn: &mut PgConnection, economy: Economy) -> sqlx::Result<Vec<LottoRow>> {
         sqlx::query_as!(
             LottoRow,
-            "SELECT g.id, g.coins, i.quantity AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.id = i.user_id AND i.item_id = $1",
+            "SELECT g.id, g.coins, i.quantity AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.guild_id = i.guild_id AND g.id = i.user_id AND i.item_id = $2 WHERE g.guild_id = $1",
+            economy.get(),
             LOTTO_TICKET.id
         )
         .fetch_all(conn)
         .await
     }
 
-    async fn total_tickets(conn: &mut PgConnection) -> sqlx::Result<i64> {
+    async fn total_tickets(conn: &mut PgConnection, economy: Economy) -> sqlx::Result<i64> {
         sqlx::query_scalar!(
-            "SELECT SUM(quantity) FROM gambling_inventory WHERE item_id = $1",
+            "SELECT SUM(quantity) FROM gambling_inventory WHERE guild_id = $1 AND item_id = $2",
+            economy.get(),
             LOTTO_TICKET.id
         )
         .fetch_one(conn)
@@ -48,9 +53,13 @@ impl LottoManager<Postgres> for LottoTable {
         .map(|x| x.to_i64().unwrap_or_default())
     }
 
-    async fn delete_tickets(conn: &mut PgConnection) -> sqlx::Result<AnyQueryResult> {
+    async fn delete_tickets(
+        conn: &mut PgConnection,
+        economy: Economy,
+    ) -> sqlx::Result<AnyQueryResult> {
         sqlx::query!(
-            "DELETE FROM gambling_inventory WHERE item_id = $1",
+            "DELETE FROM gambling_inventory WHERE guild_id = $1 AND item_id = $2",
+            economy.get(),
             LOTTO_TICKET.id
         )
         .execute(conn)
@@ -60,33 +69,103 @@ impl LottoManager<Postgres> for LottoTable {
 
     async fn add_coins(
         conn: &mut PgConnection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult> {
+    ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
 
-        sqlx::query!(
-            "UPDATE gambling SET coins = coins + $2 WHERE id = $1",
+        sqlx::query_scalar!(
+            "UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins",
+            economy.get(),
             id.get() as i64,
             amount
         )
-        .execute(conn)
+        .fetch_optional(conn)
         .await
-        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl LottoManager<Sqlite> for LottoTable {
+    async fn row(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<LottoRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT g.id, g.coins, COALESCE(i.quantity, 0) AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.guild_id = i.guild_id AND g.id = i.user_id AND i.item_id = $3 WHERE g.guild_id = $1 AND g.id = $2",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .bind(LOTTO_TICKET.id)
+        .fetch_optional(conn)
+        .await
+    }
+
+    async fn rows(conn: &mut SqliteConnection, economy: Economy) -> sqlx::Result<Vec<LottoRow>> {
+        sqlx::query_as(
+            "SELECT g.id, g.coins, i.quantity AS quantity FROM gambling g LEFT JOIN gambling_inventory i ON g.guild_id = i.guild_id AND g.id = i.user_id AND i.item_id = $2 WHERE g.guild_id = $1",
+        )
+        .bind(economy.get())
+        .bind(LOTTO_TICKET.id)
+        .fetch_all(conn)
+        .await
+    }
+
+    async fn total_tickets(conn: &mut SqliteConnection, economy: Economy) -> sqlx::Result<i64> {
+        sqlx::query_scalar::<_, Option<i64>>(
+            "SELECT SUM(quantity) FROM gambling_inventory WHERE guild_id = $1 AND item_id = $2",
+        )
+        .bind(economy.get())
+        .bind(LOTTO_TICKET.id)
+        .fetch_one(conn)
+        .await
+        .map(|x| x.unwrap_or_default())
+    }
+
+    async fn delete_tickets(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM gambling_inventory WHERE guild_id = $1 AND item_id = $2")
+            .bind(economy.get())
+            .bind(LOTTO_TICKET.id)
+            .execute(conn)
+            .await
+            .map(AnyQueryResult::from)
+    }
+
+    async fn add_coins(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar("UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins")
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .bind(amount)
+            .fetch_optional(conn)
+            .await
     }
 }
 
 pub struct Lotto;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Lotto {
+impl<Db: Backend> SlashCommand<Error, Db> for Lotto {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::lotto::<Postgres, LottoTable>(ctx, interaction, pool).await?;
+        Commands::lotto::<Db, Db::Lotto>(ctx, interaction, pool).await?;
         Ok(())
     }
 
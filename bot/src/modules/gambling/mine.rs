@@ -1,38 +1,63 @@
 use async_trait::async_trait;
-use gambling::Commands;
 use gambling::commands::mine::{MineManager, MineRow};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgPool, Pool, Postgres, Sqlite, SqlitePool};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct MineTable;
 
 #[async_trait]
 impl MineManager<Postgres> for MineTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<MineRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<MineRow>> {
         let id = id.into();
 
         sqlx::query_as!(
             MineRow,
-            "SELECT miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige FROM gambling_mine WHERE id = $1",
+            "SELECT miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige FROM gambling_mine WHERE guild_id = $1 AND id = $2",
+            economy.get(),
             id.get() as i64
         ).fetch_optional(pool).await
     }
 }
 
+#[async_trait]
+impl MineManager<Sqlite> for MineTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<MineRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige FROM gambling_mine WHERE guild_id = $1 AND id = $2",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+}
+
 pub struct Mine;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Mine {
+impl<Db: Backend> SlashCommand<Error, Db> for Mine {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::mine::<Postgres, MineTable>(ctx, interaction, pool).await?;
+        Commands::mine::<Db, Db::Mine>(ctx, interaction, pool).await?;
 
         Ok(())
     }
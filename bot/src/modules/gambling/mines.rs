@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use gambling::{Commands, Components};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateCommand, ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{Component, SlashCommand};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct Mines;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Mines {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        Commands::mines::<Db, Db::Gambling, Db::Effects, Db::Game, Db::Sessions, Db::Ledger>(
+            ctx,
+            interaction,
+            options,
+            pool,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        Ok(Commands::register_mines())
+    }
+}
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for Mines {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        Components::mines::<Db, Db::Goals, Db::Effects, Db::Game, Db::Sessions, Db::Ledger>(
+            ctx,
+            interaction,
+            pool,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
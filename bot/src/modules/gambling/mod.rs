@@ -1,105 +1,123 @@
 use async_trait::async_trait;
 use gambling::{GamblingManager, GameManager, GameRow};
-use serenity::all::{Context, CreateCommand, UserId};
-use sqlx::{PgConnection, PgPool, Postgres, any::AnyQueryResult, postgres::PgQueryResult};
-use zayden_core::SlashCommand;
+use serenity::all::UserId;
+use sqlx::sqlite::SqliteQueryResult;
+use sqlx::{
+    Database, PgConnection, PgPool, Pool, Postgres, Sqlite, SqliteConnection, SqlitePool,
+    postgres::PgQueryResult,
+};
+use zayden_core::Registry;
+
+use crate::Error;
+use crate::backend::Backend;
 
 mod blackjack;
 mod coinflip;
 mod craft;
+mod crash;
 mod daily;
 mod dig;
+mod economy;
 mod effects;
 mod gift;
 mod goals;
 mod higher_lower;
+mod history;
 mod inventory;
 mod leaderboard;
 mod lotto;
 mod mine;
+mod mines;
+mod poker;
 mod prestige;
 mod profile;
 mod roll;
+mod roulette;
 mod rps;
 mod send;
+mod sessions;
 mod shop;
+mod slots;
 mod stamina;
 mod tictactoe;
 mod work;
 
 pub use blackjack::Blackjack;
 pub use coinflip::Coinflip;
-pub use craft::Craft;
-pub use daily::Daily;
-pub use dig::Dig;
+pub use craft::{Craft, CraftTable};
+pub use crash::Crash;
+pub use daily::{Daily, DailyTable};
+pub use dig::{Dig, DigTable};
+pub use economy::{Economy, EconomyTable};
 pub use effects::EffectsTable;
-pub use gift::Gift;
+pub use gift::{Gift, GiftTable};
 pub use goals::{Goals, GoalsTable};
 pub use higher_lower::HigherLower;
-pub use inventory::Inventory;
-pub use leaderboard::Leaderboard;
+pub use history::{History, LedgerTable};
+pub use inventory::{Inventory, InventoryTable};
+pub use leaderboard::{Leaderboard, LeaderboardTable};
 pub use lotto::{Lotto, LottoTable};
 pub use mine::{Mine, MineTable};
-pub use prestige::Prestige;
-pub use profile::Profile;
+pub use mines::Mines;
+pub use poker::Poker;
+pub use prestige::{Prestige, PrestigeTable};
+pub use profile::{Profile, ProfileTable};
 pub use roll::Roll;
+pub use roulette::Roulette;
 pub use rps::RockPaperScissors;
-pub use send::Send;
-pub use shop::Shop;
+pub use send::{Send, SendTable};
+pub use sessions::SessionTable;
+pub use shop::{Shop, ShopTable};
+pub use slots::Slots;
 pub use stamina::StaminaTable;
 pub use tictactoe::TicTacToe;
-pub use work::Work;
-
-pub fn register(ctx: &Context) -> [CreateCommand; 20] {
-    [
-        Blackjack::register(ctx).unwrap(),
-        Coinflip::register(ctx).unwrap(),
-        Craft::register(ctx).unwrap(),
-        Daily::register(ctx).unwrap(),
-        Dig::register(ctx).unwrap(),
-        Gift::register(ctx).unwrap(),
-        Goals::register(ctx).unwrap(),
-        HigherLower::register(ctx).unwrap(),
-        Inventory::register(ctx).unwrap(),
-        Leaderboard::register(ctx).unwrap(),
-        Lotto::register(ctx).unwrap(),
-        Mine::register(ctx).unwrap(),
-        Prestige::register(ctx).unwrap(),
-        Profile::register(ctx).unwrap(),
-        Roll::register(ctx).unwrap(),
-        RockPaperScissors::register(ctx).unwrap(),
-        Send::register(ctx).unwrap(),
-        Shop::register(ctx).unwrap(),
-        TicTacToe::register(ctx).unwrap(),
-        Work::register(ctx).unwrap(),
-    ]
+pub use work::{Work, WorkTable};
+
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<Blackjack>("blackjack")
+        .component::<Blackjack>("blackjack_")
+        .command::<Coinflip>("coinflip")
+        .command::<Craft>("craft")
+        .command::<Crash>("crash")
+        .command::<Daily>("daily")
+        .command::<Dig>("dig")
+        .command::<Economy>("economy")
+        .command::<Gift>("gift")
+        .command::<Goals>("goals")
+        .command::<HigherLower>("higherorlower")
+        .component::<HigherLower>("hol_")
+        .command::<History>("history")
+        .command::<Inventory>("inventory")
+        .command::<Leaderboard>("leaderboard")
+        .command::<Lotto>("lotto")
+        .command::<Mine>("mine")
+        .command::<Mines>("mines")
+        .component::<Mines>("mines_")
+        .command::<Poker>("poker")
+        .component::<Poker>("poker_")
+        .command::<Prestige>("prestige")
+        .command::<Profile>("profile")
+        .command::<Roll>("roll")
+        .command::<Roulette>("roulette")
+        .component::<Roulette>("roulette_")
+        .modal::<Roulette>("roulette_")
+        .command::<RockPaperScissors>("rps")
+        .command::<Send>("send")
+        .command::<Shop>("shop")
+        .command::<Slots>("slots")
+        .command::<TicTacToe>("tictactoe")
+        .component::<TicTacToe>("ttt_")
+        .command::<Work>("work")
 }
 
 pub struct GamblingTable;
 
-impl GamblingTable {
-    pub async fn add_coins(
-        pool: &PgPool,
-        id: impl Into<UserId>,
-        amount: i64,
-    ) -> sqlx::Result<AnyQueryResult> {
-        let id = id.into();
-
-        sqlx::query!(
-            "UPDATE gambling SET coins = coins + $2 WHERE id = $1",
-            id.get() as i64,
-            amount
-        )
-        .execute(pool)
-        .await
-        .map(|r| r.into())
-    }
-}
-
 #[async_trait]
 impl GamblingManager<Postgres> for GamblingTable {
     async fn max_bet(
         conn: &mut PgConnection,
+        economy: gambling::Economy,
         id: impl Into<UserId> + std::marker::Send,
     ) -> sqlx::Result<i64> {
         let id = id.into();
@@ -114,10 +132,11 @@ impl GamblingManager<Postgres> for GamblingTable {
             FROM
                 levels l
             LEFT JOIN
-                gambling_mine m ON l.id = m.id
+                gambling_mine m ON l.id = m.id AND m.guild_id = $1
             WHERE
-                l.id = $1
+                l.id = $2
             "#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_one(conn)
@@ -126,7 +145,8 @@ impl GamblingManager<Postgres> for GamblingTable {
     }
 
     async fn bet(
-        pool: &PgPool,
+        conn: &mut PgConnection,
+        economy: gambling::Economy,
         id: impl Into<UserId> + std::marker::Send,
         bet: i64,
     ) -> sqlx::Result<PgQueryResult> {
@@ -134,10 +154,11 @@ impl GamblingManager<Postgres> for GamblingTable {
 
         sqlx::query_file!(
             "./sql/gambling/GamblingManager/bet.sql",
+            economy.get(),
             id.get() as i64,
             bet
         )
-        .execute(pool)
+        .execute(conn)
         .await
     }
 }
@@ -148,6 +169,7 @@ pub struct GameTable;
 impl GameManager<Postgres> for GameTable {
     async fn row(
         pool: &PgPool,
+        economy: gambling::Economy,
         id: impl Into<UserId> + std::marker::Send,
     ) -> sqlx::Result<Option<GameRow>> {
         let id = id.into();
@@ -155,23 +177,150 @@ impl GameManager<Postgres> for GameTable {
         sqlx::query_file_as!(
             GameRow,
             "./sql/gambling/GameManager/row.sql",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn save(pool: &PgPool, row: GameRow) -> sqlx::Result<PgQueryResult> {
+    async fn save(
+        conn: &mut PgConnection,
+        economy: gambling::Economy,
+        row: GameRow,
+    ) -> sqlx::Result<PgQueryResult> {
         sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems)
-            VALUES ($1, $2, $3)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
         )
-        .execute(pool)
+        .execute(conn)
+        .await
+    }
+
+    async fn add_coins(
+        conn: &mut PgConnection,
+        economy: gambling::Economy,
+        id: impl Into<UserId> + std::marker::Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar!(
+            "UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins",
+            economy.get(),
+            id.get() as i64,
+            amount
+        )
+        .fetch_optional(conn)
+        .await
+    }
+}
+
+#[async_trait]
+impl GamblingManager<Sqlite> for GamblingTable {
+    async fn max_bet(
+        conn: &mut SqliteConnection,
+        economy: gambling::Economy,
+        id: impl Into<UserId> + std::marker::Send,
+    ) -> sqlx::Result<i64> {
+        let id = id.into();
+
+        sqlx::query_scalar(
+            r#"
+            SELECT
+                (
+                    MAX(l.level * 10000, 10000)
+                    * (COALESCE(m.prestige, 0) + 10)
+                ) / 10
+            FROM
+                levels l
+            LEFT JOIN
+                gambling_mine m ON l.id = m.id AND m.guild_id = $1
+            WHERE
+                l.id = $2
+            "#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_one(conn)
+        .await
+    }
+
+    async fn bet(
+        conn: &mut SqliteConnection,
+        economy: gambling::Economy,
+        id: impl Into<UserId> + std::marker::Send,
+        bet: i64,
+    ) -> sqlx::Result<SqliteQueryResult> {
+        let id = id.into();
+
+        sqlx::query(include_str!(
+            "../../../sql/gambling/GamblingManager/bet.sql"
+        ))
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .bind(bet)
+        .execute(conn)
+        .await
+    }
+}
+
+#[async_trait]
+impl GameManager<Sqlite> for GameTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: gambling::Economy,
+        id: impl Into<UserId> + std::marker::Send,
+    ) -> sqlx::Result<Option<GameRow>> {
+        let id = id.into();
+
+        sqlx::query_as(include_str!("../../../sql/gambling/GameManager/row.sql"))
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: gambling::Economy,
+        row: GameRow,
+    ) -> sqlx::Result<SqliteQueryResult> {
+        sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .execute(conn)
+        .await
+    }
+
+    async fn add_coins(
+        conn: &mut SqliteConnection,
+        economy: gambling::Economy,
+        id: impl Into<UserId> + std::marker::Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar(
+            "UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .bind(amount)
+        .fetch_optional(conn)
         .await
     }
 }
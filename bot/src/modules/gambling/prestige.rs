@@ -1,12 +1,13 @@
 use async_trait::async_trait;
 use gambling::commands::prestige::{PrestigeManager, PrestigeRow};
-use gambling::{Commands, GamblingItem};
+use gambling::{Commands, Economy, GamblingItem};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::any::AnyQueryResult;
 use sqlx::types::Json;
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use sqlx::{Pool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 use super::stamina::MAX_STAMINA;
@@ -15,18 +16,27 @@ pub struct PrestigeTable;
 
 #[async_trait]
 impl PrestigeManager<Postgres> for PrestigeTable {
-    async fn miners(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<i64>> {
+    async fn miners(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
 
         sqlx::query_scalar!(
-            "SELECT miners FROM gambling_mine WHERE id = $1;",
+            "SELECT miners FROM gambling_mine WHERE guild_id = $1 AND id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<PrestigeRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<PrestigeRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -45,7 +55,7 @@ impl PrestigeManager<Postgres> for PrestigeTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>",
                 
                 m.miners,
@@ -70,60 +80,67 @@ impl PrestigeManager<Postgres> for PrestigeTable {
                 m.production
 
                 FROM gambling g
-                LEFT JOIN gambling_inventory i on g.id = i.id
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;"#,
+                LEFT JOIN gambling_inventory i on g.guild_id = i.guild_id AND g.id = i.id
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn save(pool: &PgPool, row: PrestigeRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: PrestigeRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems, stamina)
-            VALUES ($1, $2, $3, $4)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
             MAX_STAMINA,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         let result2 = sqlx::query!(
             "DELETE FROM gambling_inventory
-            WHERE user_id = $1;",
+            WHERE guild_id = $1 AND user_id = $2;",
+            economy.get(),
             row.id,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         let result3 = sqlx::query!(
-            "INSERT INTO gambling_inventory (user_id, item_id, quantity)
+            "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
             SELECT
-                $1 AS user_id,
+                $1 AS guild_id,
+                $2 AS user_id,
                 (elem->>'item_id')::TEXT AS item_id,
                 (elem->>'quantity')::INTEGER AS quantity
             FROM
-                jsonb_array_elements($2::JSONB) AS elem;",
+                jsonb_array_elements($3::JSONB) AS elem;",
+            economy.get(),
             row.id,
             serde_json::to_value(row.inventory.unwrap_or_default().0).unwrap()
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         let result4 = sqlx::query!(
-            "INSERT INTO gambling_mine (id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling_mine (guild_id, id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
                 miners = EXCLUDED.miners,
                 mines = EXCLUDED.mines,
                 land = EXCLUDED.land,
@@ -144,6 +161,7 @@ impl PrestigeManager<Postgres> for PrestigeTable {
                 tech = EXCLUDED.tech,
                 utility = EXCLUDED.utility,
                 production = EXCLUDED.production;",
+                economy.get(),
                 row.id,
                 row.miners,
                 row.mines,
@@ -165,11 +183,183 @@ impl PrestigeManager<Postgres> for PrestigeTable {
                 row.tech,
                 row.utility,
                 row.production
-            ).execute(&mut *tx)
+            ).execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2, result3, result4]);
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl PrestigeManager<Sqlite> for PrestigeTable {
+    async fn miners(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar("SELECT miners FROM gambling_mine WHERE guild_id = $1 AND id = $2;")
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<PrestigeRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"SELECT
+                g.id,
+                g.coins,
+                g.gems,
+                g.stamina,
+
+                (
+                    SELECT json_group_array(
+                        json_object(
+                            'quantity', inv.quantity,
+                            'item_id', inv.item_id
+                        )
+                    )
+                    FROM gambling_inventory inv
+                    WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+                ) as inventory,
+
+                m.miners,
+                m.mines,
+                m.land,
+                m.countries,
+                m.continents,
+                m.planets,
+                m.solar_systems,
+                m.galaxies,
+                m.universes,
+                m.prestige,
+                m.coal,
+                m.iron,
+                m.gold,
+                m.redstone,
+                m.lapis,
+                m.diamonds,
+                m.emeralds,
+                m.tech,
+                m.utility,
+                m.production
+
+                FROM gambling g
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: PrestigeRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .bind(MAX_STAMINA)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
-        tx.commit().await?;
+        let result2 =
+            sqlx::query("DELETE FROM gambling_inventory WHERE guild_id = $1 AND user_id = $2;")
+                .bind(economy.get())
+                .bind(row.id)
+                .execute(&mut *conn)
+                .await
+                .map(AnyQueryResult::from)?;
+
+        let result3 = sqlx::query(
+            "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+            SELECT
+                $1 AS guild_id,
+                $2 AS user_id,
+                json_extract(elem.value, '$.item_id') AS item_id,
+                json_extract(elem.value, '$.quantity') AS quantity
+            FROM
+                json_each($3) AS elem;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.inventory.unwrap_or_default())
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result4 = sqlx::query(
+            "INSERT INTO gambling_mine (guild_id, id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+                miners = EXCLUDED.miners,
+                mines = EXCLUDED.mines,
+                land = EXCLUDED.land,
+                countries = EXCLUDED.countries,
+                continents = EXCLUDED.continents,
+                planets = EXCLUDED.planets,
+                solar_systems = EXCLUDED.solar_systems,
+                galaxies = EXCLUDED.galaxies,
+                universes = EXCLUDED.universes,
+                prestige = EXCLUDED.prestige,
+                coal = EXCLUDED.coal,
+                iron = EXCLUDED.iron,
+                gold = EXCLUDED.gold,
+                redstone = EXCLUDED.redstone,
+                lapis = EXCLUDED.lapis,
+                diamonds = EXCLUDED.diamonds,
+                emeralds = EXCLUDED.emeralds,
+                tech = EXCLUDED.tech,
+                utility = EXCLUDED.utility,
+                production = EXCLUDED.production;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.miners)
+        .bind(row.mines)
+        .bind(row.land)
+        .bind(row.countries)
+        .bind(row.continents)
+        .bind(row.planets)
+        .bind(row.solar_systems)
+        .bind(row.galaxies)
+        .bind(row.universes)
+        .bind(row.prestige)
+        .bind(row.coal)
+        .bind(row.iron)
+        .bind(row.gold)
+        .bind(row.redstone)
+        .bind(row.lapis)
+        .bind(row.diamonds)
+        .bind(row.emeralds)
+        .bind(row.tech)
+        .bind(row.utility)
+        .bind(row.production)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
 
         result.extend([result2, result3, result4]);
 
@@ -180,14 +370,14 @@ impl PrestigeManager<Postgres> for PrestigeTable {
 pub struct Prestige;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Prestige {
+impl<Db: Backend> SlashCommand<Error, Db> for Prestige {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::prestige::<Postgres, PrestigeTable>(ctx, interaction, pool).await?;
+        Commands::prestige::<Db, Db::Prestige, Db::Ledger>(ctx, interaction, pool).await?;
         Ok(())
     }
 
@@ -1,18 +1,23 @@
 use async_trait::async_trait;
 use gambling::commands::profile::{ProfileManager, ProfileRow};
-use gambling::{Commands, GamblingItem};
+use gambling::{Commands, Economy, GamblingItem};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::types::Json;
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
+use sqlx::{Pool, types::Json};
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct ProfileTable;
 
 #[async_trait]
 impl ProfileManager<Postgres> for ProfileTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<ProfileRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<ProfileRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -32,15 +37,16 @@ impl ProfileManager<Postgres> for ProfileTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>",
 
             COALESCE(m.prestige, 0) as prestige
             
             FROM gambling g
             LEFT JOIN levels l ON g.id = l.id
-            LEFT JOIN gambling_mine m on g.id = m.id
-            WHERE g.id = $1;"#,
+            LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+            WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -48,17 +54,80 @@ impl ProfileManager<Postgres> for ProfileTable {
     }
 }
 
+#[async_trait]
+impl ProfileManager<Sqlite> for ProfileTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<ProfileRow>> {
+        let id = id.into();
+
+        let row = sqlx::query_as::<
+            _,
+            (
+                i64,
+                i64,
+                Option<i32>,
+                Option<i32>,
+                Option<Json<Vec<GamblingItem>>>,
+                Option<i64>,
+            ),
+        >(
+            r#"SELECT
+            g.coins,
+            g.gems,
+
+            COALESCE(l.xp, 0) AS xp,
+            COALESCE(l.level, 0) AS level,
+
+            (
+                SELECT json_group_array(
+                    json_object(
+                        'quantity', inv.quantity,
+                        'item_id', inv.item_id
+                    )
+                )
+                FROM gambling_inventory inv
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+            ) as inventory,
+
+            COALESCE(m.prestige, 0) as prestige
+
+            FROM gambling g
+            LEFT JOIN levels l ON g.id = l.id
+            LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+            WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(
+            row.map(|(coins, gems, xp, level, inventory, prestige)| ProfileRow {
+                coins,
+                gems,
+                inventory,
+                xp,
+                level,
+                prestige,
+            }),
+        )
+    }
+}
+
 pub struct Profile;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Profile {
+impl<Db: Backend> SlashCommand<Error, Db> for Profile {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::profile::<Postgres, ProfileTable>(ctx, interaction, options, pool).await?;
+        Commands::profile::<Db, Db::Profile>(ctx, interaction, options, pool).await?;
 
         Ok(())
     }
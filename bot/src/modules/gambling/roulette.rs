@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use gambling::{Commands, Components, Modals};
+use serenity::all::{
+    ActionRow, CommandInteraction, ComponentInteraction, Context, CreateCommand, ModalInteraction,
+    ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{Component, Modal, SlashCommand};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct Roulette;
+
+#[async_trait]
+impl<Db: Backend> SlashCommand<Error, Db> for Roulette {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        Commands::roulette::<Db, Db::Sessions>(ctx, interaction, options, pool).await?;
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand> {
+        Ok(Commands::register_roulette())
+    }
+}
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for Roulette {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        Components::roulette::<
+            Db,
+            Db::Gambling,
+            Db::Goals,
+            Db::Effects,
+            Db::Game,
+            Db::Sessions,
+            Db::Ledger,
+        >(ctx, interaction, pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for Roulette {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        Modals::roulette::<Db, Db::Sessions>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
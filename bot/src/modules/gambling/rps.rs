@@ -1,24 +1,23 @@
 use async_trait::async_trait;
 use gambling::Commands;
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::{EffectsTable, GamblingTable, GameTable, GoalsTable};
-
 pub struct RockPaperScissors;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for RockPaperScissors {
+impl<Db: Backend> SlashCommand<Error, Db> for RockPaperScissors {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::rps::<Postgres, GamblingTable, GoalsTable, EffectsTable, GameTable>(
+        Commands::rps::<Db, Db::Gambling, Db::Goals, Db::Effects, Db::Game, Db::Ledger>(
             ctx,
             interaction,
             options,
@@ -1,22 +1,21 @@
 use async_trait::async_trait;
-use gambling::Commands;
 use gambling::commands::send::{SendManager, SendRow};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::any::AnyQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use sqlx::{Pool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::StaminaTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::goals::GoalsTable;
-
 pub struct SendTable;
 
 #[async_trait]
 impl SendManager<Postgres> for SendTable {
     async fn row(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + std::marker::Send,
     ) -> sqlx::Result<Option<SendRow>> {
         let id = id.into();
@@ -35,8 +34,9 @@ impl SendManager<Postgres> for SendTable {
 
                 FROM gambling g
                 LEFT JOIN levels l ON g.id = l.id
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;",
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -44,34 +44,120 @@ impl SendManager<Postgres> for SendTable {
     }
 
     async fn add_coins(
-        pool: &PgPool,
+        conn: &mut PgConnection,
+        economy: Economy,
         id: impl Into<UserId> + std::marker::Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult> {
+    ) -> sqlx::Result<Option<i64>> {
         let id = id.into();
 
-        sqlx::query!(
-            "UPDATE gambling SET coins = coins + $2 WHERE id = $1",
+        sqlx::query_scalar!(
+            "UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins",
+            economy.get(),
             id.get() as i64,
             amount
         )
-        .execute(pool)
+        .fetch_optional(conn)
         .await
-        .map(AnyQueryResult::from)
     }
 
-    async fn save(pool: &PgPool, row: SendRow) -> sqlx::Result<AnyQueryResult> {
+    async fn save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: SendRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems, stamina)
-            VALUES ($1, $2, $3, $4)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
             row.stamina
         )
-        .execute(pool)
+        .execute(conn)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl SendManager<Sqlite> for SendTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + std::marker::Send,
+    ) -> sqlx::Result<Option<SendRow>> {
+        let id = id.into();
+
+        let row = sqlx::query_as::<_, (i64, i64, i64, i32, Option<i32>, i64)>(
+            "SELECT
+                g.id,
+                g.coins,
+                g.gems,
+                g.stamina,
+
+                COALESCE(l.level, 0) AS level,
+
+                COALESCE(m.prestige, 0) AS prestige
+
+                FROM gambling g
+                LEFT JOIN levels l ON g.id = l.id
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(
+            row.map(|(id, coins, gems, stamina, level, prestige)| SendRow {
+                id,
+                coins,
+                gems,
+                stamina,
+                level,
+                prestige,
+            }),
+        )
+    }
+
+    async fn add_coins(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        id: impl Into<UserId> + std::marker::Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = id.into();
+
+        sqlx::query_scalar("UPDATE gambling SET coins = coins + $3 WHERE guild_id = $1 AND id = $2 RETURNING coins")
+            .bind(economy.get())
+            .bind(id.get() as i64)
+            .bind(amount)
+            .fetch_optional(conn)
+            .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: SendRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .bind(row.stamina)
+        .execute(conn)
         .await
         .map(AnyQueryResult::from)
     }
@@ -80,14 +166,14 @@ impl SendManager<Postgres> for SendTable {
 pub struct Send;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Send {
+impl<Db: Backend> SlashCommand<Error, Db> for Send {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::send::<Postgres, StaminaTable, GoalsTable, SendTable>(
+        Commands::send::<Db, Db::Stamina, Db::Goals, Db::Sends, Db::Ledger>(
             ctx,
             interaction,
             options,
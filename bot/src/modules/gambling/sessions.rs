@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use gambling::sessions::Session;
+use gambling::{SessionManager, SessionRow};
+use serenity::all::MessageId;
+use sqlx::any::AnyQueryResult;
+use sqlx::types::Json;
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+
+pub struct SessionTable;
+
+#[async_trait]
+impl SessionManager<Postgres> for SessionTable {
+    async fn row(
+        pool: &PgPool,
+        message_id: impl Into<MessageId> + Send,
+    ) -> sqlx::Result<Option<SessionRow>> {
+        let message_id = message_id.into();
+
+        sqlx::query_as!(
+            SessionRow,
+            r#"SELECT message_id, channel_id, state AS "state: Json<Session>", expires_at, version FROM game_sessions WHERE message_id = $1"#,
+            message_id.get() as i64
+        )
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn insert(pool: &PgPool, row: &SessionRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query!(
+            "INSERT INTO game_sessions (message_id, channel_id, state, expires_at) VALUES ($1, $2, $3, $4)",
+            row.message_id,
+            row.channel_id,
+            &row.state as &Json<Session>,
+            row.expires_at
+        )
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn update(conn: &mut PgConnection, row: &SessionRow) -> sqlx::Result<bool> {
+        let result = sqlx::query!(
+            "UPDATE game_sessions SET state = $2, expires_at = $3, version = version + 1 WHERE message_id = $1 AND version = $4",
+            row.message_id,
+            &row.state as &Json<Session>,
+            row.expires_at,
+            row.version
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(
+        conn: &mut PgConnection,
+        message_id: impl Into<MessageId> + Send,
+        version: i64,
+    ) -> sqlx::Result<bool> {
+        let message_id = message_id.into();
+
+        let result = sqlx::query!(
+            "DELETE FROM game_sessions WHERE message_id = $1 AND version = $2",
+            message_id.get() as i64,
+            version
+        )
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn expired(pool: &PgPool, now: DateTime<Utc>) -> sqlx::Result<Vec<SessionRow>> {
+        sqlx::query_as!(
+            SessionRow,
+            r#"SELECT message_id, channel_id, state AS "state: Json<Session>", expires_at, version FROM game_sessions WHERE expires_at <= $1"#,
+            now
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
+
+type SqliteSessionRow = (i64, i64, Json<Session>, DateTime<Utc>, i64);
+
+fn sqlite_row(
+    (message_id, channel_id, state, expires_at, version): SqliteSessionRow,
+) -> SessionRow {
+    SessionRow {
+        message_id,
+        channel_id,
+        state,
+        expires_at,
+        version,
+    }
+}
+
+#[async_trait]
+impl SessionManager<Sqlite> for SessionTable {
+    async fn row(
+        pool: &SqlitePool,
+        message_id: impl Into<MessageId> + Send,
+    ) -> sqlx::Result<Option<SessionRow>> {
+        let message_id = message_id.into();
+
+        let row = sqlx::query_as::<_, SqliteSessionRow>(
+            "SELECT message_id, channel_id, state, expires_at, version FROM game_sessions WHERE message_id = $1",
+        )
+        .bind(message_id.get() as i64)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(sqlite_row))
+    }
+
+    async fn insert(pool: &SqlitePool, row: &SessionRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO game_sessions (message_id, channel_id, state, expires_at) VALUES ($1, $2, $3, $4)",
+        )
+        .bind(row.message_id)
+        .bind(row.channel_id)
+        .bind(&row.state)
+        .bind(row.expires_at)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn update(conn: &mut SqliteConnection, row: &SessionRow) -> sqlx::Result<bool> {
+        let result = sqlx::query(
+            "UPDATE game_sessions SET state = $2, expires_at = $3, version = version + 1 WHERE message_id = $1 AND version = $4",
+        )
+        .bind(row.message_id)
+        .bind(&row.state)
+        .bind(row.expires_at)
+        .bind(row.version)
+        .execute(conn)
+        .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn delete(
+        conn: &mut SqliteConnection,
+        message_id: impl Into<MessageId> + Send,
+        version: i64,
+    ) -> sqlx::Result<bool> {
+        let message_id = message_id.into();
+
+        let result =
+            sqlx::query("DELETE FROM game_sessions WHERE message_id = $1 AND version = $2")
+                .bind(message_id.get() as i64)
+                .bind(version)
+                .execute(conn)
+                .await?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn expired(pool: &SqlitePool, now: DateTime<Utc>) -> sqlx::Result<Vec<SessionRow>> {
+        let rows = sqlx::query_as::<_, SqliteSessionRow>(
+            "SELECT message_id, channel_id, state, expires_at, version FROM game_sessions WHERE expires_at <= $1",
+        )
+        .bind(now)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows.into_iter().map(sqlite_row).collect())
+    }
+}
@@ -1,19 +1,25 @@
 use async_trait::async_trait;
 use gambling::commands::shop::{BuyRow, ListRow, SellRow, ShopManager};
-use gambling::{Commands, GamblingItem};
+use gambling::{Commands, Economy, GamblingItem};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::types::Json;
-use sqlx::{PgPool, Postgres, any::AnyQueryResult};
+use sqlx::{
+    PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool, any::AnyQueryResult,
+};
+use sqlx::{Pool, types::Json};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::GoalsTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct ShopTable;
 
 #[async_trait]
 impl ShopManager<Postgres> for ShopTable {
-    async fn buy_row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<BuyRow>> {
+    async fn buy_row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<BuyRow>> {
         let id = id.into();
 
         sqlx::query_as!(BuyRow,
@@ -32,7 +38,7 @@ impl ShopManager<Postgres> for ShopTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>",
 
             COALESCE(m.miners, 0) AS "miners!",
@@ -49,38 +55,43 @@ impl ShopManager<Postgres> for ShopTable {
             COALESCE(m.utility, 0) AS "utility!",
             COALESCE(m.production, 0) AS "production!"
 
-            FROM gambling g LEFT JOIN levels l ON g.id = l.id LEFT JOIN gambling_mine m ON g.id = m.id WHERE g.id = $1;"#,
+            FROM gambling g LEFT JOIN levels l ON g.id = l.id LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         ).fetch_optional(pool).await
     }
 
-    async fn buy_save(pool: &PgPool, row: BuyRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn buy_save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: BuyRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems)
-            VALUES ($1, $2, $3)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         for item in row.inventory.unwrap_or_default().0 {
             let result2 = sqlx::query!(
-                "INSERT INTO gambling_inventory (user_id, item_id, quantity)
-                VALUES ($1, $2, $3)
-                ON CONFLICT (user_id, item_id) DO UPDATE
+                "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (guild_id, user_id, item_id) DO UPDATE
                 SET quantity = EXCLUDED.quantity",
+                economy.get(),
                 row.id,
                 item.item_id,
                 item.quantity
             )
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await
             .map(AnyQueryResult::from)?;
 
@@ -88,9 +99,9 @@ impl ShopManager<Postgres> for ShopTable {
         }
 
         let result3 = sqlx::query!(
-            "INSERT INTO gambling_mine (id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, tech, utility, production)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-            ON CONFLICT (id) DO UPDATE
+            "INSERT INTO gambling_mine (guild_id, id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (guild_id, id) DO UPDATE
             SET
             miners = EXCLUDED.miners,
             mines = EXCLUDED.mines,
@@ -104,6 +115,7 @@ impl ShopManager<Postgres> for ShopTable {
             tech = EXCLUDED.tech,
             utility = EXCLUDED.utility,
             production = EXCLUDED.production;",
+            economy.get(),
             row.id,
             row.miners,
             row.mines,
@@ -117,7 +129,7 @@ impl ShopManager<Postgres> for ShopTable {
             row.tech,
             row.utility,
             row.production,
-        ).execute(&mut *tx).await.map(AnyQueryResult::from)?;
+        ).execute(&mut *conn).await.map(AnyQueryResult::from)?;
 
         result.extend([result3]);
 
@@ -128,6 +140,7 @@ impl ShopManager<Postgres> for ShopTable {
 
     async fn list_row(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<ListRow>> {
         let id = id.into();
@@ -146,10 +159,11 @@ impl ShopManager<Postgres> for ShopTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>"
             
-            FROM gambling g LEFT JOIN levels l ON g.id = l.id WHERE g.id = $1;"#,
+            FROM gambling g LEFT JOIN levels l ON g.id = l.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
@@ -158,6 +172,7 @@ impl ShopManager<Postgres> for ShopTable {
 
     async fn sell_row(
         pool: &PgPool,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<SellRow>> {
         let id = id.into();
@@ -176,42 +191,47 @@ impl ShopManager<Postgres> for ShopTable {
                     )
                 )
                 FROM gambling_inventory inv
-                WHERE inv.user_id = g.id
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
             ) as "inventory: Json<Vec<GamblingItem>>"
             
-            FROM gambling g LEFT JOIN levels l ON g.id = l.id WHERE g.id = $1;"#,
+            FROM gambling g LEFT JOIN levels l ON g.id = l.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn sell_save(pool: &PgPool, row: SellRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn sell_save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: SellRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins)
-            VALUES ($1, $2)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins;",
+            economy.get(),
             row.id,
             row.coins,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         for item in row.inventory.unwrap_or_default().0 {
             let result2 = sqlx::query!(
-                "INSERT INTO gambling_inventory (user_id, item_id, quantity)
-                VALUES ($1, $2, $3)
-                ON CONFLICT (user_id, item_id) DO UPDATE
+                "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (guild_id, user_id, item_id) DO UPDATE
                 SET quantity = EXCLUDED.quantity",
+                economy.get(),
                 row.id,
                 item.item_id,
                 item.quantity
             )
-            .execute(&mut *tx)
+            .execute(&mut *conn)
             .await
             .map(AnyQueryResult::from)?;
 
@@ -224,17 +244,253 @@ impl ShopManager<Postgres> for ShopTable {
     }
 }
 
+#[async_trait]
+impl ShopManager<Sqlite> for ShopTable {
+    async fn buy_row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<BuyRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"SELECT
+            g.id,
+            g.coins,
+            g.gems,
+
+            COALESCE(l.level, 0) AS level,
+
+            (
+                SELECT json_group_array(
+                    json_object(
+                        'quantity', inv.quantity,
+                        'item_id', inv.item_id
+                    )
+                )
+                FROM gambling_inventory inv
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+            ) as inventory,
+
+            COALESCE(m.miners, 0) AS miners,
+            COALESCE(m.mines, 0) AS mines,
+            COALESCE(m.land, 0) AS land,
+            COALESCE(m.countries, 0) AS countries,
+            COALESCE(m.continents, 0) AS continents,
+            COALESCE(m.planets, 0) AS planets,
+            COALESCE(m.solar_systems, 0) AS solar_systems,
+            COALESCE(m.galaxies, 0) AS galaxies,
+            COALESCE(m.universes, 0) AS universes,
+            COALESCE(m.prestige, 0) AS prestige,
+            COALESCE(m.tech, 0) AS tech,
+            COALESCE(m.utility, 0) AS utility,
+            COALESCE(m.production, 0) AS production
+
+            FROM gambling g LEFT JOIN levels l ON g.id = l.id LEFT JOIN gambling_mine m ON g.guild_id = m.guild_id AND g.id = m.id WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn buy_save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: BuyRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        for item in row.inventory.unwrap_or_default().0 {
+            let result2 = sqlx::query(
+                "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (guild_id, user_id, item_id) DO UPDATE
+                SET quantity = EXCLUDED.quantity",
+            )
+            .bind(economy.get())
+            .bind(row.id)
+            .bind(item.item_id)
+            .bind(item.quantity)
+            .execute(&mut *conn)
+            .await
+            .map(AnyQueryResult::from)?;
+
+            result.extend([result2]);
+        }
+
+        let result3 = sqlx::query(
+            "INSERT INTO gambling_mine (guild_id, id, miners, mines, land, countries, continents, planets, solar_systems, galaxies, universes, tech, utility, production)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            ON CONFLICT (guild_id, id) DO UPDATE
+            SET
+            miners = EXCLUDED.miners,
+            mines = EXCLUDED.mines,
+            land = EXCLUDED.land,
+            countries = EXCLUDED.countries,
+            continents = EXCLUDED.continents,
+            planets = EXCLUDED.planets,
+            solar_systems = EXCLUDED.solar_systems,
+            galaxies = EXCLUDED.galaxies,
+            universes = EXCLUDED.universes,
+            tech = EXCLUDED.tech,
+            utility = EXCLUDED.utility,
+            production = EXCLUDED.production;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.miners)
+        .bind(row.mines)
+        .bind(row.land)
+        .bind(row.countries)
+        .bind(row.continents)
+        .bind(row.planets)
+        .bind(row.solar_systems)
+        .bind(row.galaxies)
+        .bind(row.universes)
+        .bind(row.tech)
+        .bind(row.utility)
+        .bind(row.production)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result3]);
+
+        Ok(result)
+    }
+
+    async fn list_row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<ListRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"SELECT
+            g.id,
+            g.coins,
+
+            (
+                SELECT json_group_array(
+                    json_object(
+                        'quantity', inv.quantity,
+                        'item_id', inv.item_id
+                    )
+                )
+                FROM gambling_inventory inv
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+            ) as inventory
+
+            FROM gambling g WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn sell_row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<SellRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"SELECT
+            g.id,
+            g.coins,
+
+            (
+                SELECT json_group_array(
+                    json_object(
+                        'quantity', inv.quantity,
+                        'item_id', inv.item_id
+                    )
+                )
+                FROM gambling_inventory inv
+                WHERE inv.guild_id = g.guild_id AND inv.user_id = g.id
+            ) as inventory
+
+            FROM gambling g WHERE g.guild_id = $1 AND g.id = $2;"#,
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn sell_save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: SellRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        for item in row.inventory.unwrap_or_default().0 {
+            let result2 = sqlx::query(
+                "INSERT INTO gambling_inventory (guild_id, user_id, item_id, quantity)
+                VALUES ($1, $2, $3, $4)
+                ON CONFLICT (guild_id, user_id, item_id) DO UPDATE
+                SET quantity = EXCLUDED.quantity",
+            )
+            .bind(economy.get())
+            .bind(row.id)
+            .bind(item.item_id)
+            .bind(item.quantity)
+            .execute(&mut *conn)
+            .await
+            .map(AnyQueryResult::from)?;
+
+            result.extend([result2]);
+        }
+
+        Ok(result)
+    }
+}
+
 pub struct Shop;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Shop {
+impl<Db: Backend> SlashCommand<Error, Db> for Shop {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::shop::<Postgres, GoalsTable, ShopTable>(ctx, interaction, options, pool).await?;
+        Commands::shop::<Db, Db::Goals, Db::Shop, Db::Permissions, Db::Ledger>(
+            ctx,
+            interaction,
+            options,
+            pool,
+        )
+        .await?;
         Ok(())
     }
 
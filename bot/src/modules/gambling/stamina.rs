@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use gambling::StaminaManager;
-use sqlx::{PgPool, Postgres, any::AnyQueryResult};
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool, any::AnyQueryResult};
 
 pub const MAX_STAMINA: i32 = 3;
 
@@ -18,3 +18,14 @@ impl StaminaManager<Postgres> for StaminaTable {
         .map(AnyQueryResult::from)
     }
 }
+
+#[async_trait]
+impl StaminaManager<Sqlite> for StaminaTable {
+    async fn update(pool: &SqlitePool) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("UPDATE gambling SET stamina = MIN(stamina + 1, $1)")
+            .bind(MAX_STAMINA)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+}
@@ -1,24 +1,25 @@
 use async_trait::async_trait;
-use gambling::Commands;
-use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
-use zayden_core::SlashCommand;
+use gambling::{Commands, Components};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, Context, CreateCommand, ResolvedOption,
+};
+use sqlx::Pool;
+use zayden_core::{Component, SlashCommand};
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::{EffectsTable, GamblingTable, GameTable, GoalsTable};
-
 pub struct TicTacToe;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for TicTacToe {
+impl<Db: Backend> SlashCommand<Error, Db> for TicTacToe {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::tictactoe::<Postgres, GamblingTable, GoalsTable, EffectsTable, GameTable>(
+        Commands::tictactoe::<Db, Db::Gambling, Db::Effects, Db::Game, Db::Sessions>(
             ctx,
             interaction,
             options,
@@ -33,3 +34,21 @@ impl SlashCommand<Error, Postgres> for TicTacToe {
         Ok(Commands::register_tictactoe())
     }
 }
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for TicTacToe {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        Components::tictactoe::<
+            Db,
+            Db::Gambling,
+            Db::Goals,
+            Db::Effects,
+            Db::Game,
+            Db::Sessions,
+            Db::Ledger,
+        >(ctx, interaction, pool)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,21 +1,23 @@
 use async_trait::async_trait;
-use gambling::Commands;
 use gambling::commands::work::{WorkManager, WorkRow};
+use gambling::{Commands, Economy};
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption, UserId};
-use sqlx::any::AnyQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::{PgConnection, PgPool, Postgres, Sqlite, SqliteConnection, SqlitePool};
+use sqlx::{Pool, any::AnyQueryResult};
 use zayden_core::SlashCommand;
 
-use crate::modules::gambling::StaminaTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::goals::GoalsTable;
-
 pub struct WorkTable;
 
 #[async_trait]
 impl WorkManager<Postgres> for WorkTable {
-    async fn row(pool: &PgPool, id: impl Into<UserId> + Send) -> sqlx::Result<Option<WorkRow>> {
+    async fn row(
+        pool: &PgPool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<WorkRow>> {
         let id = id.into();
 
         sqlx::query_as!(
@@ -34,46 +36,121 @@ impl WorkManager<Postgres> for WorkTable {
 
                 FROM gambling g
                 LEFT JOIN levels l ON g.id = l.id
-                LEFT JOIN gambling_mine m on g.id = m.id
-                WHERE g.id = $1;",
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+            economy.get(),
             id.get() as i64
         )
         .fetch_optional(pool)
         .await
     }
 
-    async fn save(pool: &PgPool, row: WorkRow) -> sqlx::Result<AnyQueryResult> {
-        let mut tx = pool.begin().await?;
-
+    async fn save(
+        conn: &mut PgConnection,
+        economy: Economy,
+        row: WorkRow,
+    ) -> sqlx::Result<AnyQueryResult> {
         let mut result = sqlx::query!(
-            "INSERT INTO gambling (id, coins, gems, stamina)
-            VALUES ($1, $2, $3, $4)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+            economy.get(),
             row.id,
             row.coins,
             row.gems,
             row.stamina
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         let result2 = sqlx::query!(
-            "INSERT INTO gambling_mine (id, mine_activity)
-            VALUES ($1, $2)
-            ON CONFLICT (id) DO UPDATE SET
+            "INSERT INTO gambling_mine (guild_id, id, mine_activity)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
             mine_activity = EXCLUDED.mine_activity;",
+            economy.get(),
             row.id,
             row.mine_activity,
         )
-        .execute(&mut *tx)
+        .execute(&mut *conn)
         .await
         .map(AnyQueryResult::from)?;
 
         result.extend([result2]);
 
-        tx.commit().await?;
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl WorkManager<Sqlite> for WorkTable {
+    async fn row(
+        pool: &SqlitePool,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<WorkRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT
+                g.id,
+                g.coins,
+                g.gems,
+                g.stamina,
+
+                COALESCE(l.level, 0) AS level,
+
+                COALESCE(m.miners, 0) AS miners,
+                COALESCE(m.prestige, 0) AS prestige,
+                COALESCE(m.mine_activity, datetime('now')) AS mine_activity
+
+                FROM gambling g
+                LEFT JOIN levels l ON g.id = l.id
+                LEFT JOIN gambling_mine m on g.guild_id = m.guild_id AND g.id = m.id
+                WHERE g.guild_id = $1 AND g.id = $2;",
+        )
+        .bind(economy.get())
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn save(
+        conn: &mut SqliteConnection,
+        economy: Economy,
+        row: WorkRow,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let mut result = sqlx::query(
+            "INSERT INTO gambling (guild_id, id, coins, gems, stamina)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            coins = EXCLUDED.coins, gems = EXCLUDED.gems, stamina = EXCLUDED.stamina;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.coins)
+        .bind(row.gems)
+        .bind(row.stamina)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        let result2 = sqlx::query(
+            "INSERT INTO gambling_mine (guild_id, id, mine_activity)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (guild_id, id) DO UPDATE SET
+            mine_activity = EXCLUDED.mine_activity;",
+        )
+        .bind(economy.get())
+        .bind(row.id)
+        .bind(row.mine_activity)
+        .execute(&mut *conn)
+        .await
+        .map(AnyQueryResult::from)?;
+
+        result.extend([result2]);
 
         Ok(result)
     }
@@ -82,14 +159,14 @@ impl WorkManager<Postgres> for WorkTable {
 pub struct Work;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Work {
+impl<Db: Backend> SlashCommand<Error, Db> for Work {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::work::<Postgres, StaminaTable, GoalsTable, WorkTable>(ctx, interaction, pool)
+        Commands::work::<Db, Db::Stamina, Db::Goals, Db::Work, Db::Ledger>(ctx, interaction, pool)
             .await?;
 
         Ok(())
@@ -1,69 +1,68 @@
 use async_trait::async_trait;
 use levels::Commands;
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::LevelsTable;
-
 pub struct Levels;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Levels {
+impl<Db: Backend> SlashCommand<Error, Db> for Levels {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        levels::Commands::levels::<Postgres, LevelsTable>(ctx, interaction, pool).await;
+        levels::Commands::levels::<Db, Db::Levels>(ctx, interaction, pool).await;
 
         Ok(())
     }
 
     fn register(_ctx: &Context) -> Result<CreateCommand> {
-        unimplemented!()
+        Ok(Commands::register_levels())
     }
 }
 
 pub struct Rank;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Rank {
+impl<Db: Backend> SlashCommand<Error, Db> for Rank {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::rank::<Postgres, LevelsTable>(ctx, interaction, options, pool).await;
+        Commands::rank::<Db, Db::Levels>(ctx, interaction, options, pool).await;
 
         Ok(())
     }
 
     fn register(_ctx: &Context) -> Result<CreateCommand> {
-        unimplemented!()
+        Ok(Commands::register_rank())
     }
 }
 
 pub struct Xp;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Xp {
+impl<Db: Backend> SlashCommand<Error, Db> for Xp {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        Commands::xp::<Postgres, LevelsTable>(ctx, interaction, options, pool).await;
+        Commands::xp::<Db, Db::Levels>(ctx, interaction, options, pool).await;
 
         Ok(())
     }
 
     fn register(_ctx: &Context) -> Result<CreateCommand> {
-        unimplemented!()
+        Ok(Commands::register_xp())
     }
 }
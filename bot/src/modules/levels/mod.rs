@@ -7,7 +7,19 @@ pub use levels::Commands;
 use levels::{FullLevelRow, LeaderboardRow, LevelsManager, RankRow, XpRow};
 use serenity::all::UserId;
 use sqlx::any::AnyQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::types::Json;
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
+use zayden_core::Registry;
+
+use crate::Error;
+use crate::backend::Backend;
+
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<Levels>("levels")
+        .command::<Rank>("rank")
+        .command::<Xp>("xp")
+}
 
 pub struct LevelsTable;
 
@@ -107,3 +119,93 @@ impl LevelsManager<Postgres> for LevelsTable {
         .map(AnyQueryResult::from)
     }
 }
+
+#[async_trait]
+impl LevelsManager<Sqlite> for LevelsTable {
+    async fn leaderboard(
+        pool: &SqlitePool,
+        users: &[i64],
+        page: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>> {
+        let offset = (page - 1) * 10;
+
+        sqlx::query_as(
+            "SELECT id, xp, level, message_count FROM levels WHERE id IN (SELECT value FROM json_each($1)) ORDER BY level DESC, xp DESC LIMIT 10 OFFSET $2",
+        )
+        .bind(Json(users))
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+    }
+
+    async fn user_rank(
+        pool: &SqlitePool,
+        user_id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>> {
+        let id = user_id.into().get() as i64;
+
+        sqlx::query_scalar(
+            "SELECT row_number FROM (SELECT id, ROW_NUMBER() OVER (ORDER BY level DESC, xp DESC) AS row_number FROM levels) AS ranked WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_one(pool)
+        .await
+    }
+
+    async fn rank_row(
+        pool: &SqlitePool,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<RankRow>> {
+        let id = id.into();
+
+        sqlx::query_as("SELECT xp, level FROM levels WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn xp_row(
+        pool: &SqlitePool,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<XpRow>> {
+        let id = id.into();
+
+        sqlx::query_as("SELECT xp, level, total_xp FROM levels WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn full_row(
+        pool: &SqlitePool,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<FullLevelRow>> {
+        let id = id.into();
+
+        sqlx::query_as("SELECT * FROM levels WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn save(pool: &SqlitePool, row: FullLevelRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            "INSERT INTO levels (id, xp, total_xp, level, message_count, last_xp)
+            VALUES ($1, $2, $3, $4, $5, datetime('now'))
+            ON CONFLICT (id) DO UPDATE
+            SET xp = EXCLUDED.xp,
+                total_xp = EXCLUDED.total_xp,
+                level = EXCLUDED.level,
+                message_count = EXCLUDED.message_count,
+                last_xp = datetime('now');",
+        )
+        .bind(row.id)
+        .bind(row.xp)
+        .bind(row.total_xp)
+        .bind(row.level)
+        .bind(row.message_count)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
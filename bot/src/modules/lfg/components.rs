@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use serenity::all::{ComponentInteraction, Context};
+use sqlx::Pool;
+use zayden_core::Component;
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct LfgJoin;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgJoin {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::join::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgLeave;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgLeave {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::leave::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgAlternative;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgAlternative {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::alternative::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgSettings;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgSettings {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::settings::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgEdit;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgEdit {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::edit::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgCopy;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgCopy {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::copy::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgKick;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgKick {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::kick::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgKickMenu;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgKickMenu {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::KickComponent::run::<Db, Db::LfgPosts>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgDelete;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for LfgDelete {
+    async fn run(ctx: &Context, interaction: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        lfg::Components::delete::<Db, Db::LfgPosts, Db::CronJobs>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
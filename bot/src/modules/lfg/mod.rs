@@ -1,6 +1,9 @@
+mod components;
+mod modals;
 mod slash_command;
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use chrono_tz::Tz;
 use lfg::commands::{JoinedManager, SetupManager};
 use lfg::components::{EditManager, EditRow};
@@ -10,10 +13,33 @@ use lfg::{JoinedRow, PostManager, PostRow, Savable, TimezoneManager}; // PostRow
 use serenity::all::{ChannelId, GuildId, MessageId, RoleId, UserId};
 use sqlx::any::AnyQueryResult;
 use sqlx::postgres::PgQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::sqlite::{SqliteQueryResult, SqliteRow};
+use sqlx::types::Json;
+use sqlx::{PgPool, Postgres, Row, Sqlite, SqlitePool};
+use zayden_core::Registry;
 
 pub use slash_command::Lfg;
 
+use crate::Error;
+use crate::backend::Backend;
+
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<Lfg>("lfg")
+        .autocomplete::<Lfg>("lfg")
+        .component::<components::LfgJoin>("lfg_join")
+        .component::<components::LfgLeave>("lfg_leave")
+        .component::<components::LfgAlternative>("lfg_alternative")
+        .component::<components::LfgSettings>("lfg_settings")
+        .component::<components::LfgEdit>("lfg_edit")
+        .component::<components::LfgCopy>("lfg_copy")
+        .component::<components::LfgKick>("lfg_kick")
+        .component::<components::LfgKickMenu>("lfg_kick_menu")
+        .component::<components::LfgDelete>("lfg_delete")
+        .modal::<modals::LfgCreate>("lfg_create")
+        .modal::<modals::LfgEdit>("lfg_edit")
+}
+
 pub struct PostTable;
 
 #[async_trait]
@@ -320,3 +346,320 @@ impl GuildManager<Postgres> for GuildTable {
         .await
     }
 }
+
+fn post_row(row: SqliteRow) -> sqlx::Result<PostRow> {
+    Ok(PostRow {
+        id: row.try_get("id")?,
+        owner: row.try_get("owner")?,
+        activity: row.try_get("activity")?,
+        start_time: row.try_get("start_time")?,
+        description: row.try_get("description")?,
+        fireteam_size: row.try_get("fireteam_size")?,
+        fireteam: row.try_get::<Json<Vec<i64>>, _>("fireteam")?.0,
+        alternatives: row.try_get::<Json<Vec<i64>>, _>("alternatives")?.0,
+        alt_message: row.try_get("alt_message")?,
+        alt_channel: row.try_get("alt_channel")?,
+    })
+}
+
+#[async_trait]
+impl PostManager<Sqlite> for PostTable {
+    async fn exists(pool: &SqlitePool, id: impl Into<ChannelId> + Send) -> sqlx::Result<bool> {
+        let id = id.into();
+
+        sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM lfg_posts WHERE id = $1)")
+            .bind(id.get() as i64)
+            .fetch_one(pool)
+            .await
+    }
+
+    async fn owner(pool: &SqlitePool, id: impl Into<ChannelId> + Send) -> sqlx::Result<UserId> {
+        let id = id.into();
+
+        sqlx::query_scalar::<_, i64>("SELECT owner from lfg_posts WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_one(pool)
+            .await
+            .map(|id| UserId::new(id as u64))
+    }
+
+    async fn row(pool: &SqlitePool, id: impl Into<ChannelId> + Send) -> sqlx::Result<PostRow> {
+        let id = id.into();
+
+        sqlx::query(
+            r#"
+            SELECT
+                p.id,
+                p.owner,
+                p.activity,
+                p.start_time,
+                p.description,
+                p.fireteam_size,
+
+                (SELECT json_group_array(f.user_id) FROM lfg_fireteam f WHERE f.post = p.id) AS fireteam,
+
+                (SELECT json_group_array(a.user_id) FROM lfg_alternatives a WHERE a.post = p.id) AS alternatives,
+
+                m.message AS alt_message,
+                m.channel AS alt_channel
+
+            FROM
+                lfg_posts p
+            LEFT JOIN
+                lfg_messages m on p.id = m.id
+            WHERE
+                p.id = $1
+            "#,
+        )
+        .bind(id.get() as i64)
+        .try_map(post_row)
+        .fetch_one(pool)
+        .await
+    }
+
+    async fn delete(
+        pool: &SqlitePool,
+        id: impl Into<ChannelId> + Send,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let id = id.into();
+
+        sqlx::query("DELETE FROM lfg_posts WHERE id = $1")
+            .bind(id.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl Savable<Sqlite, PostRow> for PostTable {
+    async fn save(pool: &SqlitePool, row: PostRow) -> sqlx::Result<SqliteQueryResult> {
+        let mut tx = pool.begin().await?;
+
+        let mut result = sqlx::query(
+            r#"
+            INSERT INTO lfg_posts (id, owner, activity, start_time, description, fireteam_size)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (id) DO UPDATE
+            SET
+                owner = EXCLUDED.owner,
+                activity = EXCLUDED.activity,
+                start_time = EXCLUDED.start_time,
+                description = EXCLUDED.description,
+                fireteam_size = EXCLUDED.fireteam_size;
+            "#,
+        )
+        .bind(row.id)
+        .bind(row.owner)
+        .bind(&row.activity)
+        .bind(row.start_time)
+        .bind(&row.description)
+        .bind(row.fireteam_size)
+        .execute(&mut *tx)
+        .await?;
+
+        let temp_result1 = sqlx::query("DELETE FROM lfg_fireteam WHERE post = $1")
+            .bind(row.id)
+            .execute(&mut *tx)
+            .await?;
+
+        let temp_result2 = sqlx::query("DELETE FROM lfg_alternatives WHERE post = $1")
+            .bind(row.id)
+            .execute(&mut *tx)
+            .await?;
+
+        result.extend([temp_result1, temp_result2]);
+
+        if !row.fireteam.is_empty() {
+            let temp_result = sqlx::query(
+                "INSERT INTO lfg_fireteam (post, user_id) SELECT $1, value FROM json_each($2)",
+            )
+            .bind(row.id)
+            .bind(Json(&row.fireteam))
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([temp_result]);
+        }
+
+        if !row.alternatives.is_empty() {
+            let temp_result = sqlx::query(
+                "INSERT INTO lfg_alternatives (post, user_id) SELECT $1, value FROM json_each($2)",
+            )
+            .bind(row.id)
+            .bind(Json(&row.alternatives))
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([temp_result]);
+        }
+
+        if let (Some(channel), Some(message)) = (row.alt_channel, row.alt_message) {
+            let temp_result = sqlx::query(
+                "INSERT INTO lfg_messages (id, message, channel) VALUES ($1, $2, $3) ON CONFLICT (id) DO NOTHING",
+            )
+            .bind(row.id)
+            .bind(message)
+            .bind(channel)
+            .execute(&mut *tx)
+            .await?;
+
+            result.extend([temp_result]);
+        }
+
+        tx.commit().await?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl SetupManager<Sqlite> for PostTable {
+    async fn insert(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+        channel: impl Into<ChannelId> + Send,
+        role: Option<impl Into<RoleId> + Send>,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let id = id.into();
+        let channel = channel.into();
+        let role = role.map(|role| role.into());
+
+        sqlx::query(
+            r#"
+            INSERT INTO lfg_guilds (id, channel_id, role_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (id) DO UPDATE
+            SET
+                channel_id = EXCLUDED.channel_id,
+                role_id = EXCLUDED.role_id;
+            "#,
+        )
+        .bind(id.get() as i64)
+        .bind(channel.get() as i64)
+        .bind(role.map(|role| role.get() as i64))
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl JoinedManager<Sqlite> for PostTable {
+    async fn upcoming(
+        pool: &SqlitePool,
+        user: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Vec<JoinedRow>> {
+        let user = user.into();
+
+        let rows = sqlx::query_as::<_, (i64, String, DateTime<Utc>, Json<Vec<i64>>)>(
+            r#"
+            SELECT
+                p.id,
+                p.activity,
+                p.start_time,
+
+                (SELECT json_group_array(f.user_id) FROM lfg_fireteam f WHERE f.post = p.id) AS fireteam
+
+            FROM
+                lfg_posts p
+            JOIN lfg_fireteam f ON p.id = f.post
+            WHERE
+                f.user_id = $1
+            "#,
+        )
+        .bind(user.get() as i64)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(id, activity, start_time, fireteam)| JoinedRow {
+                id,
+                activity,
+                start_time,
+                fireteam: fireteam.0,
+            })
+            .collect())
+    }
+}
+
+#[async_trait]
+impl EditManager<Sqlite> for PostTable {
+    async fn edit_row(pool: &SqlitePool, id: impl Into<MessageId> + Send) -> sqlx::Result<EditRow> {
+        let id = id.into();
+
+        sqlx::query_as(
+            r#"
+            SELECT
+                p.owner,
+                p.activity,
+                p.start_time,
+                p.description,
+                p.fireteam_size,
+                u.timezone
+            FROM
+                lfg_posts AS p
+            LEFT JOIN
+                lfg_users AS u ON p.owner = u.id
+            WHERE
+                p.id = $1
+            "#,
+        )
+        .bind(id.get() as i64)
+        .fetch_one(pool)
+        .await
+    }
+}
+
+#[async_trait]
+impl TimezoneManager<Sqlite> for UsersTable {
+    async fn get(pool: &SqlitePool, id: impl Into<UserId> + Send, local: &str) -> sqlx::Result<Tz> {
+        let id = id.into();
+
+        let tz: Option<String> = sqlx::query_scalar("SELECT timezone FROM lfg_users WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await?;
+
+        match tz {
+            Some(tz) => Ok(tz.parse().unwrap_or(chrono_tz::UTC)),
+            None => Ok(LOCALE_TO_TIMEZONE
+                .get(local)
+                .copied()
+                .unwrap_or(chrono_tz::UTC)),
+        }
+    }
+
+    async fn save(
+        pool: &SqlitePool,
+        id: impl Into<UserId> + Send,
+        tz: Tz,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let id = id.into();
+
+        sqlx::query(
+            "INSERT INTO lfg_users (id, timezone) VALUES ($1, $2) ON CONFLICT (id) DO UPDATE SET timezone = $2",
+        )
+        .bind(id.get() as i64)
+        .bind(tz.name())
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+}
+
+#[async_trait]
+impl GuildManager<Sqlite> for GuildTable {
+    async fn row(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<GuildRow>> {
+        let id = id.into();
+
+        sqlx::query_as("SELECT channel_id, scheduled_thread_id FROM lfg_guilds WHERE id = $1")
+            .bind(id.get() as i64)
+            .fetch_optional(pool)
+            .await
+    }
+}
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+use serenity::all::{ActionRow, Context, ModalInteraction};
+use sqlx::Pool;
+use zayden_core::Modal;
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct LfgCreate;
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for LfgCreate {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        lfg::modals::Create::run::<Db, Db::LfgGuilds, Db::LfgPosts, Db::LfgUsers, Db::CronJobs>(
+            ctx,
+            interaction,
+            pool,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
+
+pub struct LfgEdit;
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for LfgEdit {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        lfg::modals::Edit::run::<Db, Db::LfgPosts, Db::LfgUsers, Db::CronJobs>(
+            ctx,
+            interaction,
+            pool,
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -2,24 +2,23 @@ use async_trait::async_trait;
 use serenity::all::{
     AutocompleteOption, CommandInteraction, Context, CreateCommand, ResolvedOption,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::{Autocomplete, SlashCommand};
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::{PostTable, UsersTable};
-
 pub struct Lfg;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Lfg {
+impl<Db: Backend> SlashCommand<Error, Db> for Lfg {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        lfg::Command::lfg::<Postgres, UsersTable, PostTable>(ctx, interaction, options, pool)
+        lfg::Command::lfg::<Db, Db::LfgUsers, Db::LfgPosts>(ctx, interaction, options, pool)
             .await?;
 
         Ok(())
@@ -31,12 +30,12 @@ impl SlashCommand<Error, Postgres> for Lfg {
 }
 
 #[async_trait]
-impl Autocomplete<Error, Postgres> for Lfg {
+impl<Db: Backend> Autocomplete<Error, Db> for Lfg {
     async fn autocomplete(
         ctx: &Context,
         interaction: &CommandInteraction,
         option: AutocompleteOption<'_>,
-        _pool: &PgPool,
+        _pool: &Pool<Db>,
     ) -> Result<()> {
         lfg::Command::autocomplete(ctx, interaction, option).await?;
 
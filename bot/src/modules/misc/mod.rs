@@ -1,9 +1,11 @@
 pub use random::Random;
-use serenity::all::{Context, CreateCommand};
-use zayden_core::SlashCommand;
+use zayden_core::Registry;
+
+use crate::Error;
+use crate::backend::Backend;
 
 mod random;
 
-pub fn register(ctx: &Context) -> CreateCommand {
-    Random::register(ctx).unwrap()
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry.command::<Random>("random")
 }
@@ -5,20 +5,21 @@ use serenity::all::{
     CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage, ResolvedOption,
     ResolvedValue,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
 pub struct Random;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Random {
+impl<Db: Backend> SlashCommand<Error, Db> for Random {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        _pool: &PgPool,
+        _pool: &Pool<Db>,
     ) -> Result<()> {
         let option = {
             let mut rng = rng();
@@ -1,12 +1,19 @@
-use serenity::all::{Context, CreateCommand};
+use std::collections::HashMap;
+
+use serenity::all::{Context, GuildId};
+use serenity::prelude::TypeMapKey;
+use sqlx::Pool;
+use zayden_core::Registry;
+
+use crate::backend::Backend;
+use crate::sqlx_lib::ModulesManager;
+use crate::{Error, Result};
 
 pub mod levels;
 pub mod lfg;
-use zayden_core::SlashCommand;
-
-use crate::modules::lfg::Lfg;
 
 // pub mod moderation;
+pub mod admin;
 pub mod ai;
 pub mod destiny2;
 pub mod events;
@@ -17,17 +24,107 @@ pub mod suggestions;
 pub mod temp_voice;
 pub mod ticket;
 
-pub fn global_register(ctx: &Context) -> Vec<CreateCommand> {
-    let mut cmds = destiny2::register(ctx).to_vec();
+pub const AI: &str = "ai";
+pub const GAMBLING: &str = "gambling";
+pub const LEVELS: &str = "levels";
+pub const LFG: &str = "lfg";
+pub const REACTION_ROLES: &str = "reaction-roles";
+pub const SUGGESTIONS: &str = "suggestions";
+pub const TEMP_VOICE: &str = "temp-voice";
+pub const TICKET: &str = "ticket";
+
+/// Modules that can be turned off per guild with `/config modules`.
+pub const MODULES: [&str; 8] = [
+    AI,
+    GAMBLING,
+    LEVELS,
+    LFG,
+    REACTION_ROLES,
+    SUGGESTIONS,
+    TEMP_VOICE,
+    TICKET,
+];
+
+/// The handlers shared by every backend, see [`Backend::registry`].
+pub fn registry<Db: Backend>() -> Registry<Error, Db> {
+    let registry = Registry::new()
+        .module(GAMBLING, gambling::register)
+        .module(LEVELS, levels::register)
+        .module(LFG, lfg::register)
+        .module(REACTION_ROLES, reaction_roles::register)
+        .module(SUGGESTIONS, suggestions::register)
+        .module(TEMP_VOICE, temp_voice::register)
+        .module(TICKET, ticket::register);
+
+    let registry = admin::register(registry);
+    let registry = destiny2::register(registry);
+    let registry = events::register(registry);
+    misc::register(registry)
+}
+
+/// The modules each guild has turned off, read from the database the first
+/// time they are needed and kept up to date by `/config modules`.
+#[derive(Debug, Default)]
+pub struct DisabledModules(HashMap<GuildId, Vec<String>>);
+
+impl DisabledModules {
+    /// Replaces what is kept for the guild after its modules were changed.
+    pub async fn set(ctx: &Context, guild_id: GuildId, disabled: Vec<String>) {
+        let mut data = ctx.data.write().await;
+        let modules = data.entry::<Self>().or_insert_with(Self::default);
+
+        modules.0.insert(guild_id, disabled);
+    }
+}
+
+impl TypeMapKey for DisabledModules {
+    type Value = DisabledModules;
+}
+
+/// The modules turned off in the guild, which is none outside of one.
+pub async fn disabled_modules<Db: Backend>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: Option<GuildId>,
+) -> Result<Vec<String>> {
+    let Some(guild_id) = guild_id else {
+        return Ok(Vec::new());
+    };
+
+    {
+        let data = ctx.data.read().await;
+        if let Some(disabled) = data
+            .get::<DisabledModules>()
+            .and_then(|modules| modules.0.get(&guild_id))
+        {
+            return Ok(disabled.clone());
+        }
+    }
+
+    let disabled = Db::Guilds::disabled_modules(pool, guild_id).await?;
+    DisabledModules::set(ctx, guild_id, disabled.clone()).await;
+
+    Ok(disabled)
+}
+
+/// Refuses to run handlers of a module that is disabled in the guild.
+pub async fn check_enabled<Db: Backend>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    guild_id: Option<GuildId>,
+    module: Option<&'static str>,
+) -> Result<()> {
+    let Some(module) = module else {
+        return Ok(());
+    };
 
-    cmds.extend(gambling::register(ctx));
-    cmds.extend(levels::Commands::register());
-    cmds.push(Lfg::register(ctx).unwrap());
-    cmds.push(misc::register(ctx));
-    cmds.push(reaction_roles::register(ctx));
-    cmds.push(suggestions::register(ctx));
-    cmds.push(temp_voice::register(ctx));
-    cmds.extend(ticket::register(ctx));
+    if disabled_modules(ctx, pool, guild_id)
+        .await?
+        .iter()
+        .any(|name| name == module)
+    {
+        return Err(Error::ModuleDisabled(module));
+    }
 
-    cmds
+    Ok(())
 }
@@ -1,15 +1,17 @@
 use async_trait::async_trait;
 use serenity::all::{
     CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
-    CreateEmbed, EditInteractionResponse, Permissions, Ready, ResolvedOption, ResolvedValue,
+    CreateEmbed, Permissions, Ready, ResolvedOption, ResolvedValue,
 };
 use sqlx::{PgPool, Postgres};
-use core::{SlashCommand, parse_options};
+use core::{Paginator, SlashCommand, parse_options};
 
 use crate::{Error, Result};
 
 use super::InfractionRow;
 
+const PAGE_SIZE: usize = 5;
+
 pub struct Logs;
 
 #[async_trait]
@@ -36,33 +38,40 @@ impl SlashCommand<Error, Postgres> for Logs {
         let infractions =
             InfractionRow::user_infractions(pool, user.id, filter == "recent").await?;
 
-        let fields = infractions.into_iter().map(|infraction| {
-            (
-                format!("Case #{}", infraction.id),
-                format!("**Type:** {}\n", infraction.infraction_type)
-                    + &format!(
-                        "**User:** ({}) {}\n",
-                        infraction.user_id, infraction.username
-                    )
-                    + &format!(
-                        "**Moderator:** ({}) {}\n",
-                        infraction.moderator_id, infraction.moderator_username
-                    )
-                    + &format!("**Reason:** {}", infraction.reason),
-                false,
-            )
-        });
-
-        let embed = CreateEmbed::new()
-            .title(format!("Logs for {}", user.name))
-            .fields(fields);
+        let fields = infractions
+            .into_iter()
+            .map(|infraction| {
+                (
+                    format!("Case #{}", infraction.id),
+                    format!("**Type:** {}\n", infraction.infraction_type)
+                        + &format!(
+                            "**User:** ({}) {}\n",
+                            infraction.user_id, infraction.username
+                        )
+                        + &format!(
+                            "**Moderator:** ({}) {}\n",
+                            infraction.moderator_id, infraction.moderator_username
+                        )
+                        + &format!("**Reason:** {}", infraction.reason),
+                    false,
+                )
+            })
+            .collect::<Vec<_>>();
 
-        interaction
-            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
-            .await
-            .unwrap();
+        let title = format!("Logs for {}", user.name);
+        let (fields, title) = (&fields, &title);
 
-        Ok(())
+        Paginator::new(fields.len().div_ceil(PAGE_SIZE), |page| async move {
+            Ok(CreateEmbed::new().title(title).fields(
+                fields
+                    .iter()
+                    .skip((page - 1) * PAGE_SIZE)
+                    .take(PAGE_SIZE)
+                    .cloned(),
+            ))
+        })
+        .run(ctx, interaction)
+        .await
     }
 
     fn register(_ctx: &Context) -> Result<CreateCommand> {
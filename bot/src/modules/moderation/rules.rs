@@ -3,8 +3,8 @@ use std::str::FromStr;
 
 use async_trait::async_trait;
 use serenity::all::{
-    ChannelId, Colour, CommandInteraction, CreateEmbed, EditInteractionResponse, EditMessage,
-    MessageId, Ready, ResolvedOption,
+    Colour, CommandInteraction, CreateEmbed, EditInteractionResponse, EditMessage, Ready,
+    ResolvedOption,
 };
 use serenity::builder::CreateCommand;
 use serenity::model::Permissions;
@@ -12,10 +12,7 @@ use serenity::prelude::Context;
 use sqlx::{PgPool, Postgres};
 use core::SlashCommand;
 
-use crate::{Error, Result};
-
-const CHANNEL_ID: ChannelId = ChannelId::new(747430712617074718);
-const MESSAGE_ID: MessageId = MessageId::new(788539168980336701);
+use crate::{Error, Result, config};
 
 pub struct RulesCommand;
 
@@ -41,7 +38,16 @@ impl SlashCommand<Error, Postgres> for RulesCommand {
 
         let embed = CreateEmbed::new().colour(Colour::from_rgb(255, 0, 0)).title("College Kings Server Rules").description("The below rules are a truncated version of the rules found in the [Code of Conduct](https://gist.github.com/KiloOscarSix/201a919b5650e511f11287c0a9c4c2fc).").fields(fields);
 
-        let mut message = CHANNEL_ID.message(ctx, MESSAGE_ID).await.unwrap();
+        let moderation = config::get()
+            .moderation
+            .as_ref()
+            .expect("the rules command needs a moderation config");
+
+        let mut message = moderation
+            .rules_channel
+            .message(ctx, moderation.rules_message)
+            .await
+            .unwrap();
         message
             .edit(ctx, EditMessage::new().embed(embed))
             .await
@@ -1,21 +1,23 @@
 use async_trait::async_trait;
 use reaction_roles::ReactionRolesManager;
 use reaction_roles::reaction_roles_manager::ReactionRole;
-use serenity::all::{Context, CreateCommand};
 use sqlx::any::AnyQueryResult;
-use sqlx::{Pool, Postgres};
-use zayden_core::SlashCommand;
+use sqlx::{Pool, Postgres, Sqlite};
+use zayden_core::Registry;
 
 pub use slash_command::ReactionRoleCommand;
 
+use crate::Error;
+use crate::backend::Backend;
+
 pub mod reaction;
 pub mod slash_command;
 
-pub fn register(ctx: &Context) -> CreateCommand {
-    ReactionRoleCommand::register(ctx).unwrap()
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry.command::<ReactionRoleCommand>("reaction_role")
 }
 
-struct ReactionRolesTable;
+pub struct ReactionRolesTable;
 
 #[async_trait]
 impl ReactionRolesManager<Postgres> for ReactionRolesTable {
@@ -87,3 +89,66 @@ impl ReactionRolesManager<Postgres> for ReactionRolesTable {
         Ok(result.into())
     }
 }
+
+#[async_trait]
+impl ReactionRolesManager<Sqlite> for ReactionRolesTable {
+    async fn create_row(
+        pool: &Pool<Sqlite>,
+        guild_id: impl Into<i64> + Send,
+        channel_id: impl Into<i64> + Send,
+        message_id: impl Into<i64> + Send,
+        role_id: impl Into<i64> + Send,
+        emoji: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let result = sqlx::query("INSERT INTO reaction_roles (guild_id, channel_id, message_id, role_id, emoji) VALUES ($1, $2, $3, $4, $5)")
+            .bind(guild_id.into())
+            .bind(channel_id.into())
+            .bind(message_id.into())
+            .bind(role_id.into())
+            .bind(emoji)
+            .execute(pool)
+            .await?;
+
+        Ok(result.into())
+    }
+
+    async fn get_guild_rows(
+        pool: &Pool<Sqlite>,
+        guild_id: impl Into<i64> + Send,
+    ) -> sqlx::Result<Vec<ReactionRole>> {
+        sqlx::query_as("SELECT * FROM reaction_roles WHERE guild_id = $1")
+            .bind(guild_id.into())
+            .fetch_all(pool)
+            .await
+    }
+
+    async fn get_row(
+        pool: &Pool<Sqlite>,
+        message_id: impl Into<i64> + Send,
+        emoji: &str,
+    ) -> sqlx::Result<Option<ReactionRole>> {
+        sqlx::query_as("SELECT * FROM reaction_roles WHERE message_id = $1 AND emoji = $2")
+            .bind(message_id.into())
+            .bind(emoji)
+            .fetch_optional(pool)
+            .await
+    }
+
+    async fn delete_row(
+        pool: &Pool<Sqlite>,
+        guild_id: impl Into<i64> + Send,
+        channel_id: impl Into<i64> + Send,
+        message_id: impl Into<i64> + Send,
+        emoji: &str,
+    ) -> sqlx::Result<AnyQueryResult> {
+        let result = sqlx::query("DELETE FROM reaction_roles WHERE guild_id = $1 AND channel_id = $2 AND message_id = $3 AND emoji = $4")
+            .bind(guild_id.into())
+            .bind(channel_id.into())
+            .bind(message_id.into())
+            .bind(emoji)
+            .execute(pool)
+            .await?;
+
+        Ok(result.into())
+    }
+}
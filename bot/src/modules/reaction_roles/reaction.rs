@@ -1,26 +1,22 @@
 use reaction_roles::ReactionRoleReaction;
 use serenity::all::{Context, Reaction};
-use sqlx::Postgres;
 
 use crate::Result;
-use crate::sqlx_lib::PostgresPool;
+use crate::backend::Backend;
+use crate::sqlx_lib::DatabasePool;
 
-use super::ReactionRolesTable;
+pub async fn reaction_add<Db: Backend>(ctx: &Context, reaction: &Reaction) -> Result<()> {
+    let pool = DatabasePool::<Db>::get(ctx).await;
 
-pub async fn reaction_add(ctx: &Context, reaction: &Reaction) -> Result<()> {
-    let pool = PostgresPool::get(ctx).await;
-
-    ReactionRoleReaction::reaction_add::<Postgres, ReactionRolesTable>(ctx, reaction, &pool)
-        .await?;
+    ReactionRoleReaction::reaction_add::<Db, Db::ReactionRoles>(ctx, reaction, &pool).await?;
 
     Ok(())
 }
 
-pub async fn reaction_remove(ctx: &Context, reaction: &Reaction) -> Result<()> {
-    let pool = PostgresPool::get(ctx).await;
+pub async fn reaction_remove<Db: Backend>(ctx: &Context, reaction: &Reaction) -> Result<()> {
+    let pool = DatabasePool::<Db>::get(ctx).await;
 
-    ReactionRoleReaction::reaction_remove::<Postgres, ReactionRolesTable>(ctx, reaction, &pool)
-        .await?;
+    ReactionRoleReaction::reaction_remove::<Db, Db::ReactionRoles>(ctx, reaction, &pool).await?;
 
     Ok(())
 }
@@ -3,31 +3,26 @@ use serenity::all::{
     CommandInteraction, Context, CreateCommand, EditInteractionResponse, Permissions,
     ResolvedOption,
 };
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::ReactionRolesTable;
-
 pub struct ReactionRoleCommand;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for ReactionRoleCommand {
+impl<Db: Backend> SlashCommand<Error, Db> for ReactionRoleCommand {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
         interaction.defer_ephemeral(ctx).await.unwrap();
 
-        reaction_roles::ReactionRoleCommand::run::<Postgres, ReactionRolesTable>(
-            ctx,
-            interaction,
-            pool,
-        )
-        .await?;
+        reaction_roles::ReactionRoleCommand::run::<Db, Db::ReactionRoles>(ctx, interaction, pool)
+            .await?;
 
         interaction
             .edit_response(ctx, EditInteractionResponse::new().content("Success."))
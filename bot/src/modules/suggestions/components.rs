@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use serenity::all::{ActionRow, ComponentInteraction, Context, ModalInteraction};
+use sqlx::Pool;
+use suggestions::Suggestions;
+use zayden_core::{Component, Modal};
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct SuggestionsAccept;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for SuggestionsAccept {
+    async fn run(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        _pool: &Pool<Db>,
+    ) -> Result<()> {
+        Suggestions::components(ctx, interaction, true).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for SuggestionsAccept {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        _pool: &Pool<Db>,
+    ) -> Result<()> {
+        Suggestions::modal(ctx, interaction, true).await;
+
+        Ok(())
+    }
+}
+
+pub struct SuggestionsReject;
+
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for SuggestionsReject {
+    async fn run(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        _pool: &Pool<Db>,
+    ) -> Result<()> {
+        Suggestions::components(ctx, interaction, false).await;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for SuggestionsReject {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        _pool: &Pool<Db>,
+    ) -> Result<()> {
+        Suggestions::modal(ctx, interaction, false).await;
+
+        Ok(())
+    }
+}
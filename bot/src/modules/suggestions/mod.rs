@@ -1,17 +1,29 @@
 use async_trait::async_trait;
-use serenity::all::{Context, CreateCommand, GuildId};
-use sqlx::{PgPool, Postgres};
+use serenity::all::GuildId;
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
 use suggestions::{SuggestionsGuildManager, SuggestionsGuildRow};
-use zayden_core::SlashCommand;
+use zayden_core::Registry;
 
+pub mod components;
 pub mod slash_command;
 
+pub use components::{SuggestionsAccept, SuggestionsReject};
 pub use slash_command::FetchSuggestions;
 
+use crate::Error;
+use crate::backend::Backend;
 use crate::sqlx_lib::GuildTable;
 
-pub fn register(ctx: &Context) -> CreateCommand {
-    FetchSuggestions::register(ctx).unwrap()
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<FetchSuggestions>("fetch_suggestions")
+        .component::<SuggestionsAccept>("suggestions_accept")
+        .component::<SuggestionsAccept>("suggestions_added")
+        .component::<SuggestionsAccept>("accept")
+        .component::<SuggestionsReject>("suggestions_reject")
+        .component::<SuggestionsReject>("reject")
+        .modal::<SuggestionsAccept>("suggestions_accept")
+        .modal::<SuggestionsReject>("suggestions_reject")
 }
 
 #[async_trait]
@@ -34,3 +46,20 @@ impl SuggestionsGuildManager<Postgres> for GuildTable {
         Ok(row)
     }
 }
+
+#[async_trait]
+impl SuggestionsGuildManager<Sqlite> for GuildTable {
+    async fn get(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<SuggestionsGuildRow>> {
+        let id = id.into();
+
+        sqlx::query_as(
+            "SELECT id, suggestions_channel_id, review_channel_id FROM guilds WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await
+    }
+}
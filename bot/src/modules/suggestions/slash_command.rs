@@ -1,21 +1,22 @@
 use async_trait::async_trait;
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
-use crate::{Error, Result, sqlx_lib::GuildTable};
+use crate::backend::Backend;
+use crate::{Error, Result};
 
 pub struct FetchSuggestions;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for FetchSuggestions {
+impl<Db: Backend> SlashCommand<Error, Db> for FetchSuggestions {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        suggestions::FetchSuggestions::run::<Postgres, GuildTable>(ctx, interaction, options, pool)
+        suggestions::FetchSuggestions::run::<Db, Db::Guilds>(ctx, interaction, options, pool)
             .await?;
 
         Ok(())
@@ -1,26 +1,23 @@
 use async_trait::async_trait;
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use temp_voice::VoiceCommand;
 use zayden_core::SlashCommand;
 
-use crate::sqlx_lib::GuildTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::VoiceChannelTable;
-
 pub struct Voice;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for Voice {
+impl<Db: Backend> SlashCommand<Error, Db> for Voice {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         _options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        VoiceCommand::run::<Postgres, GuildTable, VoiceChannelTable>(ctx, interaction, pool)
-            .await?;
+        VoiceCommand::run::<Db, Db::Guilds, Db::VoiceChannels>(ctx, interaction, pool).await?;
 
         Ok(())
     }
@@ -4,17 +4,21 @@ pub use commands::Voice;
 pub mod events;
 
 use async_trait::async_trait;
-use serenity::all::{ChannelId, Context, CreateCommand, GuildId, UserId};
+use serenity::all::{ChannelId, GuildId, UserId};
 use sqlx::any::AnyQueryResult;
-use sqlx::{PgPool, Postgres};
+use sqlx::sqlite::SqliteRow;
+use sqlx::types::Json;
+use sqlx::{PgPool, Postgres, Row, Sqlite, SqlitePool};
 use temp_voice::voice_channel_manager::VoiceChannelMode;
 use temp_voice::{TempVoiceGuildManager, TempVoiceRow, VoiceChannelManager, VoiceChannelRow};
-use zayden_core::SlashCommand;
+use zayden_core::Registry;
 
+use crate::Error;
+use crate::backend::Backend;
 use crate::sqlx_lib::GuildTable;
 
-pub fn register(ctx: &Context) -> CreateCommand {
-    Voice::register(ctx).unwrap()
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry.command::<Voice>("voice")
 }
 
 #[async_trait]
@@ -101,7 +105,7 @@ impl From<TempVoiceMode> for VoiceChannelMode {
     }
 }
 
-struct VoiceChannelTable;
+pub struct VoiceChannelTable;
 
 #[async_trait]
 impl VoiceChannelManager<Postgres> for VoiceChannelTable {
@@ -129,6 +133,14 @@ impl VoiceChannelManager<Postgres> for VoiceChannelTable {
         Ok(count.unwrap())
     }
 
+    async fn count(pool: &PgPool) -> sqlx::Result<i64> {
+        let count = sqlx::query_scalar!("SELECT COUNT(*) FROM voice_channels")
+            .fetch_one(pool)
+            .await?;
+
+        Ok(count.unwrap_or_default())
+    }
+
     async fn save(pool: &PgPool, row: VoiceChannelRow) -> sqlx::Result<AnyQueryResult> {
         let mode = TempVoiceMode::from(row.mode);
 
@@ -164,3 +176,131 @@ impl VoiceChannelManager<Postgres> for VoiceChannelTable {
         Ok(result.into())
     }
 }
+
+#[async_trait]
+impl TempVoiceGuildManager<Sqlite> for GuildTable {
+    async fn save(
+        pool: &SqlitePool,
+        id: GuildId,
+        category: ChannelId,
+        creator_channel: ChannelId,
+    ) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            r#"
+            INSERT INTO guilds (id, temp_voice_category, temp_voice_creator_channel)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (id) DO UPDATE
+            SET temp_voice_category = $2, temp_voice_creator_channel = $3
+            "#,
+        )
+        .bind(id.get() as i64)
+        .bind(category.get() as i64)
+        .bind(creator_channel.get() as i64)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn get(pool: &SqlitePool, id: GuildId) -> sqlx::Result<TempVoiceRow> {
+        sqlx::query_as(
+            "SELECT id, temp_voice_category, temp_voice_creator_channel FROM guilds WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .fetch_one(pool)
+        .await
+    }
+
+    async fn get_category(pool: &SqlitePool, id: GuildId) -> sqlx::Result<ChannelId> {
+        let category: Option<i64> =
+            sqlx::query_scalar("SELECT temp_voice_category FROM guilds WHERE id = $1")
+                .bind(id.get() as i64)
+                .fetch_one(pool)
+                .await?;
+
+        let category = category.expect("Category ID is required when saving") as u64;
+
+        Ok(ChannelId::from(category))
+    }
+
+    async fn get_creator_channel(
+        pool: &SqlitePool,
+        id: GuildId,
+    ) -> sqlx::Result<Option<ChannelId>> {
+        let channel_id: Option<i64> =
+            sqlx::query_scalar("SELECT temp_voice_creator_channel FROM guilds WHERE id = $1")
+                .bind(id.get() as i64)
+                .fetch_one(pool)
+                .await?;
+
+        Ok(channel_id.map(|id| ChannelId::new(id as u64)))
+    }
+}
+
+fn voice_channel_row(row: SqliteRow) -> sqlx::Result<VoiceChannelRow> {
+    Ok(VoiceChannelRow {
+        id: row.try_get("id")?,
+        owner_id: row.try_get("owner_id")?,
+        trusted_ids: row.try_get::<Json<Vec<i64>>, _>("trusted_ids")?.0,
+        invites: row.try_get::<Json<Vec<i64>>, _>("invites")?.0,
+        password: row.try_get("password")?,
+        persistent: row.try_get("persistent")?,
+        mode: row.try_get("mode")?,
+    })
+}
+
+#[async_trait]
+impl VoiceChannelManager<Sqlite> for VoiceChannelTable {
+    async fn get(pool: &SqlitePool, id: ChannelId) -> sqlx::Result<Option<VoiceChannelRow>> {
+        sqlx::query(
+            "SELECT id, owner_id, trusted_ids, invites, password, persistent, mode FROM voice_channels WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .try_map(voice_channel_row)
+        .fetch_optional(pool)
+        .await
+    }
+
+    async fn count_persistent_channels(pool: &SqlitePool, user_id: UserId) -> sqlx::Result<i64> {
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM voice_channels WHERE owner_id = $1 AND persistent = true",
+        )
+        .bind(user_id.get() as i64)
+        .fetch_one(pool)
+        .await
+    }
+
+    async fn count(pool: &SqlitePool) -> sqlx::Result<i64> {
+        sqlx::query_scalar("SELECT COUNT(*) FROM voice_channels")
+            .fetch_one(pool)
+            .await
+    }
+
+    async fn save(pool: &SqlitePool, row: VoiceChannelRow) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query(
+            r#"
+            INSERT INTO voice_channels (id, owner_id, trusted_ids, password, persistent, invites, mode)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (id) DO UPDATE
+            SET owner_id = $2, trusted_ids = $3, password = $4, persistent = $5, invites = $6, mode = $7
+            "#,
+        )
+        .bind(row.id)
+        .bind(row.owner_id)
+        .bind(Json(row.trusted_ids))
+        .bind(row.password)
+        .bind(row.persistent)
+        .bind(Json(row.invites))
+        .bind(row.mode)
+        .execute(pool)
+        .await
+        .map(AnyQueryResult::from)
+    }
+
+    async fn delete(pool: &SqlitePool, id: ChannelId) -> sqlx::Result<AnyQueryResult> {
+        sqlx::query("DELETE FROM voice_channels WHERE id = $1")
+            .bind(id.get() as i64)
+            .execute(pool)
+            .await
+            .map(AnyQueryResult::from)
+    }
+}
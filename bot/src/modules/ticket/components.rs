@@ -1,30 +1,39 @@
+use async_trait::async_trait;
 use serenity::all::{ComponentInteraction, Context, CreateInputText, InputTextStyle};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use ticket::TicketComponent;
+use zayden_core::Component;
 
-use crate::sqlx_lib::GuildTable;
-use crate::Result;
+use crate::backend::Backend;
+use crate::{Error, Result};
 
-use super::Ticket;
+pub struct SupportClose;
 
-impl Ticket {
-    pub async fn support_close(ctx: &Context, component: &ComponentInteraction) -> Result<()> {
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for SupportClose {
+    async fn run(ctx: &Context, component: &ComponentInteraction, _pool: &Pool<Db>) -> Result<()> {
         TicketComponent::support_close(ctx, component).await?;
 
         Ok(())
     }
+}
+
+pub struct SupportFaq;
 
-    pub async fn support_faq(
-        ctx: &Context,
-        component: &ComponentInteraction,
-        pool: &PgPool,
-    ) -> Result<()> {
-        TicketComponent::support_faq::<Postgres, GuildTable>(ctx, component, pool).await?;
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for SupportFaq {
+    async fn run(ctx: &Context, component: &ComponentInteraction, pool: &Pool<Db>) -> Result<()> {
+        TicketComponent::support_faq::<Db, Db::Guilds>(ctx, component, pool).await?;
 
         Ok(())
     }
+}
+
+pub struct TicketCreate;
 
-    pub async fn ticket_create(ctx: &Context, component: &ComponentInteraction) -> Result<()> {
+#[async_trait]
+impl<Db: Backend> Component<Error, Db> for TicketCreate {
+    async fn run(ctx: &Context, component: &ComponentInteraction, _pool: &Pool<Db>) -> Result<()> {
         let version =
             CreateInputText::new(InputTextStyle::Short, "Version", "version").placeholder("1.0.0");
 
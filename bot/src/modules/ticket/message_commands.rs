@@ -1,12 +1,12 @@
 use serenity::all::{Context, Message};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use ticket::SupportMessageCommand;
 
-use crate::sqlx_lib::GuildTable;
 use crate::Result;
+use crate::backend::Backend;
 
-pub async fn support(ctx: &Context, msg: &Message, pool: &PgPool) -> Result<()> {
-    SupportMessageCommand::run::<Postgres, GuildTable>(ctx, msg, pool).await?;
+pub async fn support<Db: Backend>(ctx: &Context, msg: &Message, pool: &Pool<Db>) -> Result<()> {
+    SupportMessageCommand::run::<Db, Db::Guilds>(ctx, msg, pool).await?;
 
     Ok(())
 }
@@ -1,28 +1,37 @@
 use async_trait::async_trait;
-use serenity::all::{Context, CreateCommand, GuildId, MessageId};
+use serenity::all::{GuildId, MessageId};
 use slash_commands::{SupportCommand, TicketCommand};
-use sqlx::{PgPool, Postgres};
+use sqlx::types::Json;
+use sqlx::{PgPool, Postgres, Sqlite, SqlitePool};
 use ticket::{
     TicketGuildManager,
     support_guild_manager::TicketGuildRow,
     ticket_manager::{TicketManager, TicketRow},
 };
-use zayden_core::SlashCommand;
+use zayden_core::Registry;
 
+use crate::Error;
+use crate::backend::Backend;
 use crate::sqlx_lib::GuildTable;
 
 pub mod components;
 pub mod message_commands;
+pub mod modal;
 pub mod slash_commands;
 
-pub fn register(ctx: &Context) -> [CreateCommand; 2] {
-    [
-        TicketCommand::register(ctx).unwrap(),
-        SupportCommand::register(ctx).unwrap(),
-    ]
-}
+use components::{SupportClose, SupportFaq, TicketCreate};
+use modal::CreateTicket;
 
-pub struct Ticket;
+pub fn register<Db: Backend>(registry: Registry<Error, Db>) -> Registry<Error, Db> {
+    registry
+        .command::<TicketCommand>("ticket")
+        .command::<SupportCommand>("support")
+        .component::<TicketCreate>("ticket_create")
+        .component::<TicketCreate>("support_ticket")
+        .component::<SupportClose>("support_close")
+        .component::<SupportFaq>("support_faq")
+        .modal::<CreateTicket>("create_ticket")
+}
 
 #[async_trait]
 impl TicketGuildManager<Postgres> for GuildTable {
@@ -79,3 +88,70 @@ impl TicketManager<Postgres> for TicketTable {
         Ok(())
     }
 }
+
+#[async_trait]
+impl TicketGuildManager<Sqlite> for GuildTable {
+    async fn get(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Option<TicketGuildRow>> {
+        let id = id.into();
+
+        let row = sqlx::query_as::<_, (i64, i32, Option<i64>, Json<Vec<i64>>, Option<i64>)>(
+            "SELECT id, thread_id, support_channel_id, support_role_ids, faq_channel_id FROM guilds WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(row.map(
+            |(id, thread_id, support_channel_id, support_role_ids, faq_channel_id)| {
+                TicketGuildRow {
+                    id,
+                    thread_id,
+                    support_channel_id,
+                    support_role_ids: support_role_ids.0,
+                    faq_channel_id,
+                }
+            },
+        ))
+    }
+
+    async fn update_thread_id(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<()> {
+        sqlx::query("UPDATE guilds SET thread_id = thread_id + 1 WHERE id = $1")
+            .bind(id.into().get() as i64)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TicketManager<Sqlite> for TicketTable {
+    async fn get(pool: &SqlitePool, id: impl Into<MessageId> + Send) -> sqlx::Result<TicketRow> {
+        let (id, role_ids) = sqlx::query_as::<_, (i64, Json<Vec<i64>>)>(
+            "SELECT id, role_ids FROM tickets WHERE id = $1",
+        )
+        .bind(id.into().get() as i64)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(TicketRow {
+            id,
+            role_ids: role_ids.0,
+        })
+    }
+
+    async fn delete(pool: &SqlitePool, id: impl Into<MessageId> + Send) -> sqlx::Result<()> {
+        sqlx::query("DELETE FROM tickets WHERE id = $1")
+            .bind(id.into().get() as i64)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use serenity::all::{ActionRow, Context, ModalInteraction};
+use sqlx::Pool;
+use ticket::TicketModal;
+use zayden_core::Modal;
+
+use crate::backend::Backend;
+use crate::{Error, Result};
+
+pub struct CreateTicket;
+
+#[async_trait]
+impl<Db: Backend> Modal<Error, Db> for CreateTicket {
+    async fn run(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        _components: &[ActionRow],
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        TicketModal::run::<Db, Db::Guilds, Db::Tickets>(ctx, interaction, pool).await?;
+
+        Ok(())
+    }
+}
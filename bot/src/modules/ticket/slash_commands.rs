@@ -1,30 +1,23 @@
 use async_trait::async_trait;
 use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
-use sqlx::{PgPool, Postgres};
+use sqlx::Pool;
 use zayden_core::SlashCommand;
 
-use crate::sqlx_lib::GuildTable;
+use crate::backend::Backend;
 use crate::{Error, Result};
 
-use super::TicketTable;
-
 pub struct TicketCommand;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for TicketCommand {
+impl<Db: Backend> SlashCommand<Error, Db> for TicketCommand {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        ticket::TicketCommand::run::<Postgres, GuildTable, TicketTable>(
-            ctx,
-            interaction,
-            pool,
-            options,
-        )
-        .await?;
+        ticket::TicketCommand::run::<Db, Db::Guilds, Db::Tickets>(ctx, interaction, pool, options)
+            .await?;
 
         Ok(())
     }
@@ -37,15 +30,14 @@ impl SlashCommand<Error, Postgres> for TicketCommand {
 pub struct SupportCommand;
 
 #[async_trait]
-impl SlashCommand<Error, Postgres> for SupportCommand {
+impl<Db: Backend> SlashCommand<Error, Db> for SupportCommand {
     async fn run(
         ctx: &Context,
         interaction: &CommandInteraction,
         options: Vec<ResolvedOption<'_>>,
-        pool: &PgPool,
+        pool: &Pool<Db>,
     ) -> Result<()> {
-        ticket::SupportCommand::run::<Postgres, GuildTable>(ctx, interaction, pool, options)
-            .await?;
+        ticket::SupportCommand::run::<Db, Db::Guilds>(ctx, interaction, pool, options).await?;
 
         Ok(())
     }
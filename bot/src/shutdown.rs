@@ -0,0 +1,54 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use serenity::all::ShardManager;
+use serenity::prelude::{RwLock, TypeMap};
+use sqlx::Pool;
+use zayden_core::{Cooldowns, Shutdown};
+
+use crate::backend::Backend;
+
+/// Waits for SIGTERM or Ctrl+C, then refuses new commands, gives running
+/// commands up to `grace` to finish, saves the cooldowns and closes the gateway.
+pub async fn on_signal<Db: Backend>(
+    shutdown: Shutdown,
+    shards: Arc<ShardManager>,
+    data: Arc<RwLock<TypeMap>>,
+    pool: Pool<Db>,
+    grace: Duration,
+) {
+    signal().await;
+
+    tracing::info!(running = shutdown.running(), "shutting down");
+    shutdown.start();
+
+    if !shutdown.idle(grace).await {
+        tracing::warn!(
+            running = shutdown.running(),
+            "grace period over, closing with handlers still running"
+        );
+    }
+
+    if let Err(e) = Cooldowns::save::<Db, Db::Cooldowns>(&data, &pool).await {
+        tracing::error!(error = ?e, "failed to save cooldowns");
+    }
+
+    shards.shutdown_all().await;
+}
+
+#[cfg(unix)]
+async fn signal() {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let mut terminate = signal(SignalKind::terminate()).unwrap();
+
+    tokio::select! {
+        _ = terminate.recv() => {}
+        _ = tokio::signal::ctrl_c() => {}
+    }
+}
+
+#[cfg(not(unix))]
+async fn signal() {
+    let _ = tokio::signal::ctrl_c().await;
+}
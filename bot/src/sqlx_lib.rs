@@ -1,54 +1,165 @@
 use std::env;
-use std::ops::Deref;
+use std::marker::PhantomData;
 
-use serenity::all::Context;
+use async_trait::async_trait;
+use serenity::all::{Context, GuildId};
 use serenity::prelude::TypeMapKey;
-use sqlx::PgPool;
-use sqlx::postgres::PgPoolOptions;
+use sqlx::pool::PoolOptions;
+use sqlx::types::Json;
+use sqlx::{Database, PgPool, Pool, Postgres, Sqlite, SqlitePool};
 
 use crate::Result;
 
-#[derive(Clone)]
-pub struct PostgresPool {
-    pub pool: PgPool,
-}
+/// The connection pool of the backend the bot was started with.
+pub struct DatabasePool<Db: Database>(PhantomData<Db>);
 
-impl PostgresPool {
-    pub async fn new() -> Result<Self> {
-        let pool = PgPoolOptions::new()
+impl<Db: Database> DatabasePool<Db> {
+    pub async fn connect() -> Result<Pool<Db>> {
+        let pool = PoolOptions::<Db>::new()
             .max_connections(10)
             .min_connections(1)
             .connect(&env::var("DATABASE_URL").unwrap())
             .await?;
 
-        Ok(Self { pool })
+        Ok(pool)
     }
 
-    pub async fn get(ctx: &Context) -> PgPool {
+    pub async fn get(ctx: &Context) -> Pool<Db> {
         let data = ctx.data.read().await;
-        match data.get::<PostgresPool>() {
-            Some(pool) => pool.pool.clone(),
+        match data.get::<Self>() {
+            Some(pool) => pool.clone(),
             None => {
                 drop(data);
-                let pool = Self::new().await.unwrap();
+                let pool = Self::connect().await.unwrap();
                 let mut data = ctx.data.write().await;
-                data.insert::<PostgresPool>(pool.clone());
-                pool.pool
+                data.insert::<Self>(pool.clone());
+                pool
             }
         }
     }
 }
 
-impl Deref for PostgresPool {
-    type Target = PgPool;
-
-    fn deref(&self) -> &Self::Target {
-        &self.pool
-    }
+impl<Db: Database> TypeMapKey for DatabasePool<Db> {
+    type Value = Pool<Db>;
 }
 
-impl TypeMapKey for PostgresPool {
-    type Value = PostgresPool;
+#[async_trait]
+pub trait ModulesManager<Db: Database> {
+    async fn disabled_modules(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<String>>;
+
+    /// Enables or disables `module`, returning the guild's disabled modules.
+    async fn set_module(
+        pool: &Pool<Db>,
+        id: impl Into<GuildId> + Send,
+        module: &str,
+        enabled: bool,
+    ) -> sqlx::Result<Vec<String>>;
 }
 
 pub struct GuildTable;
+
+#[async_trait]
+impl ModulesManager<Postgres> for GuildTable {
+    async fn disabled_modules(
+        pool: &PgPool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<String>> {
+        let id = id.into();
+
+        let modules = sqlx::query_scalar!(
+            "SELECT disabled_modules FROM guilds WHERE id = $1",
+            id.get() as i64
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(modules.unwrap_or_default())
+    }
+
+    async fn set_module(
+        pool: &PgPool,
+        id: impl Into<GuildId> + Send,
+        module: &str,
+        enabled: bool,
+    ) -> sqlx::Result<Vec<String>> {
+        let id = id.into();
+
+        sqlx::query_scalar!(
+            r#"
+            INSERT INTO guilds (id, disabled_modules)
+            VALUES ($1, CASE WHEN $3::BOOLEAN THEN '{}'::TEXT[] ELSE ARRAY[$2::TEXT] END)
+            ON CONFLICT (id) DO UPDATE
+            SET disabled_modules = CASE
+                WHEN $3::BOOLEAN THEN array_remove(guilds.disabled_modules, $2::TEXT)
+                ELSE array_append(array_remove(guilds.disabled_modules, $2::TEXT), $2::TEXT)
+            END
+            RETURNING disabled_modules
+            "#,
+            id.get() as i64,
+            module,
+            enabled
+        )
+        .fetch_one(pool)
+        .await
+    }
+}
+
+#[async_trait]
+impl ModulesManager<Sqlite> for GuildTable {
+    async fn disabled_modules(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<Vec<String>> {
+        let id = id.into();
+
+        let modules = sqlx::query_scalar::<_, Json<Vec<String>>>(
+            "SELECT disabled_modules FROM guilds WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(modules.map(|modules| modules.0).unwrap_or_default())
+    }
+
+    async fn set_module(
+        pool: &SqlitePool,
+        id: impl Into<GuildId> + Send,
+        module: &str,
+        enabled: bool,
+    ) -> sqlx::Result<Vec<String>> {
+        let id = id.into();
+
+        let mut tx = pool.begin().await?;
+
+        let mut modules = sqlx::query_scalar::<_, Json<Vec<String>>>(
+            "SELECT disabled_modules FROM guilds WHERE id = $1",
+        )
+        .bind(id.get() as i64)
+        .fetch_optional(&mut *tx)
+        .await?
+        .map(|modules| modules.0)
+        .unwrap_or_default();
+
+        modules.retain(|name| name != module);
+        if !enabled {
+            modules.push(module.to_string());
+        }
+
+        sqlx::query(
+            "INSERT INTO guilds (id, disabled_modules) VALUES ($1, $2)
+            ON CONFLICT (id) DO UPDATE SET disabled_modules = EXCLUDED.disabled_modules",
+        )
+        .bind(id.get() as i64)
+        .bind(Json(&modules))
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(modules)
+    }
+}
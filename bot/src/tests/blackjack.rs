@@ -0,0 +1,432 @@
+use chrono::{TimeDelta, Utc};
+use gambling::commands::blackjack::Shoes;
+use gambling::{Economy, LedgerManager, SessionManager, Sessions};
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::fixtures::USER_ID;
+use zayden_core::testing::{InteractionBuilder, Request, TestDiscord};
+use zayden_core::{Cooldowns, FormatNum};
+
+use crate::backend::Backend;
+
+/// Deals `cards` in order, as indexes into the deck, starting with the
+/// player's two cards and then the dealer's.
+pub(super) async fn stack_shoe(discord: &TestDiscord, cards: &[u8]) {
+    let mut shoe = (0..52).cycle().take(52 * 8).collect::<Vec<u8>>();
+    shoe.extend(cards.iter().rev());
+
+    Shoes::put(discord.ctx(), USER_ID, shoe).await;
+}
+
+/// Starts a 100 coin game, out of 1000, dealt from `cards`.
+async fn deal<Db: Backend>(pool: &Pool<Db>, cards: &[u8]) -> (TestDiscord, Request) {
+    super::seed_player(pool, 1000).await;
+
+    let discord = super::start(pool).await;
+    stack_shoe(&discord, cards).await;
+
+    let game = bet(&discord).await;
+
+    (discord, game)
+}
+
+async fn bet(discord: &TestDiscord) -> Request {
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "blackjack",
+        json!([{ "name": "bet", "type": 4, "value": 100 }]),
+    ));
+
+    discord.edited_response(&command.token()).await
+}
+
+async fn click(discord: &TestDiscord, custom_id: &str, game: &Request) -> Request {
+    let message_id = game.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let button = InteractionBuilder::new();
+    discord.interaction(button.component_json(custom_id, message_id));
+
+    discord.response(&button.token()).await
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn standing_settles_the_bet(pool: PgPool) {
+    standing(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn standing_settles_the_bet_sqlite(pool: SqlitePool) {
+    standing(pool).await
+}
+
+async fn standing<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+
+    // Ten and seven against the dealer's nine
+    stack_shoe(&discord, &[9, 6, 8, 7]).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "blackjack",
+        json!([{ "name": "bet", "type": 4, "value": 100 }]),
+    ));
+
+    let defer = discord.response(&command.token()).await;
+    assert_eq!(defer.body["type"], 5);
+
+    let game = discord.edited_response(&command.token()).await;
+    assert_eq!(game.body["embeds"][0]["title"], "Blackjack");
+    assert_eq!(
+        game.body["components"][0]["components"][1]["custom_id"],
+        "blackjack_stand"
+    );
+
+    // The bet is taken before the first card is dealt
+    assert_eq!(super::coins(&pool).await, 900);
+
+    let message_id = game.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+    let stand = InteractionBuilder::new();
+    discord.interaction(stand.component_json("blackjack_stand", message_id));
+
+    let result = discord.response(&stand.token()).await;
+    assert_eq!(result.body["type"], 7);
+    let embed = &result.body["data"]["embeds"][0];
+    assert!(embed["title"].as_str().unwrap().starts_with("Blackjack - "));
+    assert_eq!(result.body["data"]["components"], json!([]));
+
+    let coins = super::coins(&pool).await;
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains(&format!("Your coins: {}", coins.format()))
+    );
+
+    // The bet and the payout are both in the ledger, newest first
+    let history = Db::Ledger::history(&pool, Economy::GLOBAL, USER_ID, 1, 10)
+        .await
+        .unwrap();
+    let bet = history.last().unwrap();
+    assert_eq!(
+        (bet.source.as_str(), bet.amount, bet.balance),
+        ("blackjack", -100, 900)
+    );
+    assert_eq!(history[0].balance, coins);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn idle_games_stand(pool: PgPool) {
+    idle(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn idle_games_stand_sqlite(pool: SqlitePool) {
+    idle(pool).await
+}
+
+async fn idle<Db: Backend>(pool: Pool<Db>) {
+    let (discord, game) = deal(&pool, &[9, 6, 8, 7]).await;
+    let message_id = game.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let mut session = Db::Sessions::row(&pool, message_id).await.unwrap().unwrap();
+    session.expires_at = Utc::now() - TimeDelta::seconds(1);
+    Db::Sessions::update(&mut *pool.acquire().await.unwrap(), &session)
+        .await
+        .unwrap();
+
+    Sessions::expire::<Db, Db::Goals, Db::Effects, Db::Game, Db::Sessions, Db::Ledger>(
+        &discord.ctx().http,
+        &pool,
+    )
+    .await
+    .unwrap();
+
+    // The hand is played out as if the player stood, rather than refunded
+    let settled = discord
+        .wait_for("PATCH", &format!("/channels/*/messages/{message_id}"))
+        .await;
+    let embed = &settled.body["embeds"][0];
+    assert!(embed["title"].as_str().unwrap().starts_with("Blackjack - "));
+    assert_eq!(settled.body["components"], json!([]));
+
+    let history = Db::Ledger::history(&pool, Economy::GLOBAL, USER_ID, 1, 10)
+        .await
+        .unwrap();
+    assert_eq!(history[0].balance, super::coins(&pool).await);
+    assert!(
+        Db::Sessions::row(&pool, message_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn splitting_plays_two_hands(pool: PgPool) {
+    splitting(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn splitting_plays_two_hands_sqlite(pool: SqlitePool) {
+    splitting(pool).await
+}
+
+async fn splitting<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+
+    // A pair of eights against the dealer's six and ten, who busts on a king
+    stack_shoe(&discord, &[7, 20, 5, 9, 2, 1, 12]).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "blackjack",
+        json!([{ "name": "bet", "type": 4, "value": 100 }]),
+    ));
+    let game = discord.edited_response(&command.token()).await;
+    let message_id = game.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let split = InteractionBuilder::new();
+    discord.interaction(split.component_json("blackjack_split", message_id));
+    let hands = discord.response(&split.token()).await;
+    let desc = hands.body["data"]["embeds"][0]["description"]
+        .as_str()
+        .unwrap();
+    assert!(desc.contains("**Hand 2**"));
+
+    // The second hand has its own bet
+    assert_eq!(super::coins(&pool).await, 800);
+
+    for _ in 0..2 {
+        let stand = InteractionBuilder::new();
+        discord.interaction(stand.component_json("blackjack_stand", message_id));
+        discord.response(&stand.token()).await;
+    }
+
+    assert_eq!(super::coins(&pool).await, 1200);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn naturals_pay_three_to_two(pool: PgPool) {
+    natural(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn naturals_pay_three_to_two_sqlite(pool: SqlitePool) {
+    natural(pool).await
+}
+
+async fn natural<Db: Backend>(pool: Pool<Db>) {
+    // Ace and king against the dealer's six and five
+    let (_discord, game) = deal(&pool, &[0, 12, 5, 4]).await;
+
+    assert_eq!(game.body["embeds"][0]["title"], "Blackjack - You Won!");
+    assert_eq!(game.body["components"], json!([]));
+    assert_eq!(super::coins(&pool).await, 1150);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn dealer_peeks_for_blackjack(pool: PgPool) {
+    dealer_peek(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn dealer_peeks_for_blackjack_sqlite(pool: SqlitePool) {
+    dealer_peek(pool).await
+}
+
+async fn dealer_peek<Db: Backend>(pool: Pool<Db>) {
+    // The dealer's king hides an ace, so the hand is over before a move
+    let (_discord, game) = deal(&pool, &[9, 6, 12, 0]).await;
+
+    let embed = &game.body["embeds"][0];
+    assert_eq!(embed["title"], "Blackjack - You Lost!");
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains("Dealer has blackjack!")
+    );
+    assert_eq!(super::coins(&pool).await, 900);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn insurance_pays_two_to_one(pool: PgPool) {
+    insurance(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn insurance_pays_two_to_one_sqlite(pool: SqlitePool) {
+    insurance(pool).await
+}
+
+async fn insurance<Db: Backend>(pool: Pool<Db>) {
+    // Ten and seven against the dealer's ace and king
+    let (discord, game) = deal(&pool, &[9, 6, 0, 12]).await;
+    assert_eq!(
+        game.body["components"][0]["components"][0]["custom_id"],
+        "blackjack_insurance"
+    );
+
+    let result = click(&discord, "blackjack_insurance", &game).await;
+    let embed = &result.body["data"]["embeds"][0];
+    assert_eq!(embed["title"], "Blackjack - Draw!");
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains("Insurance: 50")
+    );
+
+    // The 50 coin insurance wins 100, covering the lost bet
+    assert_eq!(super::coins(&pool).await, 1000);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn surrendering_returns_half(pool: PgPool) {
+    surrender(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn surrendering_returns_half_sqlite(pool: SqlitePool) {
+    surrender(pool).await
+}
+
+async fn surrender<Db: Backend>(pool: Pool<Db>) {
+    // Ten and six against the dealer's nine and eight
+    let (discord, game) = deal(&pool, &[9, 5, 8, 7]).await;
+
+    let result = click(&discord, "blackjack_surrender", &game).await;
+    assert_eq!(
+        result.body["data"]["embeds"][0]["title"],
+        "Blackjack - Surrendered"
+    );
+    assert_eq!(super::coins(&pool).await, 950);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn split_aces_get_one_card(pool: PgPool) {
+    split_aces(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn split_aces_get_one_card_sqlite(pool: SqlitePool) {
+    split_aces(pool).await
+}
+
+async fn split_aces<Db: Backend>(pool: Pool<Db>) {
+    // A pair of aces against the dealer's seventeen, then a king and a queen
+    let (discord, game) = deal(&pool, &[0, 13, 8, 7, 12, 11]).await;
+
+    // Both hands stand on their second card, so the split ends the game
+    let result = click(&discord, "blackjack_split", &game).await;
+    let embed = &result.body["data"]["embeds"][0];
+    assert_eq!(embed["title"], "Blackjack - You Won!");
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains("**Hand 2** - Won")
+    );
+    assert_eq!(result.body["data"]["components"], json!([]));
+
+    // Twenty-one on a split isn't a natural, so each hand pays 1:1
+    assert_eq!(super::coins(&pool).await, 1200);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn resplitting_stops_at_four_hands(pool: PgPool) {
+    resplitting(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn resplitting_stops_at_four_hands_sqlite(pool: SqlitePool) {
+    resplitting(pool).await
+}
+
+async fn resplitting<Db: Backend>(pool: Pool<Db>) {
+    // Eights keep coming until the first hand is a pair again at four hands
+    let (discord, game) = deal(&pool, &[7, 7, 5, 9, 7, 7, 7, 7, 7, 2]).await;
+
+    for _ in 0..2 {
+        click(&discord, "blackjack_split", &game).await;
+    }
+    let result = click(&discord, "blackjack_split", &game).await;
+
+    let data = &result.body["data"];
+    assert!(
+        data["embeds"][0]["description"]
+            .as_str()
+            .unwrap()
+            .contains("**Hand 4**")
+    );
+    assert_eq!(
+        data["components"][1]["components"][0]["custom_id"],
+        "blackjack_split"
+    );
+    assert_eq!(data["components"][1]["components"][0]["disabled"], true);
+
+    assert_eq!(super::coins(&pool).await, 600);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn shoe_is_kept_until_the_cut_card(pool: PgPool) {
+    shoe(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn shoe_is_kept_until_the_cut_card_sqlite(pool: SqlitePool) {
+    shoe(pool).await
+}
+
+async fn shoe<Db: Backend>(pool: Pool<Db>) {
+    // A push on seventeen, then a natural from the same shoe
+    let (discord, game) = deal(&pool, &[9, 6, 8, 7, 0, 12, 5, 4]).await;
+    let result = click(&discord, "blackjack_stand", &game).await;
+    assert_eq!(
+        result.body["data"]["embeds"][0]["title"],
+        "Blackjack - Draw!"
+    );
+
+    // Games share a cooldown, which isn't what is being tested
+    discord
+        .ctx()
+        .data
+        .write()
+        .await
+        .insert::<Cooldowns>(Cooldowns::new());
+
+    let game = bet(&discord).await;
+    assert_eq!(game.body["embeds"][0]["title"], "Blackjack - You Won!");
+    assert_eq!(super::coins(&pool).await, 1150);
+
+    // Past the cut card the shoe is replaced rather than dealt dry
+    Shoes::put(discord.ctx(), USER_ID, vec![0, 12, 5]).await;
+
+    let game = bet(&discord).await;
+    assert!(
+        game.body["embeds"][0]["title"]
+            .as_str()
+            .unwrap()
+            .starts_with("Blackjack")
+    );
+}
@@ -0,0 +1,82 @@
+use chrono::{Duration, Utc};
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::USER_ID;
+use zayden_core::{CooldownManager, CooldownRow, Cooldowns};
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn games_share_a_cooldown(pool: PgPool) {
+    shared_game_cooldown(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn games_share_a_cooldown_sqlite(pool: SqlitePool) {
+    shared_game_cooldown(pool).await
+}
+
+async fn shared_game_cooldown<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+
+    let options = json!([
+        { "name": "prediction", "type": 3, "value": "heads" },
+        { "name": "bet", "type": 4, "value": 100 }
+    ]);
+
+    let first = InteractionBuilder::new();
+    discord.interaction(first.command_json("coinflip", options.clone()));
+    let result = discord.edited_response(&first.token()).await;
+    assert!(
+        result.body["embeds"][0]["title"]
+            .as_str()
+            .unwrap()
+            .starts_with("Coin Flip")
+    );
+
+    let second = InteractionBuilder::new();
+    discord.interaction(second.command_json("coinflip", options));
+    let refused = discord.edited_response(&second.token()).await;
+    assert!(
+        refused.body["content"]
+            .as_str()
+            .unwrap()
+            .starts_with("You are on cooldown")
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn persistent_uses_are_restored(pool: PgPool) {
+    restore_uses(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn persistent_uses_are_restored_sqlite(pool: SqlitePool) {
+    restore_uses(pool).await
+}
+
+async fn restore_uses<Db: Backend>(pool: Pool<Db>) {
+    let row = CooldownRow {
+        name: String::from("gift"),
+        bucket_id: USER_ID.get() as i64,
+        used_at: Utc::now() - Duration::seconds(30),
+    };
+    Db::Cooldowns::replace(&pool, vec![row]).await.unwrap();
+
+    let discord = super::start(&pool).await;
+    Cooldowns::load::<Db, Db::Cooldowns>(discord.ctx(), &pool)
+        .await
+        .unwrap();
+
+    let gift = InteractionBuilder::new();
+    let result = Cooldowns::check(discord.ctx(), "gift", &gift.command("gift", json!([]))).await;
+    assert!(result.is_err());
+
+    Cooldowns::save::<Db, Db::Cooldowns>(&discord.ctx().data, &pool)
+        .await
+        .unwrap();
+    assert_eq!(Db::Cooldowns::rows(&pool).await.unwrap().len(), 1);
+}
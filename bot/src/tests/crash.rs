@@ -0,0 +1,103 @@
+use gambling::SessionManager;
+use serde_json::json;
+use serenity::all::UserId;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::fixtures::{USER_ID, snowflake};
+use zayden_core::testing::{InteractionBuilder, TestDiscord};
+
+use crate::backend::Backend;
+
+/// Waits for the round's message to change phase, returning its new title.
+async fn next_phase(discord: &TestDiscord, token: &str, title: &str) -> String {
+    loop {
+        let edit = discord.edited_response(token).await;
+        let next = edit.body["embeds"][0]["title"].as_str().unwrap();
+
+        if next != title {
+            return next.to_string();
+        }
+    }
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn players_share_a_round(pool: PgPool) {
+    shared_round(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn players_share_a_round_sqlite(pool: SqlitePool) {
+    shared_round(pool).await
+}
+
+async fn shared_round<Db: Backend>(pool: Pool<Db>) {
+    let other = UserId::new(snowflake());
+
+    for id in [USER_ID, other] {
+        super::seed_user(&pool, id, 1000).await;
+    }
+
+    let discord = super::start(&pool).await;
+    let bet = json!([{ "name": "bet", "type": 4, "value": 100 }]);
+
+    let host = InteractionBuilder::new();
+    discord.interaction(host.command_json("crash", bet.clone()));
+    let round = discord.edited_response(&host.token()).await;
+    assert_eq!(round.body["embeds"][0]["title"], "Crash");
+    let message_id = round.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let join = InteractionBuilder::new().user(other);
+    discord.interaction(join.command_json("crash", bet));
+    let joined = discord.edited_response(&join.token()).await;
+    assert!(
+        joined.body["content"]
+            .as_str()
+            .unwrap()
+            .starts_with("You joined the round")
+    );
+
+    assert_eq!(super::user_coins(&pool, other).await, 900);
+
+    let click = |user: UserId, custom_id: &str| {
+        let button = InteractionBuilder::new().user(user);
+        discord.interaction(button.component_json(custom_id, message_id));
+        button.token()
+    };
+
+    let start = click(USER_ID, "crash_start");
+    discord.response(&start).await;
+
+    // Bets are taken as players join
+    assert_eq!(super::user_coins(&pool, USER_ID).await, 900);
+
+    let mut title = next_phase(&discord, &host.token(), "Crash").await;
+
+    // A round can crash before anyone gets the chance to cash out, and clicks
+    // that come in after the crash go unanswered
+    if !title.starts_with("Crash - Crashed") {
+        for user in [USER_ID, other] {
+            click(user, "crash_cash_out");
+        }
+
+        while !title.starts_with("Crash - Crashed") {
+            title = next_phase(&discord, &host.token(), &title).await;
+        }
+    }
+
+    // Everyone either lost their bet or cashed out at x1.00 or more
+    for user in [USER_ID, other] {
+        let coins = super::user_coins(&pool, user).await;
+        assert!(coins == 900 || coins >= 1000, "{coins}");
+    }
+
+    // The stored round is only needed to refund bets if the bot goes down
+    assert!(
+        Db::Sessions::row(&pool, message_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
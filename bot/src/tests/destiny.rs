@@ -0,0 +1,84 @@
+use endgame_analysis::{DestinyPerk, DestinyPerkManager, DestinyWeapon, DestinyWeaponManager};
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn perks_are_looked_up_by_name(pool: PgPool) {
+    perk(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn perks_are_looked_up_by_name_sqlite(pool: SqlitePool) {
+    perk(pool).await
+}
+
+async fn perk<Db: Backend>(pool: Pool<Db>) {
+    let perks = [
+        DestinyPerk {
+            id: 1,
+            name: String::from("Outlaw"),
+            description: String::from("Precision kills reload faster."),
+        },
+        DestinyPerk {
+            id: 2,
+            name: String::from("Rampage"),
+            description: String::from("Kills increase damage."),
+        },
+    ];
+    Db::DestinyPerks::replace(&pool, &perks).await.unwrap();
+
+    let found = Db::DestinyPerks::get_all(&pool, &[String::from("Rampage")])
+        .await
+        .unwrap();
+    assert_eq!(found.iter().map(|perk| perk.id).collect::<Vec<_>>(), [2]);
+
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "perk",
+        json!([{ "name": "perk", "type": 3, "value": "Outlaw" }]),
+    ));
+    let result = discord.edited_response(&command.token()).await;
+    assert_eq!(
+        result.body["content"],
+        "__Outlaw__\nPrecision kills reload faster."
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn weapons_keep_their_perk_columns(pool: PgPool) {
+    weapon(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn weapons_keep_their_perk_columns_sqlite(pool: SqlitePool) {
+    weapon(pool).await
+}
+
+async fn weapon<Db: Backend>(pool: Pool<Db>) {
+    let weapons = [DestinyWeapon {
+        id: 10,
+        icon: String::from("/icon.png"),
+        name: String::from("Fatebringer"),
+        column_1: vec![1, 2],
+        column_2: vec![3],
+        perk_1: vec![4, 5],
+        perk_2: Vec::new(),
+    }];
+    Db::DestinyWeapons::replace(&pool, &weapons).await.unwrap();
+
+    let found = Db::DestinyWeapons::get_by_prefix(&pool, "Fate")
+        .await
+        .unwrap();
+    assert_eq!(found.len(), 1);
+
+    let weapon = Db::DestinyWeapons::get(&pool, "Fatebringer").await.unwrap();
+    assert_eq!(weapon.icon, "/icon.png");
+    assert_eq!(weapon.column_1, [1, 2]);
+    assert_eq!(weapon.perk_1, [4, 5]);
+    assert!(weapon.perk_2.is_empty());
+}
@@ -0,0 +1,141 @@
+use gambling::{Economies, Economy, EconomyManager, GameManager, GameRow, LedgerManager};
+use serde_json::json;
+use serenity::all::UserId;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::{GUILD_ID, USER_ID};
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn guild_economy_is_separate(pool: PgPool) {
+    separate(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn guild_economy_is_separate_sqlite(pool: SqlitePool) {
+    separate(pool).await
+}
+
+async fn separate<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    Db::Economies::enable(&pool, GUILD_ID).await.unwrap();
+
+    let discord = super::start(&pool).await;
+    Economies::set(discord.ctx(), GUILD_ID, true).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json("daily", json!([])));
+    discord.edited_response(&command.token()).await;
+
+    // The daily coins go to a new balance in the server's economy
+    let economy = Economy::guild(GUILD_ID);
+    let guild = Db::Game::row(&pool, economy, USER_ID).await.unwrap();
+    assert!(guild.unwrap().coins > 0);
+    assert_eq!(Db::Ledger::count(&pool, economy, USER_ID).await.unwrap(), 1);
+
+    assert_eq!(super::coins(&pool).await, 1000);
+    assert_eq!(
+        Db::Ledger::count(&pool, Economy::GLOBAL, USER_ID)
+            .await
+            .unwrap(),
+        0
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn import_and_reset_keep_the_ledger(pool: PgPool) {
+    import_and_reset(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn import_and_reset_keep_the_ledger_sqlite(pool: SqlitePool) {
+    import_and_reset(pool).await
+}
+
+async fn import_and_reset<Db: Backend>(pool: Pool<Db>) {
+    let other = UserId::new(USER_ID.get() + 1);
+    let economy = Economy::guild(GUILD_ID);
+
+    for id in [USER_ID, other] {
+        let row = GameRow {
+            id: id.get() as i64,
+            coins: 1000,
+            gems: 5,
+            level: None,
+            prestige: None,
+        };
+        Db::Game::save(&mut *pool.acquire().await.unwrap(), Economy::GLOBAL, row)
+            .await
+            .unwrap();
+    }
+
+    // The other user already played in the server's economy
+    let row = GameRow {
+        id: other.get() as i64,
+        coins: 50,
+        gems: 0,
+        level: None,
+        prestige: None,
+    };
+    Db::Game::save(&mut *pool.acquire().await.unwrap(), economy, row)
+        .await
+        .unwrap();
+
+    Db::Economies::enable(&pool, GUILD_ID).await.unwrap();
+    Db::Economies::import(&pool, GUILD_ID, &[USER_ID.get() as i64, other.get() as i64])
+        .await
+        .unwrap();
+
+    let imported = Db::Game::row(&pool, economy, USER_ID)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!((imported.coins, imported.gems), (1000, 5));
+
+    let history = Db::Ledger::history(&pool, economy, USER_ID, 1, 10)
+        .await
+        .unwrap();
+    let mut entries = history
+        .iter()
+        .map(|row| {
+            (
+                row.source.as_str(),
+                row.currency.as_str(),
+                row.amount,
+                row.balance,
+            )
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+    assert_eq!(
+        entries,
+        [("import", "coins", 1000, 1000), ("import", "gems", 5, 5)]
+    );
+
+    // Existing balances aren't overwritten or recorded again
+    let skipped = Db::Game::row(&pool, economy, other).await.unwrap().unwrap();
+    assert_eq!((skipped.coins, skipped.gems), (50, 0));
+    assert_eq!(Db::Ledger::count(&pool, economy, other).await.unwrap(), 0);
+
+    Db::Economies::reset(&pool, GUILD_ID).await.unwrap();
+
+    // The balances are gone but the ledger closes each of them at 0
+    assert!(
+        Db::Game::row(&pool, economy, USER_ID)
+            .await
+            .unwrap()
+            .is_none()
+    );
+    let history = Db::Ledger::history(&pool, economy, USER_ID, 1, 10)
+        .await
+        .unwrap();
+    assert_eq!(history.len(), 4);
+    assert!(
+        history[..2]
+            .iter()
+            .all(|row| row.source == "reset" && row.balance == 0)
+    );
+    assert_eq!(Db::Ledger::count(&pool, economy, other).await.unwrap(), 1);
+}
@@ -0,0 +1,133 @@
+use chrono::{Duration, Utc};
+use lfg::{PostManager, PostRow, Savable};
+use serde_json::Value;
+use serenity::all::{ChannelId, UserId};
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::{self, USER_ID};
+
+use crate::backend::Backend;
+
+async fn create_post<Db: Backend>(pool: &Pool<Db>, owner: UserId) -> ChannelId {
+    let thread = ChannelId::new(fixtures::snowflake());
+
+    let row = PostRow {
+        id: thread.get() as i64,
+        owner: owner.get() as i64,
+        activity: String::from("Vault of Glass"),
+        start_time: Utc::now() + Duration::days(1),
+        description: String::new(),
+        fireteam_size: 6,
+        fireteam: vec![owner.get() as i64],
+        alternatives: Vec::new(),
+        alt_channel: None,
+        alt_message: None,
+    };
+    Db::LfgPosts::save(pool, row).await.unwrap();
+
+    thread
+}
+
+async fn in_fireteam<Db: Backend>(pool: &Pool<Db>, thread: ChannelId, user: UserId) -> bool {
+    Db::LfgPosts::row(pool, thread)
+        .await
+        .unwrap()
+        .fireteam
+        .contains(&(user.get() as i64))
+}
+
+fn field<'a>(embed: &'a Value, name: &str) -> Option<&'a Value> {
+    embed["fields"]
+        .as_array()?
+        .iter()
+        .find(|field| field["name"] == name)
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn join_button_adds_user_to_fireteam(pool: PgPool) {
+    join_adds_user(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn join_button_adds_user_to_fireteam_sqlite(pool: SqlitePool) {
+    join_adds_user(pool).await
+}
+
+async fn join_adds_user<Db: Backend>(pool: Pool<Db>) {
+    let discord = super::start(&pool).await;
+    let owner = UserId::new(fixtures::snowflake());
+    let thread = create_post(&pool, owner).await;
+
+    let interaction = InteractionBuilder::new().channel(thread);
+    discord.interaction(interaction.component_json("lfg_join", thread.get()));
+
+    let edit = discord
+        .wait_for("PATCH", &format!("/channels/{thread}/messages/{thread}"))
+        .await;
+    let joined = field(&edit.body["embeds"][0], "Joined: 2/6").expect("joined field");
+    assert!(
+        joined["value"]
+            .as_str()
+            .unwrap()
+            .contains(&format!("<@{USER_ID}>"))
+    );
+
+    let announcement = discord
+        .wait_for("POST", &format!("/channels/{thread}/messages"))
+        .await;
+    assert_eq!(
+        announcement.body["content"],
+        format!("<@{USER_ID}> joined the fireteam")
+    );
+
+    let response = discord.response(&interaction.token()).await;
+    assert_eq!(response.body["type"], 6);
+
+    assert!(in_fireteam(&pool, thread, USER_ID).await);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn join_button_uses_guild_locale(pool: PgPool) {
+    let discord = super::start(&pool).await;
+    let owner = UserId::new(fixtures::snowflake());
+    let thread = create_post(&pool, owner).await;
+
+    let interaction = InteractionBuilder::new().channel(thread).guild_locale("de");
+    discord.interaction(interaction.component_json("lfg_join", thread.get()));
+
+    let edit = discord
+        .wait_for("PATCH", &format!("/channels/{thread}/messages/{thread}"))
+        .await;
+    assert!(field(&edit.body["embeds"][0], "Beigetreten: 2/6").is_some());
+
+    let announcement = discord
+        .wait_for("POST", &format!("/channels/{thread}/messages"))
+        .await;
+    assert_eq!(
+        announcement.body["content"],
+        format!("<@{USER_ID}> ist dem Einsatztrupp beigetreten")
+    );
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn join_button_rejects_existing_member(pool: PgPool) {
+    let discord = super::start(&pool).await;
+    let thread = create_post(&pool, USER_ID).await;
+
+    let interaction = InteractionBuilder::new().channel(thread);
+    discord.interaction(interaction.component_json("lfg_join", thread.get()));
+
+    let response = discord.response(&interaction.token()).await;
+    assert_eq!(response.body["type"], 5);
+    assert_eq!(response.body["data"]["flags"], 64);
+
+    let error = discord.edited_response(&interaction.token()).await;
+    assert_eq!(error.body["content"], "You have already joined this LFG.");
+
+    assert!(
+        !discord
+            .requests()
+            .iter()
+            .any(|request| request.is("PATCH", &format!("/channels/{thread}/messages/*")))
+    );
+}
@@ -0,0 +1,64 @@
+use gambling::commands::mines::Minefields;
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::USER_ID;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn cashing_out_pays_the_multiplier(pool: PgPool) {
+    cashing_out(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn cashing_out_pays_the_multiplier_sqlite(pool: SqlitePool) {
+    cashing_out(pool).await
+}
+
+async fn cashing_out<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+    Minefields::put(discord.ctx(), USER_ID, vec![21, 22, 23]).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "mines",
+        json!([
+            { "name": "bet", "type": 4, "value": 100 },
+            { "name": "bombs", "type": 4, "value": 3 },
+        ]),
+    ));
+    let board = discord.edited_response(&command.token()).await;
+    assert_eq!(board.body["embeds"][0]["title"], "Mines");
+    assert_eq!(board.body["components"].as_array().unwrap().len(), 5);
+    let message_id = board.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    assert_eq!(super::coins(&pool).await, 900);
+
+    let click = |custom_id: &str| {
+        let button = InteractionBuilder::new();
+        discord.interaction(button.component_json(custom_id, message_id));
+        button.token()
+    };
+
+    // The bombs are in the bottom corner, away from the first tile
+    let reveal = click("mines_0");
+    let revealed = discord.response(&reveal).await;
+    assert_eq!(revealed.body["data"]["embeds"][0]["title"], "Mines");
+
+    let cash_out = click("mines_cash_out");
+    let result = discord.response(&cash_out).await;
+    assert_eq!(
+        result.body["data"]["embeds"][0]["title"],
+        "Mines - You Won!"
+    );
+
+    // One safe tile with 3 bombs pays 24/21 less the house edge, x1.10
+    assert_eq!(super::coins(&pool).await, 1010);
+}
@@ -0,0 +1,88 @@
+//! End-to-end tests that run the bot's event handler against
+//! [`TestDiscord`] and a disposable database.
+//!
+//! Postgres tests need `DATABASE_URL` to point at a server that
+//! `#[sqlx::test]` can create databases on. Each one gets a fresh database
+//! with the `migrations` scripts run against it. SQLite tests run the
+//! `migrations-sqlite` scripts against a temporary file instead.
+
+use gambling::{Economy, GameManager, GameRow};
+use levels::{FullLevelRow, LevelsManager};
+use serenity::all::UserId;
+use serenity::prelude::TypeMap;
+use sqlx::Pool;
+use zayden_core::testing::TestDiscord;
+use zayden_core::testing::fixtures::USER_ID;
+use zayden_core::{Cooldowns, Shutdown};
+
+use crate::backend::Backend;
+use crate::handler::Handler;
+use crate::sqlx_lib::DatabasePool;
+
+mod blackjack;
+mod cooldowns;
+mod crash;
+mod destiny;
+mod economy;
+mod lfg;
+mod mines;
+mod modules;
+mod paginator;
+mod poker;
+mod registry;
+mod roulette;
+mod shutdown;
+mod slots;
+
+/// Connects the bot's handler to a [`TestDiscord`] using `pool`.
+async fn start<Db: Backend>(pool: &Pool<Db>) -> TestDiscord {
+    crate::config::load_from(concat!(env!("CARGO_MANIFEST_DIR"), "/../config.toml")).unwrap();
+
+    let mut type_map = TypeMap::new();
+    type_map.insert::<DatabasePool<Db>>(pool.clone());
+    type_map.insert::<Cooldowns>(crate::cooldowns::limits());
+    type_map.insert::<Shutdown>(Shutdown::new());
+
+    TestDiscord::builder()
+        .type_map(type_map)
+        .raw_event_handler(Handler::<Db>::new())
+        .start()
+        .await
+}
+
+/// Gives [`USER_ID`] `coins` to play with, see [`seed_user`].
+async fn seed_player<Db: Backend>(pool: &Pool<Db>, coins: i64) {
+    seed_user(pool, USER_ID, coins).await
+}
+
+/// Gives `user` `coins` in the global economy and a first level, which the
+/// maximum bet is based on.
+async fn seed_user<Db: Backend>(pool: &Pool<Db>, user: UserId, coins: i64) {
+    let row = GameRow {
+        id: user.get() as i64,
+        coins,
+        gems: 0,
+        level: None,
+        prestige: None,
+    };
+    Db::Game::save(&mut *pool.acquire().await.unwrap(), Economy::GLOBAL, row)
+        .await
+        .unwrap();
+    Db::Levels::save(pool, FullLevelRow::new(user))
+        .await
+        .unwrap();
+}
+
+/// The global coins of [`USER_ID`].
+async fn coins<Db: Backend>(pool: &Pool<Db>) -> i64 {
+    user_coins(pool, USER_ID).await
+}
+
+/// The global coins of `user`.
+async fn user_coins<Db: Backend>(pool: &Pool<Db>, user: UserId) -> i64 {
+    Db::Game::row(pool, Economy::GLOBAL, user)
+        .await
+        .unwrap()
+        .unwrap()
+        .coins
+}
@@ -0,0 +1,60 @@
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn disabling_a_module_refuses_its_commands(pool: PgPool) {
+    disabling(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn disabling_a_module_refuses_its_commands_sqlite(pool: SqlitePool) {
+    disabling(pool).await
+}
+
+async fn disabling<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+
+    let slots = json!([{ "name": "bet", "type": 4, "value": 100 }]);
+
+    // Playing first keeps the guild's modules in memory
+    let first = InteractionBuilder::new();
+    discord.interaction(first.command_json("slots", slots.clone()));
+    let played = discord.edited_response(&first.token()).await;
+    assert!(
+        played.body["embeds"][0]["title"]
+            .as_str()
+            .unwrap()
+            .starts_with("Slots - ")
+    );
+
+    let config = InteractionBuilder::new();
+    discord.interaction(config.command_json(
+        "config",
+        json!([{
+            "name": "modules",
+            "type": 1,
+            "options": [
+                { "name": "module", "type": 3, "value": "gambling" },
+                { "name": "enabled", "type": 5, "value": false },
+            ],
+        }]),
+    ));
+    let modules = discord.edited_response(&config.token()).await;
+    assert_eq!(
+        modules.body["embeds"][0]["description"],
+        "`gambling` - Disabled"
+    );
+
+    let second = InteractionBuilder::new();
+    discord.interaction(second.command_json("slots", slots));
+    let refused = discord.edited_response(&second.token()).await;
+    assert_eq!(
+        refused.body["content"],
+        "The `gambling` module is disabled in this server."
+    );
+}
@@ -0,0 +1,98 @@
+use serde_json::json;
+use serenity::all::UserId;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn buttons_change_page(pool: PgPool) {
+    change_page(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn buttons_change_page_sqlite(pool: SqlitePool) {
+    change_page(pool).await
+}
+
+async fn change_page<Db: Backend>(pool: Pool<Db>) {
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "inventory",
+        json!([{ "name": "show", "type": 1, "options": [] }]),
+    ));
+
+    let inventory = discord.edited_response(&command.token()).await;
+    assert_eq!(inventory.body["embeds"][0]["footer"]["text"], "Page 1/3");
+    assert_eq!(
+        inventory.body["components"][0]["components"][0]["disabled"],
+        true
+    );
+
+    let message_id = inventory.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    // Only the user who ran the command can turn the pages
+    let other = InteractionBuilder::new().user(UserId::new(fixtures::snowflake()));
+    discord.interaction(other.component_json("paginator_next", message_id));
+    let refused = discord.response(&other.token()).await;
+    assert_eq!(refused.body["data"]["flags"], 64);
+
+    let next = InteractionBuilder::new();
+    discord.interaction(next.component_json("paginator_last", message_id));
+    let last = discord.response(&next.token()).await;
+    assert_eq!(last.body["type"], 7);
+    assert_eq!(
+        last.body["data"]["embeds"][0]["fields"][0]["name"],
+        "Boosts"
+    );
+    assert_eq!(last.body["data"]["embeds"][0]["footer"]["text"], "Page 3/3");
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn jump_shows_the_page_entered(pool: PgPool) {
+    jump(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn jump_shows_the_page_entered_sqlite(pool: SqlitePool) {
+    jump(pool).await
+}
+
+async fn jump<Db: Backend>(pool: Pool<Db>) {
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "inventory",
+        json!([{ "name": "show", "type": 1, "options": [] }]),
+    ));
+
+    let inventory = discord.edited_response(&command.token()).await;
+    let message_id = inventory.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let button = InteractionBuilder::new();
+    discord.interaction(button.component_json("paginator_jump", message_id));
+    let modal = discord.response(&button.token()).await;
+    assert_eq!(modal.body["type"], 9);
+
+    // The modal is only read by the paginator, never by a modal handler
+    let custom_id = modal.body["data"]["custom_id"].as_str().unwrap();
+    assert_eq!(custom_id, format!("paginator_jump_{}", button.id()));
+
+    let submit = InteractionBuilder::new();
+    discord.interaction(submit.message_modal_json(custom_id, message_id, &[("page", "2")]));
+    let page = discord.response(&submit.token()).await;
+    assert_eq!(page.body["type"], 7);
+    assert_eq!(page.body["data"]["embeds"][0]["footer"]["text"], "Page 2/3");
+}
@@ -0,0 +1,155 @@
+use chrono::{TimeDelta, Utc};
+use gambling::{SessionManager, Sessions};
+use serde_json::json;
+use serenity::all::UserId;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::{USER_ID, snowflake};
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn folding_pays_the_blinds(pool: PgPool) {
+    folding(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn folding_pays_the_blinds_sqlite(pool: SqlitePool) {
+    folding(pool).await
+}
+
+async fn folding<Db: Backend>(pool: Pool<Db>) {
+    let other = UserId::new(snowflake());
+
+    for id in [USER_ID, other] {
+        super::seed_user(&pool, id, 1000).await;
+    }
+
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "poker",
+        json!([
+            { "name": "buy_in", "type": 4, "value": 500 },
+            { "name": "big_blind", "type": 4, "value": 20 },
+        ]),
+    ));
+    let table = discord.edited_response(&command.token()).await;
+    assert_eq!(table.body["embeds"][0]["title"], "Poker");
+    let message_id = table.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    // Each player buys in for as much as the table allows
+    assert_eq!(super::user_coins(&pool, USER_ID).await, 500);
+
+    let click = |user: UserId, custom_id: &str| {
+        let button = InteractionBuilder::new().user(user);
+        discord.interaction(button.component_json(custom_id, message_id));
+        button.token()
+    };
+
+    let join = click(other, "poker_join");
+    discord.response(&join).await;
+    assert_eq!(super::user_coins(&pool, other).await, 500);
+
+    // Heads up, the player after the host has the button and acts first
+    let deal = click(USER_ID, "poker_deal");
+    let hand = discord.response(&deal).await;
+    assert_eq!(
+        hand.body["data"]["components"][0]["components"][1]["label"],
+        "Call 10"
+    );
+
+    let fold = click(other, "poker_fold");
+    discord.response(&fold).await;
+
+    for user in [other, USER_ID] {
+        let leave = click(user, "poker_leave");
+        discord.response(&leave).await;
+    }
+
+    // The host won the small blind, and the table closed once it was empty
+    assert_eq!(super::user_coins(&pool, USER_ID).await, 1010);
+    assert_eq!(super::user_coins(&pool, other).await, 990);
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn idle_players_fold(pool: PgPool) {
+    idle(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn idle_players_fold_sqlite(pool: SqlitePool) {
+    idle(pool).await
+}
+
+async fn idle<Db: Backend>(pool: Pool<Db>) {
+    let other = UserId::new(snowflake());
+
+    for id in [USER_ID, other] {
+        super::seed_user(&pool, id, 1000).await;
+    }
+
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "poker",
+        json!([
+            { "name": "buy_in", "type": 4, "value": 500 },
+            { "name": "big_blind", "type": 4, "value": 20 },
+        ]),
+    ));
+    let table = discord.edited_response(&command.token()).await;
+    let message_id = table.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let click = |user: UserId, custom_id: &str| {
+        let button = InteractionBuilder::new().user(user);
+        discord.interaction(button.component_json(custom_id, message_id));
+        button.token()
+    };
+
+    let join = click(other, "poker_join");
+    discord.response(&join).await;
+    let deal = click(USER_ID, "poker_deal");
+    discord.response(&deal).await;
+
+    // The player on the button stops playing with the small blind to call
+    let mut session = Db::Sessions::row(&pool, message_id).await.unwrap().unwrap();
+    session.expires_at = Utc::now() - TimeDelta::seconds(1);
+    Db::Sessions::update(&mut *pool.acquire().await.unwrap(), &session)
+        .await
+        .unwrap();
+
+    Sessions::expire::<Db, Db::Goals, Db::Effects, Db::Game, Db::Sessions, Db::Ledger>(
+        &discord.ctx().http,
+        &pool,
+    )
+    .await
+    .unwrap();
+
+    // Their hand is folded and the table stays open for the next one
+    let folded = discord
+        .wait_for("PATCH", &format!("/channels/*/messages/{message_id}"))
+        .await;
+    assert_eq!(
+        folded.body["components"][0]["components"][2]["custom_id"],
+        "poker_deal"
+    );
+
+    for user in [other, USER_ID] {
+        let leave = click(user, "poker_leave");
+        discord.response(&leave).await;
+    }
+
+    assert_eq!(super::user_coins(&pool, USER_ID).await, 1010);
+    assert_eq!(super::user_coins(&pool, other).await, 990);
+}
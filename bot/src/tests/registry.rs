@@ -0,0 +1,22 @@
+use sqlx::{PgPool, Pool, SqlitePool};
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn commands_are_named_as_registered(pool: PgPool) {
+    names(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn commands_are_named_as_registered_sqlite(pool: SqlitePool) {
+    names(pool).await
+}
+
+/// A command registered under another name than it creates could never be
+/// routed.
+async fn names<Db: Backend>(pool: Pool<Db>) {
+    let discord = super::start(&pool).await;
+
+    let misnamed = Db::registry().misnamed(discord.ctx()).unwrap();
+    assert!(misnamed.is_empty(), "misnamed commands: {misnamed:?}");
+}
@@ -0,0 +1,81 @@
+use gambling::commands::roulette::Pockets;
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::USER_ID;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn spinning_settles_the_slip(pool: PgPool) {
+    spinning(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn spinning_settles_the_slip_sqlite(pool: SqlitePool) {
+    spinning(pool).await
+}
+
+async fn spinning<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json("roulette", json!([])));
+    let table = discord.edited_response(&command.token()).await;
+    assert_eq!(
+        table.body["embeds"][0]["title"],
+        "Roulette - European Wheel"
+    );
+    let message_id = table.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    // Every pocket is covered, and the spin lands on the zero
+    let bets = [
+        ("straight", vec![("numbers", "0"), ("amount", "100")]),
+        ("dozen_1", vec![("amount", "100")]),
+        ("dozen_2", vec![("amount", "100")]),
+        ("dozen_3", vec![("amount", "100")]),
+    ];
+
+    for (kind, fields) in bets {
+        let select = InteractionBuilder::new();
+        discord.interaction(select.select_json("roulette_bet", message_id, &[kind]));
+        let form = discord.response(&select.token()).await;
+        assert_eq!(form.body["type"], 9);
+        assert_eq!(
+            form.body["data"]["custom_id"],
+            format!("roulette_bet_{kind}")
+        );
+
+        let modal = InteractionBuilder::new();
+        discord.interaction(modal.message_modal_json(
+            &format!("roulette_bet_{kind}"),
+            message_id,
+            &fields,
+        ));
+        let slip = discord.response(&modal.token()).await;
+        assert_eq!(slip.body["type"], 7);
+    }
+
+    // Nothing is taken until the wheel is spun
+    assert_eq!(super::coins(&pool).await, 1000);
+
+    Pockets::put(discord.ctx(), USER_ID, 0).await;
+
+    let spin = InteractionBuilder::new();
+    discord.interaction(spin.component_json("roulette_spin", message_id));
+    let result = discord.response(&spin.token()).await;
+    assert_eq!(result.body["data"]["components"], json!([]));
+
+    // A straight up pays 35 to 1, and the dozens lose, less the 400 staked
+    let description = result.body["data"]["embeds"][0]["description"]
+        .as_str()
+        .unwrap();
+    assert!(description.contains("landed on 🟢"));
+    assert_eq!(super::coins(&pool).await, 4200);
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use gambling::{SessionManager, Sessions};
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::Shutdown;
+use zayden_core::testing::InteractionBuilder;
+
+use crate::backend::Backend;
+
+#[sqlx::test(migrations = "../migrations")]
+async fn open_games_outlive_shutdown(pool: PgPool) {
+    outlive_shutdown(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn open_games_outlive_shutdown_sqlite(pool: SqlitePool) {
+    outlive_shutdown(pool).await
+}
+
+async fn outlive_shutdown<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+    super::blackjack::stack_shoe(&discord, &[9, 6, 8, 7]).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(command.command_json(
+        "blackjack",
+        json!([{ "name": "bet", "type": 4, "value": 100 }]),
+    ));
+    let game = discord.edited_response(&command.token()).await;
+    assert_eq!(game.body["embeds"][0]["title"], "Blackjack");
+    let message_id = game.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+
+    let shutdown = discord.ctx().data.read().await.get::<Shutdown>().cloned();
+    let shutdown = shutdown.unwrap();
+    shutdown.start();
+
+    // The game is stored rather than settled, so it carries on after the restart
+    assert!(shutdown.idle(Duration::from_secs(5)).await);
+    assert!(
+        Db::Sessions::row(&pool, message_id)
+            .await
+            .unwrap()
+            .is_some()
+    );
+    assert_eq!(super::coins(&pool).await, 900);
+
+    let refused = InteractionBuilder::new();
+    discord.interaction(refused.command_json(
+        "blackjack",
+        json!([{ "name": "bet", "type": 4, "value": 100 }]),
+    ));
+    let refused = discord.edited_response(&refused.token()).await;
+    assert_eq!(
+        refused.body["content"],
+        "The bot is restarting. Please try again in a moment."
+    );
+
+    // A game that timed out while the bot was offline is refunded on startup
+    let mut session = Db::Sessions::row(&pool, message_id).await.unwrap().unwrap();
+    session.expires_at = Utc::now() - TimeDelta::seconds(1);
+    Db::Sessions::update(&mut *pool.acquire().await.unwrap(), &session)
+        .await
+        .unwrap();
+
+    Sessions::refund_expired::<Db, Db::Game, Db::Sessions, Db::Ledger>(&discord.ctx().http, &pool)
+        .await
+        .unwrap();
+
+    let refunded = discord
+        .wait_for("PATCH", &format!("/channels/*/messages/{message_id}"))
+        .await;
+    assert_eq!(refunded.body["embeds"][0]["title"], "Blackjack");
+    assert_eq!(refunded.body["components"], json!([]));
+    assert_eq!(super::coins(&pool).await, 1000);
+    assert!(
+        Db::Sessions::row(&pool, message_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
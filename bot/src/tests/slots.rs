@@ -0,0 +1,63 @@
+use gambling::commands::slots::Stops;
+use gambling::{Economy, LedgerManager};
+use serde_json::json;
+use sqlx::{PgPool, Pool, SqlitePool};
+use zayden_core::FormatNum;
+use zayden_core::testing::InteractionBuilder;
+use zayden_core::testing::fixtures::USER_ID;
+
+use crate::backend::Backend;
+
+#[test]
+fn configured_machine_keeps_a_house_edge() {
+    crate::config::load_from(concat!(env!("CARGO_MANIFEST_DIR"), "/../config.toml")).unwrap();
+
+    let rtp = gambling::config::slots().rtp();
+    assert!((0.9..1.0).contains(&rtp), "the slots return {rtp}");
+}
+
+#[sqlx::test(migrations = "../migrations")]
+async fn spinning_records_the_payout(pool: PgPool) {
+    spinning(pool).await
+}
+
+#[sqlx::test(migrations = "../migrations-sqlite")]
+async fn spinning_records_the_payout_sqlite(pool: SqlitePool) {
+    spinning(pool).await
+}
+
+async fn spinning<Db: Backend>(pool: Pool<Db>) {
+    super::seed_player(&pool, 1000).await;
+
+    let discord = super::start(&pool).await;
+    // Iron across the middle line and nothing on the others
+    Stops::put(discord.ctx(), USER_ID, vec![1, 1, 0]).await;
+
+    let command = InteractionBuilder::new();
+    discord.interaction(
+        command.command_json("slots", json!([{ "name": "bet", "type": 4, "value": 100 }])),
+    );
+    let result = discord.edited_response(&command.token()).await;
+    let embed = &result.body["embeds"][0];
+    assert!(embed["title"].as_str().unwrap().starts_with("Slots - "));
+
+    // Three iron pay twice the bet
+    let coins = super::coins(&pool).await;
+    assert_eq!(coins, 1100);
+    assert!(
+        embed["description"]
+            .as_str()
+            .unwrap()
+            .contains(&format!("Your coins: {}", coins.format()))
+    );
+
+    let history = Db::Ledger::history(&pool, Economy::GLOBAL, USER_ID, 1, 10)
+        .await
+        .unwrap();
+    let change = history
+        .iter()
+        .filter(|row| row.source == "slots" && row.currency == "coins")
+        .map(|row| row.amount)
+        .sum::<i64>();
+    assert_eq!(change, 100);
+}
@@ -19,6 +19,10 @@ pub trait DestinyWeaponManager<Db: Database> {
     async fn get(pool: &Pool<Db>, name: &str) -> sqlx::Result<DestinyWeapon>;
 
     async fn get_by_prefix(pool: &Pool<Db>, name: &str) -> sqlx::Result<Vec<DestinyWeapon>>;
+
+    /// Replaces every stored weapon with `weapons`, read from the latest
+    /// manifest.
+    async fn replace(pool: &Pool<Db>, weapons: &[DestinyWeapon]) -> sqlx::Result<()>;
 }
 
 #[derive(FromRow)]
@@ -37,6 +41,10 @@ pub trait DestinyPerkManager<Db: Database> {
     async fn get(pool: &Pool<Db>, name: &str) -> sqlx::Result<DestinyPerk>;
 
     async fn get_all(pool: &Pool<Db>, names: &[String]) -> sqlx::Result<Vec<DestinyPerk>>;
+
+    /// Replaces every stored perk with `perks`, read from the latest
+    /// manifest.
+    async fn replace(pool: &Pool<Db>, perks: &[DestinyPerk]) -> sqlx::Result<()>;
 }
 
 #[derive(FromRow)]
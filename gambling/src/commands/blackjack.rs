@@ -1,43 +1,31 @@
 use std::collections::HashMap;
-use std::sync::LazyLock;
-use std::time::Duration;
 
-use futures::StreamExt;
 use rand::rng;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Deserializer, Serialize};
 use serenity::all::{
-    ButtonStyle, Colour, CommandInteraction, CommandOptionType, Context, CreateActionRow,
-    CreateButton, CreateCommand, CreateCommandOption, CreateEmbed, CreateInteractionResponse,
-    CreateInteractionResponseMessage, EditInteractionResponse, EmojiId, ResolvedOption,
-    ResolvedValue,
+    ButtonStyle, Colour, CommandInteraction, CommandOptionType, ComponentInteraction, Context,
+    CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
+    MessageId, ResolvedOption, ResolvedValue, UserId,
 };
+use serenity::prelude::TypeMapKey;
 use sqlx::{Database, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{Cooldowns, i18n, t};
 
 use crate::events::{Dispatch, Event, GameEvent};
 use crate::models::gambling::GamblingManager;
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::utils::coins_str;
 use crate::{
-    CARD_BACK, CARD_DECK, COIN, Coins, EffectsManager, GameCache, GameManager, GameRow,
-    GoalsManager, Result,
+    CARD_BACK, CARD_DECK, Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN,
+    GameManager, GameRow, Gems, GoalsManager, Ledger, LedgerManager, Result, ShopCurrency, config,
 };
 
-static CARD_TO_NUM: LazyLock<HashMap<EmojiId, u8>> = LazyLock::new(|| {
-    CARD_DECK
-        .iter()
-        .copied()
-        .zip(
-            (1u8..=13)
-                .cycle()
-                .map(|rank| match rank {
-                    11..=13 => 10,
-                    _ => rank,
-                })
-                .take(52),
-        )
-        .collect()
-});
+use super::{Commands, Components};
 
-use super::Commands;
+/// The most hands a player can split into.
+const MAX_HANDS: usize = 4;
 
 impl Commands {
     pub async fn blackjack<
@@ -46,6 +34,8 @@ impl Commands {
         GoalsHandler: GoalsManager<Db>,
         EffectsHandler: EffectsManager<Db> + Send,
         GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -54,276 +44,860 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let Some(ResolvedValue::Integer(mut bet)) = options.pop().map(|opt| opt.value) else {
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let Some(ResolvedValue::Integer(bet)) = options.pop().map(|opt| opt.value) else {
             unreachable!("bet is required")
         };
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
             .await?
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
-        EffectsHandler::bet_limit::<GamblingHandler>(pool, interaction.user.id, bet, row.coins())
-            .await?;
-        GamblingHandler::bet(pool, interaction.user.id, bet)
-            .await
-            .unwrap();
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let start_coins = row.coins();
         row.bet(bet);
 
-        let mut card_shoe = CARD_DECK
-            .iter()
-            .copied()
-            .cycle()
-            .take(52 * 8)
-            .collect::<Vec<_>>();
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GamblingHandler::bet(&mut *tx, economy, interaction.user.id, bet).await?;
+        Ledger::new(economy, interaction.user.id, "blackjack")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins())
+            .record::<Db, LedgerHandler>(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        let mut game = Blackjack {
+            economy,
+            user: interaction.user.id,
+            bet,
+            shoe: Shoes::take(ctx, interaction.user.id).await,
+            hands: Vec::new(),
+            active: 0,
+            dealer: Vec::new(),
+            insurance: None,
+            surrendered: false,
+            locale: i18n::locale(&interaction.locale, interaction.guild_locale.as_deref())
+                .to_string(),
+        };
 
-        card_shoe.shuffle(&mut rng());
-
-        let mut player_hand = vec![card_shoe.pop().unwrap(), card_shoe.pop().unwrap()];
-        let mut player_value = sum_cards(&player_hand);
-        let mut dealer_hand = vec![card_shoe.pop().unwrap(), card_shoe.pop().unwrap()];
-        let mut dealer_value = sum_cards(&dealer_hand);
-
-        /* TODO:
-        - If the player's first two cards are an Ace and a 10-value card (10, J, Q, K), they have a "Blackjack" or a "Natural."
-        - If the dealer is not showing an Ace or 10, the player wins immediately and is typically paid out at 3:2. (e.g., a 100-credit bet wins 150 credits).
-        - If the dealer's upcard is an Ace or 10, they will check their hole card. If the dealer also has a Blackjack, the hand is a Push (a tie), and the player's bet is returned. If the dealer doesn't have Blackjack, the player wins and gets the 3:2 payout.
-        */
-
-        let embed = playing_embed(bet, &player_hand, player_value, &dealer_hand);
-
-        let hit_btn = CreateButton::new("hit")
-            .emoji('🎯')
-            .label("Hit")
-            .style(ButtonStyle::Secondary);
-        let stand_btn = CreateButton::new("stand")
-            .emoji('🛑')
-            .label("Stand")
-            .style(ButtonStyle::Secondary);
-        let double_btn = CreateButton::new("double")
-            .emoji('⏫')
-            .label("Double Down")
-            .style(ButtonStyle::Secondary)
-            .disabled(row.coins() < bet);
+        let player = vec![game.draw(), game.draw()];
+        game.hands.push(Hand::new(player));
+        game.dealer = vec![game.draw(), game.draw()];
 
         let message = interaction
             .edit_response(
                 ctx,
-                EditInteractionResponse::new().embed(embed).components(vec![
-                    CreateActionRow::Buttons(vec![
-                        hit_btn.clone(),
-                        stand_btn.clone(),
-                        double_btn.clone(),
-                    ]),
-                ]),
+                EditInteractionResponse::new()
+                    .embed(game.playing_embed())
+                    .components(game.buttons(row.coins())),
             )
             .await
             .unwrap();
 
-        let mut stream = message
-            .await_component_interactions(ctx)
-            .author_id(interaction.user.id)
-            .timeout(Duration::from_secs(120))
-            .stream();
-
-        while let Some(component) = stream.next().await {
-            let custom_id = component.data.custom_id.as_str();
-
-            match custom_id {
-                "hit" => {
-                    player_hand.push(card_shoe.pop().unwrap());
-                }
-                "stand" => break,
-                "double" => {
-                    GamblingHandler::bet(pool, interaction.user.id, bet)
-                        .await
-                        .unwrap();
-                    row.bet(bet);
-                    bet *= 2;
-                    player_hand.push(card_shoe.pop().unwrap());
-                }
-                _ => unreachable!("Invalid custom id"),
-            };
-
-            player_value = sum_cards(&player_hand);
-
-            let embed = playing_embed(bet, &player_hand, player_value, &dealer_hand);
+        // Naturals are paid, and a dealer blackjack collected, straight away
+        if game.finished() {
+            let embed = game
+                .settle::<Db, GoalsHandler, EffectsHandler, GameHandler, LedgerHandler>(
+                    pool, message.id,
+                )
+                .await?;
+            Shoes::put(ctx, game.user, game.shoe).await;
+            Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
 
-            component
-                .create_response(
+            interaction
+                .edit_response(
                     ctx,
-                    CreateInteractionResponse::UpdateMessage(
-                        CreateInteractionResponseMessage::new()
-                            .embed(embed)
-                            .components(vec![CreateActionRow::Buttons(vec![
-                                hit_btn.clone(),
-                                stand_btn.clone(),
-                                double_btn.clone().disabled(true),
-                            ])]),
-                    ),
+                    EditInteractionResponse::new()
+                        .embed(embed)
+                        .components(Vec::new()),
                 )
-                .await
-                .unwrap();
+                .await?;
+
+            return Ok(());
+        }
+
+        SessionHandler::insert(pool, &SessionRow::new(message.id, message.channel_id, game))
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn register_blackjack() -> CreateCommand {
+        CreateCommand::new("blackjack")
+            .description("Play a game of blackjack")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "bet", "The amount to bet.")
+                    .required(true),
+            )
+    }
+}
+
+impl Components {
+    pub async fn blackjack<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::Blackjack(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        if interaction.user.id != game.user {
+            return Err(Error::NotPlayer);
+        }
+
+        let economy = game.economy;
+        let coins = GameHandler::row(pool, economy, game.user)
+            .await?
+            .map(|row| row.coins())
+            .unwrap_or_default();
+
+        // The coins a move puts on the table on top of the bet
+        let mut stake = 0;
 
-            if player_value > 21 || custom_id == "double" {
-                break;
+        match interaction.data.custom_id.as_str() {
+            "blackjack_insurance" if game.insurance_offered() => {
+                stake = game.bet / 2;
+                game.insurance = Some(stake);
+            }
+            "blackjack_no_insurance" if game.insurance_offered() => {
+                game.insurance = Some(0);
+            }
+            _ if game.insurance_offered() => return acknowledge(ctx, interaction).await,
+            "blackjack_hit" => game.hit(),
+            "blackjack_stand" => game.next_hand(),
+            "blackjack_double" if game.can_double() => {
+                stake = game.bet;
+                game.double();
             }
+            "blackjack_split" if game.can_split() => {
+                stake = game.bet;
+                game.split();
+            }
+            "blackjack_surrender" if game.can_surrender() => {
+                game.surrendered = true;
+            }
+            _ => return acknowledge(ctx, interaction).await,
         }
 
-        if player_value > 21 {
-            Dispatch::<Db, GoalsHandler>::new(pool)
-                .fire(
-                    &mut row,
-                    Event::Game(GameEvent::new("blackjack", interaction.user.id, bet, false)),
-                )
-                .await?;
+        if coins < stake {
+            return Err(Error::InsufficientFunds {
+                required: stake - coins,
+                currency: ShopCurrency::Coins,
+            });
+        }
 
-            let payout =
-                EffectsHandler::payout(pool, interaction.user.id, bet, 0, Some(false)).await;
-
-            row.add_coins(payout);
-
-            let coins = row.coins();
-
-            GameHandler::save(pool, row).await.unwrap();
-            GameCache::update(ctx, interaction.user.id).await;
-
-            let desc = format!(
-                "Your bet: {} <:coin:{COIN}>\n\n**Your Hand**\n{}- {player_value}\n\n**Dealer Hand**\n{} - {dealer_value}\n\nBust!\n\nLost: {} <:coin:{COIN}>\nYour coins: {} <:coin:{COIN}>",
-                bet.format(),
-                player_hand
-                    .iter()
-                    .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-                    .map(|(num, id)| format!("<:{num}:{id}> "))
-                    .collect::<String>(),
-                dealer_hand
-                    .iter()
-                    .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-                    .map(|(num, id)| format!("<:{num}:{id}> "))
-                    .collect::<String>(),
-                (payout - bet).format(),
-                coins.format()
-            );
+        // The move and its stake are stored together, so a click that loses
+        // the race to another takes nothing
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+
+        if !game.finished() {
+            let msg = CreateInteractionResponseMessage::new()
+                .embed(game.playing_embed())
+                .components(game.buttons(coins - stake));
+            Sessions::update_in::<Db, SessionHandler>(&mut *tx, interaction, version, game).await?;
+
+            take_stake::<Db, GamblingHandler, LedgerHandler>(
+                &mut *tx,
+                economy,
+                interaction.message.id,
+                interaction.user.id,
+                coins,
+                stake,
+            )
+            .await?;
+            tx.commit().await?;
 
             interaction
-                .edit_response(
-                    ctx,
-                    EditInteractionResponse::new()
-                        .embed(
-                            CreateEmbed::new()
-                                .title("Blackjack - You Lost!")
-                                .description(desc)
-                                .colour(Colour::RED),
-                        )
-                        .components(Vec::new()),
-                )
-                .await
-                .unwrap();
+                .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                .await?;
 
             return Ok(());
         }
 
-        while dealer_value < 17 {
-            dealer_hand.push(card_shoe.pop().unwrap());
-            dealer_value = sum_cards(&dealer_hand);
+        Sessions::end_in::<Db, SessionHandler>(&mut *tx, interaction, version).await?;
+
+        take_stake::<Db, GamblingHandler, LedgerHandler>(
+            &mut *tx,
+            economy,
+            interaction.message.id,
+            interaction.user.id,
+            coins,
+            stake,
+        )
+        .await?;
+        tx.commit().await?;
+
+        let embed = game
+            .settle::<Db, GoalsHandler, EffectsHandler, GameHandler, LedgerHandler>(
+                pool,
+                interaction.message.id,
+            )
+            .await?;
+        Shoes::put(ctx, game.user, game.shoe).await;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(Vec::new()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// Takes the coins for a double, split or insurance, in the transaction that
+/// stores the move.
+async fn take_stake<
+    Db: Database,
+    GamblingHandler: GamblingManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    conn: &mut Db::Connection,
+    economy: Economy,
+    message_id: MessageId,
+    user: UserId,
+    coins: i64,
+    stake: i64,
+) -> Result<()> {
+    if stake == 0 {
+        return Ok(());
+    }
+
+    GamblingHandler::bet(&mut *conn, economy, user, stake).await?;
+    Ledger::new(economy, user, "blackjack")
+        .reference(message_id)
+        .coins(coins, coins - stake)
+        .record::<Db, LedgerHandler>(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// The shoe each player is dealt from, kept between games until the cut card
+/// comes out.
+#[derive(Debug, Default)]
+pub struct Shoes(HashMap<UserId, Vec<u8>>);
+
+impl Shoes {
+    /// The player's shoe, or a freshly shuffled one once the cut card has
+    /// come out.
+    async fn take(ctx: &Context, user: UserId) -> Vec<u8> {
+        let rules = &config::get().blackjack;
+
+        let mut data = ctx.data.write().await;
+        let shoes = data.entry::<Self>().or_insert_with(Self::default);
+
+        shoes
+            .0
+            .remove(&user)
+            .filter(|shoe| shoe.len() > rules.cut().max(4))
+            .unwrap_or_else(|| {
+                let mut shoe = (0..52)
+                    .cycle()
+                    .take(52 * rules.decks.max(1))
+                    .collect::<Vec<u8>>();
+                shoe.shuffle(&mut rng());
+                shoe
+            })
+    }
+
+    /// Keeps what is left of a shoe for the player's next game. Cards are
+    /// dealt from the end.
+    pub async fn put(ctx: &Context, user: UserId, shoe: Vec<u8>) {
+        let mut data = ctx.data.write().await;
+        let shoes = data.entry::<Self>().or_insert_with(Self::default);
+
+        shoes.0.insert(user, shoe);
+    }
+}
+
+impl TypeMapKey for Shoes {
+    type Value = Shoes;
+}
+
+/// One of the player's hands, of which there are more after a split.
+#[derive(Debug, Serialize, Deserialize)]
+struct Hand {
+    cards: Vec<u8>,
+    #[serde(default)]
+    doubled: bool,
+}
+
+impl Hand {
+    fn new(cards: Vec<u8>) -> Self {
+        Self {
+            cards,
+            doubled: false,
+        }
+    }
+
+    fn value(&self) -> u8 {
+        sum_cards(&self.cards)
+    }
+}
+
+/// How a hand ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Blackjack,
+    Win,
+    Push,
+    Lose,
+    Bust,
+}
+
+/// A game of blackjack, with cards as indexes into the deck.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Blackjack {
+    // Games stored before economies were split by guild are global
+    #[serde(default)]
+    economy: Economy,
+    user: UserId,
+    /// The bet on each hand before it is doubled.
+    bet: i64,
+    shoe: Vec<u8>,
+    // Games stored before splits were added have a single hand of cards
+    #[serde(alias = "player", deserialize_with = "hands")]
+    hands: Vec<Hand>,
+    /// The hand being played, past the last one once they are all played.
+    #[serde(default)]
+    active: usize,
+    dealer: Vec<u8>,
+    /// The insurance bet, once the player has been asked for it.
+    #[serde(default)]
+    insurance: Option<i64>,
+    #[serde(default)]
+    surrendered: bool,
+    /// The player's locale, which the game is shown in. Games stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
+}
+
+fn hands<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Vec<Hand>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Stored {
+        Cards(Vec<u8>),
+        Hands(Vec<Hand>),
+    }
+
+    Ok(match Stored::deserialize(deserializer)? {
+        Stored::Cards(cards) => vec![Hand::new(cards)],
+        Stored::Hands(hands) => hands,
+    })
+}
+
+impl Blackjack {
+    fn draw(&mut self) -> u8 {
+        // A long run of splits can outlast the cut card
+        if self.shoe.is_empty() {
+            self.shoe = (0..52).collect();
+            self.shoe.shuffle(&mut rng());
+        }
+
+        self.shoe.pop().unwrap()
+    }
+
+    fn hand(&mut self) -> &mut Hand {
+        &mut self.hands[self.active]
+    }
+
+    /// Whether the dealer's up card is an ace and the player hasn't taken or
+    /// turned down insurance yet.
+    fn insurance_offered(&self) -> bool {
+        self.insurance.is_none() && card_value(self.dealer[0]) == 1
+    }
+
+    /// Whether the dealer's first two cards make blackjack, which they peek
+    /// for under an ace or a ten.
+    fn dealer_blackjack(&self) -> bool {
+        self.dealer.len() == 2 && sum_cards(&self.dealer) == 21
+    }
+
+    /// Whether the player was dealt blackjack, which a split hand can't be.
+    fn natural(&self) -> bool {
+        self.hands.len() == 1 && self.hands[0].cards.len() == 2 && self.hands[0].value() == 21
+    }
+
+    fn finished(&self) -> bool {
+        if self.insurance_offered() {
+            return false;
+        }
+
+        self.surrendered
+            || self.dealer_blackjack()
+            || self.natural()
+            || self.active >= self.hands.len()
+    }
+
+    fn first_move(&self) -> bool {
+        self.hands.len() == 1 && self.hands[0].cards.len() == 2
+    }
+
+    fn can_double(&self) -> bool {
+        self.hands[self.active].cards.len() == 2
+    }
+
+    fn can_split(&self) -> bool {
+        let cards = &self.hands[self.active].cards;
+
+        self.hands.len() < MAX_HANDS
+            && cards.len() == 2
+            && card_value(cards[0]) == card_value(cards[1])
+    }
+
+    /// Late surrender, after the dealer has checked for blackjack.
+    fn can_surrender(&self) -> bool {
+        self.first_move()
+    }
+
+    fn next_hand(&mut self) {
+        self.active += 1;
+    }
+
+    fn hit(&mut self) {
+        let card = self.draw();
+        self.hand().cards.push(card);
+
+        if self.hand().value() >= 21 {
+            self.next_hand();
+        }
+    }
+
+    fn double(&mut self) {
+        let card = self.draw();
+        let hand = self.hand();
+        hand.cards.push(card);
+        hand.doubled = true;
+
+        self.next_hand();
+    }
+
+    /// Splits the pair into two hands, dealing each a second card. Split aces
+    /// only get one card each.
+    fn split(&mut self) {
+        let card = self.hand().cards.pop().unwrap();
+        let aces = card_value(card) == 1;
+
+        let (first, second) = (self.draw(), self.draw());
+        self.hand().cards.push(first);
+        self.hands
+            .insert(self.active + 1, Hand::new(vec![card, second]));
+
+        if aces {
+            self.active += 2;
+        } else if self.hand().value() == 21 {
+            self.next_hand();
+        }
+    }
+
+    /// The coins on each hand, including doubles.
+    fn stake(&self, hand: &Hand) -> i64 {
+        if hand.doubled { self.bet * 2 } else { self.bet }
+    }
+
+    fn outcome(&self, hand: &Hand) -> Outcome {
+        let dealer_value = sum_cards(&self.dealer);
+        let value = hand.value();
+
+        if value > 21 {
+            Outcome::Bust
+        } else if self.natural() && !self.dealer_blackjack() {
+            Outcome::Blackjack
+        } else if self.dealer_blackjack() && self.natural() {
+            Outcome::Push
+        } else if self.dealer_blackjack() {
+            Outcome::Lose
+        } else if dealer_value > 21 || value > dealer_value {
+            Outcome::Win
+        } else if value == dealer_value {
+            Outcome::Push
+        } else {
+            Outcome::Lose
+        }
+    }
+
+    /// Hands back the bet on every hand and any insurance, as a game cut off
+    /// by a restart is never played out.
+    pub(crate) fn time_out(self) -> TimedOut {
+        let staked = self.hands.iter().map(|hand| self.stake(hand)).sum::<i64>()
+            + self.insurance.unwrap_or_default();
+
+        TimedOut {
+            economy: self.economy,
+            source: "blackjack",
+            refunds: vec![(self.user, staked)],
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.blackjack.name"))
+                .description(t!(
+                    &self.locale,
+                    "gambling.interrupted",
+                    refund = coins_str(staked)
+                ))
+                .colour(Colour::TEAL),
+        }
+    }
+
+    /// Plays out the dealer's hand and pays out every bet.
+    pub(crate) async fn settle<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        &mut self,
+        pool: &Pool<Db>,
+        message_id: MessageId,
+    ) -> Result<CreateEmbed> {
+        let economy = self.economy;
+        let insurance = self.insurance.unwrap_or_default();
+
+        let live = self.hands.iter().any(|hand| hand.value() <= 21);
+        if live && !self.surrendered && !self.dealer_blackjack() && !self.natural() {
+            while sum_cards(&self.dealer) < 17 {
+                let card = self.draw();
+                self.dealer.push(card);
+            }
         }
 
-        let (win, mut payout) = if dealer_value > 21 || player_value > dealer_value {
-            (Some(true), bet * 2)
-        } else if player_value == dealer_value {
-            (None, bet)
+        let staked = self.hands.iter().map(|hand| self.stake(hand)).sum::<i64>() + insurance;
+
+        let mut payout = if self.dealer_blackjack() {
+            // Insurance pays 2:1
+            insurance * 3
         } else {
-            (Some(false), 0)
+            0
         };
 
-        Dispatch::<Db, GoalsHandler>::new(pool)
+        let outcomes = self
+            .hands
+            .iter()
+            .map(|hand| self.outcome(hand))
+            .collect::<Vec<_>>();
+
+        if self.surrendered {
+            payout += self.bet / 2;
+        } else {
+            for (hand, outcome) in self.hands.iter().zip(&outcomes) {
+                let stake = self.stake(hand);
+
+                payout += match outcome {
+                    // Naturals pay 3:2
+                    Outcome::Blackjack => stake + stake * 3 / 2,
+                    Outcome::Win => stake * 2,
+                    Outcome::Push => stake,
+                    Outcome::Lose | Outcome::Bust => 0,
+                };
+            }
+        }
+
+        let win = match payout.cmp(&staked) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Less => Some(false),
+        };
+
+        let mut row = GameHandler::row(pool, economy, self.user)
+            .await?
+            .unwrap_or_else(|| GameRow::new(self.user));
+        let (start_coins, start_gems) = (row.coins(), row.gems());
+
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Game(GameEvent::new(
                     "blackjack",
-                    interaction.user.id,
-                    bet,
+                    self.user,
+                    staked,
                     win == Some(true),
                 )),
             )
             .await?;
 
-        payout = EffectsHandler::payout(pool, interaction.user.id, bet, payout, win).await;
+        let payout = EffectsHandler::payout(pool, economy, self.user, staked, payout, win).await;
 
         row.add_coins(payout);
 
         let coins = row.coins();
-
-        GameHandler::save(pool, row).await.unwrap();
-        GameCache::update(ctx, interaction.user.id).await;
-
-        let desc = format!(
-            "Your bet: {} <:coin:{COIN}>\n\n**Your Hand**\n{}- {player_value}\n\n**Dealer Hand**\n{} - {dealer_value}",
-            bet.format(),
-            player_hand
-                .iter()
-                .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-                .map(|(num, id)| format!("<:{num}:{id}> "))
-                .collect::<String>(),
-            dealer_hand
-                .iter()
-                .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-                .map(|(num, id)| format!("<:{num}:{id}> "))
-                .collect::<String>(),
+        let ledger = Ledger::new(economy, self.user, "blackjack")
+            .reference(message_id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+
+        let locale = self.locale.as_str();
+
+        let mut desc = format!(
+            "{}\n\n{}\n**{}**\n{}- {}",
+            t!(locale, "gambling.your_bet", bet = coins_str(staked)),
+            self.hands_description(Some(&outcomes)),
+            t!(locale, "gambling.blackjack.dealer_hand"),
+            hand(&self.dealer),
+            sum_cards(&self.dealer),
         );
 
-        let embed = if win == Some(true) {
-            CreateEmbed::new()
-                .title("Blackjack - You Won!")
-                .description(format!(
-                    "{desc}\n\nProfit: {} <:coin:{COIN}>\nYour coins: {} <:coin:{COIN}>",
-                    (payout - bet).format(),
-                    coins.format()
-                ))
-                .colour(Colour::DARK_GREEN)
-        } else if win == Some(false) {
-            CreateEmbed::new()
-                            .title("Blackjack - You Lost!")
-                            .description(format!("{desc}\n\nDealer wins!\n\nLost: {} <:coin:{COIN}>\nYour coins: {} <:coin:{COIN}>", (payout - bet).format(),
-                coins.format()))
-                            .colour(Colour::RED)
+        if insurance > 0 {
+            desc.push_str("\n\n");
+            desc.push_str(&t!(
+                locale,
+                "gambling.blackjack.insurance_taken",
+                insurance = coins_str(insurance)
+            ));
+        }
+
+        let won = ("gambling.result.won", Colour::DARK_GREEN);
+        let lost = ("gambling.result.lost", Colour::RED);
+        let draw = ("gambling.result.draw", Colour::DARKER_GREY);
+
+        let (note, (title, colour)) = if self.surrendered {
+            (
+                Some("gambling.blackjack.surrendered"),
+                ("gambling.blackjack.surrendered_title", Colour::RED),
+            )
+        } else if self.dealer_blackjack() {
+            let result = match win {
+                Some(true) => won,
+                Some(false) => lost,
+                None => draw,
+            };
+            (Some("gambling.blackjack.dealer_blackjack"), result)
+        } else if outcomes == [Outcome::Blackjack] {
+            (Some("gambling.blackjack.natural"), won)
+        } else if outcomes == [Outcome::Bust] {
+            (Some("gambling.blackjack.bust"), lost)
         } else {
-            CreateEmbed::new()
-                .title("Blackjack - Draw!")
-                .description(format!(
-                    "{desc}\n\nDraw! Have your money back.\n\nYour coins: {} <:coin:{COIN}>",
-                    coins.format()
-                ))
-                .colour(Colour::DARKER_GREY)
+            let result = match win {
+                Some(true) => won,
+                Some(false) => lost,
+                None => draw,
+            };
+            (None, result)
         };
 
-        interaction
-            .edit_response(
-                ctx,
-                EditInteractionResponse::new()
-                    .embed(embed)
-                    .components(Vec::new()),
-            )
-            .await
-            .unwrap();
+        if let Some(note) = note {
+            desc.push_str("\n\n");
+            desc.push_str(&t!(locale, note));
+        }
 
-        Ok(())
+        let result = match win {
+            Some(true) => t!(
+                locale,
+                "gambling.profit",
+                amount = coins_str(payout - staked)
+            ),
+            Some(false) => t!(locale, "gambling.lost", amount = coins_str(payout - staked)),
+            None => format!("{}\n", t!(locale, "gambling.draw")),
+        };
+
+        Ok(CreateEmbed::new()
+            .title(t!(
+                locale,
+                title,
+                game = t!(locale, "gambling.blackjack.name")
+            ))
+            .description(format!(
+                "{desc}\n\n{result}\n{}",
+                t!(locale, "gambling.your_coins", coins = coins_str(coins))
+            ))
+            .colour(colour))
     }
 
-    pub fn register_blackjack() -> CreateCommand {
-        CreateCommand::new("blackjack")
-            .description("Play a game of blackjack")
-            .add_option(
-                CreateCommandOption::new(CommandOptionType::Integer, "bet", "The amount to bet.")
-                    .required(true),
-            )
+    fn hands_description(&self, outcomes: Option<&[Outcome]>) -> String {
+        let locale = self.locale.as_str();
+
+        if let [only] = self.hands.as_slice() {
+            return format!(
+                "**{}**\n{}- {}\n",
+                t!(locale, "gambling.blackjack.your_hand"),
+                hand(&only.cards),
+                only.value()
+            );
+        }
+
+        self.hands
+            .iter()
+            .enumerate()
+            .map(|(i, player)| {
+                let status = match outcomes.map(|outcomes| outcomes[i]) {
+                    Some(Outcome::Win | Outcome::Blackjack) => {
+                        format!(" - {}", t!(locale, "gambling.blackjack.hand_won"))
+                    }
+                    Some(Outcome::Push) => {
+                        format!(" - {}", t!(locale, "gambling.blackjack.hand_push"))
+                    }
+                    Some(Outcome::Lose) => {
+                        format!(" - {}", t!(locale, "gambling.blackjack.hand_lost"))
+                    }
+                    Some(Outcome::Bust) => {
+                        format!(" - {}", t!(locale, "gambling.blackjack.hand_bust"))
+                    }
+                    None if i == self.active => String::from(" ◀️"),
+                    None => String::new(),
+                };
+                let doubled = if player.doubled {
+                    format!(" ({})", t!(locale, "gambling.blackjack.doubled"))
+                } else {
+                    String::new()
+                };
+
+                format!(
+                    "**{}**{doubled}{status}\n{}- {}\n",
+                    t!(locale, "gambling.blackjack.hand", number = i + 1),
+                    hand(&player.cards),
+                    player.value()
+                )
+            })
+            .collect()
+    }
+
+    fn playing_embed(&self) -> CreateEmbed {
+        let locale = self.locale.as_str();
+
+        let mut desc = format!(
+            "{}\n\n{}\n**{}**\n{}<:blank:{CARD_BACK}>",
+            t!(locale, "gambling.your_bet", bet = coins_str(self.bet)),
+            self.hands_description(None),
+            t!(locale, "gambling.blackjack.dealer_hand"),
+            hand(&self.dealer[..1]),
+        );
+
+        if self.insurance_offered() {
+            desc.push_str("\n\n");
+            desc.push_str(&t!(
+                locale,
+                "gambling.blackjack.insurance_offered",
+                cost = coins_str(self.bet / 2)
+            ));
+        }
+
+        CreateEmbed::new()
+            .title(t!(locale, "gambling.blackjack.name"))
+            .description(desc)
+            .colour(Colour::TEAL)
+    }
+
+    fn buttons(&self, coins: i64) -> Vec<CreateActionRow> {
+        let locale = self.locale.as_str();
+
+        if self.insurance_offered() {
+            return vec![CreateActionRow::Buttons(vec![
+                CreateButton::new("blackjack_insurance")
+                    .emoji('🛡')
+                    .label(t!(locale, "gambling.blackjack.insurance"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(coins < self.bet / 2),
+                CreateButton::new("blackjack_no_insurance")
+                    .emoji('➡')
+                    .label(t!(locale, "gambling.blackjack.no_insurance"))
+                    .style(ButtonStyle::Secondary),
+            ])];
+        }
+
+        if self.finished() {
+            return Vec::new();
+        }
+
+        vec![
+            CreateActionRow::Buttons(vec![
+                CreateButton::new("blackjack_hit")
+                    .emoji('🎯')
+                    .label(t!(locale, "gambling.blackjack.hit"))
+                    .style(ButtonStyle::Secondary),
+                CreateButton::new("blackjack_stand")
+                    .emoji('🛑')
+                    .label(t!(locale, "gambling.blackjack.stand"))
+                    .style(ButtonStyle::Secondary),
+                CreateButton::new("blackjack_double")
+                    .emoji('⏫')
+                    .label(t!(locale, "gambling.blackjack.double"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(!self.can_double() || coins < self.bet),
+            ]),
+            CreateActionRow::Buttons(vec![
+                CreateButton::new("blackjack_split")
+                    .emoji('✂')
+                    .label(t!(locale, "gambling.blackjack.split"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(!self.can_split() || coins < self.bet),
+                CreateButton::new("blackjack_surrender")
+                    .emoji('🏳')
+                    .label(t!(locale, "gambling.blackjack.surrender"))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(!self.can_surrender()),
+            ]),
+        ]
+    }
+}
+
+impl From<Blackjack> for Session {
+    fn from(game: Blackjack) -> Self {
+        Self::Blackjack(game)
     }
 }
 
-fn sum_cards(hand: &[EmojiId]) -> u8 {
+/// Answers a click that doesn't change the game.
+async fn acknowledge(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    Ok(())
+}
+
+/// The value of a card, counting aces as 1 and face cards as 10.
+fn card_value(card: u8) -> u8 {
+    (card % 13 + 1).min(10)
+}
+
+fn hand(cards: &[u8]) -> String {
+    cards
+        .iter()
+        .map(|card| {
+            format!(
+                "<:{}:{}> ",
+                card_value(*card),
+                CARD_DECK[usize::from(*card)]
+            )
+        })
+        .collect()
+}
+
+fn sum_cards(hand: &[u8]) -> u8 {
     let (aces, rest) = hand
         .iter()
-        .map(|id| *CARD_TO_NUM.get(id).unwrap())
+        .map(|card| card_value(*card))
         .partition::<Vec<_>, _>(|num| *num == 1);
 
     let mut sum = rest.iter().sum();
@@ -338,35 +912,3 @@ fn sum_cards(hand: &[EmojiId]) -> u8 {
 
     sum
 }
-
-fn playing_embed(
-    bet: i64,
-    player_hand: &[EmojiId],
-    player_value: u8,
-    dealer_hand: &[EmojiId],
-) -> CreateEmbed {
-    let desc = format!(
-        "Your bet: {} <:coin:{COIN}>\n\n**Your Hand**\n{}- {player_value}\n\n**Dealer Hand**\n{}",
-        bet.format(),
-        player_hand
-            .iter()
-            .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-            .map(|(num, id)| format!("<:{num}:{id}> "))
-            .collect::<String>(),
-        dealer_hand
-            .iter()
-            .map(|id| (*CARD_TO_NUM.get(id).unwrap(), id))
-            .enumerate()
-            .map(|(idx, (num, id))| if idx == 0 {
-                format!("<:{num}:{id}> ")
-            } else {
-                format!("<:blank:{CARD_BACK}>")
-            })
-            .collect::<String>(),
-    );
-
-    CreateEmbed::new()
-        .title("Blackjack")
-        .description(desc)
-        .colour(Colour::TEAL)
-}
@@ -1,4 +1,3 @@
-use std::fmt::Display;
 use std::str::FromStr;
 
 use serenity::all::{
@@ -6,13 +5,14 @@ use serenity::all::{
     EditInteractionResponse, ResolvedOption, ResolvedValue,
 };
 use sqlx::{Database, Pool};
-use zayden_core::parse_options;
+use zayden_core::{Cooldowns, i18n, parse_options, t};
 
 use crate::events::{Dispatch, Event, GameEvent};
 use crate::models::gambling::GamblingManager;
 use crate::utils::{Emoji, GameResult, game_embed};
 use crate::{
-    COIN, Coins, EffectsManager, GameCache, GameManager, GameRow, GoalsManager, Result, TAILS,
+    COIN, Coins, Economies, EffectsManager, GAME_COOLDOWN, GameManager, GameRow, Gems,
+    GoalsManager, Ledger, LedgerManager, Result, TAILS,
 };
 
 use super::Commands;
@@ -24,6 +24,7 @@ impl Commands {
         GoalsHandler: GoalsManager<Db>,
         EffectsHandler: EffectsManager<Db> + Send,
         GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -32,6 +33,9 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
         let mut options = parse_options(options);
 
         let Some(ResolvedValue::String(prediction)) = options.remove("prediction") else {
@@ -43,13 +47,20 @@ impl Commands {
             unreachable!("bet is required")
         };
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
             .await?
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
-        EffectsHandler::bet_limit::<GamblingHandler>(pool, interaction.user.id, bet, row.coins())
-            .await?;
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
         row.bet(bet);
 
         let heads = rand::random_bool(0.5);
@@ -62,35 +73,58 @@ impl Commands {
             _ => 0,
         };
 
-        Dispatch::<Db, GoalsHandler>::new(pool)
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Game(GameEvent::new("coinflip", interaction.user.id, bet, winner)),
             )
             .await?;
 
-        payout = EffectsHandler::payout(pool, interaction.user.id, bet, payout, Some(winner)).await;
+        payout = EffectsHandler::payout(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            payout,
+            Some(winner),
+        )
+        .await;
 
         row.add_coins(payout);
 
         let coins = row.coins();
-
-        GameHandler::save(pool, row).await.unwrap();
-        GameCache::update(ctx, interaction.user.id).await;
-
+        let ledger = Ledger::new(economy, interaction.user.id, "coinflip")
+            .reference(interaction.id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        let game = t!(locale, "gambling.coinflip.name");
         let (coin, title) = if edge {
-            (prediction, "Coin Flip - EDGE ROLL!")
+            (
+                prediction,
+                t!(locale, "gambling.coinflip.edge", game = game),
+            )
         } else if winner {
-            (prediction, "Coin Flip - You Won!")
+            (prediction, t!(locale, "gambling.result.won", game = game))
         } else {
-            (prediction.opposite(), "Coin Flip - You Lost!")
+            (
+                prediction.opposite(),
+                t!(locale, "gambling.result.lost", game = game),
+            )
         };
 
         let embed = game_embed(
+            locale,
             title,
-            prediction,
-            "Coin landed on",
-            coin,
+            prediction.result(locale),
+            &t!(locale, "gambling.coinflip.landed_on"),
+            coin.result(locale),
             bet,
             payout,
             coins,
@@ -137,13 +171,16 @@ impl CoinSide {
             CoinSide::Tails => CoinSide::Heads,
         }
     }
-}
 
-impl Display for CoinSide {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            CoinSide::Heads => write!(f, "Heads"),
-            CoinSide::Tails => write!(f, "Tails"),
+    fn result(self, locale: &str) -> GameResult<'static> {
+        let (key, emoji) = match self {
+            CoinSide::Heads => ("gambling.coinflip.heads", COIN),
+            CoinSide::Tails => ("gambling.coinflip.tails", TAILS),
+        };
+
+        GameResult {
+            name: t!(locale, key),
+            emoji: Emoji::Id(emoji),
         }
     }
 }
@@ -159,17 +196,3 @@ impl FromStr for CoinSide {
         }
     }
 }
-
-impl From<CoinSide> for GameResult<'_> {
-    fn from(value: CoinSide) -> Self {
-        let emoji = match value {
-            CoinSide::Heads => Emoji::Id(COIN),
-            CoinSide::Tails => Emoji::Id(TAILS),
-        };
-
-        Self {
-            name: value.to_string(),
-            emoji,
-        }
-    }
-}
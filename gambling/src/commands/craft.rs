@@ -6,18 +6,23 @@ use serenity::all::{
 use sqlx::any::AnyQueryResult;
 use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool};
-use zayden_core::{FormatNum, parse_options};
+use zayden_core::{FormatNum, i18n, parse_options, t};
 
 use crate::shop::ShopCurrency;
-use crate::{Error, Result};
+use crate::{Economies, Economy, Error, Result};
 
 use super::Commands;
 
 #[async_trait]
 pub trait CraftManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<CraftRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<CraftRow>>;
 
-    async fn save(pool: &Pool<Db>, row: CraftRow) -> sqlx::Result<AnyQueryResult>;
+    async fn save(pool: &Pool<Db>, economy: Economy, row: CraftRow)
+    -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(FromRow)]
@@ -64,7 +69,10 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let mut row = Manager::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let mut row = Manager::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| CraftRow::new(interaction.user.id));
@@ -72,7 +80,7 @@ impl Commands {
         let mut options = parse_options(options);
 
         if !options.contains_key("type") {
-            menu(ctx, interaction, row).await;
+            menu(ctx, interaction, locale, row).await;
             return Ok(());
         }
 
@@ -139,13 +147,21 @@ impl Commands {
             c => unreachable!("Invalid item: {c}"),
         };
 
-        Manager::save(pool, row).await.unwrap();
+        Manager::save(pool, economy, row).await.unwrap();
 
         let embed = CreateEmbed::new()
             .description(format!(
-                "Crafted {item} `{}` {item:?}s\nYou now  have {item} `{}` {item:?}s",
-                amount.format(),
-                quantity.format()
+                "{}\n{}",
+                t!(
+                    locale,
+                    "gambling.craft.crafted",
+                    items = format!("{item} `{}` {item:?}s", amount.format())
+                ),
+                t!(
+                    locale,
+                    "gambling.craft.now_have",
+                    items = format!("{item} `{}` {item:?}s", quantity.format())
+                )
             ))
             .colour(Colour::ORANGE);
 
@@ -178,7 +194,7 @@ impl Commands {
     }
 }
 
-async fn menu(ctx: &Context, interaction: &CommandInteraction, row: CraftRow) {
+async fn menu(ctx: &Context, interaction: &CommandInteraction, locale: &str, row: CraftRow) {
     let mut desc = [
         ShopCurrency::Tech,
         ShopCurrency::Utility,
@@ -193,7 +209,8 @@ async fn menu(ctx: &Context, interaction: &CommandInteraction, row: CraftRow) {
     })
     .map(|(item, owned)| {
         format!(
-            "{item} **{item:?}**\nOwned: `{owned}`\n{}",
+            "{item} **{item:?}**\n{}\n{}",
+            t!(locale, "gambling.craft.owned", amount = owned),
             item.craft_req()
                 .into_iter()
                 .flatten()
@@ -220,7 +237,7 @@ async fn menu(ctx: &Context, interaction: &CommandInteraction, row: CraftRow) {
     desc.push_str("\n------------------\n`/craft <id> <amount>`");
 
     let embed = CreateEmbed::new()
-        .title("Craftable Items")
+        .title(t!(locale, "gambling.craft.title"))
         .description(desc)
         .colour(Colour::ORANGE);
 
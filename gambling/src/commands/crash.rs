@@ -0,0 +1,920 @@
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use futures::{Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ButtonStyle, ChannelId, Colour, CommandInteraction, CommandOptionType, ComponentInteraction,
+    Context, CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
+    Mentionable, MessageId, ResolvedOption, ResolvedValue, UserId,
+};
+use serenity::prelude::TypeMapKey;
+use sqlx::{Database, Pool};
+use tokio::time::{Instant, MissedTickBehavior, interval};
+use zayden_core::{Cooldowns, Localize, Shutdown, i18n, parse_options, t};
+
+use crate::events::{Dispatch, Event, GameEvent};
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::utils::coins_str;
+use crate::{
+    Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN, GamblingManager, GameManager,
+    GameRow, Gems, GoalsManager, Ledger, LedgerManager, Result, config,
+};
+
+use super::Commands;
+
+/// How often the round's message is edited.
+const TICK: Duration = Duration::from_secs(1);
+
+impl Commands {
+    /// Opens a round in the channel, or joins the one taking bets there. The
+    /// command that opened a round plays it out, so its buttons are read
+    /// here rather than through [`super::Components`].
+    pub async fn crash<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let channel = interaction.channel_id;
+
+        let round = match CrashRounds::round(ctx, channel).await {
+            Some((_, false)) => return Err(Error::RoundInProgress),
+            Some((message_id, true)) => Some(message_id),
+            None => None,
+        };
+        let open = round.is_none();
+
+        if open {
+            interaction.defer(ctx).await.unwrap();
+        } else {
+            interaction.defer_ephemeral(ctx).await.unwrap();
+        }
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        // The round is shown to the whole channel
+        let locale = i18n::guild_locale(interaction.guild_locale.as_deref());
+
+        let mut options = parse_options(options);
+
+        let Some(ResolvedValue::Integer(bet)) = options.remove("bet") else {
+            unreachable!("bet is required")
+        };
+
+        let coins = GameHandler::row(pool, economy, interaction.user.id)
+            .await?
+            .unwrap_or_else(|| GameRow::new(interaction.user.id))
+            .coins();
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            coins,
+        )
+        .await?;
+
+        let player = Player::new(interaction.user.id, bet);
+
+        if let Some(message_id) = round {
+            take_bet::<Db, GamblingHandler, LedgerHandler>(
+                pool, economy, message_id, player, coins,
+            )
+            .await?;
+
+            // The round may have started or closed since it was checked
+            if let Err(e) = CrashRounds::join(ctx, channel, message_id, player).await {
+                refund::<Db, GameHandler, LedgerHandler>(pool, economy, message_id, &player)
+                    .await?;
+                return Err(e);
+            }
+
+            store::<Db, SessionHandler>(ctx, pool, channel, message_id).await?;
+
+            Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+            interaction
+                .edit_response(
+                    ctx,
+                    EditInteractionResponse::new().content(t!(
+                        i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+                        "gambling.crash.joined",
+                        bet = coins_str(bet)
+                    )),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
+        let rules = &config::get().crash;
+        let closes = Utc::now().timestamp() + rules.betting_secs as i64;
+
+        let message = interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(betting_embed(locale, &[player], closes))
+                    .components(vec![start_button(locale)]),
+            )
+            .await?;
+
+        let stream = message
+            .await_component_interactions(ctx)
+            .stream()
+            .take_until(Shutdown::signal(ctx));
+
+        take_bet::<Db, GamblingHandler, LedgerHandler>(pool, economy, message.id, player, coins)
+            .await?;
+
+        // Stored for as long as the round can last, so the bets are only
+        // refunded by the session cron once the round can't be running
+        let longest = TimeDelta::from_std(rules.longest_round()).unwrap_or(TimeDelta::days(1));
+        let mut row = SessionRow::new(
+            message.id,
+            channel,
+            Crash {
+                economy,
+                players: vec![player],
+                locale: locale.to_string(),
+            },
+        );
+        row.expires_at += longest;
+
+        if let Err(e) = SessionHandler::insert(pool, &row).await {
+            refund::<Db, GameHandler, LedgerHandler>(pool, economy, message.id, &player).await?;
+            return Err(e.into());
+        }
+
+        if let Err(e) = CrashRounds::open(ctx, channel, message.id, economy, locale, player).await {
+            call_off::<Db, GameHandler, SessionHandler, LedgerHandler>(
+                ctx, pool, channel, message.id,
+            )
+            .await?;
+            return Err(e);
+        }
+
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        let result =
+            run::<Db, GoalsHandler, EffectsHandler, GameHandler, SessionHandler, LedgerHandler>(
+                ctx,
+                interaction,
+                pool,
+                economy,
+                locale,
+                message.id,
+                closes,
+                stream,
+            )
+            .await;
+
+        // Any bets still riding when the round failed are handed back
+        if result.is_err() {
+            let called_off = call_off::<Db, GameHandler, SessionHandler, LedgerHandler>(
+                ctx, pool, channel, message.id,
+            )
+            .await;
+
+            if let Err(e) = called_off {
+                tracing::error!("Failed to call off crash round {}: {e:?}", message.id);
+            }
+        }
+
+        // Whatever happens to the round, the channel has to be freed for the
+        // next one
+        CrashRounds::remove(ctx, channel).await;
+
+        result
+    }
+
+    pub fn register_crash() -> CreateCommand {
+        CreateCommand::new("crash")
+            .description("Ride a climbing multiplier and cash out before it crashes")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "bet", "The amount to bet.")
+                    .required(true),
+            )
+    }
+}
+
+/// Takes bets until the host starts the round or betting closes, then
+/// climbs until the crash, cashing out players as they click. `stream` ends
+/// when the bot shuts down, which calls the round off.
+#[allow(clippy::too_many_arguments)]
+async fn run<
+    Db: Database,
+    GoalsHandler: GoalsManager<Db>,
+    EffectsHandler: EffectsManager<Db> + Send,
+    GameHandler: GameManager<Db>,
+    SessionHandler: SessionManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    ctx: &Context,
+    interaction: &CommandInteraction,
+    pool: &Pool<Db>,
+    economy: Economy,
+    locale: &str,
+    message_id: MessageId,
+    closes: i64,
+    mut stream: impl Stream<Item = ComponentInteraction> + Unpin,
+) -> Result<()> {
+    let rules = &config::get().crash;
+    let channel = interaction.channel_id;
+
+    let mut ticks = interval(TICK);
+    ticks.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+    let betting_closes = Instant::now() + Duration::from_secs(rules.betting_secs);
+    let mut shown = 1;
+
+    loop {
+        tokio::select! {
+            _ = ticks.tick() => {
+                if Instant::now() >= betting_closes {
+                    break;
+                }
+
+                let players = CrashRounds::players(ctx, channel).await;
+                if players.len() != shown {
+                    shown = players.len();
+
+                    interaction
+                        .edit_response(
+                            ctx,
+                            EditInteractionResponse::new()
+                                .embed(betting_embed(locale, &players, closes)),
+                        )
+                        .await?;
+                }
+            }
+            component = stream.next() => {
+                let Some(component) = component else {
+                    CrashRounds::start(ctx, channel).await;
+                    return call_off::<Db, GameHandler, SessionHandler, LedgerHandler>(
+                        ctx, pool, channel, message_id,
+                    )
+                    .await;
+                };
+
+                acknowledge(ctx, &component).await?;
+
+                let host = component.user.id == interaction.user.id;
+                if host && component.data.custom_id == "crash_start" {
+                    break;
+                }
+            }
+        }
+    }
+
+    let mut players = CrashRounds::start(ctx, channel).await;
+
+    // Only known to this task until the round crashes
+    let crash = rules.crash_point();
+    let started = Instant::now();
+    ticks.reset_immediately();
+
+    loop {
+        tokio::select! {
+            _ = ticks.tick() => {
+                let multiplier = rules.multiplier(started.elapsed());
+                if multiplier >= crash {
+                    break;
+                }
+
+                interaction
+                    .edit_response(
+                        ctx,
+                        EditInteractionResponse::new()
+                            .embed(running_embed(locale, &players, multiplier))
+                            .components(vec![cash_out_button(locale)]),
+                    )
+                    .await?;
+            }
+            component = stream.next() => {
+                let Some(component) = component else {
+                    return call_off::<Db, GameHandler, SessionHandler, LedgerHandler>(
+                        ctx, pool, channel, message_id,
+                    )
+                    .await;
+                };
+
+                let multiplier = rules.multiplier(started.elapsed());
+                if multiplier >= crash {
+                    acknowledge(ctx, &component).await?;
+                    break;
+                }
+
+                let user = component.user.id;
+                let Some(player) = players.iter_mut().find(|player| player.user == user) else {
+                    not_player(ctx, &component).await?;
+                    continue;
+                };
+
+                if player.cashed_out.is_some() {
+                    acknowledge(ctx, &component).await?;
+                    continue;
+                }
+
+                let won = (player.bet as f64 * multiplier) as i64;
+                player.cashed_out = Some(multiplier);
+                settle::<
+                    Db,
+                    GoalsHandler,
+                    EffectsHandler,
+                    GameHandler,
+                    SessionHandler,
+                    LedgerHandler,
+                >(ctx, pool, economy, channel, message_id, player, won)
+                .await?;
+
+                component
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Message(
+                            CreateInteractionResponseMessage::new()
+                                .content(t!(
+                                    i18n::locale(
+                                        &component.locale,
+                                        component.guild_locale.as_deref()
+                                    ),
+                                    "gambling.crash.cashed_out_response",
+                                    multiplier = format!("{multiplier:.2}"),
+                                    coins = coins_str(player.payout)
+                                ))
+                                .ephemeral(true),
+                        ),
+                    )
+                    .await?;
+
+                if players.iter().all(|player| player.cashed_out.is_some()) {
+                    break;
+                }
+            }
+        }
+    }
+
+    for player in players
+        .iter_mut()
+        .filter(|player| player.cashed_out.is_none())
+    {
+        settle::<Db, GoalsHandler, EffectsHandler, GameHandler, SessionHandler, LedgerHandler>(
+            ctx, pool, economy, channel, message_id, player, 0,
+        )
+        .await?;
+    }
+
+    end::<Db, SessionHandler>(pool, message_id).await?;
+
+    interaction
+        .edit_response(
+            ctx,
+            EditInteractionResponse::new()
+                .embed(crashed_embed(locale, &players, crash))
+                .components(Vec::new()),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// The rounds being played, at most one to a channel. Only the opening
+/// command changes a round once its betting has closed.
+#[derive(Debug, Default)]
+pub struct CrashRounds(HashMap<ChannelId, Round>);
+
+impl CrashRounds {
+    /// The message of the channel's round and whether it is still taking
+    /// bets, or [`None`] without a round.
+    async fn round(ctx: &Context, channel: ChannelId) -> Option<(MessageId, bool)> {
+        let data = ctx.data.read().await;
+
+        data.get::<Self>()
+            .and_then(|rounds| rounds.0.get(&channel))
+            .map(|round| (round.message_id, round.betting))
+    }
+
+    async fn open(
+        ctx: &Context,
+        channel: ChannelId,
+        message_id: MessageId,
+        economy: Economy,
+        locale: &str,
+        host: Player,
+    ) -> Result<()> {
+        let mut data = ctx.data.write().await;
+        let rounds = data.entry::<Self>().or_insert_with(Self::default);
+
+        // Someone else opened one since the command was checked
+        let Entry::Vacant(entry) = rounds.0.entry(channel) else {
+            return Err(Error::RoundInProgress);
+        };
+
+        entry.insert(Round {
+            message_id,
+            economy,
+            locale: locale.to_string(),
+            betting: true,
+            players: vec![host],
+        });
+
+        Ok(())
+    }
+
+    /// Adds a player to the channel's round, as long as it is still the
+    /// round on `message_id`.
+    async fn join(
+        ctx: &Context,
+        channel: ChannelId,
+        message_id: MessageId,
+        player: Player,
+    ) -> Result<()> {
+        let mut data = ctx.data.write().await;
+        let rounds = data.entry::<Self>().or_insert_with(Self::default);
+
+        let round = rounds
+            .0
+            .get_mut(&channel)
+            .filter(|round| round.message_id == message_id)
+            .ok_or(Error::GameEnded)?;
+
+        if !round.betting {
+            return Err(Error::RoundInProgress);
+        }
+
+        if round
+            .players
+            .iter()
+            .any(|joined| joined.user == player.user)
+        {
+            return Err(Error::AlreadyJoined);
+        }
+
+        round.players.push(player);
+
+        Ok(())
+    }
+
+    async fn players(ctx: &Context, channel: ChannelId) -> Vec<Player> {
+        let data = ctx.data.read().await;
+
+        data.get::<Self>()
+            .and_then(|rounds| rounds.0.get(&channel))
+            .map(|round| round.players.clone())
+            .unwrap_or_default()
+    }
+
+    /// The round on `message_id` as it is stored, unless it has ended.
+    async fn session(ctx: &Context, channel: ChannelId, message_id: MessageId) -> Option<Crash> {
+        let data = ctx.data.read().await;
+
+        data.get::<Self>()
+            .and_then(|rounds| rounds.0.get(&channel))
+            .filter(|round| round.message_id == message_id)
+            .map(|round| Crash {
+                economy: round.economy,
+                players: round.players.clone(),
+                locale: round.locale.clone(),
+            })
+    }
+
+    /// Replaces a player once they have been settled.
+    async fn update(ctx: &Context, channel: ChannelId, player: Player) {
+        let mut data = ctx.data.write().await;
+
+        let joined = data
+            .get_mut::<Self>()
+            .and_then(|rounds| rounds.0.get_mut(&channel))
+            .and_then(|round| round.players.iter_mut().find(|p| p.user == player.user));
+
+        if let Some(joined) = joined {
+            *joined = player;
+        }
+    }
+
+    /// Closes betting, returning everyone who got in.
+    async fn start(ctx: &Context, channel: ChannelId) -> Vec<Player> {
+        let mut data = ctx.data.write().await;
+        let rounds = data.entry::<Self>().or_insert_with(Self::default);
+
+        match rounds.0.get_mut(&channel) {
+            Some(round) => {
+                round.betting = false;
+                round.players.clone()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    async fn remove(ctx: &Context, channel: ChannelId) {
+        let mut data = ctx.data.write().await;
+
+        if let Some(rounds) = data.get_mut::<Self>() {
+            rounds.0.remove(&channel);
+        }
+    }
+}
+
+impl TypeMapKey for CrashRounds {
+    type Value = CrashRounds;
+}
+
+#[derive(Debug)]
+struct Round {
+    message_id: MessageId,
+    economy: Economy,
+    locale: String,
+    betting: bool,
+    players: Vec<Player>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct Player {
+    user: UserId,
+    bet: i64,
+    /// The multiplier the player cashed out at.
+    cashed_out: Option<f64>,
+    /// What the player was paid once settled.
+    payout: i64,
+    settled: bool,
+}
+
+impl Player {
+    fn new(user: UserId, bet: i64) -> Self {
+        Self {
+            user,
+            bet,
+            cashed_out: None,
+            payout: 0,
+            settled: false,
+        }
+    }
+}
+
+/// A round as stored in `game_sessions`, so the bets still riding are
+/// refunded if the bot goes down before it crashes. Only its command reads
+/// the round's buttons, so the session is never played through
+/// [`super::Components`].
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Crash {
+    economy: Economy,
+    players: Vec<Player>,
+    /// The guild's locale, which the round is shown in. Rounds stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
+}
+
+impl Crash {
+    /// Hands back every bet that was never settled.
+    pub(crate) fn time_out(self) -> TimedOut {
+        TimedOut {
+            economy: self.economy,
+            source: "crash",
+            refunds: self
+                .players
+                .iter()
+                .filter(|player| !player.settled)
+                .map(|player| (player.user, player.bet))
+                .collect(),
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.crash.called_off_title"))
+                .description(t!(&self.locale, "gambling.crash.called_off"))
+                .colour(Colour::DARKER_GREY),
+        }
+    }
+}
+
+impl From<Crash> for Session {
+    fn from(game: Crash) -> Self {
+        Self::Crash(game)
+    }
+}
+
+/// Stores the round's players as they join and settle. The stored round is
+/// replaced by whatever is current when it is written, so a write that lost
+/// a race with another is simply made again.
+async fn store<Db: Database, SessionHandler: SessionManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    channel: ChannelId,
+    message_id: MessageId,
+) -> Result<()> {
+    loop {
+        let Some(stored) = SessionHandler::row(pool, message_id).await? else {
+            return Ok(());
+        };
+
+        let Some(round) = CrashRounds::session(ctx, channel, message_id).await else {
+            return Ok(());
+        };
+
+        let mut row = SessionRow::new(message_id, channel, round);
+        row.expires_at = stored.expires_at;
+        row.version = stored.version;
+
+        if SessionHandler::update(&mut *pool.acquire().await?, &row).await? {
+            return Ok(());
+        }
+    }
+}
+
+/// Removes the stored round once every player has been settled.
+async fn end<Db: Database, SessionHandler: SessionManager<Db>>(
+    pool: &Pool<Db>,
+    message_id: MessageId,
+) -> Result<()> {
+    while let Some(row) = SessionHandler::row(pool, message_id).await? {
+        let mut conn = pool.acquire().await?;
+
+        if SessionHandler::delete(&mut *conn, message_id, row.version).await? {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Takes a player's bet before they join, so it can't be spent during the
+/// round.
+async fn take_bet<
+    Db: Database,
+    GamblingHandler: GamblingManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    pool: &Pool<Db>,
+    economy: Economy,
+    message_id: MessageId,
+    player: Player,
+    coins: i64,
+) -> Result<()> {
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    GamblingHandler::bet(&mut *tx, economy, player.user, player.bet).await?;
+    Ledger::new(economy, player.user, "crash")
+        .reference(message_id)
+        .coins(coins, coins - player.bet)
+        .record::<Db, LedgerHandler>(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Pays a player `payout`, nothing if they rode into the crash, and counts
+/// the round towards their goals. What they were paid after their effects
+/// is stored with them, so they aren't refunded if the round is called off.
+#[allow(clippy::too_many_arguments)]
+async fn settle<
+    Db: Database,
+    GoalsHandler: GoalsManager<Db>,
+    EffectsHandler: EffectsManager<Db> + Send,
+    GameHandler: GameManager<Db>,
+    SessionHandler: SessionManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    economy: Economy,
+    channel: ChannelId,
+    message_id: MessageId,
+    player: &mut Player,
+    payout: i64,
+) -> Result<()> {
+    let win = match payout.cmp(&player.bet) {
+        std::cmp::Ordering::Greater => Some(true),
+        std::cmp::Ordering::Equal => None,
+        std::cmp::Ordering::Less => Some(false),
+    };
+
+    let mut row = GameHandler::row(pool, economy, player.user)
+        .await?
+        .unwrap_or_else(|| GameRow::new(player.user));
+    let (start_coins, start_gems) = (row.coins(), row.gems());
+
+    Dispatch::<Db, GoalsHandler>::new(pool, economy)
+        .fire(
+            &mut row,
+            Event::Game(GameEvent::new(
+                "crash",
+                player.user,
+                player.bet,
+                win == Some(true),
+            )),
+        )
+        .await?;
+
+    let payout = EffectsHandler::payout(pool, economy, player.user, player.bet, payout, win).await;
+
+    row.add_coins(payout);
+
+    let ledger = Ledger::new(economy, player.user, "crash")
+        .reference(message_id)
+        .coins(start_coins, row.coins())
+        .gems(start_gems, row.gems());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    GameHandler::save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
+
+    player.payout = payout;
+    player.settled = true;
+    CrashRounds::update(ctx, channel, *player).await;
+
+    store::<Db, SessionHandler>(ctx, pool, channel, message_id).await
+}
+
+/// Hands back a player's bet.
+async fn refund<Db: Database, GameHandler: GameManager<Db>, LedgerHandler: LedgerManager<Db>>(
+    pool: &Pool<Db>,
+    economy: Economy,
+    message_id: MessageId,
+    player: &Player,
+) -> Result<()> {
+    let mut row = GameHandler::row(pool, economy, player.user)
+        .await?
+        .unwrap_or_else(|| GameRow::new(player.user));
+    let coins = row.coins();
+
+    row.add_coins(player.bet);
+
+    let ledger = Ledger::new(economy, player.user, "crash")
+        .reference(message_id)
+        .coins(coins, row.coins());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    GameHandler::save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Calls the round off, handing back the bets still riding when the bot
+/// shuts down mid-round or the round fails. The stored round is settled the
+/// same way as one that outlived the bot.
+async fn call_off<
+    Db: Database,
+    GameHandler: GameManager<Db>,
+    SessionHandler: SessionManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    channel: ChannelId,
+    message_id: MessageId,
+) -> Result<()> {
+    store::<Db, SessionHandler>(ctx, pool, channel, message_id).await?;
+
+    if let Some(row) = SessionHandler::row(pool, message_id).await? {
+        Sessions::refund::<Db, GameHandler, SessionHandler, LedgerHandler>(&ctx.http, pool, row)
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn betting_embed(locale: &str, players: &[Player], closes: i64) -> CreateEmbed {
+    let players = players
+        .iter()
+        .map(|player| format!("{} - {}", player.user.mention(), coins_str(player.bet)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CreateEmbed::new()
+        .title(t!(locale, "gambling.crash.name"))
+        .description(format!(
+            "{}\n\n**{}**\n{players}",
+            t!(locale, "gambling.crash.betting", closes = closes),
+            t!(locale, "gambling.crash.players")
+        ))
+        .colour(Colour::GOLD)
+}
+
+fn running_embed(locale: &str, players: &[Player], multiplier: f64) -> CreateEmbed {
+    let players = players
+        .iter()
+        .map(|player| {
+            let status = match player.cashed_out {
+                Some(cashed_out) => t!(
+                    locale,
+                    "gambling.crash.player_cashed_out",
+                    multiplier = format!("{cashed_out:.2}"),
+                    coins = coins_str(player.payout)
+                ),
+                None => t!(
+                    locale,
+                    "gambling.crash.riding",
+                    coins = coins_str(player.bet)
+                ),
+            };
+
+            format!("{} - {status}", player.user.mention())
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CreateEmbed::new()
+        .title(t!(
+            locale,
+            "gambling.crash.running_title",
+            multiplier = format!("{multiplier:.2}")
+        ))
+        .description(format!(
+            "{}\n\n**{}**\n{players}",
+            t!(locale, "gambling.crash.cash_out_prompt"),
+            t!(locale, "gambling.crash.players")
+        ))
+        .colour(Colour::DARK_GREEN)
+}
+
+fn crashed_embed(locale: &str, players: &[Player], crash: f64) -> CreateEmbed {
+    let players = players
+        .iter()
+        .map(|player| {
+            let outcome = match player.cashed_out {
+                Some(cashed_out) => t!(
+                    locale,
+                    "gambling.crash.cashed_out",
+                    multiplier = format!("{cashed_out:.2}")
+                ),
+                None => t!(locale, "gambling.crash.crashed"),
+            };
+
+            format!(
+                "{} - {outcome} ({})",
+                player.user.mention(),
+                coins_str(player.payout - player.bet)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    CreateEmbed::new()
+        .title(t!(
+            locale,
+            "gambling.crash.crashed_title",
+            multiplier = format!("{crash:.2}")
+        ))
+        .description(format!(
+            "**{}**\n{players}",
+            t!(locale, "gambling.crash.players")
+        ))
+        .colour(Colour::RED)
+}
+
+fn start_button(locale: &str) -> CreateActionRow {
+    CreateActionRow::Buttons(vec![
+        CreateButton::new("crash_start")
+            .label(t!(locale, "gambling.crash.start"))
+            .style(ButtonStyle::Primary),
+    ])
+}
+
+fn cash_out_button(locale: &str) -> CreateActionRow {
+    CreateActionRow::Buttons(vec![
+        CreateButton::new("crash_cash_out")
+            .label(t!(locale, "gambling.crash.cash_out"))
+            .style(ButtonStyle::Success),
+    ])
+}
+
+/// Tells someone who didn't bet on the round that they can't cash out of it.
+async fn not_player(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+    interaction
+        .create_response(
+            ctx,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(Error::NotPlayer.localize(locale))
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Answers a click that doesn't change the round.
+async fn acknowledge(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    Ok(())
+}
@@ -5,17 +5,28 @@ use serenity::all::{
     UserId,
 };
 use sqlx::{Database, Pool, any::AnyQueryResult, prelude::FromRow};
-use zayden_core::FormatNum;
+use zayden_core::{i18n, t};
 
-use crate::{COIN, Coins, Error, Result, START_AMOUNT, tomorrow};
+use crate::utils::coins_str;
+use crate::{
+    Coins, Economies, Economy, Error, Ledger, LedgerManager, Result, START_AMOUNT, tomorrow,
+};
 
 use super::Commands;
 
 #[async_trait]
 pub trait DailyManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<DailyRow>>;
-
-    async fn save(pool: &Pool<Db>, row: DailyRow) -> sqlx::Result<AnyQueryResult>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DailyRow>>;
+
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: DailyRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(FromRow)]
@@ -50,14 +61,20 @@ impl Coins for DailyRow {
 }
 
 impl Commands {
-    pub async fn daily<Db: Database, Manager: DailyManager<Db>>(
+    pub async fn daily<
+        Db: Database,
+        Manager: DailyManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let mut row = Manager::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let mut row = Manager::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| DailyRow::new(interaction.user.id));
@@ -71,12 +88,23 @@ impl Commands {
 
         let amount = START_AMOUNT * (row.prestige.unwrap_or_default() + 1);
 
+        let start_coins = row.coins();
         *row.coins_mut() += amount;
+        let ledger = Ledger::new(economy, interaction.user.id, "daily")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins());
 
-        Manager::save(pool, row).await.unwrap();
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        Manager::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
 
         let embed = CreateEmbed::new()
-            .description(format!("Collected {} <:coin:{COIN}>", amount.format()))
+            .description(t!(
+                i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+                "gambling.daily.collected",
+                coins = coins_str(amount)
+            ))
             .colour(Colour::GOLD);
 
         interaction
@@ -9,12 +9,15 @@ use serenity::all::{
     UserId,
 };
 use sqlx::{Database, Pool, any::AnyQueryResult, prelude::FromRow};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, i18n, t};
 
 use crate::events::{Dispatch, Event};
 use crate::models::{MineAmount, Prestige};
 use crate::shop::ShopCurrency;
-use crate::{COIN, Coins, Gems, GoalsManager, MaxBet, MineHourly, Result, Stamina, StaminaManager};
+use crate::{
+    COIN, Coins, Economies, Economy, Gems, GoalsManager, Ledger, LedgerManager, MaxBet, MineHourly,
+    Result, Stamina, StaminaManager,
+};
 
 use super::Commands;
 
@@ -41,9 +44,17 @@ static CHANCES: LazyLock<HashMap<&str, f64>> = LazyLock::new(|| {
 
 #[async_trait]
 pub trait DigManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<DigRow>>;
-
-    async fn save(pool: &Pool<Db>, row: DigRow) -> sqlx::Result<AnyQueryResult>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<DigRow>>;
+
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: DigRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(Debug, FromRow)]
@@ -171,6 +182,7 @@ impl Commands {
         StaminaHandler: StaminaManager<Db>,
         GoalsHandler: GoalsManager<Db>,
         DigHandler: DigManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -178,12 +190,15 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await?;
 
-        let mut row = DigHandler::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let mut row = DigHandler::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| DigRow::new(interaction.user.id));
 
         row.verify_work::<Db, StaminaHandler>()?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
 
         let mut resources = HashMap::from([
             ("coal", 0),
@@ -220,7 +235,7 @@ impl Commands {
             s => unreachable!("Invalid resource: {s}"),
         });
 
-        Dispatch::<Db, GoalsHandler>::new(pool)
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
             .fire(&mut row, Event::Work(interaction.user.id))
             .await?;
 
@@ -231,8 +246,17 @@ impl Commands {
         row.mine_activity = Some(Utc::now().naive_utc());
 
         let stamina = row.stamina_str();
+        let ledger = Ledger::new(economy, interaction.user.id, "dig")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins())
+            .gems(start_gems, row.gems());
 
-        DigHandler::save(pool, row).await.unwrap();
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        DigHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
 
         let found = resources
             .drain()
@@ -247,15 +271,22 @@ impl Commands {
                 "emeralds" => (ShopCurrency::Emeralds, v, k),
                 s => unreachable!("Invalid resource: {s}"),
             })
-            .map(|(currency, amount, name)| format!("{currency} `{}` {name}", amount.format()))
+            .map(|(currency, amount, name)| {
+                format!(
+                    "{currency} `{}` {}",
+                    amount.format(),
+                    t!(locale, &format!("gambling.resources.{name}"))
+                )
+            })
             .collect::<Vec<_>>();
 
         let embed = CreateEmbed::new()
             .description(format!(
-                "You dug around in the mines and found:\n{}{}\n\nStamina: {stamina}",
+                "{}\n{}{}\n\n{}",
+                t!(locale, "gambling.dig.found"),
                 {
                     if found.is_empty() {
-                        String::from("Just a whole lot of boring stone...")
+                        t!(locale, "gambling.dig.nothing")
                     } else {
                         found.join("\n")
                     }
@@ -265,11 +296,13 @@ impl Commands {
                         String::new()
                     } else {
                         format!(
-                            "\n\nWhile you were gone, your mine made:\n<:coin:{COIN}> `{}` coins",
-                            mine_amount.format()
+                            "\n\n{}\n<:coin:{COIN}> {}",
+                            t!(locale, "gambling.dig.mine_made"),
+                            t!(locale, "gambling.dig.coins", amount = mine_amount.format())
                         )
                     }
-                }
+                },
+                t!(locale, "gambling.stamina", stamina = stamina)
             ))
             .color(Colour::GOLD);
 
@@ -0,0 +1,139 @@
+use serenity::all::{
+    Colour, CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, ResolvedOption, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+use zayden_core::cache::GuildMembersCache;
+use zayden_core::{i18n, t};
+
+use crate::economy::{Economies, EconomyManager};
+use crate::{Error, Result};
+
+use super::Commands;
+
+impl Commands {
+    pub async fn economy<Db: Database, Manager: EconomyManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        mut options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer_ephemeral(ctx).await.unwrap();
+
+        let guild_id = interaction.guild_id.unwrap();
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let subcommand = options.pop().unwrap();
+        let ResolvedValue::SubCommand(options) = subcommand.value else {
+            unreachable!("Option must be a subcommand")
+        };
+
+        let key = match subcommand.name {
+            "status" => match Economies::get(ctx, Some(guild_id)).await.guild_id() {
+                Some(_) => "gambling.economy.status_own",
+                None => "gambling.economy.status_global",
+            },
+            "enable" => {
+                Manager::enable(pool, guild_id).await?;
+
+                let import = options.iter().any(|option| {
+                    option.name == "import" && matches!(option.value, ResolvedValue::Boolean(true))
+                });
+
+                if import {
+                    let users = {
+                        let data = ctx.data.read().await;
+                        data.get::<GuildMembersCache>()
+                            .and_then(|cache| cache.get(&guild_id))
+                            .map(|members| members.iter().map(|id| id.get() as i64).collect())
+                            .unwrap_or_else(Vec::new)
+                    };
+
+                    Manager::import(pool, guild_id, &users).await?;
+                }
+
+                Economies::set(ctx, guild_id, true).await;
+
+                if import {
+                    "gambling.economy.enabled_imported"
+                } else {
+                    "gambling.economy.enabled"
+                }
+            }
+            "disable" => {
+                Manager::disable(pool, guild_id).await?;
+                Economies::set(ctx, guild_id, false).await;
+
+                "gambling.economy.disabled"
+            }
+            "reset" => {
+                let confirmed = options.iter().any(|option| {
+                    option.name == "confirm" && matches!(option.value, ResolvedValue::Boolean(true))
+                });
+
+                if !confirmed {
+                    return Err(Error::ResetNotConfirmed);
+                }
+
+                Manager::reset(pool, guild_id).await?;
+
+                "gambling.economy.reset"
+            }
+            _ => unreachable!("Invalid subcommand"),
+        };
+
+        let embed = CreateEmbed::new()
+            .title(t!(locale, "gambling.economy.name"))
+            .description(t!(locale, key))
+            .colour(Colour::TEAL);
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    pub fn register_economy() -> CreateCommand {
+        CreateCommand::new("economy")
+            .description("Choose between the global economy and one for this server")
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "status",
+                "Show which economy this server uses",
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "enable",
+                    "Give this server its own balances, inventories, mines and leaderboards",
+                )
+                .add_sub_option(CreateCommandOption::new(
+                    CommandOptionType::Boolean,
+                    "import",
+                    "Start everyone with their global balances instead of from scratch",
+                )),
+            )
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "disable",
+                "Switch this server back to the global economy",
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::SubCommand,
+                    "reset",
+                    "Delete everything in this server's economy to start a new season",
+                )
+                .add_sub_option(
+                    CreateCommandOption::new(
+                        CommandOptionType::Boolean,
+                        "confirm",
+                        "Confirm that every balance, item and mine should be deleted",
+                    )
+                    .required(true),
+                ),
+            )
+    }
+}
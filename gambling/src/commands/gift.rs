@@ -5,10 +5,11 @@ use serenity::all::{
     CreateEmbed, EditInteractionResponse, Mentionable, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool, any::AnyQueryResult, prelude::FromRow};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, i18n, t};
 
 use crate::{
-    Coins, Error, Gems, GoalsManager, MaxBet, Prestige, Result, START_AMOUNT,
+    Coins, Economies, Economy, Error, Gems, GoalsManager, Ledger, LedgerManager, MaxBet, Prestige,
+    Result, START_AMOUNT,
     events::{Dispatch, Event, SendEvent},
     tomorrow,
 };
@@ -21,16 +22,23 @@ use super::Commands;
 pub trait GiftManager<Db: Database> {
     async fn sender(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<SenderRow>>;
 
+    /// Returns the user's new balance, or `None` if they have never played.
     async fn add_coins(
-        pool: &Pool<Db>,
+        conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult>;
+    ) -> sqlx::Result<Option<i64>>;
 
-    async fn save_sender(pool: &Pool<Db>, row: SenderRow) -> sqlx::Result<AnyQueryResult>;
+    async fn save_sender(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: SenderRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(FromRow)]
@@ -122,6 +130,7 @@ impl Commands {
         Db: Database,
         GoalsHandler: GoalsManager<Db>,
         GiftHandler: GiftManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -130,6 +139,8 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let ResolvedValue::User(recipient, _) = options[0].value else {
             unreachable!("recipient is required")
         };
@@ -138,7 +149,7 @@ impl Commands {
             return Err(Error::SelfGift);
         }
 
-        let mut user_row = GiftHandler::sender(pool, interaction.user.id)
+        let mut user_row = GiftHandler::sender(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| SenderRow::new(interaction.user.id));
@@ -151,24 +162,42 @@ impl Commands {
 
         let amount = GIFT_AMOUNT * (user_row.prestige + 1);
 
-        GiftHandler::add_coins(pool, recipient.id, amount)
-            .await
-            .unwrap();
+        let (start_coins, start_gems) = (user_row.coins(), user_row.gems());
 
-        Dispatch::<Db, GoalsHandler>::new(pool)
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
             .fire(
                 &mut user_row,
                 Event::Send(SendEvent::new(amount, interaction.user.id)),
             )
             .await?;
 
-        GiftHandler::save_sender(pool, user_row).await.unwrap();
+        let ledger = Ledger::new(economy, interaction.user.id, "gift")
+            .reference(interaction.id)
+            .coins(start_coins, user_row.coins())
+            .gems(start_gems, user_row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+
+        if let Some(balance) =
+            GiftHandler::add_coins(&mut *tx, economy, recipient.id, amount).await?
+        {
+            Ledger::new(economy, recipient.id, "gift")
+                .reference(interaction.id)
+                .coins(balance - amount, balance)
+                .record::<Db, LedgerHandler>(&mut *tx)
+                .await?;
+        }
+
+        GiftHandler::save_sender(&mut *tx, economy, user_row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
 
         let embed = CreateEmbed::new()
-            .description(format!(
-                "🎁 You sent a gift of {} to {}",
-                amount.format(),
-                recipient.mention()
+            .description(t!(
+                i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+                "gambling.gift.sent",
+                amount = amount.format(),
+                user = recipient.mention()
             ))
             .colour(Colour::GOLD);
 
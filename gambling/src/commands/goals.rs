@@ -3,22 +3,32 @@ use serenity::all::{
     CommandInteraction, Context, CreateCommand, CreateEmbed, EditInteractionResponse, UserId,
 };
 use sqlx::{Database, FromRow, Pool};
+use zayden_core::{i18n, t};
 
-use crate::{COIN, Coins, GamblingGoalsRow, Gems, GoalHandler, MaxBet, Prestige, Result, tomorrow};
+use crate::{
+    COIN, Coins, Economies, Economy, GamblingGoalsRow, Gems, GoalHandler, MaxBet, Prestige, Result,
+    tomorrow,
+};
 
 use super::Commands;
 
 #[async_trait]
 pub trait GoalsManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<GoalsRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<GoalsRow>>;
 
     async fn full_rows(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Vec<GamblingGoalsRow>>;
 
     async fn update(
         pool: &Pool<Db>,
+        economy: Economy,
         rows: &[GamblingGoalsRow],
     ) -> sqlx::Result<Vec<GamblingGoalsRow>>;
 }
@@ -71,25 +81,32 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let row = Manager::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let row = Manager::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_default();
 
         let mut desc =
-            GoalHandler::get_user_progress::<Db, Manager>(pool, interaction.user.id, &row)
+            GoalHandler::get_user_progress::<Db, Manager>(pool, economy, interaction.user.id, &row)
                 .await
                 .unwrap()
                 .into_iter()
-                .map(|goal| format!("{}\n\n", goal.description()))
+                .map(|goal| format!("{}\n\n", goal.description(locale)))
                 .collect::<String>();
 
-        desc.push_str(&format!(
-            "Reward for completing __**each goals**__: 5,000 <:coin:{COIN}>\nReward for completing __**all goals**__: 1 💎\n\nGoals reset <t:{}:R>",
-            tomorrow(None)
+        desc.push_str(&t!(
+            locale,
+            "gambling.goals.rewards",
+            coins = format!("5,000 <:coin:{COIN}>"),
+            reset = tomorrow(None)
         ));
 
-        let embed = CreateEmbed::new().title("Daily Goals 📋").description(desc);
+        let embed = CreateEmbed::new()
+            .title(t!(locale, "gambling.goals.title"))
+            .description(desc);
 
         interaction
             .edit_response(ctx, EditInteractionResponse::new().embed(embed))
@@ -1,41 +1,32 @@
-use std::collections::HashMap;
-use std::sync::LazyLock;
-use std::time::Duration;
-
-use futures::StreamExt;
 use rand::rng;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 use serenity::all::{
     Colour, CommandInteraction, ComponentInteraction, Context, CreateButton, CreateCommand,
     CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage,
-    EditInteractionResponse, EmojiId, parse_emoji,
+    EditInteractionResponse, MessageId, UserId,
 };
 use sqlx::{Database, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{Cooldowns, FormatNum, i18n, t};
 
 use crate::events::{Dispatch, Event, GameEvent};
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::utils::coins_str;
 use crate::{
-    CARD_DECK, Coins, Error, GameCache, GameManager, GameRow, Gems, GoalsManager, Result,
-    ShopCurrency,
+    CARD_DECK, Coins, Economies, Economy, Error, GAME_COOLDOWN, GameManager, GameRow, Gems,
+    GoalsManager, Ledger, LedgerManager, Result, ShopCurrency,
 };
 
-use super::Commands;
+use super::{Commands, Components};
 
 const BUYIN: i64 = 1000;
 
-static CARD_TO_NUM: LazyLock<HashMap<EmojiId, u8>> = LazyLock::new(|| {
-    CARD_DECK
-        .iter()
-        .copied()
-        .zip((1u8..=13).cycle().take(52))
-        .collect()
-});
-
 impl Commands {
     pub async fn higher_lower<
         Db: Database,
-        GoalsHandler: GoalsManager<Db>,
         GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -43,7 +34,10 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
@@ -55,108 +49,202 @@ impl Commands {
             });
         }
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
 
+        let start_coins = row.coins();
         row.bet(BUYIN);
+        let coins = row.coins();
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        Ledger::new(economy, interaction.user.id, "higherorlower")
+            .reference(interaction.id)
+            .coins(start_coins, coins)
+            .record::<Db, LedgerHandler>(&mut *tx)
+            .await?;
+        tx.commit().await?;
 
-        GameHandler::save(pool, row).await.unwrap();
-
-        let mut deck = CARD_DECK.to_vec();
+        let mut deck = (0..52).collect::<Vec<u8>>();
         deck.shuffle(&mut rng());
 
-        let emoji = deck.pop().unwrap();
-        let num = CARD_TO_NUM.get(&emoji).unwrap();
+        let card = deck.pop().unwrap();
 
-        let embed = create_embed(&format!("<:{num}:{emoji}>"), 0, true);
+        let game = HigherLower {
+            economy,
+            user: interaction.user.id,
+            seq: card_emoji(card),
+            deck,
+            card,
+            payout: 0,
+            locale: locale.to_string(),
+        };
 
-        let higher_btn = CreateButton::new("hol_higher").emoji('☝').label("Higher");
-        let lower_btn = CreateButton::new("hol_lower").emoji('👇').label("Lower");
+        let higher_btn = CreateButton::new("hol_higher")
+            .emoji('☝')
+            .label(t!(locale, "gambling.higher_lower.higher"));
+        let lower_btn = CreateButton::new("hol_lower")
+            .emoji('👇')
+            .label(t!(locale, "gambling.higher_lower.lower"));
 
         let msg = interaction
             .edit_response(
                 ctx,
                 EditInteractionResponse::new()
-                    .embed(embed)
+                    .embed(create_embed(&game.locale, &game.seq, game.payout))
                     .button(higher_btn)
                     .button(lower_btn),
             )
             .await
             .unwrap();
 
-        let mut stream = msg
-            .await_component_interactions(ctx)
-            .author_id(interaction.user.id)
-            .timeout(Duration::from_secs(120))
-            .stream();
-
-        let mut payout = 0;
-        let mut prev_seq = String::new();
-
-        while let Some(interaction) = stream.next().await {
-            let mut desc_iter = interaction
-                .message
-                .embeds
-                .first()
-                .unwrap()
-                .description
-                .as_deref()
-                .unwrap()
-                .split("\n\n");
-
-            prev_seq = desc_iter.next().unwrap().to_string();
-            let prev_emoji = parse_emoji(prev_seq.split(' ').next_back().unwrap()).unwrap();
-            let prev_num = prev_emoji.name.parse::<u8>().unwrap();
-
-            let emoji = match deck.pop() {
-                Some(emoji) => emoji,
-                None => break,
-            };
-            let num = *CARD_TO_NUM.get(&emoji).unwrap();
-
-            payout = desc_iter
-                .next()
-                .unwrap()
-                .strip_prefix("Current Payout: ")
-                .unwrap()
-                .replace(',', "")
-                .parse::<i64>()
-                .unwrap();
-
-            let choice = interaction.data.custom_id.as_str();
-
-            let winner = if choice == "hol_higher" {
-                higher(
-                    ctx,
-                    &interaction,
-                    &mut prev_seq,
-                    prev_num,
-                    num,
-                    emoji,
-                    payout,
-                )
-                .await?
-            } else {
-                lower(
+        SessionHandler::insert(pool, &SessionRow::new(msg.id, msg.channel_id, game)).await?;
+
+        Ok(())
+    }
+
+    pub fn register_higher_lower() -> CreateCommand {
+        CreateCommand::new("higherorlower").description("Play a game of higher or lower")
+    }
+}
+
+impl Components {
+    pub async fn higher_lower<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::HigherLower(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        if interaction.user.id != game.user {
+            return Err(Error::NotPlayer);
+        }
+
+        let winner = match game.deck.pop() {
+            Some(next) => {
+                let (winner, emoji) = if interaction.data.custom_id == "hol_higher" {
+                    (rank(next) >= rank(game.card), '☝')
+                } else {
+                    (rank(next) <= rank(game.card), '👇')
+                };
+
+                let emoji = if winner { emoji } else { '❌' };
+                game.seq.push_str(&format!(" {emoji} {}", card_emoji(next)));
+                game.card = next;
+
+                if winner {
+                    game.payout += 1000;
+                }
+
+                winner
+            }
+            None => false,
+        };
+
+        if winner {
+            let embed = create_embed(&game.locale, &game.seq, game.payout);
+            Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
+
+            interaction
+                .create_response(
                     ctx,
-                    &interaction,
-                    &mut prev_seq,
-                    prev_num,
-                    num,
-                    emoji,
-                    payout,
+                    CreateInteractionResponse::UpdateMessage(
+                        CreateInteractionResponseMessage::new().embed(embed),
+                    ),
                 )
-                .await?
-            };
+                .await?;
 
-            if !winner {
-                break;
-            }
+            return Ok(());
         }
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
-            .await
-            .unwrap()
-            .unwrap();
+        Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
+
+        let embed = game
+            .cash_out::<Db, GoalsHandler, GameHandler, LedgerHandler>(pool, interaction.message.id)
+            .await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(Vec::new()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A game of higher or lower, with cards as indexes into the deck.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HigherLower {
+    // Games stored before economies were split by guild are global
+    #[serde(default)]
+    economy: Economy,
+    user: UserId,
+    deck: Vec<u8>,
+    seq: String,
+    card: u8,
+    payout: i64,
+    /// The player's locale, which the game is shown in. Games stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
+}
+
+impl HigherLower {
+    /// Hands back the buy-in, as a game cut off by a restart is never played
+    /// out.
+    pub(crate) fn time_out(self) -> TimedOut {
+        TimedOut {
+            economy: self.economy,
+            source: "higherorlower",
+            refunds: vec![(self.user, BUYIN)],
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.higher_lower.name"))
+                .description(format!(
+                    "{}\n\n{}",
+                    self.seq,
+                    t!(
+                        &self.locale,
+                        "gambling.interrupted",
+                        refund = coins_str(BUYIN)
+                    )
+                ))
+                .colour(Colour::TEAL),
+        }
+    }
+
+    /// Pays out what the player has won so far.
+    pub(crate) async fn cash_out<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        self,
+        pool: &Pool<Db>,
+        message_id: MessageId,
+    ) -> Result<CreateEmbed> {
+        let (economy, payout) = (self.economy, self.payout);
+
+        let mut row = GameHandler::row(pool, economy, self.user)
+            .await?
+            .unwrap_or_else(|| GameRow::new(self.user));
+        let (start_coins, start_gems) = (row.coins(), row.gems());
 
         row.add_coins(payout);
 
@@ -173,139 +261,78 @@ impl Commands {
 
         let coins = row.coins_str();
 
-        Dispatch::<Db, GoalsHandler>::new(pool)
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Game(GameEvent::new(
                     "higherorlower",
-                    interaction.user.id,
+                    self.user,
                     payout + BUYIN,
                     payout > 0,
                 )),
             )
             .await?;
 
-        GameHandler::save(pool, row).await.unwrap();
-        GameCache::update(ctx, interaction.user.id).await;
+        let ledger = Ledger::new(economy, self.user, "higherorlower")
+            .reference(message_id)
+            .coins(start_coins, row.coins())
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
 
-        let result = format!("Payout: {}", payout.format());
+        let locale = self.locale.as_str();
 
-        let embed = CreateEmbed::new()
-            .title("Higher or Lower")
+        Ok(CreateEmbed::new()
+            .title(t!(locale, "gambling.higher_lower.name"))
             .description(format!(
-                "{}\n\nFinal Payout: {}\n\nThis game has ended.\n\n{result}\nYour coins: {coins}",
-                prev_seq,
-                payout.format()
+                "{}\n\n{}\n\n{}\n\n{}\n{}",
+                self.seq,
+                t!(
+                    locale,
+                    "gambling.higher_lower.final_payout",
+                    payout = payout.format()
+                ),
+                t!(locale, "gambling.higher_lower.ended"),
+                t!(
+                    locale,
+                    "gambling.higher_lower.payout",
+                    payout = payout.format()
+                ),
+                t!(locale, "gambling.your_coins", coins = coins),
             ))
-            .colour(colour);
-
-        interaction
-            .edit_response(
-                ctx,
-                EditInteractionResponse::new()
-                    .embed(embed)
-                    .components(Vec::new()),
-            )
-            .await
-            .unwrap();
-
-        Ok(())
+            .colour(colour))
     }
+}
 
-    pub fn register_higher_lower() -> CreateCommand {
-        CreateCommand::new("higherorlower").description("Play a game of higher or lower")
+impl From<HigherLower> for Session {
+    fn from(game: HigherLower) -> Self {
+        Self::HigherLower(game)
     }
 }
 
-fn create_embed(seq: &str, payout: i64, winner: bool) -> CreateEmbed {
-    let payout = payout.format();
-
-    let desc = if winner {
-        format!("{seq}\n\nCurrent Payout: {payout}\n\nGuess the next number!")
-    } else {
-        format!("{seq}\n\nFinal Payout: {payout}")
-    };
-
-    CreateEmbed::new()
-        .title("Higher or Lower")
-        .description(desc)
-        .colour(Colour::TEAL)
+/// The rank of a card from 1 for an ace to 13 for a king.
+fn rank(card: u8) -> u8 {
+    card % 13 + 1
 }
 
-async fn higher(
-    ctx: &Context,
-    interaction: &ComponentInteraction,
-    seq: &mut String,
-    prev: u8,
-    next: u8,
-    emoji: EmojiId,
-    mut payout: i64,
-) -> Result<bool> {
-    seq.push(' ');
-
-    let winner = next >= prev;
-
-    if winner {
-        seq.push('☝');
-        payout += 1000
-    } else {
-        seq.push('❌');
-    }
-
-    seq.push_str(&format!(" <:{next}:{emoji}>"));
-
-    let embed = create_embed(seq, payout, winner);
-
-    let msg = if winner {
-        CreateInteractionResponseMessage::new().embed(embed)
-    } else {
-        CreateInteractionResponseMessage::new()
-            .embed(embed)
-            .components(Vec::new())
-    };
-
-    interaction
-        .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
-        .await?;
-
-    Ok(winner)
+fn card_emoji(card: u8) -> String {
+    format!("<:{}:{}>", rank(card), CARD_DECK[usize::from(card)])
 }
 
-async fn lower(
-    ctx: &Context,
-    interaction: &ComponentInteraction,
-    seq: &mut String,
-    prev: u8,
-    next: u8,
-    emoji: EmojiId,
-    mut payout: i64,
-) -> Result<bool> {
-    seq.push(' ');
-
-    let winner = next <= prev;
-
-    if winner {
-        seq.push('👇');
-        payout += 1000
-    } else {
-        seq.push('❌');
-    }
-
-    seq.push_str(&format!(" <:{next}:{emoji}>"));
-
-    let embed = create_embed(seq, payout, winner);
-
-    let msg = if winner {
-        CreateInteractionResponseMessage::new().embed(embed)
-    } else {
-        CreateInteractionResponseMessage::new()
-            .embed(embed)
-            .components(Vec::new())
-    };
-
-    interaction
-        .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
-        .await?;
-
-    Ok(winner)
+fn create_embed(locale: &str, seq: &str, payout: i64) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(t!(locale, "gambling.higher_lower.name"))
+        .description(format!(
+            "{seq}\n\n{}\n\n{}",
+            t!(
+                locale,
+                "gambling.higher_lower.current_payout",
+                payout = payout.format()
+            ),
+            t!(locale, "gambling.higher_lower.guess")
+        ))
+        .colour(Colour::TEAL)
 }
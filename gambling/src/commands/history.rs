@@ -0,0 +1,93 @@
+use serenity::all::{
+    Colour, CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, ResolvedOption, ResolvedValue,
+};
+use sqlx::{Database, Pool};
+use zayden_core::{FormatNum, Paginator, i18n, t};
+
+use crate::ledger::{LedgerManager, LedgerRow};
+use crate::{COIN, Economies, GEM, Result};
+
+use super::Commands;
+
+const PAGE_SIZE: usize = 10;
+
+impl Commands {
+    pub async fn history<Db: Database, Manager: LedgerManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        mut options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer(ctx).await?;
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let user = match options.pop() {
+            Some(option) => {
+                let ResolvedValue::User(user, _) = option.value else {
+                    unreachable!("value must be a user")
+                };
+                user
+            }
+            None => &interaction.user,
+        };
+
+        let count = Manager::count(pool, economy, user.id).await?;
+        let title = t!(locale, "gambling.history.title", user = user.display_name());
+        let (user, title) = (user.id, &title);
+
+        Paginator::new((count as usize).div_ceil(PAGE_SIZE), |page| async move {
+            let rows = Manager::history(pool, economy, user, page as i64, PAGE_SIZE as i64).await?;
+
+            let desc = if rows.is_empty() {
+                t!(locale, "gambling.history.empty")
+            } else {
+                rows.iter()
+                    .map(|row| as_desc(locale, row))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            };
+
+            Ok(CreateEmbed::new()
+                .title(title)
+                .description(desc)
+                .colour(Colour::TEAL))
+        })
+        .run(ctx, interaction)
+        .await
+    }
+
+    pub fn register_history() -> CreateCommand {
+        CreateCommand::new("history")
+            .description("Show where your coins and gems came from and went")
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::User,
+                "user",
+                "The user's transactions to show (moderators only)",
+            ))
+    }
+}
+
+fn as_desc(locale: &str, row: &LedgerRow) -> String {
+    let currency = match row.currency.as_str() {
+        "gems" => GEM.to_string(),
+        _ => format!("<:coin:{COIN}>"),
+    };
+
+    let sign = if row.amount > 0 { "+" } else { "" };
+
+    let reference = row
+        .reference_id
+        .map(|id| format!(" · {}", t!(locale, "gambling.history.reference", id = id)))
+        .unwrap_or_default();
+
+    format!(
+        "<t:{}:f> **{}**\n`{sign}{}` {currency} → {}{reference}",
+        row.created_at.timestamp(),
+        row.source,
+        row.amount.format(),
+        row.balance.format()
+    )
+}
@@ -7,11 +7,12 @@ use serenity::all::{
 };
 use sqlx::types::Json;
 use sqlx::{Database, Pool, prelude::FromRow};
-use zayden_core::parse_options;
+use zayden_core::{Paginator, i18n, parse_options, t};
 
 use crate::shop::{SHOP_ITEMS, ShopCurrency, ShopItem, ShopPage};
 use crate::{
-    COIN, Coins, EffectsManager, Error, GEM, GamblingItem, Gems, ItemInventory, Mining, Result,
+    COIN, Coins, Economies, Economy, EffectsManager, Error, GEM, GamblingItem, Gems, ItemInventory,
+    Mining, Result,
 };
 
 use super::Commands;
@@ -46,11 +47,13 @@ impl<'a> From<&ShopItem<'a>> for InventoryItem<'a> {
 pub trait InventoryManager<Db: Database> {
     async fn row(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<InventoryRow>>;
 
     async fn edit_item_quantity(
         conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         item_id: &str,
         amount: i64,
@@ -254,7 +257,10 @@ async fn show<Db: Database, Manager: InventoryManager<Db>>(
     interaction: &CommandInteraction,
     pool: &Pool<Db>,
 ) -> Result<()> {
-    let row = Manager::row(pool, interaction.user.id)
+    let economy = Economies::get(ctx, interaction.guild_id).await;
+    let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+    let row = Manager::row(pool, economy, interaction.user.id)
         .await
         .unwrap()
         .unwrap_or_default();
@@ -281,55 +287,70 @@ async fn show<Db: Database, Manager: InventoryManager<Db>>(
         })
         .partition::<Vec<_>, _>(|item| matches!(item.cost[0], Some((_, ShopCurrency::Coins))));
 
-    let mut embed = CreateEmbed::new()
-        .field(
-            "Currencies",
-            format!(
-                "<:coin:{COIN}> {} coins\n{GEM} {} gems",
-                row.coins_str(),
-                row.gems_str()
+    let item_list = |items: Vec<InventoryItem>| {
+        items
+            .into_iter()
+            .map(|item| format!("{} `{}` {}", item.emoji, item.quantity, item.name))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    let mut pages = vec![
+        CreateEmbed::new()
+            .field(
+                t!(locale, "gambling.inventory.currencies"),
+                format!(
+                    "<:coin:{COIN}> {}\n{GEM} {}",
+                    t!(locale, "gambling.inventory.coins", amount = row.coins_str()),
+                    t!(locale, "gambling.inventory.gems", amount = row.gems_str())
+                ),
+                false,
+            )
+            .field(
+                t!(locale, "gambling.inventory.resources"),
+                row.resources(),
+                true,
+            )
+            .field(
+                t!(locale, "gambling.inventory.crafted"),
+                row.crafted(),
+                true,
+            )
+            .field(
+                t!(locale, "gambling.inventory.weapons"),
+                t!(
+                    locale,
+                    "gambling.inventory.fists",
+                    user = interaction.user.mention()
+                ),
+                false,
             ),
+        CreateEmbed::new().field(
+            t!(locale, "gambling.inventory.items"),
+            item_list(items),
             false,
-        )
-        .field(
-            "Items",
-            items
-                .into_iter()
-                .map(|item| format!("{} `{}` {}", item.emoji, item.quantity, item.name))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            true,
-        )
-        .field(
-            "Boosts",
-            boosts
-                .into_iter()
-                .map(|item| format!("{} `{}` {}", item.emoji, item.quantity, item.name))
-                .collect::<Vec<_>>()
-                .join("\n"),
-            true,
-        )
-        .field("Resources", row.resources(), true)
-        .field("Crafted", row.crafted(), false)
-        .field(
-            "Weapons",
-            format!(
-                "{} is fighting with just their fists 👊",
-                interaction.user.mention()
-            ),
+        ),
+        CreateEmbed::new().field(
+            t!(locale, "gambling.inventory.boosts"),
+            item_list(boosts),
             false,
-        );
+        ),
+    ];
 
     if let Some(avatar) = interaction.user.avatar_url() {
-        embed = embed.thumbnail(avatar);
-    }
-
-    interaction
-        .edit_response(ctx, EditInteractionResponse::new().embed(embed))
-        .await
-        .unwrap();
-
-    Ok(())
+        pages = pages
+            .into_iter()
+            .map(|page| page.thumbnail(&avatar))
+            .collect();
+    }
+
+    let pages = &pages;
+    Paginator::new(
+        pages.len(),
+        |page| async move { Ok(pages[page - 1].clone()) },
+    )
+    .run(ctx, interaction)
+    .await
 }
 
 async fn use_item<
@@ -342,6 +363,8 @@ async fn use_item<
     options: Vec<ResolvedOption<'_>>,
     pool: &Pool<Db>,
 ) -> Result<()> {
+    let economy = Economies::get(ctx, interaction.guild_id).await;
+
     let mut options = parse_options(options);
 
     let Some(ResolvedValue::String(item_id)) = options.remove("item") else {
@@ -365,26 +388,33 @@ async fn use_item<
 
     let mut tx = pool.begin().await.unwrap();
 
-    let quantity =
-        match InventoryHandler::edit_item_quantity(&mut *tx, interaction.user.id, item_id, amount)
-            .await
-        {
-            Ok(q) => q,
-            Err(sqlx::Error::RowNotFound) => return Err(Error::InvalidAmount),
-            r => r?,
-        };
+    let quantity = match InventoryHandler::edit_item_quantity(
+        &mut *tx,
+        economy,
+        interaction.user.id,
+        item_id,
+        amount,
+    )
+    .await
+    {
+        Ok(q) => q,
+        Err(sqlx::Error::RowNotFound) => return Err(Error::InvalidAmount),
+        r => r?,
+    };
 
     for _ in 0..amount {
-        EffectsHandler::add_effect(&mut *tx, interaction.user.id, item)
+        EffectsHandler::add_effect(&mut *tx, economy, interaction.user.id, item)
             .await
             .unwrap();
     }
 
     tx.commit().await.unwrap();
 
-    let embed = CreateEmbed::new().description(format!(
-        "Successfully activated item:\n**{item}**\nUses left:{}",
-        quantity
+    let embed = CreateEmbed::new().description(t!(
+        i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+        "gambling.inventory.activated",
+        item = item,
+        uses = quantity
     ));
 
     interaction
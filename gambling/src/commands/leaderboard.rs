@@ -1,18 +1,13 @@
-use std::time::Duration;
-
 use async_trait::async_trait;
-use futures::StreamExt;
 use serenity::all::{
-    Colour, CommandInteraction, CommandOptionType, ComponentInteraction, Context, CreateButton,
-    CreateCommand, CreateCommandOption, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
-    CreateInteractionResponseMessage, EditInteractionResponse, Mentionable, Message,
-    ResolvedOption, ResolvedValue, UserId,
+    Colour, CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, Mentionable, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool, prelude::FromRow};
-use zayden_core::{FormatNum, cache::GuildMembersCache};
+use zayden_core::{FormatNum, Paginator, cache::GuildMembersCache, i18n, t};
 
 use crate::shop::{EGGPLANT, LOTTO_TICKET};
-use crate::{Coins, Gems, Result};
+use crate::{Coins, Economies, Economy, Gems, Result};
 
 use super::Commands;
 
@@ -20,52 +15,65 @@ use super::Commands;
 pub trait LeaderboardManager<Db: Database> {
     async fn networth(
         pool: &Pool<Db>,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>>;
 
     async fn networth_row_number(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>>;
 
     async fn coins(
         pool: &Pool<Db>,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>>;
 
     async fn coins_row_number(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>>;
 
     async fn gems(
         pool: &Pool<Db>,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>>;
 
     async fn gems_row_number(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>>;
 
     async fn eggplants(
         pool: &Pool<Db>,
+        economy: Economy,
         users: &[i64],
         page_num: i64,
     ) -> sqlx::Result<Vec<LeaderboardRow>>;
 
     async fn eggplants_row_number(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>>;
 
-    async fn lottotickets(pool: &Pool<Db>, page_num: i64) -> sqlx::Result<Vec<LeaderboardRow>>;
+    async fn lottotickets(
+        pool: &Pool<Db>,
+        economy: Economy,
+        page_num: i64,
+    ) -> sqlx::Result<Vec<LeaderboardRow>>;
 
     async fn lottotickets_row_number(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<i64>>;
 }
@@ -129,6 +137,8 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let ResolvedValue::String(leaderboard) = options.pop().unwrap().value else {
             unreachable!("leaderboard option is required")
         };
@@ -144,54 +154,38 @@ impl Commands {
                 .collect::<Vec<_>>()
         };
 
-        let rows = get_rows::<Db, Manager>(leaderboard, pool, &users, 1).await;
-
-        let desc = rows
-            .into_iter()
-            .enumerate()
-            .map(|(i, row)| row.as_desc(i))
-            .collect::<Vec<_>>()
-            .join("\n\n");
-
-        let embed = CreateEmbed::new()
-            .title(format!("🏁 Leaderboard ({leaderboard})"))
-            .description(desc)
-            .footer(CreateEmbedFooter::new("Page 1"))
-            .colour(Colour::TEAL);
-
-        let mut response = EditInteractionResponse::new()
-            .embed(embed)
-            .button(CreateButton::new("leaderboard_previous").label("<"));
-
-        if get_row_number::<Db, Manager>(leaderboard, pool, interaction.user.id)
-            .await
-            .is_some()
-        {
-            response = response.button(CreateButton::new("leaderboard_user").emoji('🎯'));
-        }
-
-        let msg = interaction
-            .edit_response(
-                ctx,
-                response.button(CreateButton::new("leaderboard_next").label(">")),
-            )
-            .await
-            .unwrap();
-
-        let mut stream = msg
-            .await_component_interactions(ctx)
-            .timeout(Duration::from_secs(120))
-            .stream();
-
-        while let Some(component) = stream.next().await {
-            run_component::<Db, Manager>(ctx, pool, &users, &msg, component).await?;
-        }
-
-        interaction
-            .edit_response(ctx, EditInteractionResponse::new().components(Vec::new()))
-            .await?;
-
-        Ok(())
+        let user_page =
+            get_row_number::<Db, Manager>(leaderboard, pool, economy, interaction.user.id)
+                .await
+                .map(|row_number| (row_number as usize).div_ceil(10));
+
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+        let title = t!(
+            locale,
+            "gambling.leaderboard.title",
+            board = t!(locale, &format!("gambling.leaderboard.{leaderboard}"))
+        );
+
+        let (users, title) = (&users, &title);
+        Paginator::new(users.len().div_ceil(10), |page| async move {
+            let rows =
+                get_rows::<Db, Manager>(leaderboard, pool, economy, users, page as i64).await;
+
+            let desc = rows
+                .into_iter()
+                .enumerate()
+                .map(|(i, row)| row.as_desc(i + (page - 1) * 10))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+
+            Ok(CreateEmbed::new()
+                .title(title)
+                .description(desc)
+                .colour(Colour::TEAL))
+        })
+        .user_page(user_page)
+        .run(ctx, interaction)
+        .await
     }
 
     pub fn register_leaderboard() -> CreateCommand {
@@ -213,82 +207,6 @@ impl Commands {
     }
 }
 
-async fn run_component<Db: Database, Manager: LeaderboardManager<Db>>(
-    ctx: &Context,
-    pool: &Pool<Db>,
-    users: &[i64],
-    msg: &Message,
-    interaction: ComponentInteraction,
-) -> Result<()> {
-    let custom_id = interaction
-        .data
-        .custom_id
-        .strip_prefix("leaderboard_")
-        .unwrap();
-
-    let embed = msg.embeds.first().unwrap();
-
-    let leaderboard = embed
-        .title
-        .as_ref()
-        .unwrap()
-        .strip_prefix("🏁 Leaderboard (")
-        .unwrap()
-        .strip_suffix(")")
-        .unwrap();
-
-    let mut page_number: i64 = embed
-        .footer
-        .as_ref()
-        .unwrap()
-        .text
-        .strip_prefix("Page ")
-        .unwrap()
-        .parse()
-        .unwrap();
-
-    match custom_id {
-        "previous" => {
-            page_number = (page_number - 1).max(1);
-        }
-        "user" => {
-            let row_num = get_row_number::<Db, Manager>(leaderboard, pool, interaction.user.id)
-                .await
-                .unwrap();
-            page_number = row_num / 10 + 1;
-        }
-        "next" => {
-            page_number += 1;
-        }
-        _ => unreachable!("Invalid custom id"),
-    };
-
-    let rows = get_rows::<Db, Manager>(leaderboard, pool, users, page_number).await;
-
-    let desc = rows
-        .into_iter()
-        .enumerate()
-        .map(|(i, row)| row.as_desc(i + (page_number as usize - 1) * 10))
-        .collect::<Vec<_>>()
-        .join("\n\n");
-
-    let embed = CreateEmbed::from(embed.clone())
-        .footer(CreateEmbedFooter::new(format!("Page {}", page_number)))
-        .description(desc);
-
-    interaction
-        .create_response(
-            ctx,
-            CreateInteractionResponse::UpdateMessage(
-                CreateInteractionResponseMessage::new().embed(embed),
-            ),
-        )
-        .await
-        .unwrap();
-
-    Ok(())
-}
-
 pub enum LeaderboardRow {
     NetWorth(NetworthRow),
     Coins(CoinsRow),
@@ -336,15 +254,24 @@ impl LeaderboardRow {
 async fn get_rows<Db: Database, Manager: LeaderboardManager<Db>>(
     leaderboard: &str,
     pool: &Pool<Db>,
+    economy: Economy,
     users: &[i64],
     page_num: i64,
 ) -> Vec<LeaderboardRow> {
     match leaderboard {
-        "networth" => Manager::networth(pool, users, page_num).await.unwrap(),
-        "coins" => Manager::coins(pool, users, page_num).await.unwrap(),
-        "gems" => Manager::gems(pool, users, page_num).await.unwrap(),
-        "eggplants" => Manager::eggplants(pool, users, page_num).await.unwrap(),
-        "lottotickets" => Manager::lottotickets(pool, page_num).await.unwrap(),
+        "networth" => Manager::networth(pool, economy, users, page_num)
+            .await
+            .unwrap(),
+        "coins" => Manager::coins(pool, economy, users, page_num)
+            .await
+            .unwrap(),
+        "gems" => Manager::gems(pool, economy, users, page_num).await.unwrap(),
+        "eggplants" => Manager::eggplants(pool, economy, users, page_num)
+            .await
+            .unwrap(),
+        "lottotickets" => Manager::lottotickets(pool, economy, page_num)
+            .await
+            .unwrap(),
         _ => unreachable!("Invalid leaderboard option"),
     }
 }
@@ -352,16 +279,23 @@ async fn get_rows<Db: Database, Manager: LeaderboardManager<Db>>(
 async fn get_row_number<Db: Database, Manager: LeaderboardManager<Db>>(
     leaderboard: &str,
     pool: &Pool<Db>,
+    economy: Economy,
     user: UserId,
 ) -> Option<i64> {
     match leaderboard {
-        "coins" => Manager::coins_row_number(pool, user).await.ok().flatten(),
-        "gems" => Manager::gems_row_number(pool, user).await.ok().flatten(),
-        "eggplants" => Manager::eggplants_row_number(pool, user)
+        "coins" => Manager::coins_row_number(pool, economy, user)
+            .await
+            .ok()
+            .flatten(),
+        "gems" => Manager::gems_row_number(pool, economy, user)
+            .await
+            .ok()
+            .flatten(),
+        "eggplants" => Manager::eggplants_row_number(pool, economy, user)
             .await
             .ok()
             .flatten(),
-        "lottotickets" => Manager::lottotickets_row_number(pool, user)
+        "lottotickets" => Manager::lottotickets_row_number(pool, economy, user)
             .await
             .ok()
             .flatten(),
@@ -1,11 +1,10 @@
-use serenity::all::{
-    CommandInteraction, Context, CreateCommand, CreateEmbed, EditInteractionResponse,
-};
+use serenity::all::{CommandInteraction, Context, CreateCommand, EditInteractionResponse};
 use sqlx::{Database, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, i18n, t};
 
+use crate::lotto::lotto_embed;
 use crate::shop::LOTTO_TICKET;
-use crate::{COIN, Commands, Lotto, LottoManager, LottoRow, Result, jackpot};
+use crate::{Commands, Economies, Lotto, LottoManager, LottoRow, Result, jackpot};
 
 impl Commands {
     pub async fn lotto<Db: Database, Manager: LottoManager<Db>>(
@@ -15,44 +14,29 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let mut tx = pool.begin().await.unwrap();
 
-        let total_tickets = Manager::total_tickets(&mut tx).await.unwrap();
+        let total_tickets = Manager::total_tickets(&mut tx, economy).await.unwrap();
 
-        let row = match Manager::row(&mut tx, interaction.user.id).await.unwrap() {
+        let row = match Manager::row(&mut tx, economy, interaction.user.id)
+            .await
+            .unwrap()
+        {
             Some(row) => row,
             None => LottoRow::new(interaction.user.id),
         };
 
-        let lotto_emoji = LOTTO_TICKET.emoji();
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
 
-        let timestamp = {
-            Lotto::cron_job::<Db, Manager>()
-                .schedule
-                .upcoming(chrono::Utc)
-                .next()
-                .unwrap_or_default()
-                .timestamp()
-        };
+        let timestamp = Lotto::next_draw::<Db>();
 
-        let embed = CreateEmbed::new()
-            .title(format!(
-                "<:coin:{COIN}> <:coin:{COIN}> Lottery!! <:coin:{COIN}> <:coin:{COIN}>"
-            ))
-            .description(format!("Draws are at <t:{timestamp}:F>"))
-            .field(
-                "Tickets Bought",
-                format!("{} {lotto_emoji}", total_tickets.format()),
-                false,
-            )
-            .field(
-                "Jackpot Value",
-                format!("{} <:coin:{COIN}>", jackpot(total_tickets).format()),
-                false,
-            )
+        let embed = lotto_embed(locale, total_tickets, jackpot(total_tickets))
+            .description(t!(locale, "gambling.lotto.draws_at", timestamp = timestamp))
             .field(
-                "Your Tickets",
-                format!("{} {lotto_emoji}", row.quantity().format()),
+                t!(locale, "gambling.lotto.your_tickets"),
+                format!("{} {}", row.quantity().format(), LOTTO_TICKET.emoji()),
                 false,
             );
 
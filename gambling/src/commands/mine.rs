@@ -3,13 +3,17 @@ use serenity::all::{
     CommandInteraction, Context, CreateCommand, CreateEmbed, EditInteractionResponse, UserId,
 };
 use sqlx::{Database, FromRow, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, i18n, t};
 
-use crate::{COIN, MaxValues, MineHourly, Mining, Prestige, Result};
+use crate::{COIN, Economies, Economy, MaxValues, MineHourly, Mining, Prestige, Result};
 
 #[async_trait]
 pub trait MineManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<MineRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<MineRow>>;
 }
 
 #[derive(Default, FromRow)]
@@ -126,18 +130,26 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await?;
 
-        let row = Manager::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let row = Manager::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_default();
 
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
         let embed = CreateEmbed::new()
             .field(
-                "Mine Income",
-                format!("{} <:coin:{COIN}> / hour", row.hourly().format()),
+                t!(locale, "gambling.mine.income"),
+                t!(
+                    locale,
+                    "gambling.mine.per_hour",
+                    coins = format!("{} <:coin:{COIN}>", row.hourly().format())
+                ),
                 false,
             )
-            .field("Units", row.units(), false);
+            .field(t!(locale, "gambling.mine.units"), row.units(), false);
 
         interaction
             .edit_response(ctx, EditInteractionResponse::new().embed(embed))
@@ -0,0 +1,476 @@
+use std::collections::HashMap;
+
+use rand::rng;
+use rand::seq::IteratorRandom;
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ButtonStyle, Colour, CommandInteraction, CommandOptionType, ComponentInteraction, Context,
+    CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
+    MessageId, ResolvedOption, ResolvedValue, UserId,
+};
+use serenity::prelude::TypeMapKey;
+use sqlx::{Database, Pool};
+use zayden_core::{Cooldowns, FormatNum, i18n, parse_options, t};
+
+use crate::events::{Dispatch, Event, GameEvent};
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::utils::coins_str;
+use crate::{
+    BLANK, Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN, GEM, GamblingManager,
+    GameManager, GameRow, Gems, GoalsManager, Ledger, LedgerManager, Result,
+};
+
+use super::{Commands, Components};
+
+/// The tiles on the board. A message holds at most 25 buttons, so the last
+/// spot on the 5x5 grid is taken by the cash out button.
+const TILES: u8 = 24;
+
+/// The share of a fair payout that a cash out pays, which leaves the house
+/// its edge.
+const RETURN: f64 = 0.97;
+
+const BOMB: char = '💣';
+
+impl Commands {
+    pub async fn mines<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer(ctx).await.unwrap();
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let mut options = parse_options(options);
+
+        let Some(ResolvedValue::Integer(bet)) = options.remove("bet") else {
+            unreachable!("bet is required")
+        };
+
+        let Some(ResolvedValue::Integer(bombs)) = options.remove("bombs") else {
+            unreachable!("bombs is required")
+        };
+
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
+            .await?
+            .unwrap_or_else(|| GameRow::new(interaction.user.id));
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let start_coins = row.coins();
+        row.bet(bet);
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GamblingHandler::bet(&mut *tx, economy, interaction.user.id, bet).await?;
+        Ledger::new(economy, interaction.user.id, "mines")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins())
+            .record::<Db, LedgerHandler>(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        let bombs = bombs.clamp(1, i64::from(TILES) - 1) as usize;
+
+        let game = Mines {
+            economy,
+            user: interaction.user.id,
+            bet,
+            bombs: Minefields::take(ctx, interaction.user.id, bombs).await,
+            revealed: Vec::new(),
+            locale: i18n::locale(&interaction.locale, interaction.guild_locale.as_deref())
+                .to_string(),
+        };
+
+        let msg = interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(game.playing_embed())
+                    .components(game.board(false)),
+            )
+            .await?;
+
+        SessionHandler::insert(pool, &SessionRow::new(msg.id, msg.channel_id, game)).await?;
+
+        Ok(())
+    }
+
+    pub fn register_mines() -> CreateCommand {
+        CreateCommand::new("mines")
+            .description("Reveal tiles for a growing payout, but don't hit a bomb")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "bet", "The amount to bet.")
+                    .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "bombs",
+                    "How many of the tiles hide a bomb.",
+                )
+                .required(true)
+                .min_int_value(1)
+                .max_int_value(u64::from(TILES - 1)),
+            )
+    }
+}
+
+impl Components {
+    pub async fn mines<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::Mines(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        if interaction.user.id != game.user {
+            return Err(Error::NotPlayer);
+        }
+
+        // Anything but a tile is the cash out button
+        let tile = interaction
+            .data
+            .custom_id
+            .strip_prefix("mines_")
+            .and_then(|tile| tile.parse::<u8>().ok());
+
+        let busted = match tile {
+            Some(tile) if game.revealed.contains(&tile) => {
+                return acknowledge(ctx, interaction).await;
+            }
+            Some(tile) if game.bombs.contains(&tile) => true,
+            Some(tile) => {
+                game.revealed.push(tile);
+
+                if !game.cleared() {
+                    let msg = CreateInteractionResponseMessage::new()
+                        .embed(game.playing_embed())
+                        .components(game.board(false));
+                    Sessions::update::<Db, SessionHandler>(pool, interaction, version, game)
+                        .await?;
+
+                    interaction
+                        .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                        .await?;
+
+                    return Ok(());
+                }
+
+                false
+            }
+            None => false,
+        };
+
+        Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
+
+        let board = game.board(true);
+        let embed = game
+            .settle::<Db, GoalsHandler, EffectsHandler, GameHandler, LedgerHandler>(
+                pool,
+                interaction.message.id,
+                busted,
+            )
+            .await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(board),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// A game of mines, with tiles numbered from 0 in reading order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Mines {
+    economy: Economy,
+    user: UserId,
+    bet: i64,
+    bombs: Vec<u8>,
+    revealed: Vec<u8>,
+    /// The player's locale, which the game is shown in. Games stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
+}
+
+impl Mines {
+    /// What the bet is multiplied by after `revealed` safe tiles. Each one
+    /// pays the odds of surviving it, less the house's edge.
+    fn multiplier(&self, revealed: usize) -> f64 {
+        if revealed == 0 {
+            return 1.0;
+        }
+
+        let tiles = f64::from(TILES);
+        let bombs = self.bombs.len() as f64;
+
+        let fair = (0..revealed)
+            .map(|i| (tiles - i as f64) / (tiles - bombs - i as f64))
+            .product::<f64>();
+
+        (fair * RETURN * 100.0).floor() / 100.0
+    }
+
+    /// Whether every safe tile has been revealed.
+    fn cleared(&self) -> bool {
+        self.revealed.len() + self.bombs.len() == usize::from(TILES)
+    }
+
+    /// Hands back the bet, as a game cut off by a restart is never played
+    /// out.
+    pub(crate) fn time_out(self) -> TimedOut {
+        TimedOut {
+            economy: self.economy,
+            source: "mines",
+            refunds: vec![(self.user, self.bet)],
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.mines.name"))
+                .description(t!(
+                    &self.locale,
+                    "gambling.interrupted",
+                    refund = coins_str(self.bet)
+                ))
+                .colour(Colour::TEAL),
+        }
+    }
+
+    /// Pays out the tiles revealed so far, or nothing after a bomb.
+    pub(crate) async fn settle<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        self,
+        pool: &Pool<Db>,
+        message_id: MessageId,
+        busted: bool,
+    ) -> Result<CreateEmbed> {
+        let economy = self.economy;
+
+        let multiplier = if busted {
+            0.0
+        } else {
+            self.multiplier(self.revealed.len())
+        };
+        let payout = (self.bet as f64 * multiplier) as i64;
+
+        let win = match payout.cmp(&self.bet) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Less => Some(false),
+        };
+
+        let mut row = GameHandler::row(pool, economy, self.user)
+            .await?
+            .unwrap_or_else(|| GameRow::new(self.user));
+        let (start_coins, start_gems) = (row.coins(), row.gems());
+
+        let mut event = GameEvent::new("mines", self.user, self.bet, win == Some(true));
+        if !busted {
+            event = event.multiplier(multiplier);
+        }
+
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
+            .fire(&mut row, Event::Game(event))
+            .await?;
+
+        let payout = EffectsHandler::payout(pool, economy, self.user, self.bet, payout, win).await;
+
+        row.add_coins(payout);
+
+        let coins = row.coins();
+        let ledger = Ledger::new(economy, self.user, "mines")
+            .reference(message_id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+
+        let locale = self.locale.as_str();
+
+        let result = if busted {
+            t!(locale, "gambling.mines.busted")
+        } else {
+            t!(
+                locale,
+                "gambling.mines.cashed_out",
+                multiplier = format!("{multiplier:.2}"),
+                tiles = self.revealed.len()
+            )
+        };
+
+        let (title, colour) = match win {
+            Some(true) => ("gambling.result.won", Colour::DARK_GREEN),
+            Some(false) => ("gambling.result.lost", Colour::RED),
+            None => ("gambling.result.draw", Colour::DARKER_GREY),
+        };
+
+        Ok(CreateEmbed::new()
+            .title(t!(locale, title, game = t!(locale, "gambling.mines.name")))
+            .description(format!(
+                "{}\n{}\n\n{result}\n\n{}\n{}",
+                t!(locale, "gambling.your_bet", bet = coins_str(self.bet)),
+                t!(locale, "gambling.mines.bombs", bombs = self.bombs.len()),
+                t!(
+                    locale,
+                    "gambling.payout",
+                    payout = payout.format(),
+                    profit = (payout - self.bet).format()
+                ),
+                t!(locale, "gambling.your_coins", coins = coins.format()),
+            ))
+            .colour(colour))
+    }
+
+    fn playing_embed(&self) -> CreateEmbed {
+        let revealed = self.revealed.len();
+        let multiplier = self.multiplier(revealed);
+
+        let locale = self.locale.as_str();
+
+        CreateEmbed::new()
+            .title(t!(locale, "gambling.mines.name"))
+            .description(format!(
+                "{}\n{}\n\n{}\n{}\n{}",
+                t!(locale, "gambling.your_bet", bet = coins_str(self.bet)),
+                t!(locale, "gambling.mines.bombs", bombs = self.bombs.len()),
+                t!(
+                    locale,
+                    "gambling.mines.multiplier",
+                    multiplier = format!("{multiplier:.2}")
+                ),
+                t!(
+                    locale,
+                    "gambling.mines.cash_out_value",
+                    coins = coins_str((self.bet as f64 * multiplier) as i64)
+                ),
+                t!(
+                    locale,
+                    "gambling.mines.next_tile",
+                    multiplier = format!("{:.2}", self.multiplier(revealed + 1))
+                ),
+            ))
+            .colour(Colour::TEAL)
+    }
+
+    /// The 5x5 grid of tiles and the cash out button, with the bombs shown
+    /// once the game is `finished`.
+    pub(crate) fn board(&self, finished: bool) -> Vec<CreateActionRow> {
+        let mut buttons = (0..TILES)
+            .map(|tile| {
+                let button = CreateButton::new(format!("mines_{tile}"));
+
+                if self.revealed.contains(&tile) {
+                    button.emoji(GEM).style(ButtonStyle::Success).disabled(true)
+                } else if finished && self.bombs.contains(&tile) {
+                    button.emoji(BOMB).style(ButtonStyle::Danger).disabled(true)
+                } else {
+                    button
+                        .emoji(BLANK.id())
+                        .style(ButtonStyle::Secondary)
+                        .disabled(finished)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        buttons.push(
+            CreateButton::new("mines_cash_out")
+                .label(t!(&self.locale, "gambling.mines.cash_out"))
+                .style(ButtonStyle::Primary)
+                .disabled(finished),
+        );
+
+        buttons
+            .chunks(5)
+            .map(|row| CreateActionRow::Buttons(row.to_vec()))
+            .collect()
+    }
+}
+
+impl From<Mines> for Session {
+    fn from(game: Mines) -> Self {
+        Self::Mines(game)
+    }
+}
+
+/// Boards laid out ahead of a player's next game, so that it can be played
+/// on known tiles. Games are dealt a random board when none is waiting.
+#[derive(Debug, Default)]
+pub struct Minefields(HashMap<UserId, Vec<u8>>);
+
+impl Minefields {
+    /// The board waiting for the player, or `bombs` random tiles.
+    async fn take(ctx: &Context, user: UserId, bombs: usize) -> Vec<u8> {
+        let mut data = ctx.data.write().await;
+        let fields = data.entry::<Self>().or_insert_with(Self::default);
+
+        fields
+            .0
+            .remove(&user)
+            .unwrap_or_else(|| (0..TILES).choose_multiple(&mut rng(), bombs))
+    }
+
+    /// Lays out the bombs of the player's next game, whatever number of them
+    /// they ask for.
+    pub async fn put(ctx: &Context, user: UserId, bombs: Vec<u8>) {
+        let mut data = ctx.data.write().await;
+        let fields = data.entry::<Self>().or_insert_with(Self::default);
+
+        fields.0.insert(user, bombs);
+    }
+}
+
+impl TypeMapKey for Minefields {
+    type Value = Minefields;
+}
+
+/// Answers a click that doesn't change the game.
+async fn acknowledge(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use std::cmp::Reverse;
+
+use zayden_core::t;
+
+/// The kinds of poker hand, weakest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Category {
+    HighCard,
+    Pair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+impl Category {
+    pub fn name(self, locale: &str) -> String {
+        let key = match self {
+            Self::HighCard => "gambling.poker.high_card",
+            Self::Pair => "gambling.poker.pair",
+            Self::TwoPair => "gambling.poker.two_pair",
+            Self::ThreeOfAKind => "gambling.poker.three_of_a_kind",
+            Self::Straight => "gambling.poker.straight",
+            Self::Flush => "gambling.poker.flush",
+            Self::FullHouse => "gambling.poker.full_house",
+            Self::FourOfAKind => "gambling.poker.four_of_a_kind",
+            Self::StraightFlush => "gambling.poker.straight_flush",
+        };
+
+        t!(locale, key)
+    }
+}
+
+/// The value of the best five cards a player can make. Hands compare by
+/// category and then by the ranks that break ties, highest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandRank {
+    pub category: Category,
+    ranks: [u8; 5],
+}
+
+/// The rank of a card from 2 to 14, with aces high.
+fn rank(card: u8) -> u8 {
+    match card % 13 {
+        0 => 14,
+        rank => rank + 1,
+    }
+}
+
+fn suit(card: u8) -> u8 {
+    card / 13
+}
+
+/// The best hand out of a player's hole cards and the board.
+pub fn best(cards: &[u8]) -> HandRank {
+    let n = cards.len();
+    let mut best = None;
+
+    for skip_a in 0..n {
+        for skip_b in skip_a + 1..n {
+            let five = (0..n)
+                .filter(|i| *i != skip_a && *i != skip_b)
+                .map(|i| cards[i])
+                .collect::<Vec<_>>();
+
+            let hand = evaluate(&five);
+            if best.is_none_or(|best| hand > best) {
+                best = Some(hand);
+            }
+        }
+    }
+
+    best.unwrap_or_else(|| evaluate(cards))
+}
+
+fn evaluate(cards: &[u8]) -> HandRank {
+    // Ranks grouped by how often they appear, then by rank
+    let mut groups = Vec::<(u8, u8)>::new();
+    for rank in cards.iter().map(|card| rank(*card)) {
+        match groups.iter_mut().find(|(r, _)| *r == rank) {
+            Some((_, count)) => *count += 1,
+            None => groups.push((rank, 1)),
+        }
+    }
+    groups.sort_by_key(|(rank, count)| Reverse((*count, *rank)));
+
+    let mut ranks = [0; 5];
+    let mut i = 0;
+    for (rank, count) in &groups {
+        for _ in 0..*count {
+            ranks[i] = *rank;
+            i += 1;
+        }
+    }
+
+    let flush = cards.iter().all(|card| suit(*card) == suit(cards[0]));
+    let straight_high = match groups.len() {
+        5 if ranks[0] - ranks[4] == 4 => Some(ranks[0]),
+        // The wheel, A-2-3-4-5, where the ace plays low
+        5 if ranks == [14, 5, 4, 3, 2] => Some(5),
+        _ => None,
+    };
+
+    let category = match (straight_high, flush, groups[0].1, groups[1].1) {
+        (Some(_), true, _, _) => Category::StraightFlush,
+        (_, _, 4, _) => Category::FourOfAKind,
+        (_, _, 3, 2) => Category::FullHouse,
+        (_, true, _, _) => Category::Flush,
+        (Some(_), _, _, _) => Category::Straight,
+        (_, _, 3, _) => Category::ThreeOfAKind,
+        (_, _, 2, 2) => Category::TwoPair,
+        (_, _, 2, _) => Category::Pair,
+        _ => Category::HighCard,
+    };
+
+    if let Some(high) = straight_high {
+        ranks = [high, high - 1, high - 2, high - 3, high - 4];
+    }
+
+    HandRank { category, ranks }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// Cards by rank and suit, such as `"Ah Td 2c"`.
+    pub(crate) fn cards(names: &str) -> Vec<u8> {
+        names
+            .split_whitespace()
+            .map(|name| {
+                let mut chars = name.chars();
+                let rank = match chars.next().unwrap() {
+                    'A' => 0,
+                    'T' => 9,
+                    'J' => 10,
+                    'Q' => 11,
+                    'K' => 12,
+                    n => n.to_digit(10).unwrap() as u8 - 1,
+                };
+                let suit = "cdhs".find(chars.next().unwrap()).unwrap() as u8;
+
+                suit * 13 + rank
+            })
+            .collect()
+    }
+
+    #[test]
+    fn wheel_is_the_lowest_straight() {
+        let wheel = evaluate(&cards("Ah 2d 3c 4s 5h"));
+
+        assert_eq!(wheel.category, Category::Straight);
+        assert!(wheel < evaluate(&cards("2d 3c 4s 5h 6h")));
+        assert!(wheel > evaluate(&cards("Ah Ad Kc Ks Qh")));
+    }
+
+    #[test]
+    fn kickers_break_ties() {
+        let ace_kicker = best(&cards("Kh Kd Ac 7s 5h 3d 2c"));
+        let queen_kicker = best(&cards("Kh Kd Qc 7s 5h 3d 2c"));
+
+        assert_eq!(ace_kicker.category, Category::Pair);
+        assert!(ace_kicker > queen_kicker);
+
+        // Only the best three kickers play
+        assert_eq!(
+            best(&cards("Kh Kd Ac Qs Jh 3d 2c")),
+            best(&cards("Kh Kd Ac Qs Jh 4d 2c"))
+        );
+    }
+
+    #[test]
+    fn flush_beats_straight() {
+        let flush = evaluate(&cards("2h 5h 7h 9h Jh"));
+        let straight = evaluate(&cards("9c Td Jh Qs Kc"));
+
+        assert_eq!(flush.category, Category::Flush);
+        assert_eq!(straight.category, Category::Straight);
+        assert!(flush > straight);
+    }
+
+    #[test]
+    fn best_plays_the_strongest_five_cards() {
+        // The board's straight loses to the flush made with the hole cards
+        let hand = best(&cards("2h 3h 7c 8d 9h Th Jh"));
+
+        assert_eq!(hand.category, Category::Flush);
+        assert_eq!(hand.ranks, [11, 10, 9, 3, 2]);
+    }
+}
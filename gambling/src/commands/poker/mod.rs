@@ -0,0 +1,618 @@
+use serenity::all::{
+    ButtonStyle, ChannelId, Colour, CommandInteraction, CommandOptionType, ComponentInteraction,
+    Context, CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
+    EditMessage, Http, MessageId, ResolvedOption, ResolvedValue, UserId,
+};
+use sqlx::{Database, Pool};
+use zayden_core::{Cooldowns, FormatNum, i18n, parse_options, t};
+
+use crate::events::{Dispatch, Event, GameEvent};
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::{
+    Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN, GamblingManager, GameManager,
+    GameRow, Gems, GoalsManager, Ledger, LedgerManager, Result, ShopCurrency,
+};
+
+use super::{Commands, Components};
+
+mod hand;
+mod table;
+
+pub use table::Poker;
+use table::{Action, Outcome, cards};
+
+impl Commands {
+    pub async fn poker<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer(ctx).await.unwrap();
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::guild_locale(interaction.guild_locale.as_deref());
+
+        let mut options = parse_options(options);
+
+        let Some(ResolvedValue::Integer(max_buy_in)) = options.remove("buy_in") else {
+            unreachable!("buy_in is required")
+        };
+
+        let min_buy_in = match options.remove("min_buy_in") {
+            Some(ResolvedValue::Integer(min)) => min,
+            _ => max_buy_in / 2,
+        };
+
+        let big_blind = match options.remove("big_blind") {
+            Some(ResolvedValue::Integer(big_blind)) => big_blind,
+            _ => (max_buy_in / 100).max(2),
+        };
+
+        let seats = match options.remove("seats") {
+            Some(ResolvedValue::Integer(seats)) => seats as usize,
+            _ => 8,
+        };
+
+        if big_blind < 2 || big_blind > min_buy_in || min_buy_in > max_buy_in {
+            return Err(Error::InvalidAmount);
+        }
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+
+        let mut game = Poker::new(
+            economy,
+            locale,
+            interaction.user.id,
+            (min_buy_in, max_buy_in),
+            big_blind,
+            seats,
+        );
+
+        let (coins, stack) = buy_in::<Db, GamblingHandler, EffectsHandler, GameHandler>(
+            pool,
+            &game,
+            interaction.user.id,
+        )
+        .await?;
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GamblingHandler::bet(&mut *tx, economy, interaction.user.id, stack).await?;
+        Ledger::new(economy, interaction.user.id, "poker")
+            .reference(interaction.id)
+            .coins(coins, coins - stack)
+            .record::<Db, LedgerHandler>(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        game.sit(interaction.user.id, stack);
+
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        let msg = interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(embed(&game))
+                    .components(components(&game)),
+            )
+            .await?;
+
+        SessionHandler::insert(pool, &SessionRow::new(msg.id, msg.channel_id, game)).await?;
+
+        Ok(())
+    }
+
+    pub fn register_poker() -> CreateCommand {
+        CreateCommand::new("poker")
+            .description("Host a table of Texas Hold'em")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "buy_in",
+                    "The most chips a player can buy in for.",
+                )
+                .min_int_value(2)
+                .required(true),
+            )
+            .add_option(CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "min_buy_in",
+                "The fewest chips a player can buy in for. Defaults to half the buy-in.",
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "big_blind",
+                    "The big blind. Defaults to a hundredth of the buy-in.",
+                )
+                .min_int_value(2),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "seats",
+                    "The number of seats at the table. Defaults to 8.",
+                )
+                .min_int_value(2)
+                .max_int_value(8),
+            )
+    }
+}
+
+impl Components {
+    pub async fn poker<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::Poker(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        let economy = game.economy;
+        let user = interaction.user.id;
+        let seat = game.seat(user);
+
+        let outcomes = match interaction.data.custom_id.as_str() {
+            "poker_join" => {
+                if seat.is_some() || game.hand.is_some() {
+                    return acknowledge(ctx, interaction).await;
+                }
+
+                if game.seats.len() >= game.max_seats {
+                    return Err(Error::TableFull);
+                }
+
+                let (coins, stack) =
+                    buy_in::<Db, GamblingHandler, EffectsHandler, GameHandler>(pool, &game, user)
+                        .await?;
+
+                let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+                GamblingHandler::bet(&mut *tx, economy, user, stack).await?;
+                Ledger::new(economy, user, "poker")
+                    .reference(interaction.message.id)
+                    .coins(coins, coins - stack)
+                    .record::<Db, LedgerHandler>(&mut *tx)
+                    .await?;
+                tx.commit().await?;
+
+                game.sit(user, stack);
+
+                let msg = message(&game);
+
+                // Someone else joined or acted first, so the seat was never
+                // taken
+                if let Err(e) =
+                    Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await
+                {
+                    cash_out::<Db, GameHandler, LedgerHandler>(
+                        pool,
+                        economy,
+                        interaction.message.id,
+                        user,
+                        stack,
+                    )
+                    .await?;
+                    return Err(e);
+                }
+
+                interaction
+                    .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                    .await?;
+
+                return Ok(());
+            }
+            "poker_leave" => {
+                let Some(index) = seat else {
+                    return Err(Error::NotPlayer);
+                };
+
+                if game.hand.is_some() {
+                    return acknowledge(ctx, interaction).await;
+                }
+
+                let stack = game.stand(index);
+
+                let msg = if game.seats.is_empty() {
+                    Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
+
+                    CreateInteractionResponseMessage::new()
+                        .embed(closed_embed(
+                            &game.locale,
+                            t!(&game.locale, "gambling.poker.everyone_left"),
+                        ))
+                        .components(Vec::new())
+                } else {
+                    let msg = message(&game);
+                    Sessions::update::<Db, SessionHandler>(pool, interaction, version, game)
+                        .await?;
+                    msg
+                };
+
+                cash_out::<Db, GameHandler, LedgerHandler>(
+                    pool,
+                    economy,
+                    interaction.message.id,
+                    user,
+                    stack,
+                )
+                .await?;
+
+                interaction
+                    .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                    .await?;
+
+                return Ok(());
+            }
+            "poker_cards" => {
+                let Some(index) = seat else {
+                    return Err(Error::NotPlayer);
+                };
+
+                return reveal(ctx, interaction, &game, index).await;
+            }
+            "poker_deal" => {
+                if user != game.host || game.hand.is_some() || game.seats.len() < 2 {
+                    return acknowledge(ctx, interaction).await;
+                }
+
+                game.deal()
+            }
+            custom_id => {
+                let Some(index) = seat else {
+                    return Err(Error::NotPlayer);
+                };
+
+                if game.hand.as_ref().is_none_or(|hand| hand.turn != index) {
+                    return acknowledge(ctx, interaction).await;
+                }
+
+                let action = match custom_id {
+                    "poker_fold" => Action::Fold,
+                    "poker_call" => Action::Call,
+                    "poker_raise" => Action::Raise,
+                    "poker_allin" => Action::AllIn,
+                    _ => unreachable!("Invalid custom id"),
+                };
+
+                game.act(action)
+            }
+        };
+
+        let msg = message(&game);
+        Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
+
+        if let Some(outcomes) = outcomes {
+            fire_goals::<Db, GoalsHandler, GameHandler, LedgerHandler>(
+                pool,
+                economy,
+                interaction.message.id,
+                outcomes,
+            )
+            .await?;
+        }
+
+        interaction
+            .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Poker {
+    /// Checks for the player whose turn it is, or folds their hand if they
+    /// have a bet to call, so one idle player doesn't hold up the table.
+    pub(crate) async fn skip_turn<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db>,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        mut self,
+        http: &Http,
+        pool: &Pool<Db>,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        version: i64,
+    ) -> Result<()> {
+        let economy = self.economy;
+        let turn = self.hand.as_ref().unwrap().turn;
+
+        let action = match self.to_call(turn) {
+            0 => Action::Call,
+            _ => Action::Fold,
+        };
+        let outcomes = self.act(action);
+
+        let edit = EditMessage::new()
+            .embed(embed(&self))
+            .components(components(&self));
+
+        let mut row = SessionRow::new(message_id, channel_id, self);
+        row.version = version;
+
+        if !SessionHandler::update(&mut *pool.acquire().await?, &row).await? {
+            return Ok(());
+        }
+
+        if let Some(outcomes) = outcomes {
+            fire_goals::<Db, GoalsHandler, GameHandler, LedgerHandler>(
+                pool, economy, message_id, outcomes,
+            )
+            .await?;
+        }
+
+        // The hand carries on even if the message was deleted
+        let _ = channel_id.edit_message(http, message_id, edit).await;
+
+        Ok(())
+    }
+
+    /// Calls off any hand being played and cashes everyone out, which is
+    /// how a table closes once it sits idle between hands or is cut off by a
+    /// restart.
+    pub(crate) fn time_out(mut self) -> TimedOut {
+        self.cancel();
+
+        TimedOut {
+            economy: self.economy,
+            source: "poker",
+            refunds: self
+                .seats
+                .iter()
+                .map(|seat| (seat.user, seat.stack))
+                .collect(),
+            embed: closed_embed(&self.locale, t!(&self.locale, "gambling.poker.timed_out")),
+        }
+    }
+}
+
+impl From<Poker> for Session {
+    fn from(game: Poker) -> Self {
+        Self::Poker(game)
+    }
+}
+
+/// Works out a player's buy-in, the most chips they can afford up to the
+/// table's limit, returning their coins and the chips they get for them.
+async fn buy_in<
+    Db: Database,
+    GamblingHandler: GamblingManager<Db>,
+    EffectsHandler: EffectsManager<Db> + Send,
+    GameHandler: GameManager<Db>,
+>(
+    pool: &Pool<Db>,
+    game: &Poker,
+    user: UserId,
+) -> Result<(i64, i64)> {
+    let coins = GameHandler::row(pool, game.economy, user)
+        .await?
+        .unwrap_or_else(|| GameRow::new(user))
+        .coins();
+
+    if coins < game.min_buy_in {
+        return Err(Error::InsufficientFunds {
+            required: game.min_buy_in - coins,
+            currency: ShopCurrency::Coins,
+        });
+    }
+
+    let stack = coins.min(game.max_buy_in);
+
+    EffectsHandler::bet_limit::<GamblingHandler>(pool, game.economy, user, stack, coins).await?;
+
+    Ok((coins, stack))
+}
+
+/// Turns a player's chips back into coins as they leave the table, or when
+/// the seat they paid for couldn't be taken.
+async fn cash_out<Db: Database, GameHandler: GameManager<Db>, LedgerHandler: LedgerManager<Db>>(
+    pool: &Pool<Db>,
+    economy: Economy,
+    message_id: MessageId,
+    user: UserId,
+    stack: i64,
+) -> Result<()> {
+    let mut row = GameHandler::row(pool, economy, user)
+        .await?
+        .unwrap_or_else(|| GameRow::new(user));
+    let coins = row.coins();
+
+    row.add_coins(stack);
+
+    let ledger = Ledger::new(economy, user, "poker")
+        .reference(message_id)
+        .coins(coins, row.coins());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    GameHandler::save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Counts a finished hand towards everyone's goals, paying out any that
+/// were completed.
+async fn fire_goals<
+    Db: Database,
+    GoalsHandler: GoalsManager<Db>,
+    GameHandler: GameManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
+    pool: &Pool<Db>,
+    economy: Economy,
+    message_id: MessageId,
+    outcomes: Vec<Outcome>,
+) -> Result<()> {
+    let dispatch = Dispatch::<Db, GoalsHandler>::new(pool, economy);
+
+    for outcome in outcomes {
+        let mut row = GameHandler::row(pool, economy, outcome.user)
+            .await?
+            .unwrap_or_else(|| GameRow::new(outcome.user));
+        let (coins, gems) = (row.coins(), row.gems());
+
+        dispatch
+            .fire(
+                &mut row,
+                Event::Game(GameEvent::new(
+                    "poker",
+                    outcome.user,
+                    outcome.committed,
+                    outcome.won > outcome.committed,
+                )),
+            )
+            .await?;
+
+        let ledger = Ledger::new(economy, outcome.user, "poker")
+            .reference(message_id)
+            .coins(coins, row.coins())
+            .gems(gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+    }
+
+    Ok(())
+}
+
+/// Shows a player their hole cards, and the best hand they make so far.
+async fn reveal(
+    ctx: &Context,
+    interaction: &ComponentInteraction,
+    game: &Poker,
+    index: usize,
+) -> Result<()> {
+    let seat = &game.seats[index];
+    let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+    let content = match &game.hand {
+        Some(hand) if !seat.cards.is_empty() && hand.board.is_empty() => cards(&seat.cards),
+        Some(hand) if !seat.cards.is_empty() => {
+            let rank = hand::best(&[seat.cards.as_slice(), &hand.board].concat());
+            format!("{}- {}", cards(&seat.cards), rank.category.name(locale))
+        }
+        _ => t!(locale, "gambling.poker.not_in_hand"),
+    };
+
+    interaction
+        .create_response(
+            ctx,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+fn embed(game: &Poker) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(t!(&game.locale, "gambling.poker.name"))
+        .description(game.description())
+        .colour(Colour::DARK_GREEN)
+}
+
+fn closed_embed(locale: &str, reason: String) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(t!(locale, "gambling.poker.name"))
+        .description(reason)
+        .colour(Colour::TEAL)
+}
+
+fn message(game: &Poker) -> CreateInteractionResponseMessage {
+    CreateInteractionResponseMessage::new()
+        .embed(embed(game))
+        .components(components(game))
+}
+
+fn components(game: &Poker) -> Vec<CreateActionRow> {
+    let locale = game.locale.as_str();
+
+    let Some(hand) = &game.hand else {
+        return vec![CreateActionRow::Buttons(vec![
+            CreateButton::new("poker_join")
+                .emoji('🪑')
+                .label(t!(locale, "gambling.poker.join"))
+                .style(ButtonStyle::Secondary)
+                .disabled(game.seats.len() >= game.max_seats),
+            CreateButton::new("poker_leave")
+                .emoji('🚪')
+                .label(t!(locale, "gambling.poker.leave"))
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("poker_deal")
+                .emoji('🃏')
+                .label(t!(locale, "gambling.poker.deal"))
+                .style(ButtonStyle::Secondary)
+                .disabled(game.seats.len() < 2),
+        ])];
+    };
+
+    let to_call = game.to_call(hand.turn);
+    let call = match to_call {
+        0 => t!(locale, "gambling.poker.check"),
+        amount => t!(locale, "gambling.poker.call", amount = amount.format()),
+    };
+    let raise = game.raise_to(hand.turn);
+
+    vec![
+        CreateActionRow::Buttons(vec![
+            CreateButton::new("poker_fold")
+                .label(t!(locale, "gambling.poker.fold"))
+                .style(ButtonStyle::Danger),
+            CreateButton::new("poker_call")
+                .label(call)
+                .style(ButtonStyle::Secondary),
+            CreateButton::new("poker_raise")
+                .label(match raise {
+                    Some(to) => t!(locale, "gambling.poker.raise_to", amount = to.format()),
+                    None => t!(locale, "gambling.poker.raise"),
+                })
+                .style(ButtonStyle::Secondary)
+                .disabled(raise.is_none()),
+            CreateButton::new("poker_allin")
+                .label(t!(locale, "gambling.poker.all_in_button"))
+                .style(ButtonStyle::Primary),
+        ]),
+        CreateActionRow::Buttons(vec![
+            CreateButton::new("poker_cards")
+                .emoji('👀')
+                .label(t!(locale, "gambling.poker.my_cards"))
+                .style(ButtonStyle::Secondary),
+        ]),
+    ]
+}
+
+/// Answers a click that doesn't change the game.
+async fn acknowledge(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,642 @@
+use rand::rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use serenity::all::{Mentionable, UserId};
+use zayden_core::{FormatNum, t};
+
+use crate::utils::coins_str;
+use crate::{CARD_BACK, CARD_DECK, COIN, Economy};
+
+use super::hand::{self, HandRank};
+
+/// A player's chips and their part in the hand being played.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Seat {
+    pub user: UserId,
+    pub stack: i64,
+    /// The hole cards, empty while the seat sits out a hand.
+    pub cards: Vec<u8>,
+    /// The chips put in on the current street.
+    pub bet: i64,
+    /// The chips put in over the whole hand.
+    pub committed: i64,
+    pub folded: bool,
+    pub acted: bool,
+}
+
+impl Seat {
+    fn new(user: UserId, stack: i64) -> Self {
+        Self {
+            user,
+            stack,
+            cards: Vec::new(),
+            bet: 0,
+            committed: 0,
+            folded: false,
+            acted: false,
+        }
+    }
+
+    fn in_hand(&self) -> bool {
+        !self.cards.is_empty() && !self.folded
+    }
+
+    fn can_act(&self) -> bool {
+        self.in_hand() && self.stack > 0
+    }
+}
+
+/// The cards and betting of the hand being played.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Hand {
+    deck: Vec<u8>,
+    pub board: Vec<u8>,
+    /// The seat whose move it is.
+    pub turn: usize,
+    pub current_bet: i64,
+    /// The smallest amount a raise has to add to the current bet.
+    pub min_raise: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Action {
+    Fold,
+    Call,
+    Raise,
+    AllIn,
+}
+
+/// The chips a player won in a hand and what they put in to win them.
+pub struct Outcome {
+    pub user: UserId,
+    pub committed: i64,
+    pub won: i64,
+}
+
+/// A poker table. Players buy chips when they sit down and get their stack
+/// back as coins when they leave.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Poker {
+    pub economy: Economy,
+    pub host: UserId,
+    pub min_buy_in: i64,
+    pub max_buy_in: i64,
+    pub big_blind: i64,
+    pub max_seats: usize,
+    pub seats: Vec<Seat>,
+    /// The seat with the dealer button.
+    button: usize,
+    pub hand: Option<Hand>,
+    /// How the last hand ended, shown until the next one is dealt.
+    result: Option<String>,
+    /// The guild's locale, which the table is shown in. Tables stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    pub locale: String,
+}
+
+impl Poker {
+    pub fn new(
+        economy: Economy,
+        locale: &str,
+        host: UserId,
+        (min_buy_in, max_buy_in): (i64, i64),
+        big_blind: i64,
+        max_seats: usize,
+    ) -> Self {
+        Self {
+            economy,
+            host,
+            min_buy_in,
+            max_buy_in,
+            big_blind,
+            max_seats,
+            seats: Vec::new(),
+            button: 0,
+            hand: None,
+            result: None,
+            locale: locale.to_string(),
+        }
+    }
+
+    pub fn small_blind(&self) -> i64 {
+        (self.big_blind / 2).max(1)
+    }
+
+    pub fn seat(&self, user: UserId) -> Option<usize> {
+        self.seats.iter().position(|seat| seat.user == user)
+    }
+
+    pub fn sit(&mut self, user: UserId, stack: i64) {
+        self.seats.push(Seat::new(user, stack));
+    }
+
+    /// Removes the player from the table, returning the stack they leave
+    /// with. The host's seat passes to the next player.
+    pub fn stand(&mut self, index: usize) -> i64 {
+        let seat = self.seats.remove(index);
+        let n = self.seats.len();
+
+        // The button moves back a seat so the next player after it still
+        // gets it next
+        if index <= self.button && n > 0 {
+            self.button = (self.button + n - 1) % n;
+        }
+
+        if seat.user == self.host
+            && let Some(next) = self.seats.first()
+        {
+            self.host = next.user;
+        }
+
+        seat.stack
+    }
+
+    /// The seats after `index`, going round the table once.
+    fn after(&self, index: usize) -> impl Iterator<Item = usize> {
+        let n = self.seats.len();
+        (1..=n).map(move |i| (index + i) % n)
+    }
+
+    fn post(&mut self, index: usize, amount: i64) {
+        let seat = &mut self.seats[index];
+        let amount = amount.min(seat.stack);
+
+        seat.stack -= amount;
+        seat.bet += amount;
+        seat.committed += amount;
+    }
+
+    /// Shuffles up, moves the button and posts the blinds. The hand can be
+    /// over straight away if the blinds put everyone all in.
+    pub fn deal(&mut self) -> Option<Vec<Outcome>> {
+        let mut deck = (0..52).collect::<Vec<u8>>();
+        deck.shuffle(&mut rng());
+
+        for seat in &mut self.seats {
+            seat.cards = vec![deck.pop().unwrap(), deck.pop().unwrap()];
+            seat.bet = 0;
+            seat.committed = 0;
+            seat.folded = false;
+            seat.acted = false;
+        }
+
+        let n = self.seats.len();
+        self.button = (self.button + 1) % n;
+
+        // Heads up, the button posts the small blind and acts first
+        let sb = if n == 2 {
+            self.button
+        } else {
+            (self.button + 1) % n
+        };
+        let bb = (sb + 1) % n;
+
+        self.post(sb, self.small_blind());
+        self.post(bb, self.big_blind);
+
+        self.hand = Some(Hand {
+            deck,
+            board: Vec::new(),
+            turn: bb,
+            current_bet: self.big_blind,
+            min_raise: self.big_blind,
+        });
+        self.result = None;
+
+        self.progress()
+    }
+
+    /// The amount the player at `index` has to put in to call.
+    pub fn to_call(&self, index: usize) -> i64 {
+        let hand = self.hand.as_ref().unwrap();
+        let seat = &self.seats[index];
+
+        (hand.current_bet - seat.bet).min(seat.stack)
+    }
+
+    /// The total bet a minimum raise makes, if the player can afford more
+    /// than going all in.
+    pub fn raise_to(&self, index: usize) -> Option<i64> {
+        let hand = self.hand.as_ref().unwrap();
+        let seat = &self.seats[index];
+        let to = hand.current_bet + hand.min_raise;
+
+        (seat.bet + seat.stack > to).then_some(to)
+    }
+
+    /// Makes a move for the player whose turn it is, returning the outcome
+    /// of the hand once it is over.
+    pub fn act(&mut self, action: Action) -> Option<Vec<Outcome>> {
+        let hand = self.hand.as_ref().unwrap();
+        let index = hand.turn;
+        let seat = &self.seats[index];
+
+        let total = match action {
+            Action::Fold => {
+                self.seats[index].folded = true;
+                None
+            }
+            Action::Call => Some(hand.current_bet.min(seat.bet + seat.stack)),
+            Action::Raise => self.raise_to(index).or(Some(seat.bet + seat.stack)),
+            Action::AllIn => Some(seat.bet + seat.stack),
+        };
+
+        if let Some(total) = total {
+            self.bet_to(index, total);
+        }
+
+        self.seats[index].acted = true;
+
+        self.progress()
+    }
+
+    fn bet_to(&mut self, index: usize, total: i64) {
+        self.post(index, total - self.seats[index].bet);
+
+        let hand = self.hand.as_mut().unwrap();
+        let total = self.seats[index].bet;
+
+        if total > hand.current_bet {
+            let raise = total - hand.current_bet;
+
+            // Only a full raise lets players who already acted raise again,
+            // but everyone has to call it either way
+            if raise >= hand.min_raise {
+                hand.min_raise = raise;
+
+                for seat in &mut self.seats {
+                    seat.acted = false;
+                }
+            }
+
+            hand.current_bet = total;
+        }
+    }
+
+    fn needs_action(&self, index: usize) -> bool {
+        let seat = &self.seats[index];
+        let current_bet = self.hand.as_ref().unwrap().current_bet;
+
+        seat.can_act() && (!seat.acted || seat.bet < current_bet)
+    }
+
+    /// Moves the turn on, dealing the next street once everyone has acted.
+    fn progress(&mut self) -> Option<Vec<Outcome>> {
+        loop {
+            if self.seats.iter().filter(|seat| seat.in_hand()).count() == 1 {
+                return Some(self.settle(false));
+            }
+
+            let hand = self.hand.as_ref().unwrap();
+            let (turn, current_bet, river) = (hand.turn, hand.current_bet, hand.board.len() == 5);
+
+            // No one is left to bet against once all but one player is all in
+            let betting = self.seats.iter().filter(|seat| seat.can_act()).count() > 1
+                || self
+                    .seats
+                    .iter()
+                    .any(|seat| seat.can_act() && seat.bet < current_bet);
+
+            let next = self.after(turn).find(|i| self.needs_action(*i));
+            if betting && let Some(next) = next {
+                self.hand.as_mut().unwrap().turn = next;
+                return None;
+            }
+
+            if river {
+                return Some(self.settle(true));
+            }
+
+            for seat in &mut self.seats {
+                seat.bet = 0;
+                seat.acted = false;
+            }
+
+            let hand = self.hand.as_mut().unwrap();
+            let cards = if hand.board.is_empty() { 3 } else { 1 };
+            for _ in 0..cards {
+                let card = hand.deck.pop().unwrap();
+                hand.board.push(card);
+            }
+            hand.current_bet = 0;
+            hand.min_raise = self.big_blind;
+            hand.turn = self.button;
+        }
+    }
+
+    /// The main pot and any side pots, with the seats that can win each.
+    fn pots(&self) -> Vec<(i64, Vec<usize>)> {
+        let mut levels = self
+            .seats
+            .iter()
+            .filter(|seat| seat.in_hand())
+            .map(|seat| seat.committed)
+            .collect::<Vec<_>>();
+        levels.sort_unstable();
+        levels.dedup();
+
+        let mut pots = Vec::new();
+        let mut prev = 0;
+
+        for level in levels {
+            let amount = self
+                .seats
+                .iter()
+                .map(|seat| seat.committed.min(level) - seat.committed.min(prev))
+                .sum();
+            let eligible = (0..self.seats.len())
+                .filter(|i| self.seats[*i].in_hand() && self.seats[*i].committed >= level)
+                .collect();
+
+            pots.push((amount, eligible));
+            prev = level;
+        }
+
+        // Anything folded above the last caller's stake goes to the last pot
+        let total = self.seats.iter().map(|seat| seat.committed).sum::<i64>();
+        let potted = pots.iter().map(|(amount, _)| amount).sum::<i64>();
+        if let Some((amount, _)) = pots.last_mut() {
+            *amount += total - potted;
+        }
+
+        pots
+    }
+
+    /// Pays out every pot and ends the hand.
+    fn settle(&mut self, showdown: bool) -> Vec<Outcome> {
+        let hand = self.hand.take().unwrap();
+        let mut won = vec![0; self.seats.len()];
+        let mut lines = Vec::new();
+
+        let ranks = self
+            .seats
+            .iter()
+            .map(|seat| {
+                let cards = [seat.cards.as_slice(), &hand.board].concat();
+                (showdown && seat.in_hand()).then(|| hand::best(&cards))
+            })
+            .collect::<Vec<Option<HandRank>>>();
+
+        if showdown {
+            lines.push(format!(
+                "**{}**\n{}",
+                t!(&self.locale, "gambling.poker.board"),
+                cards(&hand.board)
+            ));
+
+            for (seat, rank) in self.seats.iter().zip(&ranks) {
+                if let Some(rank) = rank {
+                    lines.push(format!(
+                        "{} {} - {}",
+                        seat.user.mention(),
+                        cards(&seat.cards),
+                        rank.category.name(&self.locale)
+                    ));
+                }
+            }
+
+            lines.push(String::new());
+        }
+
+        for (amount, eligible) in self.pots() {
+            let best = eligible.iter().filter_map(|i| ranks[*i]).max();
+            let winners = self
+                .after(self.button)
+                .filter(|i| eligible.contains(i) && ranks[*i] == best)
+                .collect::<Vec<_>>();
+
+            let share = amount / winners.len() as i64;
+            for (n, i) in winners.iter().enumerate() {
+                // The odd chip goes to the first winner after the button
+                won[*i] += share
+                    + if n == 0 {
+                        amount % winners.len() as i64
+                    } else {
+                        0
+                    };
+            }
+        }
+
+        let mut outcomes = Vec::new();
+
+        for (seat, won) in self.seats.iter_mut().zip(won) {
+            if seat.cards.is_empty() {
+                continue;
+            }
+
+            if won > 0 {
+                lines.push(t!(
+                    &self.locale,
+                    "gambling.poker.wins",
+                    player = seat.user.mention(),
+                    coins = coins_str(won)
+                ));
+            }
+
+            seat.stack += won;
+            outcomes.push(Outcome {
+                user: seat.user,
+                committed: seat.committed,
+                won,
+            });
+
+            seat.cards.clear();
+            seat.bet = 0;
+            seat.committed = 0;
+        }
+
+        // Players who lost their whole stack leave the table
+        while let Some(index) = self.seats.iter().position(|seat| seat.stack == 0) {
+            lines.push(t!(
+                &self.locale,
+                "gambling.poker.out_of_chips",
+                player = self.seats[index].user.mention()
+            ));
+            self.stand(index);
+        }
+
+        self.result = Some(lines.join("\n"));
+
+        outcomes
+    }
+
+    /// Calls off the hand being played, giving everyone back what they put
+    /// in.
+    pub fn cancel(&mut self) {
+        self.hand = None;
+
+        for seat in &mut self.seats {
+            seat.stack += seat.committed;
+            seat.cards.clear();
+            seat.bet = 0;
+            seat.committed = 0;
+        }
+    }
+
+    pub fn description(&self) -> String {
+        let mut desc = format!(
+            "{} <:coin:{COIN}>\n{} <:coin:{COIN}>\n\n",
+            t!(
+                &self.locale,
+                "gambling.poker.blinds",
+                small = self.small_blind().format(),
+                big = self.big_blind.format()
+            ),
+            t!(
+                &self.locale,
+                "gambling.poker.buy_in",
+                min = self.min_buy_in.format(),
+                max = self.max_buy_in.format()
+            ),
+        );
+
+        if let Some(hand) = &self.hand {
+            let pot = self.seats.iter().map(|seat| seat.committed).sum::<i64>();
+            let hidden = (hand.board.len()..5)
+                .map(|_| format!("<:card:{}> ", CARD_BACK))
+                .collect::<String>();
+
+            desc.push_str(&format!(
+                "**{}**\n{}{hidden}\n{}\n\n",
+                t!(&self.locale, "gambling.poker.board"),
+                cards(&hand.board),
+                t!(&self.locale, "gambling.poker.pot", coins = coins_str(pot))
+            ));
+        } else if let Some(result) = &self.result {
+            desc.push_str(&format!("{result}\n\n"));
+        }
+
+        for (i, seat) in self.seats.iter().enumerate() {
+            let marker = match &self.hand {
+                Some(hand) if hand.turn == i => "▶️ ",
+                Some(_) if self.button == i => "🔘 ",
+                _ => "",
+            };
+
+            let status = if self.hand.is_none() || seat.cards.is_empty() {
+                String::new()
+            } else if seat.folded {
+                format!(" - {}", t!(&self.locale, "gambling.poker.folded"))
+            } else if seat.stack == 0 {
+                format!(" - {}", t!(&self.locale, "gambling.poker.all_in"))
+            } else if seat.bet > 0 {
+                format!(
+                    " - {}",
+                    t!(
+                        &self.locale,
+                        "gambling.poker.bet",
+                        amount = seat.bet.format()
+                    )
+                )
+            } else {
+                String::new()
+            };
+
+            desc.push_str(&format!(
+                "{marker}{} {} <:coin:{COIN}>{status}\n",
+                seat.user.mention(),
+                seat.stack.format()
+            ));
+        }
+
+        if self.hand.is_none() {
+            desc.push_str(&format!(
+                "\n{}",
+                t!(
+                    &self.locale,
+                    "gambling.poker.waiting",
+                    taken = self.seats.len(),
+                    seats = self.max_seats,
+                    host = self.host.mention()
+                )
+            ));
+        }
+
+        desc
+    }
+}
+
+pub fn cards(cards: &[u8]) -> String {
+    cards
+        .iter()
+        .map(|card| format!("<:card:{}> ", CARD_DECK[usize::from(*card)]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::hand::tests::cards;
+    use super::*;
+
+    /// A table at showdown, with each seat's hole cards and the chips it put
+    /// into the hand.
+    fn table(board: &str, seats: &[(&str, i64)]) -> Poker {
+        let mut game = Poker::new(Economy::GLOBAL, "en-US", UserId::new(1), (100, 1000), 10, 6);
+
+        for (i, (hole, committed)) in seats.iter().enumerate() {
+            game.sit(UserId::new(i as u64 + 1), 0);
+
+            let seat = game.seats.last_mut().unwrap();
+            seat.cards = cards(hole);
+            seat.committed = *committed;
+        }
+
+        game.hand = Some(Hand {
+            deck: Vec::new(),
+            board: cards(board),
+            turn: 0,
+            current_bet: 0,
+            min_raise: 10,
+        });
+
+        game
+    }
+
+    fn winnings(game: &mut Poker) -> Vec<i64> {
+        game.settle(true)
+            .into_iter()
+            .map(|outcome| outcome.won)
+            .collect()
+    }
+
+    #[test]
+    fn unequal_all_ins_make_side_pots() {
+        let mut game = table(
+            "2c 7d 9h Js Kc",
+            &[("Kh Kd", 100), ("Ah Ad", 300), ("3h 4d", 500)],
+        );
+
+        assert_eq!(
+            game.pots(),
+            vec![(300, vec![0, 1, 2]), (400, vec![1, 2]), (200, vec![2])]
+        );
+
+        // The short stack wins the main pot, the next best hand the side pot
+        // and the big stack only gets back what nobody could match
+        assert_eq!(winnings(&mut game), vec![300, 400, 200]);
+    }
+
+    #[test]
+    fn folded_chips_go_to_the_last_pot() {
+        let mut game = table(
+            "2c 7d 9h Js Kc",
+            &[("Kh Kd", 100), ("Ah Ad", 200), ("3h 4d", 250)],
+        );
+        game.seats[2].folded = true;
+
+        assert_eq!(game.pots(), vec![(300, vec![0, 1]), (250, vec![1])]);
+    }
+
+    #[test]
+    fn split_pots_give_the_odd_chip_after_the_button() {
+        // Both players play the straight on the board
+        let mut game = table(
+            "Ah Kd Qc Js Th",
+            &[("2c 3d", 50), ("2d 3c", 50), ("4c 5d", 1)],
+        );
+        game.seats[2].folded = true;
+
+        assert_eq!(winnings(&mut game), vec![50, 51, 0]);
+    }
+}
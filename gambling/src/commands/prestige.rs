@@ -9,23 +9,33 @@ use serenity::all::{
 use sqlx::any::AnyQueryResult;
 use sqlx::types::Json;
 use sqlx::{Database, FromRow, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, Shutdown, i18n, t};
 
 use crate::shop::LOTTO_TICKET;
 use crate::{
-    Commands, GamblingItem, MaxValues, Mining, Prestige, Result, SHOP_ITEMS, START_AMOUNT,
+    Commands, Economies, Economy, GamblingItem, Ledger, LedgerManager, MaxValues, Mining, Prestige,
+    Result, SHOP_ITEMS, START_AMOUNT,
 };
 
 #[async_trait]
 pub trait PrestigeManager<Db: Database> {
-    async fn miners(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<i64>>;
+    async fn miners(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<i64>>;
 
     async fn row(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<PrestigeRow>>;
 
-    async fn save(pool: &Pool<Db>, row: PrestigeRow) -> sqlx::Result<AnyQueryResult>;
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: PrestigeRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(FromRow, Default)]
@@ -193,14 +203,21 @@ impl Prestige for PrestigeRow {
 }
 
 impl Commands {
-    pub async fn prestige<Db: Database, Manager: PrestigeManager<Db>>(
+    pub async fn prestige<
+        Db: Database,
+        Manager: PrestigeManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
         ctx: &Context,
         interaction: &CommandInteraction,
         pool: &Pool<Db>,
     ) -> Result<()> {
         interaction.defer(ctx).await?;
 
-        let row = Manager::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let row = Manager::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_default();
@@ -209,10 +226,11 @@ impl Commands {
 
         if row.miners() < req_miners {
             let embed = CreateEmbed::new()
-                .description(format!(
-                    "❌ You need at least `{}` miners before you can prestige.\nYou only have `{}`",
-                    req_miners.format(),
-                    row.miners().format()
+                .description(t!(
+                    locale,
+                    "gambling.prestige.not_enough_miners",
+                    required = req_miners.format(),
+                    miners = row.miners().format()
                 ))
                 .colour(Colour::RED);
 
@@ -224,14 +242,16 @@ impl Commands {
             return Ok(());
         }
 
-        let embed = CreateEmbed::new().description("Are you sure you want to prestige your mine?\n\nPrestiging will **reset your mine, coins, items and resources**, but you'll unlock powerful upgrades!").colour(Colour::TEAL);
+        let embed = CreateEmbed::new()
+            .description(t!(locale, "gambling.prestige.confirm_prompt"))
+            .colour(Colour::TEAL);
 
         let confirm = CreateButton::new("confirm")
-            .label("Confirm")
+            .label(t!(locale, "gambling.prestige.confirm"))
             .emoji('✅')
             .style(ButtonStyle::Secondary);
         let cancel = CreateButton::new("cancel")
-            .label("Cancel")
+            .label(t!(locale, "gambling.prestige.cancel"))
             .emoji('❌')
             .style(ButtonStyle::Secondary);
 
@@ -250,11 +270,12 @@ impl Commands {
             .await_component_interactions(ctx)
             .author_id(interaction.user.id)
             .timeout(Duration::from_secs(120))
-            .stream();
+            .stream()
+            .take_until(Shutdown::signal(ctx));
 
         if let Some(component) = stream.next().await {
             if component.data.custom_id == "confirm" {
-                let mut row = Manager::row(pool, interaction.user.id)
+                let mut row = Manager::row(pool, economy, interaction.user.id)
                     .await
                     .unwrap()
                     .unwrap();
@@ -263,16 +284,24 @@ impl Commands {
                     return Ok(());
                 }
 
+                let (start_coins, start_gems) = (row.coins, row.gems);
                 row.do_prestige();
+                let ledger = Ledger::new(economy, interaction.user.id, "prestige")
+                    .reference(interaction.id)
+                    .coins(start_coins, row.coins)
+                    .gems(start_gems, row.gems);
 
-                Manager::save(pool, row).await.unwrap();
+                let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+                Manager::save(&mut *tx, economy, row).await?;
+                ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+                tx.commit().await?;
 
                 component
                     .create_response(
                         ctx,
                         CreateInteractionResponse::UpdateMessage(
                             CreateInteractionResponseMessage::new()
-                                .content("Done (message wip)")
+                                .content(t!(locale, "gambling.prestige.done"))
                                 .embeds(Vec::new())
                                 .components(Vec::new()),
                         ),
@@ -6,16 +6,22 @@ use serenity::all::{
     CreateEmbed, EditInteractionResponse, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool, types::Json};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, i18n, t};
 
-use crate::{COIN, Coins, GamblingItem, Gems, ItemInventory, MaxBet, Prestige, Result, ShopItem};
+use crate::{
+    COIN, Coins, Economies, Economy, GamblingItem, Gems, ItemInventory, MaxBet, Prestige, Result,
+    ShopItem,
+};
 
 use super::Commands;
 
 #[async_trait]
 pub trait ProfileManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send)
-    -> sqlx::Result<Option<ProfileRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<ProfileRow>>;
 }
 
 #[derive(Default)]
@@ -99,17 +105,24 @@ impl MaxBet for ProfileRow {
     }
 }
 
-impl From<ProfileRow> for CreateEmbed {
-    fn from(value: ProfileRow) -> Self {
-        let mut betting_max = value.max_bet_str();
-        if value.prestige() != 0 {
-            betting_max.push_str(&format!("\n(Prestige Boost: +{}%)", 10 * value.prestige()));
+impl ProfileRow {
+    fn embed(self, locale: &str) -> CreateEmbed {
+        let mut betting_max = self.max_bet_str();
+        if self.prestige() != 0 {
+            betting_max.push_str(&format!(
+                "\n{}",
+                t!(
+                    locale,
+                    "gambling.profile.prestige_boost",
+                    boost = 10 * self.prestige()
+                )
+            ));
         }
 
-        let inventory = value.inventory();
+        let inventory = self.inventory();
 
         let loot_str = if inventory.is_empty() {
-            String::from("You've got no loot, not even a 🥄")
+            t!(locale, "gambling.profile.no_loot")
         } else {
             inventory
                 .iter()
@@ -121,19 +134,36 @@ impl From<ProfileRow> for CreateEmbed {
         };
 
         CreateEmbed::new()
-            .field(format!("Coins <:coin:{COIN}>"), value.coins_str(), false)
-            .field("Gems 💎", value.gems_str(), false)
             .field(
-                format!("Level {}", LevelsRow::level(&value).format()),
-                format!(
-                    "{} / {} xp",
-                    value.xp().format(),
-                    level_up_xp(LevelsRow::level(&value)).format()
+                format!("{} <:coin:{COIN}>", t!(locale, "gambling.profile.coins")),
+                self.coins_str(),
+                false,
+            )
+            .field(
+                format!("{} 💎", t!(locale, "gambling.profile.gems")),
+                self.gems_str(),
+                false,
+            )
+            .field(
+                t!(
+                    locale,
+                    "gambling.profile.level",
+                    level = LevelsRow::level(&self).format()
+                ),
+                t!(
+                    locale,
+                    "gambling.profile.xp",
+                    xp = self.xp().format(),
+                    next = level_up_xp(LevelsRow::level(&self)).format()
                 ),
                 false,
             )
-            .field("Betting Maximum", betting_max, false)
-            .field("Loot", loot_str, false)
+            .field(
+                t!(locale, "gambling.profile.betting_max"),
+                betting_max,
+                false,
+            )
+            .field(t!(locale, "gambling.profile.loot"), loot_str, false)
             .colour(Colour::TEAL)
     }
 }
@@ -147,6 +177,8 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let user = match options.pop() {
             Some(option) => {
                 let ResolvedValue::User(user, _) = option.value else {
@@ -157,9 +189,12 @@ impl Commands {
             None => &interaction.user,
         };
 
-        let row = Manager::row(pool, user.id).await?.unwrap_or_default();
+        let row = Manager::row(pool, economy, user.id)
+            .await?
+            .unwrap_or_default();
 
-        let mut embed = CreateEmbed::from(row).title(user.display_name());
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+        let mut embed = row.embed(locale).title(user.display_name());
 
         if let Some(avatar) = user.avatar_url() {
             embed = embed.thumbnail(avatar);
@@ -3,12 +3,15 @@ use serenity::all::{
     EditInteractionResponse, ResolvedOption, ResolvedValue,
 };
 use sqlx::{Database, Pool};
-use zayden_core::parse_options;
+use zayden_core::{Cooldowns, i18n, parse_options, t};
 
 use crate::events::{Dispatch, Event, GameEvent};
 use crate::models::GamblingManager;
 use crate::utils::{GameResult, game_embed};
-use crate::{Coins, EffectsManager, Error, GameCache, GameManager, GameRow, GoalsManager, Result};
+use crate::{
+    Coins, Economies, EffectsManager, Error, GAME_COOLDOWN, GameManager, GameRow, Gems,
+    GoalsManager, Ledger, LedgerManager, Result,
+};
 
 use super::Commands;
 
@@ -19,6 +22,7 @@ impl Commands {
         GoalHandler: GoalsManager<Db>,
         EffectsHandler: EffectsManager<Db> + Send,
         GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -27,6 +31,9 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await?;
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
         let mut options = parse_options(options);
 
         let Some(ResolvedValue::String(dice)) = options.remove("dice") else {
@@ -41,30 +48,41 @@ impl Commands {
 
         verify_prediction(prediction, 1, n_sides)?;
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
 
         let Some(ResolvedValue::Integer(bet)) = options.remove("bet") else {
             unreachable!("bet option is required")
         };
 
-        EffectsHandler::bet_limit::<GamblingHandler>(pool, interaction.user.id, bet, row.coins())
-            .await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
         row.bet(bet);
 
         let roll = rand::random_range(1..=n_sides);
 
+        let game = t!(locale, "gambling.roll.name");
         let (title, mut payout) = if roll == prediction {
-            ("🎲 Dice Roll 🎲 - You Won!", bet * n_sides)
+            (
+                t!(locale, "gambling.result.won", game = game),
+                bet * n_sides,
+            )
         } else {
-            ("🎲 Dice Roll 🎲 - You Lost!", 0)
+            (t!(locale, "gambling.result.lost", game = game), 0)
         };
 
-        Dispatch::<Db, GoalHandler>::new(pool)
+        Dispatch::<Db, GoalHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Game(GameEvent::new(
@@ -78,6 +96,7 @@ impl Commands {
 
         payout = EffectsHandler::payout(
             pool,
+            economy,
             interaction.user.id,
             bet,
             payout,
@@ -88,14 +107,22 @@ impl Commands {
         row.add_coins(payout);
 
         let coins = row.coins();
+        let ledger = Ledger::new(economy, interaction.user.id, "roll")
+            .reference(interaction.id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
 
-        GameHandler::save(pool, row).await.unwrap();
-        GameCache::update(ctx, interaction.user.id).await;
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
 
         let embed = game_embed(
+            locale,
             title,
             GameResult::new_with_str(prediction.to_string(), "🎲"),
-            "Result",
+            &t!(locale, "gambling.roll.result"),
             GameResult::new_with_str(roll.to_string(), "🎲"),
             bet,
             payout,
@@ -0,0 +1,722 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ButtonStyle, Colour, CommandInteraction, CommandOptionType, ComponentInteraction,
+    ComponentInteractionDataKind, Context, CreateActionRow, CreateButton, CreateCommand,
+    CreateCommandOption, CreateEmbed, CreateInputText, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateModal, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, EditInteractionResponse, InputTextStyle, ModalInteraction,
+    ResolvedOption, ResolvedValue, UserId,
+};
+use serenity::prelude::TypeMapKey;
+use sqlx::{Database, Pool};
+use zayden_core::{Cooldowns, FormatNum, i18n, parse_modal_data, parse_options, t};
+
+use crate::events::{Dispatch, Event, GameEvent};
+use crate::models::gambling::GamblingManager;
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
+use crate::utils::coins_str;
+use crate::{
+    Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN, GameManager, GameRow, Gems,
+    GoalsManager, Ledger, LedgerManager, Result,
+};
+
+use super::{Commands, Components, Modals};
+
+/// The most bets a slip can hold, which keeps it readable in one embed.
+const MAX_BETS: usize = 10;
+
+/// The American wheel's double zero, stored after the 36 numbers.
+const DOUBLE_ZERO: u8 = 37;
+
+const RED: [u8; 18] = [
+    1, 3, 5, 7, 9, 12, 14, 16, 18, 19, 21, 23, 25, 27, 30, 32, 34, 36,
+];
+
+/// Every bet in the order the menu lists them.
+const KINDS: [BetKind; 14] = [
+    BetKind::Straight,
+    BetKind::Split,
+    BetKind::Street,
+    BetKind::Corner,
+    BetKind::Dozen(1),
+    BetKind::Dozen(2),
+    BetKind::Dozen(3),
+    BetKind::Column(1),
+    BetKind::Column(2),
+    BetKind::Column(3),
+    BetKind::Red,
+    BetKind::Black,
+    BetKind::Odd,
+    BetKind::Even,
+];
+
+impl Commands {
+    pub async fn roulette<Db: Database, SessionHandler: SessionManager<Db>>(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer(ctx).await.unwrap();
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let mut options = parse_options(options);
+
+        let wheel = match options.remove("wheel") {
+            Some(ResolvedValue::String(wheel)) => wheel.parse::<Wheel>().unwrap(),
+            _ => Wheel::European,
+        };
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+
+        let game = Roulette {
+            economy,
+            user: interaction.user.id,
+            wheel,
+            bets: Vec::new(),
+            locale: i18n::locale(&interaction.locale, interaction.guild_locale.as_deref())
+                .to_string(),
+        };
+
+        let msg = interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(game.slip_embed())
+                    .components(game.components()),
+            )
+            .await
+            .unwrap();
+
+        SessionHandler::insert(pool, &SessionRow::new(msg.id, msg.channel_id, game)).await?;
+
+        Ok(())
+    }
+
+    pub fn register_roulette() -> CreateCommand {
+        CreateCommand::new("roulette")
+            .description("Place your bets on a spin of the roulette wheel")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "wheel",
+                    "The wheel to play on, European by default",
+                )
+                .add_string_choice("European", "european")
+                .add_string_choice("American", "american"),
+            )
+    }
+}
+
+impl Components {
+    pub async fn roulette<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::Roulette(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        if interaction.user.id != game.user {
+            return Err(Error::NotPlayer);
+        }
+
+        match interaction.data.custom_id.as_str() {
+            "roulette_bet" => {
+                let ComponentInteractionDataKind::StringSelect { values } = &interaction.data.kind
+                else {
+                    unreachable!("roulette_bet is a select menu")
+                };
+
+                if game.bets.len() >= MAX_BETS {
+                    return Err(Error::BetSlipFull(MAX_BETS));
+                }
+
+                let kind = values[0].parse::<BetKind>().unwrap();
+
+                interaction
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Modal(kind.modal(&game.locale)),
+                    )
+                    .await?;
+
+                return Ok(());
+            }
+            "roulette_clear" => {
+                game.bets.clear();
+
+                let msg = game.message();
+                Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
+
+                interaction
+                    .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                    .await?;
+
+                return Ok(());
+            }
+            _ => {}
+        }
+
+        let economy = game.economy;
+        let bet = game.bets.iter().map(|bet| bet.amount).sum::<i64>();
+
+        let mut row = GameHandler::row(pool, economy, game.user)
+            .await?
+            .unwrap_or_else(|| GameRow::new(game.user));
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(pool, economy, game.user, bet, row.coins())
+            .await?;
+
+        // Only the spin that ends the session pays out
+        Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
+
+        let (start_coins, start_gems) = (row.coins(), row.gems());
+        row.bet(bet);
+
+        let pocket = Pockets::take(ctx, game.user, game.wheel).await;
+        let mut payout = game
+            .bets
+            .iter()
+            .filter(|slip| slip.pockets.contains(&pocket))
+            .map(Bet::payout)
+            .sum::<i64>();
+        let winner = payout > bet;
+
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
+            .fire(
+                &mut row,
+                Event::Game(GameEvent::new("roulette", game.user, bet, winner)),
+            )
+            .await?;
+
+        payout = EffectsHandler::payout(pool, economy, game.user, bet, payout, Some(winner)).await;
+
+        row.add_coins(payout);
+
+        let coins = row.coins();
+        let ledger = Ledger::new(economy, game.user, "roulette")
+            .reference(interaction.message.id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(game.result_embed(pocket, bet, payout, coins))
+                        .components(Vec::new()),
+                ),
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+impl Modals {
+    /// Adds the bet from the form opened by the bet menu to the slip.
+    pub async fn roulette<Db: Database, SessionHandler: SessionManager<Db>>(
+        ctx: &Context,
+        interaction: &ModalInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::Roulette(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
+
+        if interaction.user.id != game.user {
+            return Err(Error::NotPlayer);
+        }
+
+        if game.bets.len() >= MAX_BETS {
+            return Err(Error::BetSlipFull(MAX_BETS));
+        }
+
+        let Some(kind) = interaction
+            .data
+            .custom_id
+            .strip_prefix("roulette_bet_")
+            .and_then(|kind| kind.parse::<BetKind>().ok())
+        else {
+            unreachable!("roulette modals are named after their bet")
+        };
+
+        let data = parse_modal_data(&interaction.data.components);
+
+        let amount = data
+            .get("amount")
+            .and_then(|amount| amount.trim().replace(',', "").parse::<i64>().ok())
+            .ok_or(Error::InvalidAmount)?;
+
+        if amount < 0 {
+            return Err(Error::NegativeAmount);
+        }
+
+        if amount == 0 {
+            return Err(Error::ZeroAmount);
+        }
+
+        let numbers = match data.get("numbers") {
+            Some(numbers) => game.wheel.parse(numbers).ok_or(Error::InvalidBet)?,
+            None => Vec::new(),
+        };
+
+        let pockets = kind.pockets(game.wheel, numbers).ok_or(Error::InvalidBet)?;
+
+        game.bets.push(Bet {
+            kind,
+            pockets,
+            amount,
+        });
+
+        let msg = game.message();
+        Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
+
+        interaction
+            .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Wheel {
+    European,
+    American,
+}
+
+impl Wheel {
+    fn spin(self) -> u8 {
+        match self {
+            Self::European => rand::random_range(0..=36),
+            Self::American => rand::random_range(0..=DOUBLE_ZERO),
+        }
+    }
+
+    /// The pockets a player typed, e.g. `17 20` or `0, 00`, or `None` if one
+    /// of them isn't on this wheel.
+    fn parse(self, input: &str) -> Option<Vec<u8>> {
+        input
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|number| !number.is_empty())
+            .map(|number| match (self, number) {
+                (Self::American, "00") => Some(DOUBLE_ZERO),
+                (_, "00") => None,
+                (_, number) => number.parse::<u8>().ok().filter(|n| *n <= 36),
+            })
+            .collect()
+    }
+}
+
+/// Pockets set aside for a player's next spin, so that it lands somewhere
+/// known. The wheel is spun when none is waiting.
+#[derive(Debug, Default)]
+pub struct Pockets(HashMap<UserId, u8>);
+
+impl Pockets {
+    /// The pocket waiting for the player, or a spin of the wheel.
+    async fn take(ctx: &Context, user: UserId, wheel: Wheel) -> u8 {
+        let mut data = ctx.data.write().await;
+        let pockets = data.entry::<Self>().or_insert_with(Self::default);
+
+        pockets.0.remove(&user).unwrap_or_else(|| wheel.spin())
+    }
+
+    /// Sets the pocket the player's next spin lands on, with the double zero
+    /// stored as 37.
+    pub async fn put(ctx: &Context, user: UserId, pocket: u8) {
+        let mut data = ctx.data.write().await;
+        let pockets = data.entry::<Self>().or_insert_with(Self::default);
+
+        pockets.0.insert(user, pocket);
+    }
+}
+
+impl TypeMapKey for Pockets {
+    type Value = Pockets;
+}
+
+impl Wheel {
+    fn name(self, locale: &str) -> String {
+        match self {
+            Self::European => t!(locale, "gambling.roulette.european"),
+            Self::American => t!(locale, "gambling.roulette.american"),
+        }
+    }
+}
+
+impl FromStr for Wheel {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "european" => Ok(Self::European),
+            "american" => Ok(Self::American),
+            _ => Err(()),
+        }
+    }
+}
+
+/// What a bet is placed on. Dozens and columns are numbered from 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum BetKind {
+    Straight,
+    Split,
+    Street,
+    Corner,
+    Dozen(u8),
+    Column(u8),
+    Red,
+    Black,
+    Odd,
+    Even,
+}
+
+impl BetKind {
+    fn id(self) -> String {
+        match self {
+            Self::Straight => String::from("straight"),
+            Self::Split => String::from("split"),
+            Self::Street => String::from("street"),
+            Self::Corner => String::from("corner"),
+            Self::Dozen(n) => format!("dozen_{n}"),
+            Self::Column(n) => format!("column_{n}"),
+            Self::Red => String::from("red"),
+            Self::Black => String::from("black"),
+            Self::Odd => String::from("odd"),
+            Self::Even => String::from("even"),
+        }
+    }
+
+    /// The catalog key of the numbers an inside bet asks for, with an
+    /// example.
+    fn numbers(self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Self::Straight => Some(("gambling.roulette.straight_numbers", "17")),
+            Self::Split => Some(("gambling.roulette.split_numbers", "17 20")),
+            Self::Street => Some(("gambling.roulette.street_numbers", "16 17 18")),
+            Self::Corner => Some(("gambling.roulette.corner_numbers", "17 18 20 21")),
+            _ => None,
+        }
+    }
+
+    fn modal(self, locale: &str) -> CreateModal {
+        let mut inputs = Vec::new();
+
+        if let Some((label, placeholder)) = self.numbers() {
+            let numbers = CreateInputText::new(InputTextStyle::Short, t!(locale, label), "numbers")
+                .placeholder(placeholder)
+                .required(true);
+            inputs.push(CreateActionRow::InputText(numbers));
+        }
+
+        let amount = CreateInputText::new(
+            InputTextStyle::Short,
+            t!(locale, "gambling.roulette.amount"),
+            "amount",
+        )
+        .placeholder("100")
+        .required(true);
+        inputs.push(CreateActionRow::InputText(amount));
+
+        CreateModal::new(
+            format!("roulette_bet_{}", self.id()),
+            t!(
+                locale,
+                "gambling.roulette.modal_title",
+                bet = self.name(locale)
+            ),
+        )
+        .components(inputs)
+    }
+
+    /// The pockets the bet covers, or `None` if `numbers` isn't a valid
+    /// choice for it on `wheel`.
+    fn pockets(self, wheel: Wheel, mut numbers: Vec<u8>) -> Option<Vec<u8>> {
+        numbers.sort_unstable();
+        numbers.dedup();
+
+        let valid = match (self, numbers.as_slice()) {
+            (Self::Straight, [_]) => true,
+            (Self::Split, &[a, b]) => match (wheel, a, b) {
+                (Wheel::European, 0, 1..=3) => true,
+                (Wheel::American, 0, 1 | 2 | DOUBLE_ZERO) => true,
+                (Wheel::American, 2 | 3, DOUBLE_ZERO) => true,
+                (_, 1..=36, 1..=36) => b - a == 3 || (b - a == 1 && a % 3 != 0),
+                _ => false,
+            },
+            (Self::Street, &[a, b, c]) => a % 3 == 1 && b == a + 1 && c == a + 2,
+            (Self::Corner, &[a, b, c, d]) => {
+                (1..=32).contains(&a) && a % 3 != 0 && b == a + 1 && c == a + 3 && d == a + 4
+            }
+            (Self::Dozen(n), []) => {
+                return Some(((n - 1) * 12 + 1..=n * 12).collect());
+            }
+            (Self::Column(n), []) => {
+                return Some((1..=36).filter(|pocket| pocket % 3 == n % 3).collect());
+            }
+            (Self::Red, []) => return Some(RED.to_vec()),
+            (Self::Black, []) => {
+                return Some((1..=36).filter(|pocket| !RED.contains(pocket)).collect());
+            }
+            (Self::Odd, []) => return Some((1..=36).filter(|pocket| pocket % 2 == 1).collect()),
+            (Self::Even, []) => return Some((1..=36).filter(|pocket| pocket % 2 == 0).collect()),
+            _ => false,
+        };
+
+        valid.then_some(numbers)
+    }
+}
+
+impl BetKind {
+    fn name(self, locale: &str) -> String {
+        match self {
+            Self::Straight => t!(locale, "gambling.roulette.straight"),
+            Self::Split => t!(locale, "gambling.roulette.split"),
+            Self::Street => t!(locale, "gambling.roulette.street"),
+            Self::Corner => t!(locale, "gambling.roulette.corner"),
+            Self::Dozen(1) => t!(locale, "gambling.roulette.dozen_1"),
+            Self::Dozen(2) => t!(locale, "gambling.roulette.dozen_2"),
+            Self::Dozen(_) => t!(locale, "gambling.roulette.dozen_3"),
+            Self::Column(n) => t!(locale, "gambling.roulette.column", number = n),
+            Self::Red => t!(locale, "gambling.roulette.red"),
+            Self::Black => t!(locale, "gambling.roulette.black"),
+            Self::Odd => t!(locale, "gambling.roulette.odd"),
+            Self::Even => t!(locale, "gambling.roulette.even"),
+        }
+    }
+}
+
+impl FromStr for BetKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        KINDS.into_iter().find(|kind| kind.id() == s).ok_or(())
+    }
+}
+
+/// A bet on the slip, with the pockets it covers.
+#[derive(Debug, Serialize, Deserialize)]
+struct Bet {
+    kind: BetKind,
+    pockets: Vec<u8>,
+    amount: i64,
+}
+
+impl Bet {
+    /// What the bet returns if it wins, including the stake. Every bet pays
+    /// as if the wheel had 36 pockets, which is where the zeros give the
+    /// house its edge.
+    fn payout(&self) -> i64 {
+        self.amount * 36 / self.pockets.len() as i64
+    }
+
+    /// The bet as a line of the slip.
+    fn line(&self, locale: &str) -> String {
+        let mut line = format!("**{}**", self.kind.name(locale));
+
+        if self.kind.numbers().is_some() {
+            let numbers = self
+                .pockets
+                .iter()
+                .map(|pocket| pocket_name(*pocket))
+                .collect::<Vec<_>>();
+            line.push_str(&format!(" ({})", numbers.join(", ")));
+        }
+
+        format!("{line}: {}", coins_str(self.amount))
+    }
+}
+
+/// A bet slip waiting to be spun. No coins are taken until the spin.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Roulette {
+    economy: Economy,
+    user: UserId,
+    wheel: Wheel,
+    bets: Vec<Bet>,
+    /// The player's locale, which the slip is shown in. Slips stored before
+    /// it was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
+}
+
+impl Roulette {
+    /// Leaves the slip unspun, which costs the player nothing.
+    pub(crate) fn time_out(self) -> TimedOut {
+        TimedOut {
+            economy: self.economy,
+            source: "roulette",
+            refunds: Vec::new(),
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.roulette.name"))
+                .description(t!(&self.locale, "gambling.roulette.timed_out"))
+                .colour(Colour::TEAL),
+        }
+    }
+
+    fn message(&self) -> CreateInteractionResponseMessage {
+        CreateInteractionResponseMessage::new()
+            .embed(self.slip_embed())
+            .components(self.components())
+    }
+
+    fn slip_embed(&self) -> CreateEmbed {
+        let locale = self.locale.as_str();
+
+        let slip = if self.bets.is_empty() {
+            t!(locale, "gambling.roulette.empty_slip")
+        } else {
+            self.bets
+                .iter()
+                .map(|bet| bet.line(locale))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let total = self.bets.iter().map(|bet| bet.amount).sum::<i64>();
+
+        CreateEmbed::new()
+            .title(t!(
+                locale,
+                "gambling.roulette.slip_title",
+                wheel = self.wheel.name(locale)
+            ))
+            .description(format!(
+                "{slip}\n\n{}",
+                t!(
+                    locale,
+                    "gambling.roulette.total_bet",
+                    bet = coins_str(total)
+                )
+            ))
+            .colour(Colour::TEAL)
+    }
+
+    fn components(&self) -> Vec<CreateActionRow> {
+        let locale = self.locale.as_str();
+
+        let options = KINDS
+            .into_iter()
+            .map(|kind| CreateSelectMenuOption::new(kind.name(locale), kind.id()))
+            .collect();
+
+        let menu = CreateSelectMenu::new("roulette_bet", CreateSelectMenuKind::String { options })
+            .placeholder(t!(locale, "gambling.roulette.add_bet"))
+            .disabled(self.bets.len() >= MAX_BETS);
+
+        let spin = CreateButton::new("roulette_spin")
+            .label(t!(locale, "gambling.roulette.spin"))
+            .style(ButtonStyle::Success)
+            .disabled(self.bets.is_empty());
+        let clear = CreateButton::new("roulette_clear")
+            .label(t!(locale, "gambling.roulette.clear"))
+            .style(ButtonStyle::Secondary)
+            .disabled(self.bets.is_empty());
+
+        vec![
+            CreateActionRow::SelectMenu(menu),
+            CreateActionRow::Buttons(vec![spin, clear]),
+        ]
+    }
+
+    fn result_embed(&self, pocket: u8, bet: i64, payout: i64, coins: i64) -> CreateEmbed {
+        let locale = self.locale.as_str();
+
+        let bets = self
+            .bets
+            .iter()
+            .map(|slip| {
+                let mark = if slip.pockets.contains(&pocket) {
+                    '✅'
+                } else {
+                    '❌'
+                };
+                format!("{mark} {}", slip.line(locale))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (title, colour) = if payout > bet {
+            ("gambling.result.won", Colour::DARK_GREEN)
+        } else {
+            ("gambling.result.lost", Colour::RED)
+        };
+
+        CreateEmbed::new()
+            .title(t!(
+                locale,
+                title,
+                game = t!(locale, "gambling.roulette.name")
+            ))
+            .description(format!(
+                "{}\n\n{bets}\n\n{}\n{}",
+                t!(
+                    locale,
+                    "gambling.roulette.landed_on",
+                    colour = pocket_colour(pocket),
+                    pocket = pocket_name(pocket)
+                ),
+                t!(
+                    locale,
+                    "gambling.payout",
+                    payout = payout.format(),
+                    profit = (payout - bet).format()
+                ),
+                t!(locale, "gambling.your_coins", coins = coins.format()),
+            ))
+            .colour(colour)
+    }
+}
+
+impl From<Roulette> for Session {
+    fn from(game: Roulette) -> Self {
+        Self::Roulette(game)
+    }
+}
+
+fn pocket_name(pocket: u8) -> String {
+    match pocket {
+        DOUBLE_ZERO => String::from("00"),
+        pocket => pocket.to_string(),
+    }
+}
+
+fn pocket_colour(pocket: u8) -> char {
+    if pocket == 0 || pocket == DOUBLE_ZERO {
+        '🟢'
+    } else if RED.contains(&pocket) {
+        '🔴'
+    } else {
+        '⚫'
+    }
+}
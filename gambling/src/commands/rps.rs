@@ -1,4 +1,4 @@
-use std::{fmt::Display, str::FromStr};
+use std::str::FromStr;
 
 use rand::seq::IndexedRandom;
 use serenity::all::{
@@ -6,11 +6,15 @@ use serenity::all::{
     CreateEmbed, EditInteractionResponse, ResolvedOption, ResolvedValue,
 };
 use sqlx::{Database, Pool};
-use zayden_core::{FormatNum, parse_options};
+use zayden_core::{Cooldowns, FormatNum, i18n, parse_options, t};
 
 use crate::events::{Dispatch, Event, GameEvent};
 use crate::models::GamblingManager;
-use crate::{COIN, Coins, EffectsManager, GameCache, GameManager, GameRow, GoalsManager, Result};
+use crate::utils::coins_str;
+use crate::{
+    Coins, Economies, EffectsManager, GAME_COOLDOWN, GameManager, GameRow, Gems, GoalsManager,
+    Ledger, LedgerManager, Result,
+};
 
 use super::Commands;
 
@@ -21,6 +25,7 @@ impl Commands {
         GoalHandler: GoalsManager<Db>,
         EffectsHandler: EffectsManager<Db> + Send,
         GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -29,6 +34,8 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let mut options = parse_options(options);
 
         let Some(ResolvedValue::String(selection)) = options.remove("selection") else {
@@ -40,13 +47,20 @@ impl Commands {
             unreachable!("bet is required")
         };
 
-        let mut row = GameHandler::row(pool, interaction.user.id)
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
             .await?
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
-        EffectsHandler::bet_limit::<GamblingHandler>(pool, interaction.user.id, bet, row.coins())
-            .await?;
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
         row.bet(bet);
 
         let computer_choice = *CHOICES.choose(&mut rand::rng()).unwrap();
@@ -60,7 +74,7 @@ impl Commands {
             0
         };
 
-        Dispatch::<Db, GoalHandler>::new(pool)
+        Dispatch::<Db, GoalHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Game(GameEvent::new(
@@ -72,37 +86,50 @@ impl Commands {
             )
             .await?;
 
-        payout = EffectsHandler::payout(pool, interaction.user.id, bet, payout, winner).await;
+        payout =
+            EffectsHandler::payout(pool, economy, interaction.user.id, bet, payout, winner).await;
 
         row.add_coins(payout);
 
         let coins = row.coins();
+        let ledger = Ledger::new(economy, interaction.user.id, "rps")
+            .reference(interaction.id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
 
-        GameHandler::save(pool, row).await?;
-        GameCache::update(ctx, interaction.user.id).await;
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+        let game = t!(locale, "gambling.rps.name");
 
         let title = if winner == Some(true) {
-            "Rock 🪨 Paper 🗞️ Scissors ✂ - You Won!"
+            t!(locale, "gambling.result.won", game = game)
         } else if winner == Some(false) {
-            "Rock 🪨 Paper 🗞️ Scissors ✂ - You Lost!"
+            t!(locale, "gambling.result.lost", game = game)
         } else {
-            "Rock 🪨 Paper 🗞️ Scissors ✂ - You Tied!"
+            t!(locale, "gambling.result.draw", game = game)
         };
 
         let desc = format!(
-            "Your bet: {} <:coin:{COIN}>
-        
-            **You picked:** {} ({user_choice})
-            **Zayden picked:** {} ({computer_choice})
-            
-            Payout: {} ({})
-            Your coins: {}",
-            bet.format(),
+            "{}\n\n**{}:** {} ({})\n**{}:** {} ({})\n\n{}\n{}",
+            t!(locale, "gambling.your_bet", bet = coins_str(bet)),
+            t!(locale, "gambling.rps.you_picked"),
             user_choice.emoji(),
+            user_choice.name(locale),
+            t!(locale, "gambling.rps.bot_picked"),
             computer_choice.emoji(),
-            payout.format(),
-            (payout - bet).format(),
-            coins.format()
+            computer_choice.name(locale),
+            t!(
+                locale,
+                "gambling.payout",
+                payout = payout.format(),
+                profit = (payout - bet).format()
+            ),
+            t!(locale, "gambling.your_coins", coins = coins.format())
         );
 
         let colour = if winner == Some(true) {
@@ -174,14 +201,12 @@ impl RPSChoice {
             Self::Scissors => "✂",
         }
     }
-}
 
-impl Display for RPSChoice {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn name(&self, locale: &str) -> String {
         match self {
-            RPSChoice::Rock => write!(f, "Rock"),
-            RPSChoice::Paper => write!(f, "Paper"),
-            RPSChoice::Scissors => write!(f, "Scissors"),
+            Self::Rock => t!(locale, "gambling.rps.rock"),
+            Self::Paper => t!(locale, "gambling.rps.paper"),
+            Self::Scissors => t!(locale, "gambling.rps.scissors"),
         }
     }
 }
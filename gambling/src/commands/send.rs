@@ -5,12 +5,13 @@ use serenity::all::{
 };
 use sqlx::any::AnyQueryResult;
 use sqlx::{Database, Pool};
-use zayden_core::{FormatNum, parse_options};
+use zayden_core::{i18n, parse_options, t};
 
 use crate::events::{Dispatch, Event, SendEvent};
+use crate::utils::coins_str;
 use crate::{
-    COIN, Coins, Commands, Error, Gems, GoalsManager, MaxBet, Prestige, Result, ShopCurrency,
-    Stamina, StaminaManager,
+    Coins, Commands, Economies, Economy, Error, Gems, GoalsManager, Ledger, LedgerManager, MaxBet,
+    Prestige, Result, ShopCurrency, Stamina, StaminaManager,
 };
 
 pub struct SendRow {
@@ -81,15 +82,25 @@ impl Prestige for SendRow {
 
 #[async_trait]
 pub trait SendManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<SendRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<SendRow>>;
 
+    /// Returns the user's new balance, or `None` if they have never played.
     async fn add_coins(
-        pool: &Pool<Db>,
+        conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult>;
+    ) -> sqlx::Result<Option<i64>>;
 
-    async fn save(pool: &Pool<Db>, row: SendRow) -> sqlx::Result<AnyQueryResult>;
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: SendRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 impl Commands {
@@ -98,6 +109,7 @@ impl Commands {
         StaminaHandler: StaminaManager<Db>,
         GoalHandler: GoalsManager<Db>,
         SendHandler: SendManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -106,6 +118,8 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
         let mut options = parse_options(options);
 
         let Some(ResolvedValue::User(recipient, _)) = options.remove("recipient") else {
@@ -124,7 +138,10 @@ impl Commands {
             return Err(Error::NegativeAmount);
         }
 
-        let mut row = match SendHandler::row(pool, interaction.user.id).await.unwrap() {
+        let mut row = match SendHandler::row(pool, economy, interaction.user.id)
+            .await
+            .unwrap()
+        {
             Some(row) => row,
             None => SendRow::new(interaction.user.id),
         };
@@ -143,15 +160,14 @@ impl Commands {
             return Err(Error::MaximumSendAmount(max_send));
         }
 
+        let (start_coins, start_gems) = (row.coins(), row.gems());
         *row.coins_mut() -= amount;
 
-        SendHandler::add_coins(pool, recipient.id, amount).await?;
-
         row.done_work();
 
         let stamina = row.stamina_str();
 
-        Dispatch::<Db, GoalHandler>::new(pool)
+        Dispatch::<Db, GoalHandler>::new(pool, economy)
             .fire(
                 &mut row,
                 Event::Send(SendEvent::new(amount, interaction.user.id)),
@@ -159,12 +175,38 @@ impl Commands {
             .await
             .unwrap();
 
-        SendHandler::save(pool, row).await?;
+        let ledger = Ledger::new(economy, interaction.user.id, "send")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins())
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+
+        if let Some(balance) =
+            SendHandler::add_coins(&mut *tx, economy, recipient.id, amount).await?
+        {
+            Ledger::new(economy, recipient.id, "send")
+                .reference(interaction.id)
+                .coins(balance - amount, balance)
+                .record::<Db, LedgerHandler>(&mut *tx)
+                .await?;
+        }
+
+        SendHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
 
         let embed = CreateEmbed::new().description(format!(
-            "You sent {} <:coin:{COIN}> to {}\nStamina: {stamina}",
-            amount.format(),
-            recipient.mention()
+            "{}\n{}",
+            t!(
+                locale,
+                "gambling.send.sent",
+                coins = coins_str(amount),
+                user = recipient.mention()
+            ),
+            t!(locale, "gambling.stamina", stamina = stamina)
         ));
 
         interaction
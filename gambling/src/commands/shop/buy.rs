@@ -2,11 +2,13 @@ use serenity::all::{
     CommandInteraction, Context, EditInteractionResponse, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool, prelude::FromRow, types::Json};
-use zayden_core::{FormatNum, parse_options};
+use zayden_core::{
+    FormatNum, PermissionLevel, PermissionsManager, i18n, parse_options, permissions, t,
+};
 
 use crate::{
-    Coins, Error, Gems, GoalsManager, ItemInventory, MaxBet, MaxValues, Prestige, Result,
-    SHOP_ITEMS, SUPER_USER, ShopCurrency, ShopItem, ShopPage,
+    Coins, Economies, Error, Gems, GoalsManager, ItemInventory, Ledger, LedgerManager, MaxBet,
+    MaxValues, Prestige, Result, SHOP_ITEMS, ShopCurrency, ShopItem, ShopPage,
     commands::shop::ShopManager,
     events::{Dispatch, Event, ShopPurchaseEvent},
     models::{GamblingItem, Mining},
@@ -184,12 +186,20 @@ impl MaxBet for BuyRow {
     }
 }
 
-pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopManager<Db>>(
+pub async fn buy<
+    Db: Database,
+    GoalsHandler: GoalsManager<Db>,
+    BuyHandler: ShopManager<Db>,
+    PermsHandler: PermissionsManager<Db>,
+    LedgerHandler: LedgerManager<Db>,
+>(
     ctx: &Context,
     interaction: &CommandInteraction,
     pool: &Pool<Db>,
     options: Vec<ResolvedOption<'_>>,
 ) -> Result<()> {
+    let economy = Economies::get(ctx, interaction.guild_id).await;
+
     let mut options = parse_options(options);
 
     let Some(ResolvedValue::String(item)) = options.remove("item") else {
@@ -204,7 +214,7 @@ pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopM
         unreachable!("amount is required")
     };
 
-    let mut row = match BuyHandler::buy_row(pool, interaction.user.id).await? {
+    let mut row = match BuyHandler::buy_row(pool, economy, interaction.user.id).await? {
         Some(row) => row,
         None => BuyRow::new(interaction.user.id),
     };
@@ -219,7 +229,14 @@ pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopM
     let amount: i64 = match amount.parse() {
         Ok(x) => x,
         Err(_) if amount == "a" => {
-            if SUPER_USER != interaction.user.id {
+            if !permissions::has_level::<Db, PermsHandler>(
+                pool,
+                interaction.user.id,
+                interaction.member.as_deref(),
+                PermissionLevel::Owner,
+            )
+            .await?
+            {
                 return Err(Error::PremiumRequired);
             }
 
@@ -250,6 +267,8 @@ pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopM
         })
         .collect::<Result<Vec<_>>>()?;
 
+    let (start_coins, start_gems) = (row.coins(), row.gems());
+
     for (cost, currency) in costs.iter().copied() {
         let funds = match currency {
             ShopCurrency::Coins => row.coins_mut(),
@@ -275,14 +294,22 @@ pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopM
         edit_inv(&mut row, item, amount)
     };
 
-    Dispatch::<Db, GoalsHandler>::new(pool)
+    Dispatch::<Db, GoalsHandler>::new(pool, economy)
         .fire(
             &mut row,
             Event::ShopPurchase(ShopPurchaseEvent::new(interaction.user.id, item.id)),
         )
         .await?;
 
-    BuyHandler::buy_save(pool, row).await.unwrap();
+    let ledger = Ledger::new(economy, interaction.user.id, "shop")
+        .reference(interaction.id)
+        .coins(start_coins, row.coins())
+        .gems(start_gems, row.gems());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    BuyHandler::buy_save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
 
     let cost = costs
         .into_iter()
@@ -292,11 +319,12 @@ pub async fn buy<Db: Database, GoalsHandler: GoalsManager<Db>, BuyHandler: ShopM
     interaction
         .edit_response(
             ctx,
-            EditInteractionResponse::new().content(format!(
-                "You bought {} {item} for {}\nYou now have {}.",
-                amount.format(),
-                cost.join("\n"),
-                quantity.format()
+            EditInteractionResponse::new().content(t!(
+                i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+                "gambling.shop.bought",
+                item = format!("{} {item}", amount.format()),
+                cost = cost.join("\n"),
+                quantity = quantity.format()
             )),
         )
         .await?;
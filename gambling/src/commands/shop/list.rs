@@ -1,23 +1,18 @@
-use std::time::Duration;
-
 use async_trait::async_trait;
-use futures::StreamExt;
 use serenity::all::{
-    ButtonStyle, CommandInteraction, Context, CreateActionRow, CreateButton, CreateEmbed,
-    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
-    ReactionType, ResolvedOption, ResolvedValue, UserId,
+    CommandInteraction, Context, CreateEmbed, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, FromRow, Pool, any::AnyQueryResult, types::Json};
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, Paginator, i18n, t};
 
 use crate::{
-    COIN, Coins, GamblingItem, ItemInventory, Result, SHOP_ITEMS, ShopPage,
+    COIN, Coins, Economies, Economy, GamblingItem, ItemInventory, Result, SHOP_ITEMS, ShopPage,
     commands::shop::ShopManager, shop::SALES_TAX,
 };
 
 #[async_trait]
 pub trait ListManager<Db: Database> {
-    async fn save(pool: &Pool<Db>, row: ListRow) -> sqlx::Result<AnyQueryResult>;
+    async fn save(pool: &Pool<Db>, economy: Economy, row: ListRow) -> sqlx::Result<AnyQueryResult>;
 }
 
 #[derive(FromRow)]
@@ -68,110 +63,37 @@ pub async fn list<Db: Database, Manager: ShopManager<Db>>(
     pool: &Pool<Db>,
     mut options: Vec<ResolvedOption<'_>>,
 ) -> Result<()> {
+    let economy = Economies::get(ctx, interaction.guild_id).await;
+    let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
     let page = match options.pop().map(|opt| opt.value) {
         Some(ResolvedValue::String(page)) => page.parse().unwrap(),
         _ => ShopPage::Item,
     };
 
-    let row = match Manager::list_row(pool, interaction.user.id).await.unwrap() {
+    let row = match Manager::list_row(pool, economy, interaction.user.id)
+        .await
+        .unwrap()
+    {
         Some(row) => row,
         None => ListRow::new(interaction.user.id),
     };
 
-    let embed = create_embed(page, &row);
-
-    let left_arrow = ReactionType::Unicode(String::from("⬅️"));
-    let right_arrow = ReactionType::Unicode(String::from("➡️"));
-
-    let prev = CreateButton::new("shop_prev")
-        .emoji(left_arrow)
-        .style(ButtonStyle::Secondary);
-    let next = CreateButton::new("shop_next")
-        .emoji(right_arrow)
-        .style(ButtonStyle::Secondary);
-
-    let msg = interaction
-        .edit_response(
-            ctx,
-            EditInteractionResponse::new()
-                .embed(embed)
-                .components(vec![CreateActionRow::Buttons(vec![prev, next])]),
-        )
-        .await?;
-
-    let mut stream = msg
-        .await_component_interactions(ctx)
-        .timeout(Duration::from_secs(120))
-        .stream();
-
-    while let Some(interaction) = stream.next().await {
-        let title = interaction
-            .message
-            .embeds
-            .first()
-            .and_then(|embed| embed.title.as_deref());
-
-        let (embed, components) = if interaction.data.custom_id == "shop_next" {
-            shop(&row, title, 1)
-        } else {
-            shop(&row, title, -1)
-        };
-
-        interaction
-            .create_response(
-                ctx,
-                CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new()
-                        .embed(embed)
-                        .components(components),
-                ),
-            )
-            .await
-            .unwrap();
-    }
-
-    interaction
-        .edit_response(ctx, EditInteractionResponse::new().components(Vec::new()))
-        .await?;
-
-    Ok(())
-}
-
-fn shop(
-    row: &ListRow,
-    title: Option<&str>,
-    page_change: i8,
-) -> (CreateEmbed, Vec<CreateActionRow>) {
-    let current_cat = title
-        .map(|title| title.strip_suffix(" Shop").unwrap().parse().unwrap())
-        .unwrap_or(ShopPage::Item);
-
-    let category_idx = ShopPage::pages()
+    let start = ShopPage::pages()
         .iter()
-        .position(|cat| *cat == current_cat)
-        .unwrap() as i8;
-
-    let category = ShopPage::pages()
-        .get(usize::try_from(category_idx + page_change).unwrap_or_default())
-        .copied()
-        .unwrap_or(ShopPage::Item);
-
-    let embed = create_embed(category, row);
-
-    let left_arrow = ReactionType::Unicode(String::from("⬅️"));
-    let right_arrow = ReactionType::Unicode(String::from("➡️"));
-
-    let prev = CreateButton::new("shop_prev")
-        .emoji(left_arrow)
-        .style(ButtonStyle::Secondary);
-    let next = CreateButton::new("shop_next")
-        .emoji(right_arrow)
-        .style(ButtonStyle::Secondary);
-
-    (embed, vec![CreateActionRow::Buttons(vec![prev, next])])
+        .position(|category| *category == page)
+        .unwrap_or_default();
+
+    let row = &row;
+    Paginator::new(ShopPage::pages().len(), |page| async move {
+        Ok(create_embed(locale, ShopPage::pages()[page - 1], row))
+    })
+    .start(start + 1)
+    .run(ctx, interaction)
+    .await
 }
 
-fn create_embed(category: ShopPage, row: &ListRow) -> CreateEmbed {
+fn create_embed(locale: &str, category: ShopPage, row: &ListRow) -> CreateEmbed {
     let inv = row.inventory();
 
     let items = SHOP_ITEMS
@@ -193,11 +115,17 @@ fn create_embed(category: ShopPage, row: &ListRow) -> CreateEmbed {
             }
 
             s.push_str(&format!(
-                "\nOwned: `{}`\nCost:",
-                inv.iter()
-                    .find(|inv_item| inv_item.item_id == item.id)
-                    .map(|item| item.quantity)
-                    .unwrap_or_default()
+                "\n{}\n{}",
+                t!(
+                    locale,
+                    "gambling.shop.owned",
+                    amount = inv
+                        .iter()
+                        .find(|inv_item| inv_item.item_id == item.id)
+                        .map(|item| item.quantity)
+                        .unwrap_or_default()
+                ),
+                t!(locale, "gambling.shop.cost")
             ));
 
             if costs.len() == 1 {
@@ -214,12 +142,22 @@ fn create_embed(category: ShopPage, row: &ListRow) -> CreateEmbed {
         .join("\n\n");
 
     let desc = format!(
-        "Sales tax: {}%\nYour coins: {}  <:coin:{COIN}>\n--------------------\n{items}\n--------------------\nBuy with `/shop buy <item> <amount>`\nSell with `/shop sell <item> <amount>`",
-        SALES_TAX * 100.0,
-        row.coins_str()
+        "{}\n{}\n--------------------\n{items}\n--------------------\n{}\n{}",
+        t!(
+            locale,
+            "gambling.shop.sales_tax",
+            percent = SALES_TAX * 100.0
+        ),
+        t!(
+            locale,
+            "gambling.your_coins",
+            coins = format!("{}  <:coin:{COIN}>", row.coins_str())
+        ),
+        t!(locale, "gambling.shop.buy_with"),
+        t!(locale, "gambling.shop.sell_with")
     );
 
     CreateEmbed::new()
-        .title(format!("{} Shop", category))
+        .title(t!(locale, "gambling.shop.title", category = category))
         .description(desc)
 }
@@ -5,6 +5,7 @@ use serenity::all::{
 };
 use sqlx::any::AnyQueryResult;
 use sqlx::{Database, Pool};
+use zayden_core::PermissionsManager;
 
 pub mod buy;
 pub mod list;
@@ -14,28 +15,41 @@ pub use buy::{BuyRow, buy};
 pub use list::{ListRow, list};
 pub use sell::{SellRow, sell};
 
-use crate::{GoalsManager, Result, SHOP_ITEMS, ShopPage};
+use crate::{Economy, GoalsManager, LedgerManager, Result, SHOP_ITEMS, ShopPage};
 
 use super::Commands;
 
 #[async_trait]
 pub trait ShopManager<Db: Database> {
-    async fn buy_row(pool: &Pool<Db>, id: impl Into<UserId> + Send)
-    -> sqlx::Result<Option<BuyRow>>;
+    async fn buy_row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<BuyRow>>;
 
-    async fn buy_save(pool: &Pool<Db>, row: BuyRow) -> sqlx::Result<AnyQueryResult>;
+    async fn buy_save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: BuyRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 
     async fn list_row(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<ListRow>>;
 
     async fn sell_row(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<SellRow>>;
 
-    async fn sell_save(pool: &Pool<Db>, row: SellRow) -> sqlx::Result<AnyQueryResult>;
+    async fn sell_save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: SellRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 impl Commands {
@@ -43,6 +57,8 @@ impl Commands {
         Db: Database,
         GoalsHandler: GoalsManager<Db>,
         ShopHandler: ShopManager<Db>,
+        PermsHandler: PermissionsManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -59,8 +75,18 @@ impl Commands {
 
         match command.name {
             "list" => list::<Db, ShopHandler>(ctx, interaction, pool, options).await?,
-            "buy" => buy::<Db, GoalsHandler, ShopHandler>(ctx, interaction, pool, options).await?,
-            "sell" => sell::<Db, ShopHandler>(ctx, interaction, pool, options).await?,
+            "buy" => {
+                buy::<Db, GoalsHandler, ShopHandler, PermsHandler, LedgerHandler>(
+                    ctx,
+                    interaction,
+                    pool,
+                    options,
+                )
+                .await?
+            }
+            "sell" => {
+                sell::<Db, ShopHandler, LedgerHandler>(ctx, interaction, pool, options).await?
+            }
             _ => unreachable!("Invalid subcommand name"),
         };
 
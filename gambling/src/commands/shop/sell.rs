@@ -4,12 +4,13 @@ use serenity::all::{
 use sqlx::prelude::FromRow;
 use sqlx::types::Json;
 use sqlx::{Database, Pool};
-use zayden_core::{FormatNum, parse_options};
+use zayden_core::{FormatNum, i18n, parse_options, t};
 
 use crate::commands::shop::ShopManager;
 use crate::models::{GamblingItem, ItemInventory};
 use crate::shop::SALES_TAX;
-use crate::{COIN, Coins, Error, Result, SHOP_ITEMS};
+use crate::utils::coins_str;
+use crate::{Coins, Economies, Error, Ledger, LedgerManager, Result, SHOP_ITEMS};
 
 #[derive(FromRow)]
 pub struct SellRow {
@@ -53,12 +54,14 @@ impl ItemInventory for SellRow {
     }
 }
 
-pub async fn sell<Db: Database, Manager: ShopManager<Db>>(
+pub async fn sell<Db: Database, Manager: ShopManager<Db>, LedgerHandler: LedgerManager<Db>>(
     ctx: &Context,
     interaction: &CommandInteraction,
     pool: &Pool<Db>,
     options: Vec<ResolvedOption<'_>>,
 ) -> Result<()> {
+    let economy = Economies::get(ctx, interaction.guild_id).await;
+
     let mut options = parse_options(options);
 
     let Some(ResolvedValue::String(item)) = options.remove("item") else {
@@ -78,7 +81,10 @@ pub async fn sell<Db: Database, Manager: ShopManager<Db>>(
         .expect("Preset choices so item should always exist");
     let payment = ((item.coin_cost().unwrap() as f64) * (amount as f64) * (1.0 - SALES_TAX)) as i64;
 
-    let mut row = match Manager::sell_row(pool, interaction.user.id).await.unwrap() {
+    let mut row = match Manager::sell_row(pool, economy, interaction.user.id)
+        .await
+        .unwrap()
+    {
         Some(row) => row,
         None => SellRow::new(interaction.user.id),
     };
@@ -98,17 +104,26 @@ pub async fn sell<Db: Database, Manager: ShopManager<Db>>(
 
     let quantity = row.edit_item_quantity(item.id, -amount).unwrap();
 
+    let start_coins = row.coins();
     *row.coins_mut() += payment;
-    Manager::sell_save(pool, row).await.unwrap();
+    let ledger = Ledger::new(economy, interaction.user.id, "shop")
+        .reference(interaction.id)
+        .coins(start_coins, row.coins());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    Manager::sell_save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
 
     interaction
         .edit_response(
             ctx,
-            EditInteractionResponse::new().content(format!(
-                "You sold {} {item} for {} <:coin:{COIN}>\nYou now have {}.",
-                amount.format(),
-                payment.format(),
-                quantity.format()
+            EditInteractionResponse::new().content(t!(
+                i18n::locale(&interaction.locale, interaction.guild_locale.as_deref()),
+                "gambling.shop.sold",
+                item = format!("{} {item}", amount.format()),
+                cost = coins_str(payment),
+                quantity = quantity.format()
             )),
         )
         .await?;
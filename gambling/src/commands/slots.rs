@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+
+use serenity::all::{
+    Colour, CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, ResolvedOption, ResolvedValue, UserId,
+};
+use serenity::prelude::TypeMapKey;
+use sqlx::{Database, Pool};
+use zayden_core::{Cooldowns, FormatNum, i18n, parse_options, t};
+
+use crate::events::{Dispatch, Event, GameEvent};
+use crate::models::gambling::GamblingManager;
+use crate::slots::{Play, ROWS, emoji};
+use crate::utils::coins_str;
+use crate::{
+    Coins, Economies, EffectsManager, GAME_COOLDOWN, GameManager, GameRow, Gems, GoalsManager,
+    Ledger, LedgerManager, Result, config,
+};
+
+use super::Commands;
+
+impl Commands {
+    pub async fn slots<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalsHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        interaction.defer(ctx).await.unwrap();
+
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
+        let mut options = parse_options(options);
+
+        let Some(ResolvedValue::Integer(bet)) = options.remove("bet") else {
+            unreachable!("bet is required")
+        };
+
+        let mut row = GameHandler::row(pool, economy, interaction.user.id)
+            .await?
+            .unwrap_or_else(|| GameRow::new(interaction.user.id));
+
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
+        row.bet(bet);
+
+        let stops = Stops::take(ctx, interaction.user.id).await;
+        let play = config::slots().play(bet, stops);
+        let winner = play.payout > bet;
+
+        Dispatch::<Db, GoalsHandler>::new(pool, economy)
+            .fire(
+                &mut row,
+                Event::Game(GameEvent::new("slots", interaction.user.id, bet, winner)),
+            )
+            .await?;
+
+        // Free spins belong to the paid spin, so a Lucky Chip only refunds
+        // the bet if they don't win it back, and payout boosts apply to them
+        // too
+        let payout = EffectsHandler::payout(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            play.payout,
+            Some(winner),
+        )
+        .await;
+
+        row.add_coins(payout);
+
+        let coins = row.coins();
+        let ledger = Ledger::new(economy, interaction.user.id, "slots")
+            .reference(interaction.id)
+            .coins(start_coins, coins)
+            .gems(start_gems, row.gems());
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        GameHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
+
+        interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(create_embed(locale, &play, bet, payout, coins)),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub fn register_slots() -> CreateCommand {
+        CreateCommand::new("slots")
+            .description("Spin the slot machine")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "bet", "The amount to bet.")
+                    .required(true),
+            )
+    }
+}
+
+/// Where the reels stop on a player's next spin, so that it shows something
+/// known. The reels are spun when nothing is waiting.
+#[derive(Debug, Default)]
+pub struct Stops(HashMap<UserId, Vec<usize>>);
+
+impl Stops {
+    /// The stops waiting for the player, if there are any.
+    async fn take(ctx: &Context, user: UserId) -> Option<Vec<usize>> {
+        let mut data = ctx.data.write().await;
+        let stops = data.entry::<Self>().or_insert_with(Self::default);
+
+        stops.0.remove(&user)
+    }
+
+    /// Sets where each reel stops on the player's next paid spin, by the
+    /// index of the symbol in the middle row. Free spins it wins are still
+    /// random.
+    pub async fn put(ctx: &Context, user: UserId, stops: Vec<usize>) {
+        let mut data = ctx.data.write().await;
+        let waiting = data.entry::<Self>().or_insert_with(Self::default);
+
+        waiting.0.insert(user, stops);
+    }
+}
+
+impl TypeMapKey for Stops {
+    type Value = Stops;
+}
+
+fn create_embed(locale: &str, play: &Play, bet: i64, payout: i64, coins: i64) -> CreateEmbed {
+    let reels = (0..ROWS)
+        .map(|row| {
+            play.window
+                .iter()
+                .map(|reel| emoji(reel[row]))
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut wins = play
+        .wins
+        .iter()
+        .map(|win| {
+            t!(
+                locale,
+                "gambling.slots.line",
+                line = win.line + 1,
+                count = win.count,
+                symbol = emoji(win.symbol),
+                pays = ((bet as f64 * win.pays) as i64).format()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some((free, won)) = play.free_spins {
+        wins.push(t!(
+            locale,
+            "gambling.slots.free_spins",
+            symbol = emoji(&free.symbol),
+            spins = free.spins,
+            multiplier = free.multiplier,
+            won = won.format()
+        ));
+    }
+
+    let wins = if wins.is_empty() {
+        t!(locale, "gambling.slots.no_wins")
+    } else {
+        wins.join("\n")
+    };
+
+    let (title, colour) = if payout > bet {
+        ("gambling.result.won", Colour::DARK_GREEN)
+    } else {
+        ("gambling.result.lost", Colour::RED)
+    };
+
+    CreateEmbed::new()
+        .title(t!(locale, title, game = t!(locale, "gambling.slots.name")))
+        .description(format!(
+            "{}\n\n{reels}\n\n{wins}\n\n{}\n{}",
+            t!(locale, "gambling.your_bet", bet = coins_str(bet)),
+            t!(
+                locale,
+                "gambling.payout",
+                payout = payout.format(),
+                profit = (payout - bet).format()
+            ),
+            t!(locale, "gambling.your_coins", coins = coins.format()),
+        ))
+        .colour(colour)
+}
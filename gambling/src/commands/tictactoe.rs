@@ -1,23 +1,23 @@
-use std::{marker::PhantomData, time::Duration};
-
-use futures::StreamExt;
 use rand::{rng, seq::IndexedRandom};
+use serde::{Deserialize, Serialize};
 use serenity::all::{
-    ActionRow, ActionRowComponent, ButtonStyle, Colour, CommandInteraction, CommandOptionType,
-    ComponentInteraction, Context, CreateActionRow, CreateButton, CreateCommand,
-    CreateCommandOption, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage,
-    EditInteractionResponse, Mentionable, ReactionType, ResolvedOption, ResolvedValue, UserId,
+    ButtonStyle, Colour, CommandInteraction, CommandOptionType, ComponentInteraction, Context,
+    CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse,
+    Mentionable, MessageId, ResolvedOption, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool};
-use zayden_core::parse_options;
+use zayden_core::{Cooldowns, cooldown::Target, i18n, parse_options, t};
 
+use crate::sessions::{Session, SessionManager, SessionRow, Sessions, TimedOut};
 use crate::{
-    BLANK, COIN, Coins, EffectsManager, GameCache, GameManager, GameRow, GoalsManager, Result,
+    BLANK, COIN, Coins, Economies, Economy, EffectsManager, Error, GAME_COOLDOWN, GameManager,
+    GameRow, Gems, GoalsManager, Ledger, LedgerManager, Result,
     events::{Dispatch, Event, GameEvent},
     models::GamblingManager,
 };
 
-use super::Commands;
+use super::{Commands, Components};
 
 const EMOJI_P1: char = '❌';
 const EMOJI_P2: char = '⭕';
@@ -26,9 +26,9 @@ impl Commands {
     pub async fn tictactoe<
         Db: Database,
         GamblingHandler: GamblingManager<Db>,
-        GoalHandler: GoalsManager<Db>,
         EffectsHandler: EffectsManager<Db> + Send,
         GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -37,12 +37,14 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await.unwrap();
 
-        let row = GameHandler::row(pool, interaction.user.id)
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let row = GameHandler::row(pool, economy, interaction.user.id)
             .await
             .unwrap()
             .unwrap_or_else(|| GameRow::new(interaction.user.id));
 
-        GameCache::can_play(ctx, interaction.user.id).await?;
+        Cooldowns::check(ctx, GAME_COOLDOWN, interaction).await?;
 
         let mut options = parse_options(options);
 
@@ -54,31 +56,43 @@ impl Commands {
             unreachable!("bet is required option")
         };
 
-        EffectsHandler::bet_limit::<GamblingHandler>(pool, interaction.user.id, bet, row.coins())
-            .await?;
+        EffectsHandler::bet_limit::<GamblingHandler>(
+            pool,
+            economy,
+            interaction.user.id,
+            bet,
+            row.coins(),
+        )
+        .await?;
 
-        GameHandler::save(pool, row).await.unwrap();
-        GameCache::update(ctx, interaction.user.id).await;
+        Cooldowns::trigger(ctx, GAME_COOLDOWN, interaction).await;
 
-        let embed = CreateEmbed::new().title("TicTacToe").description(format!(
-            "{} wants to play tic-tac-toe ({size}x{size}) for **{bet}** <:coin:{COIN}>",
-            interaction.user.mention(),
-        ));
+        let locale = i18n::guild_locale(interaction.guild_locale.as_deref());
+
+        let embed = CreateEmbed::new()
+            .title(t!(locale, "gambling.tictactoe.name"))
+            .description(t!(
+                locale,
+                "gambling.tictactoe.challenge",
+                player = interaction.user.mention(),
+                size = size,
+                bet = format!("**{bet}** <:coin:{COIN}>")
+            ));
 
         let msg = interaction
             .edit_response(
                 ctx,
                 EditInteractionResponse::new()
-                    .embed(embed.clone())
+                    .embed(embed)
                     .button(
                         CreateButton::new("ttt_accept")
-                            .label("Accept")
+                            .label(t!(locale, "gambling.tictactoe.accept"))
                             .emoji('✅')
                             .style(ButtonStyle::Secondary),
                     )
                     .button(
                         CreateButton::new("ttt_cancel")
-                            .label("Cancel")
+                            .label(t!(locale, "gambling.tictactoe.cancel"))
                             .emoji('❌')
                             .style(ButtonStyle::Secondary),
                     ),
@@ -86,94 +100,15 @@ impl Commands {
             .await
             .unwrap();
 
-        let mut stream = msg
-            .await_component_interactions(ctx)
-            .timeout(Duration::from_secs(120))
-            .stream();
-
-        let mut state =
-            GameState::<Db, GameHandler>::new(interaction.user.id, size.parse().unwrap(), bet);
-
-        while let Some(component) = stream.next().await {
-            if !run_component::<Db, GamblingHandler, EffectsHandler, GameHandler>(
-                ctx,
-                interaction,
-                component,
-                pool,
-                &mut state,
-            )
-            .await?
-            {
-                break;
-            }
-        }
-
-        let mut p1_row = state.p1_row(pool).await;
-        let mut p2_row = state.p2_row(pool).await;
-
-        let [p1, p2] = state.players;
-
-        let embed = if let Some(winner) = state.winner {
-            let row = if p1 == winner {
-                &mut p1_row
-            } else {
-                &mut p2_row
-            };
-
-            row.add_coins(bet * 2);
-
-            CreateEmbed::new()
-                .title("TicTacToe")
-                .description(format!("Winner! {} 🎉", winner.mention()))
-                .colour(Colour::DARK_GREEN)
-        } else if p1 != p2 {
-            p1_row.add_coins(bet);
-            p2_row.add_coins(bet);
-
-            CreateEmbed::new()
-                .title("TicTacToe")
-                .description("It's a draw!")
-                .colour(Colour::ORANGE)
-        } else {
-            p1_row.add_coins(bet);
-
-            CreateEmbed::new()
-                .title("TicTacToe")
-                .description("This game timed out after 2 minutes of inactivity")
-                .colour(Colour::TEAL)
-        };
-
-        let dispatch = Dispatch::<Db, GoalHandler>::new(pool);
-
-        dispatch
-            .fire(
-                &mut p1_row,
-                Event::Game(GameEvent::new("rps", p1, state.bet, false)), // TODO: Fix win logic
-            )
-            .await?;
-
-        dispatch
-            .fire(
-                &mut p2_row,
-                Event::Game(GameEvent::new("rps", p2, state.bet, false)), // TODO: Fix win logic
-            )
-            .await?;
-
-        GameHandler::save(pool, p1_row).await?;
-        GameHandler::save(pool, p2_row).await?;
-
-        GameCache::update(ctx, p1).await;
-        GameCache::update(ctx, p2).await;
+        let game = TicTacToe::new(
+            economy,
+            locale,
+            interaction.user.id,
+            size.parse().unwrap(),
+            bet,
+        );
 
-        interaction
-            .edit_response(
-                ctx,
-                EditInteractionResponse::new()
-                    .embed(embed)
-                    .components(Vec::new()),
-            )
-            .await
-            .unwrap();
+        SessionHandler::insert(pool, &SessionRow::new(msg.id, msg.channel_id, game)).await?;
 
         Ok(())
     }
@@ -199,305 +134,397 @@ impl Commands {
     }
 }
 
-struct GameState<Db: Database, Manager: GameManager<Db>> {
-    size: usize,
-    players: [UserId; 2],
-    current_turn: UserId,
-    bet: i64,
-    winner: Option<UserId>,
+impl Components {
+    pub async fn tictactoe<
+        Db: Database,
+        GamblingHandler: GamblingManager<Db>,
+        GoalHandler: GoalsManager<Db>,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        ctx: &Context,
+        interaction: &ComponentInteraction,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        let (Session::TicTacToe(mut game), version) =
+            Sessions::get::<Db, SessionHandler>(pool, interaction).await?
+        else {
+            return Err(Error::GameEnded);
+        };
 
-    _db: PhantomData<Db>,
-    _manager: PhantomData<Manager>,
-}
+        let economy = game.economy;
+        let user = interaction.user.id;
+        let custom_id = interaction.data.custom_id.as_str();
 
-impl<Db, Manager> GameState<Db, Manager>
-where
-    Db: Database,
-    Manager: GameManager<Db>,
-{
-    fn new(p1: impl Into<UserId>, size: usize, bet: i64) -> Self {
-        let p1 = p1.into();
+        if game.accepted() && !game.players.contains(&user) {
+            return Err(Error::NotPlayer);
+        }
 
-        Self {
-            size,
-            players: [p1, p1],
-            current_turn: p1,
-            bet,
-            winner: None,
+        if custom_id == "ttt_cancel" {
+            if user != game.players[0] || game.accepted() {
+                return acknowledge(ctx, interaction).await;
+            }
 
-            _db: PhantomData,
-            _manager: PhantomData,
-        }
-    }
+            Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
 
-    async fn p1_row(&self, pool: &Pool<Db>) -> GameRow {
-        let id = self.players[0];
+            let embed = CreateEmbed::new()
+                .title(t!(&game.locale, "gambling.tictactoe.name"))
+                .description(t!(&game.locale, "gambling.tictactoe.cancelled"));
 
-        Manager::row(pool, id)
-            .await
-            .unwrap()
-            .unwrap_or_else(|| GameRow::new(id))
-    }
+            interaction
+                .create_response(
+                    ctx,
+                    CreateInteractionResponse::UpdateMessage(
+                        CreateInteractionResponseMessage::new()
+                            .embed(embed)
+                            .components(Vec::new()),
+                    ),
+                )
+                .await?;
 
-    async fn p2_row(&self, pool: &Pool<Db>) -> GameRow {
-        let id = self.players[1];
+            return Ok(());
+        }
 
-        Manager::row(pool, id)
-            .await
-            .unwrap()
-            .unwrap_or_else(|| GameRow::new(id))
-    }
-}
+        if custom_id == "ttt_accept" {
+            if user == game.players[0] || game.accepted() {
+                return acknowledge(ctx, interaction).await;
+            }
 
-async fn run_component<
-    Db: Database,
-    GamblingHandler: GamblingManager<Db>,
-    EffectsHandler: EffectsManager<Db> + Send,
-    GameHandler: GameManager<Db>,
->(
-    ctx: &Context,
-    interaction: &CommandInteraction,
-    component: ComponentInteraction,
-    pool: &Pool<Db>,
-    state: &mut GameState<Db, GameHandler>,
-) -> Result<bool> {
-    let custom_id = &component.data.custom_id;
+            game.players[1] = user;
 
-    if custom_id == "ttt_cancel" && component.user == interaction.user {
-        let embed = CreateEmbed::new()
-            .title("TicTacToe")
-            .description("Game cancelled");
+            let p1_row = game.row::<Db, GameHandler>(pool, game.players[0]).await?;
+            let p2_row = game.row::<Db, GameHandler>(pool, game.players[1]).await?;
 
-        let msg = CreateInteractionResponseMessage::new()
-            .embed(embed)
-            .components(Vec::new());
+            EffectsHandler::bet_limit::<GamblingHandler>(
+                pool,
+                economy,
+                game.players[0],
+                game.bet,
+                p1_row.coins(),
+            )
+            .await?;
+            EffectsHandler::bet_limit::<GamblingHandler>(
+                pool,
+                economy,
+                game.players[1],
+                game.bet,
+                p2_row.coins(),
+            )
+            .await?;
 
-        component
-            .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
-            .await
-            .unwrap();
+            game.turn = *game.players.choose(&mut rng()).unwrap();
+
+            let msg = game.board_message();
+            let bet = game.bet;
+            Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
+
+            for mut row in [p1_row, p2_row] {
+                let start = (row.coins(), row.gems());
+                row.add_coins(-bet);
+                save::<Db, GameHandler, LedgerHandler>(
+                    pool,
+                    economy,
+                    interaction.message.id,
+                    row,
+                    start,
+                )
+                .await?;
+            }
 
-        return Ok(false);
-    }
+            interaction
+                .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                .await?;
 
-    if custom_id == "ttt_accept" && component.user == interaction.user {
-        return Ok(true);
-    }
+            return Ok(());
+        }
 
-    if custom_id == "ttt_accept" {
-        let msg = accept::<Db, GamblingHandler, EffectsHandler, GameHandler>(
-            pool,
-            state,
-            component.user.id,
-        )
-        .await?;
+        if !game.accepted() || user != game.turn {
+            return acknowledge(ctx, interaction).await;
+        }
 
-        component
-            .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
-            .await
-            .unwrap();
+        let mut pos = custom_id.strip_prefix("ttt_").unwrap().chars();
+        let i = pos.next().unwrap().to_digit(10).unwrap() as usize;
+        let j = pos.next().unwrap().to_digit(10).unwrap() as usize;
+        let cell = i * game.size + j;
 
-        return Ok(true);
-    }
+        if game.board[cell].is_some() {
+            return acknowledge(ctx, interaction).await;
+        }
 
-    if component.user.id != state.current_turn {
-        return Ok(true);
-    }
+        let player = game.players.iter().position(|p| *p == user).unwrap() as u8;
+        game.board[cell] = Some(player);
 
-    let mut pos = custom_id.strip_prefix("ttt_").unwrap().chars();
-    let i = pos.next().unwrap().to_digit(10).unwrap() as usize;
-    let j = pos.next().unwrap().to_digit(10).unwrap() as usize;
+        let winner = game.check_win(player).then_some(user);
 
-    let mut components = component.message.components.clone();
+        if winner.is_none() && game.board.iter().any(Option::is_none) {
+            game.turn = game.players[1 - usize::from(player)];
 
-    let ActionRowComponent::Button(button) = components
-        .get_mut(i)
-        .unwrap()
-        .components
-        .get_mut(j)
-        .unwrap()
-    else {
-        unreachable!("Component must be a button")
-    };
+            let msg = game.board_message();
+            Sessions::update::<Db, SessionHandler>(pool, interaction, version, game).await?;
 
-    if button.emoji == Some(EMOJI_P1.into()) || button.emoji == Some(EMOJI_P2.into()) {
-        return Ok(true);
-    }
+            interaction
+                .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
+                .await?;
 
-    let emoji = if state.current_turn == state.players[0] {
-        ReactionType::from(EMOJI_P1)
-    } else {
-        ReactionType::from(EMOJI_P2)
-    };
+            return Ok(());
+        }
 
-    button.emoji = Some(emoji.clone());
+        Sessions::end::<Db, SessionHandler>(pool, interaction, version).await?;
+
+        let mut p1_row = game.row::<Db, GameHandler>(pool, game.players[0]).await?;
+        let mut p2_row = game.row::<Db, GameHandler>(pool, game.players[1]).await?;
+        let p1_start = (p1_row.coins(), p1_row.gems());
+        let p2_start = (p2_row.coins(), p2_row.gems());
+
+        let embed = match winner {
+            Some(winner) => {
+                let row = if game.players[0] == winner {
+                    &mut p1_row
+                } else {
+                    &mut p2_row
+                };
+
+                row.add_coins(game.bet * 2);
+
+                CreateEmbed::new()
+                    .title(t!(&game.locale, "gambling.tictactoe.name"))
+                    .description(t!(
+                        &game.locale,
+                        "gambling.tictactoe.winner",
+                        player = winner.mention()
+                    ))
+                    .colour(Colour::DARK_GREEN)
+            }
+            None => {
+                p1_row.add_coins(game.bet);
+                p2_row.add_coins(game.bet);
+
+                CreateEmbed::new()
+                    .title(t!(&game.locale, "gambling.tictactoe.name"))
+                    .description(t!(&game.locale, "gambling.tictactoe.draw"))
+                    .colour(Colour::ORANGE)
+            }
+        };
 
-    if check_win(state, &components, emoji) {
-        state.winner = Some(state.current_turn);
-        return Ok(false);
-    } else if check_draw(&components) {
-        return Ok(false);
-    }
+        let [p1, p2] = game.players;
 
-    let components = components
-        .into_iter()
-        .map(|row| {
-            let buttons = row
-                .components
-                .into_iter()
-                .map(|c| {
-                    let ActionRowComponent::Button(b) = c else {
-                        unreachable!("Component must be of type Button")
-                    };
-
-                    b.into()
-                })
-                .collect::<Vec<CreateButton>>();
-
-            CreateActionRow::Buttons(buttons)
-        })
-        .collect::<Vec<CreateActionRow>>();
-
-    // Next player
-    state.current_turn = if state.current_turn == state.players[0] {
-        state.players[1]
-    } else {
-        state.players[0]
-    };
-
-    let embed = CreateEmbed::new()
-        .title("TicTacToe")
-        .description(format!("{}'s Turn", state.current_turn.mention()));
-
-    let msg = CreateInteractionResponseMessage::new()
-        .embed(embed)
-        .components(components);
-
-    component
-        .create_response(ctx, CreateInteractionResponse::UpdateMessage(msg))
-        .await
-        .unwrap();
-
-    Ok(true)
-}
+        let dispatch = Dispatch::<Db, GoalHandler>::new(pool, economy);
 
-async fn accept<
-    Db: Database,
-    GamblingHandler: GamblingManager<Db>,
-    EffectsHandler: EffectsManager<Db> + Send,
-    GameHandler: GameManager<Db>,
->(
-    pool: &Pool<Db>,
-    state: &mut GameState<Db, GameHandler>,
-    p2: UserId,
-) -> Result<CreateInteractionResponseMessage> {
-    state.players[1] = p2;
+        dispatch
+            .fire(
+                &mut p1_row,
+                Event::Game(GameEvent::new("rps", p1, game.bet, false)), // TODO: Fix win logic
+            )
+            .await?;
 
-    let mut p1_row = state.p1_row(pool).await;
-    let mut p2_row = state.p2_row(pool).await;
+        dispatch
+            .fire(
+                &mut p2_row,
+                Event::Game(GameEvent::new("rps", p2, game.bet, false)), // TODO: Fix win logic
+            )
+            .await?;
 
-    EffectsHandler::bet_limit::<GamblingHandler>(pool, p1_row.id as u64, state.bet, p1_row.coins())
+        save::<Db, GameHandler, LedgerHandler>(
+            pool,
+            economy,
+            interaction.message.id,
+            p1_row,
+            p1_start,
+        )
         .await?;
-    EffectsHandler::bet_limit::<GamblingHandler>(pool, p2_row.id as u64, state.bet, p2_row.coins())
+        save::<Db, GameHandler, LedgerHandler>(
+            pool,
+            economy,
+            interaction.message.id,
+            p2_row,
+            p2_start,
+        )
         .await?;
 
-    state.current_turn = *state.players.choose(&mut rng()).unwrap();
-
-    p1_row.add_coins(-state.bet);
-    p2_row.add_coins(-state.bet);
-
-    GameHandler::save(pool, p1_row).await.unwrap();
-    GameHandler::save(pool, p2_row).await.unwrap();
-
-    let embed = CreateEmbed::new()
-        .title("TicTacToe")
-        .description(format!("{}'s Turn", state.current_turn.mention()));
+        Cooldowns::trigger(
+            ctx,
+            GAME_COOLDOWN,
+            Target {
+                user: p1,
+                ..interaction.into()
+            },
+        )
+        .await;
+        Cooldowns::trigger(
+            ctx,
+            GAME_COOLDOWN,
+            Target {
+                user: p2,
+                ..interaction.into()
+            },
+        )
+        .await;
 
-    let components = (0..state.size)
-        .map(|i| {
-            let row = (0..state.size)
-                .map(|j| {
-                    CreateButton::new(format!("ttt_{}{}", i, j))
-                        .emoji(BLANK)
-                        .style(ButtonStyle::Secondary)
-                })
-                .collect::<Vec<_>>();
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(embed)
+                        .components(Vec::new()),
+                ),
+            )
+            .await?;
 
-            CreateActionRow::Buttons(row)
-        })
-        .collect::<Vec<_>>();
+        Ok(())
+    }
+}
 
-    Ok(CreateInteractionResponseMessage::new()
-        .embed(embed)
-        .components(components))
+/// A game of tic-tac-toe. Until someone accepts, both players are the one
+/// who started it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TicTacToe {
+    // Games stored before economies were split by guild are global
+    #[serde(default)]
+    economy: Economy,
+    size: usize,
+    players: [UserId; 2],
+    turn: UserId,
+    bet: i64,
+    /// The index of the player who took each cell, row by row.
+    board: Vec<Option<u8>>,
+    /// The guild's locale, which the game is shown in. Games stored before it
+    /// was kept are shown in the default one.
+    #[serde(default)]
+    locale: String,
 }
 
-fn check_win<Db: Database, Manager: GameManager<Db>>(
-    state: &GameState<Db, Manager>,
-    components: &[ActionRow],
-    target: ReactionType,
-) -> bool {
-    let get_emoji = |r: usize, c: usize| -> Option<&ReactionType> {
-        match components.get(r).unwrap().components.get(c).unwrap() {
-            ActionRowComponent::Button(b) => b.emoji.as_ref(),
-            _ => unreachable!("Component must be a button"),
+impl TicTacToe {
+    fn new(economy: Economy, locale: &str, p1: UserId, size: usize, bet: i64) -> Self {
+        Self {
+            economy,
+            size,
+            players: [p1, p1],
+            turn: p1,
+            bet,
+            board: vec![None; size * size],
+            locale: locale.to_string(),
         }
-    };
+    }
 
-    let target = Some(target);
+    fn accepted(&self) -> bool {
+        self.players[0] != self.players[1]
+    }
 
-    // Check rows
-    for r in 0..3 {
-        if (0..state.size)
-            .map(|c| get_emoji(r, c))
-            .all(|emoji| emoji == target.as_ref())
-        {
-            return true;
-        }
+    async fn row<Db: Database, Manager: GameManager<Db>>(
+        &self,
+        pool: &Pool<Db>,
+        id: UserId,
+    ) -> Result<GameRow> {
+        Ok(Manager::row(pool, self.economy, id)
+            .await?
+            .unwrap_or_else(|| GameRow::new(id)))
     }
 
-    // Check columns
-    for c in 0..3 {
-        if (0..state.size)
-            .map(|r| get_emoji(r, c))
-            .all(|emoji| emoji == target.as_ref())
-        {
-            return true;
+    /// Refunds both players once the game has been accepted, which is how a
+    /// game that timed out ends.
+    pub(crate) fn time_out(self) -> TimedOut {
+        let refunds = if self.accepted() {
+            self.players.iter().map(|id| (*id, self.bet)).collect()
+        } else {
+            Vec::new()
+        };
+
+        TimedOut {
+            economy: self.economy,
+            source: "tictactoe",
+            refunds,
+            embed: CreateEmbed::new()
+                .title(t!(&self.locale, "gambling.tictactoe.name"))
+                .description(t!(&self.locale, "gambling.tictactoe.timed_out"))
+                .colour(Colour::TEAL),
         }
     }
 
-    // Check diagonals
-    if (0..state.size)
-        .map(|i| get_emoji(i, i))
-        .all(|emoji| emoji == target.as_ref())
-    {
-        return true;
+    fn board_message(&self) -> CreateInteractionResponseMessage {
+        let embed = CreateEmbed::new()
+            .title(t!(&self.locale, "gambling.tictactoe.name"))
+            .description(t!(
+                &self.locale,
+                "gambling.tictactoe.turn",
+                player = self.turn.mention()
+            ));
+
+        let components = self
+            .board
+            .chunks(self.size)
+            .enumerate()
+            .map(|(i, row)| {
+                let buttons = row
+                    .iter()
+                    .enumerate()
+                    .map(|(j, cell)| {
+                        let button =
+                            CreateButton::new(format!("ttt_{i}{j}")).style(ButtonStyle::Secondary);
+
+                        match cell {
+                            Some(0) => button.emoji(EMOJI_P1),
+                            Some(_) => button.emoji(EMOJI_P2),
+                            None => button.emoji(BLANK.id()),
+                        }
+                    })
+                    .collect();
+
+                CreateActionRow::Buttons(buttons)
+            })
+            .collect();
+
+        CreateInteractionResponseMessage::new()
+            .embed(embed)
+            .components(components)
     }
 
-    if (0..state.size)
-        .map(|row| get_emoji(row, state.size - 1 - row)) // Get element at (row, n-1-row)
-        .all(|emoji| emoji == target.as_ref())
-    {
-        return true;
+    fn check_win(&self, player: u8) -> bool {
+        let n = self.size;
+        let taken = |r: usize, c: usize| self.board[r * n + c] == Some(player);
+
+        (0..n).any(|r| (0..n).all(|c| taken(r, c)))
+            || (0..n).any(|c| (0..n).all(|r| taken(r, c)))
+            || (0..n).all(|i| taken(i, i))
+            || (0..n).all(|i| taken(i, n - 1 - i))
     }
+}
 
-    // No win condition met
-    false
+impl From<TicTacToe> for Session {
+    fn from(game: TicTacToe) -> Self {
+        Self::TicTacToe(game)
+    }
 }
 
-fn check_draw(components: &[ActionRow]) -> bool {
-    let x_emoji = Some(ReactionType::from(EMOJI_P1));
-    let o_emoji = Some(ReactionType::from(EMOJI_P2));
-
-    components
-        .iter()
-        .flat_map(|row| row.components.iter())
-        .filter_map(|component| {
-            if let ActionRowComponent::Button(button) = component {
-                Some(button)
-            } else {
-                None
-            }
-        })
-        .all(|button| button.emoji == x_emoji || button.emoji == o_emoji)
+/// Saves a player's row, recording the change in their balances since
+/// `start` in the ledger.
+async fn save<Db: Database, GameHandler: GameManager<Db>, LedgerHandler: LedgerManager<Db>>(
+    pool: &Pool<Db>,
+    economy: Economy,
+    message_id: MessageId,
+    row: GameRow,
+    (coins, gems): (i64, i64),
+) -> Result<()> {
+    let ledger = Ledger::new(economy, row.id as u64, "tictactoe")
+        .reference(message_id)
+        .coins(coins, row.coins())
+        .gems(gems, row.gems());
+
+    let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+    GameHandler::save(&mut *tx, economy, row).await?;
+    ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Answers a click that doesn't change the game.
+async fn acknowledge(ctx: &Context, interaction: &ComponentInteraction) -> Result<()> {
+    interaction
+        .create_response(ctx, CreateInteractionResponse::Acknowledge)
+        .await?;
+
+    Ok(())
 }
@@ -7,12 +7,14 @@ use serenity::all::{
 use sqlx::any::AnyQueryResult;
 use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool};
-use zayden_core::FormatNum;
+use zayden_core::{i18n, t};
 
 use crate::events::{Dispatch, Event};
 use crate::models::MineAmount;
+use crate::utils::coins_str;
 use crate::{
-    COIN, Coins, Gems, GoalsManager, MaxBet, MineHourly, Prestige, Result, Stamina, StaminaManager,
+    Coins, Economies, Economy, Gems, GoalsManager, Ledger, LedgerManager, MaxBet, MineHourly,
+    Prestige, Result, Stamina, StaminaManager,
 };
 
 use super::Commands;
@@ -102,9 +104,17 @@ impl Prestige for WorkRow {
 
 #[async_trait]
 pub trait WorkManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<WorkRow>>;
-
-    async fn save(pool: &Pool<Db>, row: WorkRow) -> sqlx::Result<AnyQueryResult>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<WorkRow>>;
+
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: WorkRow,
+    ) -> sqlx::Result<AnyQueryResult>;
 }
 
 impl Commands {
@@ -113,6 +123,7 @@ impl Commands {
         StaminaHandler: StaminaManager<Db>,
         GoalHandler: GoalsManager<Db>,
         WorkHandler: WorkManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
     >(
         ctx: &Context,
         interaction: &CommandInteraction,
@@ -120,12 +131,18 @@ impl Commands {
     ) -> Result<()> {
         interaction.defer(ctx).await?;
 
-        let mut row = match WorkHandler::row(pool, interaction.user.id).await.unwrap() {
+        let economy = Economies::get(ctx, interaction.guild_id).await;
+
+        let mut row = match WorkHandler::row(pool, economy, interaction.user.id)
+            .await
+            .unwrap()
+        {
             Some(row) => row,
             None => WorkRow::new(interaction.user.id),
         };
 
         row.verify_work::<Db, StaminaHandler>()?;
+        let (start_coins, start_gems) = (row.coins(), row.gems());
 
         let base_amount = rand::random_range(100..=500);
         let mine_amount = row.mine_amount();
@@ -133,16 +150,18 @@ impl Commands {
 
         *row.coins_mut() += total_amount;
 
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+
         let gem_desc = if rand::random_bool(1.0 / 200.0) {
             row.add_gems(1);
-            "\n💎 You found a GEM!"
+            format!("\n{}", t!(locale, "gambling.work.gem"))
         } else {
-            ""
+            String::new()
         };
 
         let coins = row.coins_str();
 
-        Dispatch::<Db, GoalHandler>::new(pool)
+        Dispatch::<Db, GoalHandler>::new(pool, economy)
             .fire(&mut row, Event::Work(interaction.user.id))
             .await?;
 
@@ -150,12 +169,26 @@ impl Commands {
         row.mine_activity = Some(Utc::now().naive_utc());
 
         let stamina = row.stamina_str();
+        let ledger = Ledger::new(economy, interaction.user.id, "work")
+            .reference(interaction.id)
+            .coins(start_coins, row.coins())
+            .gems(start_gems, row.gems());
 
-        WorkHandler::save(pool, row).await.unwrap();
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+        WorkHandler::save(&mut *tx, economy, row).await?;
+        ledger.record::<Db, LedgerHandler>(&mut *tx).await?;
+        tx.commit().await?;
 
         let embed = CreateEmbed::new()
             .description(format!(
-                "Collected {} <:coin:{COIN}> for working{gem_desc}\nYour coins: {coins}\nStamina: {stamina}", total_amount.format()
+                "{}{gem_desc}\n{}\n{}",
+                t!(
+                    locale,
+                    "gambling.work.collected",
+                    coins = coins_str(total_amount)
+                ),
+                t!(locale, "gambling.your_coins", coins = coins),
+                t!(locale, "gambling.stamina", stamina = stamina)
             ))
             .colour(Colour::GOLD);
 
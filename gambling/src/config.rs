@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serenity::all::{ChannelId, EmojiId};
+use zayden_core::{ConfigError, emojis};
+
+use crate::slots::SlotsConfig;
+use crate::{EMOJIS, card_names};
+
+/// The `[gambling]` section of the bot's config file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Where the lotto draw is announced.
+    pub lotto_channel: ChannelId,
+    /// Emoji ids by name, e.g. `coin` or `hearts_q`, used when there is no
+    /// application emoji of the same name.
+    #[serde(default)]
+    pub emojis: HashMap<String, EmojiId>,
+    #[serde(default)]
+    pub blackjack: BlackjackConfig,
+    #[serde(default)]
+    pub slots: SlotsConfig,
+    #[serde(default)]
+    pub crash: CrashConfig,
+}
+
+/// The `[gambling.blackjack]` section.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BlackjackConfig {
+    /// The number of decks shuffled into a shoe.
+    pub decks: usize,
+    /// How much of the shoe is dealt before it is reshuffled, from 0 to 1.
+    pub penetration: f64,
+}
+
+impl BlackjackConfig {
+    /// The cards left in the shoe when the cut card comes out.
+    pub fn cut(&self) -> usize {
+        let cards = (self.decks * 52) as f64;
+        (cards * (1.0 - self.penetration.clamp(0.0, 1.0))) as usize
+    }
+}
+
+impl Default for BlackjackConfig {
+    fn default() -> Self {
+        Self {
+            decks: 8,
+            penetration: 0.75,
+        }
+    }
+}
+
+/// The `[gambling.crash]` section.
+#[derive(Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CrashConfig {
+    /// The share of every bet the house keeps in the long run, from 0 to 1.
+    pub house_edge: f64,
+    /// How long players have to join a round before the multiplier starts.
+    pub betting_secs: u64,
+    /// How fast the multiplier climbs, as its growth rate per second.
+    pub growth: f64,
+    /// Where every round crashes if it gets that far.
+    pub max_multiplier: f64,
+}
+
+impl CrashConfig {
+    /// Draws where a round crashes. It survives to any multiplier `x` with a
+    /// chance of `(1 - house_edge) / x`, so cashing out there returns
+    /// `1 - house_edge` of the bet on average, whatever `x` is.
+    pub fn crash_point(&self) -> f64 {
+        let edge = self.house_edge.clamp(0.0, 1.0);
+        let roll = rand::random::<f64>();
+
+        hundredths(((1.0 - edge) / (1.0 - roll)).clamp(1.0, self.max_multiplier.max(1.0)))
+    }
+
+    /// The multiplier `elapsed` after it started climbing.
+    pub fn multiplier(&self, elapsed: Duration) -> f64 {
+        hundredths((self.growth * elapsed.as_secs_f64()).exp())
+    }
+
+    /// The longest a round can take, from opening until it crashes at
+    /// `max_multiplier`. A multiplier that never climbs is given a day.
+    pub fn longest_round(&self) -> Duration {
+        let climb = self.max_multiplier.max(1.0).ln() / self.growth;
+        let climb = Duration::try_from_secs_f64(climb).unwrap_or(Duration::from_secs(86_400));
+
+        Duration::from_secs(self.betting_secs) + climb
+    }
+}
+
+impl Default for CrashConfig {
+    fn default() -> Self {
+        Self {
+            house_edge: 0.04,
+            betting_secs: 15,
+            growth: 0.06,
+            max_multiplier: 1000.0,
+        }
+    }
+}
+
+/// Rounds a multiplier down to the hundredths it is shown with.
+fn hundredths(multiplier: f64) -> f64 {
+    (multiplier * 100.0).floor() / 100.0
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Makes `config` available to the module. Only the first call sets it.
+pub fn init(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// Checks that every emoji used by the module was synced as an application
+/// emoji, see [`zayden_core::emojis::sync`], or is set in the config.
+pub fn validate() -> Result<(), ConfigError> {
+    let missing = EMOJIS
+        .iter()
+        .map(|emoji| emoji.0.to_string())
+        .chain(card_names())
+        .find(|name| resolve(name).is_none());
+
+    if let Some(name) = missing {
+        return Err(ConfigError::MissingEmoji(name));
+    }
+
+    get().slots.validate()
+}
+
+/// The slot machine played by `/slots`.
+pub fn slots() -> &'static SlotsConfig {
+    &get().slots
+}
+
+pub(crate) fn get() -> &'static Config {
+    CONFIG
+        .get()
+        .expect("the gambling config is set with init at startup")
+}
+
+pub(crate) fn resolve(name: &str) -> Option<EmojiId> {
+    emojis::get(name).or_else(|| get().emojis.get(name).copied())
+}
+
+pub(crate) fn emoji(name: &str) -> EmojiId {
+    resolve(name).unwrap_or_else(|| panic!("no `{name}` emoji, which validate checks at startup"))
+}
+
+/// An emoji looked up by name, displayed as its id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigEmoji(pub &'static str);
+
+impl ConfigEmoji {
+    pub fn id(self) -> EmojiId {
+        emoji(self.0)
+    }
+}
+
+impl Display for ConfigEmoji {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
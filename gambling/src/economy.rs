@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serenity::all::{Context, GuildId};
+use serenity::prelude::TypeMapKey;
+use sqlx::any::AnyQueryResult;
+use sqlx::{Database, Pool};
+
+/// The economy that balances, inventories, mines and goals belong to.
+///
+/// Every server shares the global economy unless it opted into its own, in
+/// which case its members start over with separate balances and
+/// leaderboards.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Economy(u64);
+
+impl Economy {
+    pub const GLOBAL: Self = Self(0);
+
+    pub fn guild(id: impl Into<GuildId>) -> Self {
+        Self(id.into().get())
+    }
+
+    /// The value of the `guild_id` columns, which is 0 for the global
+    /// economy.
+    pub fn get(self) -> i64 {
+        self.0 as i64
+    }
+
+    pub fn guild_id(self) -> Option<GuildId> {
+        (self.0 != 0).then(|| GuildId::new(self.0))
+    }
+}
+
+#[async_trait]
+pub trait EconomyManager<Db: Database> {
+    /// The guilds that have their own economy.
+    async fn guilds(pool: &Pool<Db>) -> sqlx::Result<Vec<i64>>;
+
+    async fn enable(
+        pool: &Pool<Db>,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// Switches the guild back to the global economy, keeping its own
+    /// balances in case it switches again.
+    async fn disable(
+        pool: &Pool<Db>,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// Copies the global balances, inventories and mines of `users` into
+    /// the guild's economy, skipping users that already have a balance there.
+    /// Each copied balance is recorded in the ledger as an import.
+    async fn import(
+        pool: &Pool<Db>,
+        guild_id: impl Into<GuildId> + Send,
+        users: &[i64],
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// Deletes everything in the guild's economy so a new season can start,
+    /// apart from the ledger, where each balance is closed by a reset entry.
+    async fn reset(
+        pool: &Pool<Db>,
+        guild_id: impl Into<GuildId> + Send,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The guilds with their own economy, kept in the context data so commands
+/// don't need a query to find out which economy they are in.
+#[derive(Debug, Default)]
+pub struct Economies(HashSet<GuildId>);
+
+impl Economies {
+    /// The economy of commands used in `guild_id`.
+    pub async fn get(ctx: &Context, guild_id: Option<GuildId>) -> Economy {
+        let Some(guild_id) = guild_id else {
+            return Economy::GLOBAL;
+        };
+
+        let data = ctx.data.read().await;
+        match data.get::<Self>() {
+            Some(economies) if economies.0.contains(&guild_id) => Economy::guild(guild_id),
+            _ => Economy::GLOBAL,
+        }
+    }
+
+    /// The global economy followed by every guild's own.
+    pub async fn all(ctx: &Context) -> Vec<Economy> {
+        let data = ctx.data.read().await;
+        let guilds = data.get::<Self>().map(|economies| economies.0.iter());
+
+        std::iter::once(Economy::GLOBAL)
+            .chain(guilds.into_iter().flatten().copied().map(Economy::guild))
+            .collect()
+    }
+
+    pub async fn load<Db: Database, Manager: EconomyManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+    ) -> sqlx::Result<()> {
+        let guilds = Manager::guilds(pool)
+            .await?
+            .into_iter()
+            .map(|id| GuildId::new(id as u64))
+            .collect();
+
+        ctx.data.write().await.insert::<Self>(Self(guilds));
+
+        Ok(())
+    }
+
+    /// Switches the guild between its own economy and the global one.
+    pub async fn set(ctx: &Context, guild_id: GuildId, own: bool) {
+        let mut data = ctx.data.write().await;
+        let economies = data.entry::<Self>().or_insert_with(Self::default);
+
+        if own {
+            economies.0.insert(guild_id);
+        } else {
+            economies.0.remove(&guild_id);
+        }
+    }
+}
+
+impl TypeMapKey for Economies {
+    type Value = Economies;
+}
@@ -1,5 +1,5 @@
-use zayden_core::Error as ZaydenError;
-use zayden_core::FormatNum;
+use zayden_core::i18n::DEFAULT_LOCALE;
+use zayden_core::{Error as ZaydenError, FormatNum, Localize, t};
 
 use crate::ShopCurrency;
 
@@ -28,63 +28,86 @@ pub enum Error {
     Cooldown(i64),
     InvalidPrediction,
     InvalidAmount,
+    InvalidBet,
+    BetSlipFull(usize),
     InsufficientCapacity(i64),
     ItemNotInInventory,
     InsufficientItemQuantity(i64),
+    GameEnded,
+    NotPlayer,
+    TableFull,
+    RoundInProgress,
+    AlreadyJoined,
+    ResetNotConfirmed,
 
     Serenity(serenity::Error),
     Sqlx(sqlx::Error),
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Localize for Error {
+    fn localize(&self, locale: &str) -> String {
         match self {
-            Error::Overflow(max) => write!(f, "Overflow Error: Please enter a maximum of `{max}`"),
-            Error::MessageConflict => ZaydenError::MessageConflict.fmt(f),
-            Error::PremiumRequired => write!(f, "Sorry, only supporters can use this option"),
-            Error::InsufficientFunds { required, currency } => write!(
-                f,
-                "You do not have enough to make this.\nYou need the following resource: {} {currency}",
-                required.format()
+            Error::Overflow(max) => t!(locale, "gambling.error.overflow", max = max),
+            Error::MessageConflict => ZaydenError::MessageConflict.localize(locale),
+            Error::PremiumRequired => t!(locale, "gambling.error.premium_required"),
+            Error::InsufficientFunds { required, currency } => t!(
+                locale,
+                "gambling.error.insufficient_funds",
+                required = required.format(),
+                currency = currency
             ),
             Error::MinimumBetAmount(min) => {
-                write!(f, "The minimum bet for this game is `{}`!", min.format())
+                t!(locale, "gambling.error.minimum_bet", min = min.format())
             }
             Error::MaximumBetAmount(max) => {
-                write!(f, "The maximum bet you've unlocked is `{}`!", max.format())
+                t!(locale, "gambling.error.maximum_bet", max = max.format())
             }
             Error::MaximumSendAmount(max) => {
-                write!(f, "The maximum you can send is `{}`!", max.format())
+                t!(locale, "gambling.error.maximum_send", max = max.format())
             }
             Error::DailyClaimed(timestamp) => {
-                write!(f, "You collected today, try again <t:{timestamp}:R>",)
+                t!(
+                    locale,
+                    "gambling.error.daily_claimed",
+                    timestamp = timestamp
+                )
             }
             Error::OutOfStamina(timestamp) => {
-                write!(f, "You're out of stamina! Try again <t:{timestamp}:R>")
+                t!(
+                    locale,
+                    "gambling.error.out_of_stamina",
+                    timestamp = timestamp
+                )
             }
-            Error::GiftUsed(timestamp) => write!(
-                f,
-                "You can only gift someone once a day, try again <t:{timestamp}:R>",
-            ),
-            Error::SelfGift => write!(f, "You can't give yourself a gift... How selfish!"),
-            Error::SelfSend => write!(f, "You cannot send funds to yourself"),
-            Error::NegativeAmount => write!(f, "Amount cannot be negative"),
-            Error::ZeroAmount => write!(f, "Amount cannot be 0"),
-            Error::Cooldown(timestamp) => {
-                write!(f, "You are on a game cooldown. Try again <t:{timestamp}:R>")
+            Error::GiftUsed(timestamp) => {
+                t!(locale, "gambling.error.gift_used", timestamp = timestamp)
             }
-            Error::InvalidPrediction => write!(f, "Invalid prediction value."),
-            Error::InvalidAmount => write!(f, "Invalid amount value."),
-            Error::InsufficientCapacity(remaining) => write!(
-                f,
-                "You don't have enough capacity to buy that many.\nYou can buy `{remaining}` more before you are at capacity"
+            Error::SelfGift => t!(locale, "gambling.error.self_gift"),
+            Error::SelfSend => t!(locale, "gambling.error.self_send"),
+            Error::NegativeAmount => t!(locale, "gambling.error.negative_amount"),
+            Error::ZeroAmount => t!(locale, "gambling.error.zero_amount"),
+            Error::Cooldown(timestamp) => ZaydenError::Cooldown(*timestamp).localize(locale),
+            Error::InvalidPrediction => t!(locale, "gambling.error.invalid_prediction"),
+            Error::InvalidAmount => t!(locale, "gambling.error.invalid_amount"),
+            Error::InvalidBet => t!(locale, "gambling.error.invalid_bet"),
+            Error::BetSlipFull(max) => t!(locale, "gambling.error.bet_slip_full", max = max),
+            Error::InsufficientCapacity(remaining) => t!(
+                locale,
+                "gambling.error.insufficient_capacity",
+                remaining = remaining
             ),
-            Error::ItemNotInInventory => write!(f, "You don't have that item in your inventory."),
-            Error::InsufficientItemQuantity(quantity) => write!(
-                f,
-                "Cannot sell that many. You only have {} of this item.",
-                quantity.format()
+            Error::ItemNotInInventory => t!(locale, "gambling.error.item_not_in_inventory"),
+            Error::InsufficientItemQuantity(quantity) => t!(
+                locale,
+                "gambling.error.insufficient_item_quantity",
+                quantity = quantity.format()
             ),
+            Error::GameEnded => t!(locale, "gambling.error.game_ended"),
+            Error::NotPlayer => t!(locale, "gambling.error.not_player"),
+            Error::TableFull => t!(locale, "gambling.error.table_full"),
+            Error::RoundInProgress => t!(locale, "gambling.error.round_in_progress"),
+            Error::AlreadyJoined => t!(locale, "gambling.error.already_joined"),
+            Error::ResetNotConfirmed => t!(locale, "gambling.error.reset_not_confirmed"),
 
             Error::Serenity(e) => unimplemented!("Unhandled Serenity error: {e:?}"),
             Error::Sqlx(e) => unimplemented!("Unhandled SQLx error: {e:?}"),
@@ -92,11 +115,20 @@ impl std::fmt::Display for Error {
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.localize(DEFAULT_LOCALE))
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl From<zayden_core::Error> for Error {
-    fn from(_value: zayden_core::Error) -> Self {
-        Self::MessageConflict
+    fn from(value: zayden_core::Error) -> Self {
+        match value {
+            zayden_core::Error::Cooldown(timestamp) => Self::Cooldown(timestamp),
+            _ => Self::MessageConflict,
+        }
     }
 }
 
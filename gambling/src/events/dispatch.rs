@@ -2,13 +2,14 @@ use std::marker::PhantomData;
 
 use sqlx::{Database, Pool};
 
-use crate::GoalsManager;
 use crate::goals::GoalHandler;
+use crate::{Economy, GoalsManager};
 
 use super::{Event, EventRow};
 
 pub struct Dispatch<'a, Db: Database, Manager: GoalsManager<Db>> {
     pool: &'a Pool<Db>,
+    economy: Economy,
     _manager: PhantomData<Manager>,
 }
 
@@ -17,14 +18,15 @@ where
     Db: Database,
     Manager: GoalsManager<Db>,
 {
-    pub fn new(pool: &'a Pool<Db>) -> Self {
+    pub fn new(pool: &'a Pool<Db>, economy: Economy) -> Self {
         Self {
             pool,
+            economy,
             _manager: PhantomData,
         }
     }
 
     pub async fn fire(&self, row: &mut dyn EventRow, event: Event) -> sqlx::Result<Event> {
-        GoalHandler::process_goals::<Db, Manager>(self.pool, row, event).await
+        GoalHandler::process_goals::<Db, Manager>(self.pool, self.economy, row, event).await
     }
 }
@@ -32,6 +32,8 @@ pub struct GameEvent {
     pub user_id: UserId,
     pub bet: i64,
     pub win: bool,
+    /// What the bet was multiplied by, for games that climb one.
+    pub multiplier: Option<f64>,
 }
 
 impl GameEvent {
@@ -41,8 +43,14 @@ impl GameEvent {
             user_id: user_id.into(),
             bet,
             win,
+            multiplier: None,
         }
     }
+
+    pub fn multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = Some(multiplier);
+        self
+    }
 }
 
 pub struct ShopPurchaseEvent {
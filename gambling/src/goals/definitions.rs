@@ -107,6 +107,22 @@ const HIGHERLOWER: GoalDefinition = GoalDefinition::new("higherlower")
         true
     });
 
+const MINES: GoalDefinition = GoalDefinition::new("mines")
+    .set_target(|_| rand::random_range(3..=5))
+    .set_description(|t| format!("Cash out at x{t} or more on Mines"))
+    .set_update_fn(|goal: &mut GamblingGoalsRow, event: &Event| {
+        let Event::Game(event) = event else {
+            return false;
+        };
+
+        let Some(multiplier) = event.multiplier.filter(|_| event.game_id == "mines") else {
+            return false;
+        };
+
+        goal.progress = goal.progress.max(multiplier as i64).min(goal.target);
+        true
+    });
+
 const WIN_MAX_BET: GoalDefinition = GoalDefinition::new("winmaxbet")
     .set_target(|row| row.max_bet().min(row.coins()))
     .set_description(|t| format!("Win {} coins", t.format()))
@@ -179,7 +195,7 @@ const WORK: GoalDefinition = GoalDefinition::new("work")
 pub struct GoalRegistry(HashMap<&'static str, GoalDefinition>);
 
 impl GoalRegistry {
-    pub fn new(goals: [GoalDefinition; 9]) -> Self {
+    pub fn new(goals: [GoalDefinition; 10]) -> Self {
         Self(goals.into_iter().map(|goal| (goal.id, goal)).collect())
     }
 
@@ -198,6 +214,7 @@ pub static GOAL_REGISTRY: LazyLock<GoalRegistry> = LazyLock::new(|| {
         GIFT,
         WIN_10,
         HIGHERLOWER,
+        MINES,
         WIN_MAX_BET,
         WIN_3_ROW,
         ALL_IN,
@@ -1,10 +1,10 @@
 use serenity::all::UserId;
 use sqlx::{Database, Pool};
 
-use crate::GamblingGoalsRow;
 use crate::GoalsManager;
 use crate::events::Event;
 use crate::events::EventRow;
+use crate::{Economy, GamblingGoalsRow};
 
 use super::GOAL_REGISTRY;
 
@@ -13,6 +13,7 @@ pub struct GoalHandler;
 impl GoalHandler {
     pub async fn daily_reset<Db: Database, Manager: GoalsManager<Db>>(
         pool: &Pool<Db>,
+        economy: Economy,
         id: impl Into<UserId>,
         row: &dyn EventRow,
     ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
@@ -29,22 +30,23 @@ impl GoalHandler {
             .map(|(goal_id, target)| GamblingGoalsRow::new(id, goal_id, target))
             .collect::<Vec<_>>();
 
-        let rows = Manager::update(pool, &goals).await?;
+        let rows = Manager::update(pool, economy, &goals).await?;
 
         Ok(rows)
     }
 
     pub async fn get_user_progress<Db: Database, Manager: GoalsManager<Db>>(
         pool: &Pool<Db>,
+        economy: Economy,
         user_id: impl Into<UserId>,
         row: &dyn EventRow,
     ) -> sqlx::Result<Vec<GamblingGoalsRow>> {
         let user_id = user_id.into();
 
-        let mut goals = Manager::full_rows(pool, user_id).await?;
+        let mut goals = Manager::full_rows(pool, economy, user_id).await?;
 
         if goals.is_empty() || !goals[0].is_today() {
-            goals = Self::daily_reset::<Db, Manager>(pool, user_id, row).await?;
+            goals = Self::daily_reset::<Db, Manager>(pool, economy, user_id, row).await?;
         }
 
         Ok(goals)
@@ -52,12 +54,14 @@ impl GoalHandler {
 
     pub async fn process_goals<Db: Database, Manager: GoalsManager<Db>>(
         pool: &Pool<Db>,
+        economy: Economy,
         row: &mut dyn EventRow,
         event: Event,
     ) -> sqlx::Result<Event> {
         let user_id = event.user_id();
 
-        let mut all_goals = Self::get_user_progress::<Db, Manager>(pool, user_id, row).await?;
+        let mut all_goals =
+            Self::get_user_progress::<Db, Manager>(pool, economy, user_id, row).await?;
 
         let changed = all_goals
             .iter_mut()
@@ -87,7 +91,7 @@ impl GoalHandler {
                 row.add_gems(1);
             }
 
-            Manager::update(pool, &all_goals).await.unwrap();
+            Manager::update(pool, economy, &all_goals).await.unwrap();
         }
 
         Ok(event)
@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serenity::all::UserId;
+use sqlx::any::AnyQueryResult;
+use sqlx::prelude::FromRow;
+use sqlx::{Database, Pool};
+
+use crate::Economy;
+
+/// A change to one of a user's balances as stored in the ledger.
+#[derive(FromRow)]
+pub struct LedgerRow {
+    pub id: i64,
+    pub user_id: i64,
+    pub amount: i64,
+    pub currency: String,
+    pub source: String,
+    pub reference_id: Option<i64>,
+    pub balance: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A change to be appended to the ledger.
+pub struct Transaction {
+    pub economy: Economy,
+    pub user_id: i64,
+    pub amount: i64,
+    pub currency: &'static str,
+    pub source: &'static str,
+    pub reference_id: Option<i64>,
+    pub balance: i64,
+}
+
+#[async_trait]
+pub trait LedgerManager<Db: Database> {
+    async fn insert(
+        conn: &mut Db::Connection,
+        transaction: &Transaction,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    /// A page of `page_size` of the user's transactions, newest first.
+    async fn history(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        page_num: i64,
+        page_size: i64,
+    ) -> sqlx::Result<Vec<LedgerRow>>;
+
+    async fn count(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<i64>;
+}
+
+/// The changes a command made to a user's balances, recorded in the same
+/// transaction as the row holding them is saved.
+///
+/// Balances are compared before and after the command, so anything it paid
+/// out along the way, such as goal rewards, is part of the same entry.
+pub struct Ledger {
+    economy: Economy,
+    user_id: UserId,
+    source: &'static str,
+    reference_id: Option<i64>,
+    changes: Vec<(&'static str, i64, i64)>,
+}
+
+impl Ledger {
+    pub fn new(economy: Economy, user_id: impl Into<UserId>, source: &'static str) -> Self {
+        Self {
+            economy,
+            user_id: user_id.into(),
+            source,
+            reference_id: None,
+            changes: Vec::new(),
+        }
+    }
+
+    /// The interaction, message or other id the change can be traced back to.
+    pub fn reference(mut self, id: impl Into<u64>) -> Self {
+        self.reference_id = Some(id.into() as i64);
+        self
+    }
+
+    pub fn coins(self, before: i64, after: i64) -> Self {
+        self.change("coins", before, after)
+    }
+
+    pub fn gems(self, before: i64, after: i64) -> Self {
+        self.change("gems", before, after)
+    }
+
+    fn change(mut self, currency: &'static str, before: i64, after: i64) -> Self {
+        if before != after {
+            self.changes.push((currency, after - before, after));
+        }
+        self
+    }
+
+    pub async fn record<Db: Database, Manager: LedgerManager<Db>>(
+        self,
+        conn: &mut Db::Connection,
+    ) -> sqlx::Result<()> {
+        for (currency, amount, balance) in self.changes {
+            let transaction = Transaction {
+                economy: self.economy,
+                user_id: self.user_id.get() as i64,
+                amount,
+                currency,
+                source: self.source,
+                reference_id: self.reference_id,
+                balance,
+            };
+
+            Manager::insert(&mut *conn, &transaction).await?;
+        }
+
+        Ok(())
+    }
+}
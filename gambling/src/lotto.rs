@@ -2,34 +2,41 @@ use async_trait::async_trait;
 use rand::distr::weighted::WeightedIndex;
 use rand::rng;
 use rand_distr::Distribution;
-use serenity::all::{ChannelId, CreateEmbed, CreateMessage, Mentionable, UserId};
+use serenity::all::{Context, CreateEmbed, CreateMessage, Mentionable, UserId};
 use sqlx::any::AnyQueryResult;
-use sqlx::{Database, FromRow};
-use zayden_core::{CronJob, FormatNum};
+use sqlx::{Database, FromRow, Pool};
+use zayden_core::{CatchUp, CronJob, FormatNum, i18n, t};
 
 use crate::shop::LOTTO_TICKET;
-use crate::{COIN, Coins};
+use crate::{COIN, Coins, Economies, Economy, Ledger, LedgerManager, Result, config};
 
-const CHANNEL_ID: ChannelId = ChannelId::new(1383573049563156502);
+/// Every Friday at 17:00 UTC.
+const SCHEDULE: &str = "0 0 17 * * Fri *";
 
 #[async_trait]
 pub trait LottoManager<Db: Database> {
     async fn row(
         conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
     ) -> sqlx::Result<Option<LottoRow>>;
 
-    async fn rows(conn: &mut Db::Connection) -> sqlx::Result<Vec<LottoRow>>;
+    async fn rows(conn: &mut Db::Connection, economy: Economy) -> sqlx::Result<Vec<LottoRow>>;
 
-    async fn total_tickets(conn: &mut Db::Connection) -> sqlx::Result<i64>;
+    async fn total_tickets(conn: &mut Db::Connection, economy: Economy) -> sqlx::Result<i64>;
 
-    async fn delete_tickets(conn: &mut Db::Connection) -> sqlx::Result<AnyQueryResult>;
+    async fn delete_tickets(
+        conn: &mut Db::Connection,
+        economy: Economy,
+    ) -> sqlx::Result<AnyQueryResult>;
 
+    /// Returns the user's new balance, or `None` if they have never played.
     async fn add_coins(
         conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         amount: i64,
-    ) -> sqlx::Result<AnyQueryResult>;
+    ) -> sqlx::Result<Option<i64>>;
 }
 
 #[derive(FromRow)]
@@ -74,83 +81,152 @@ pub fn jackpot(tickets: i64) -> i64 {
     (tickets * LOTTO_TICKET.coin_cost().unwrap()).max(1_000_000)
 }
 
+/// The lottery's tickets and jackpot, shared by `/lotto` and the draw.
+pub(crate) fn lotto_embed(locale: &str, tickets: i64, jackpot: i64) -> CreateEmbed {
+    CreateEmbed::new()
+        .title(format!(
+            "<:coin:{COIN}> <:coin:{COIN}> {} <:coin:{COIN}> <:coin:{COIN}>",
+            t!(locale, "gambling.lotto.name")
+        ))
+        .field(
+            t!(locale, "gambling.lotto.tickets_bought"),
+            format!("{} {}", tickets.format(), LOTTO_TICKET.emoji()),
+            false,
+        )
+        .field(
+            t!(locale, "gambling.lotto.jackpot"),
+            format!("{} <:coin:{COIN}>", jackpot.format()),
+            false,
+        )
+}
+
 pub struct Lotto;
 
 impl Lotto {
-    pub fn cron_job<Db: Database, Manager: LottoManager<Db>>() -> CronJob<Db> {
-        CronJob::new("lotto", "0 0 17 * * Fri *").set_action(|ctx, pool| async move {
-            let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await.unwrap();
+    /// The timestamp of the next draw.
+    pub fn next_draw<Db: Database>() -> i64 {
+        CronJob::<Db>::new("lotto", SCHEDULE)
+            .expect("Schedule should be valid")
+            .schedule
+            .upcoming(chrono::Utc)
+            .next()
+            .unwrap_or_default()
+            .timestamp()
+    }
 
-            let mut rows = Manager::rows(&mut *tx).await.unwrap();
+    pub fn cron_job<Db: Database, Manager: LottoManager<Db>, LedgerHandler: LedgerManager<Db>>()
+    -> CronJob<Db> {
+        CronJob::new("lotto", SCHEDULE)
+            .expect("Schedule should be valid")
+            .catch_up(CatchUp::Once)
+            .set_action(|ctx, pool| async move {
+                // A draw that fails is logged so the other economies still
+                // get theirs
+                for economy in Economies::all(&ctx).await {
+                    if let Err(e) =
+                        Self::draw::<Db, Manager, LedgerHandler>(&ctx, &pool, economy).await
+                    {
+                        tracing::error!(
+                            "Failed to draw the lottery for economy {}: {e:?}",
+                            economy.get()
+                        );
+                    }
+                }
+            })
+    }
 
-            let prize_share = [0.5, 0.3, 0.2];
+    /// Draws the winners of the economy's lottery, announcing them in the
+    /// lotto channel for the global economy and in the server's system
+    /// channel for a server's own.
+    async fn draw<Db: Database, Manager: LottoManager<Db>, LedgerHandler: LedgerManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+        economy: Economy,
+    ) -> Result<()> {
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
 
-            let expected_winners = prize_share.len();
+        let mut rows = Manager::rows(&mut *tx, economy).await?;
 
-            if rows.len() < expected_winners {
-                return;
-            }
+        let prize_share = [0.5, 0.3, 0.2];
 
-            let total_tickets: i64 = rows.iter().map(|row| row.quantity()).sum();
+        let expected_winners = prize_share.len();
 
-            let mut dist = WeightedIndex::new(rows.iter().map(|row| row.quantity())).unwrap();
+        if rows.len() < expected_winners {
+            return Ok(());
+        }
 
-            let jackpot = jackpot(total_tickets);
+        let total_tickets: i64 = rows.iter().map(|row| row.quantity()).sum();
 
-            let winners = prize_share
-                .into_iter()
-                .map(|share| {
-                    let index = dist.sample(&mut rng());
-                    let winner = rows.remove(index);
-                    dist = WeightedIndex::new(rows.iter().map(|row| row.quantity())).unwrap();
-                    (winner.user_id(), (jackpot as f64 * share) as i64)
-                })
-                .collect::<Vec<_>>();
+        let mut dist = WeightedIndex::new(rows.iter().map(|row| row.quantity())).unwrap();
 
-            Manager::delete_tickets(&mut *tx).await.unwrap();
+        let jackpot = jackpot(total_tickets);
 
-            let mut lines = Vec::with_capacity(expected_winners);
+        let winners = prize_share
+            .into_iter()
+            .map(|share| {
+                let index = dist.sample(&mut rng());
+                let winner = rows.remove(index);
+                dist = WeightedIndex::new(rows.iter().map(|row| row.quantity())).unwrap();
+                (winner.user_id(), (jackpot as f64 * share) as i64)
+            })
+            .collect::<Vec<_>>();
 
-            for (winner, payout) in winners {
-                Manager::add_coins(&mut *tx, winner, payout).await.unwrap();
+        Manager::delete_tickets(&mut *tx, economy).await?;
 
-                let line = format!(
-                    "{} ({}) has won {} <:coin:{COIN}> from the lottery!",
-                    winner.mention(),
-                    winner.to_user(&ctx).await.unwrap().display_name(),
-                    payout.format()
-                );
+        let mut results = Vec::with_capacity(expected_winners);
 
-                lines.push(line);
+        for (winner, payout) in winners {
+            if let Some(balance) = Manager::add_coins(&mut *tx, economy, winner, payout).await? {
+                Ledger::new(economy, winner, "lotto")
+                    .coins(balance - payout, balance)
+                    .record::<Db, LedgerHandler>(&mut *tx)
+                    .await?;
             }
 
-            tx.commit().await.unwrap();
-
-            let embed = CreateEmbed::new()
-                .title(format!(
-                    "<:coin:{COIN}> <:coin:{COIN}> Lottery!! <:coin:{COIN}> <:coin:{COIN}>"
-                ))
-                .field(
-                    "Tickets Bought",
-                    format!("{} {}", total_tickets.format(), LOTTO_TICKET.emoji()),
-                    false,
-                )
-                .field(
-                    "Jackpot Value",
-                    format!("{} <:coin:{COIN}>", jackpot.format()),
-                    false,
-                );
-
-            CHANNEL_ID
-                .send_message(
-                    &ctx,
-                    CreateMessage::new().content(lines.join("\n")).embed(embed),
-                )
-                .await
-                .unwrap()
-                .crosspost(&ctx)
-                .await
-                .unwrap();
-        })
+            let name = winner.to_user(ctx).await?.display_name().to_string();
+            results.push((winner, name, payout));
+        }
+
+        tx.commit().await?;
+
+        let message = |locale: &str| {
+            let lines = results
+                .iter()
+                .map(|(winner, name, payout)| {
+                    t!(
+                        locale,
+                        "gambling.lotto.winner",
+                        user = winner.mention(),
+                        name = name,
+                        coins = format!("{} <:coin:{COIN}>", payout.format())
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            CreateMessage::new()
+                .content(lines.join("\n"))
+                .embed(lotto_embed(locale, total_tickets, jackpot))
+        };
+
+        match economy.guild_id() {
+            None => {
+                config::get()
+                    .lotto_channel
+                    .send_message(ctx, message(i18n::DEFAULT_LOCALE))
+                    .await?
+                    .crosspost(ctx)
+                    .await?;
+            }
+            Some(guild_id) => {
+                let guild = guild_id.to_partial_guild(ctx).await?;
+
+                if let Some(channel_id) = guild.system_channel_id {
+                    let locale = i18n::guild_locale(Some(&guild.preferred_locale));
+                    channel_id.send_message(ctx, message(locale)).await?;
+                }
+            }
+        }
+
+        Ok(())
     }
 }
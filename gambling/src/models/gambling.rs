@@ -1,13 +1,20 @@
 use async_trait::async_trait;
 use serenity::all::UserId;
-use sqlx::{Database, Pool};
+use sqlx::Database;
+
+use crate::Economy;
 
 #[async_trait]
 pub trait GamblingManager<Db: Database> {
-    async fn max_bet(conn: &mut Db::Connection, id: impl Into<UserId> + Send) -> sqlx::Result<i64>;
+    async fn max_bet(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<i64>;
 
     async fn bet(
-        pool: &Pool<Db>,
+        conn: &mut Db::Connection,
+        economy: Economy,
         id: impl Into<UserId> + Send,
         bet: i64,
     ) -> sqlx::Result<Db::QueryResult>;
@@ -6,7 +6,7 @@ use serenity::all::UserId;
 use sqlx::{Database, Pool};
 
 use crate::{
-    Error, Result, ShopCurrency,
+    Economy, Error, Result, ShopCurrency,
     models::gambling::GamblingManager,
     shop::{ALL_INS, LUCKY_CHIP, SHOP_ITEMS, ShopItem},
 };
@@ -15,17 +15,20 @@ use crate::{
 pub trait EffectsManager<Db: Database> {
     async fn get_effects(
         conn: &mut Db::Connection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
     ) -> sqlx::Result<HashMap<String, i32>>;
 
     async fn get_effect(
         conn: &mut Db::Connection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         effect: &str,
     ) -> sqlx::Result<Option<EffectsRow>>;
 
     async fn add_effect(
         conn: &mut Db::Connection,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         item: &ShopItem<'_>,
     ) -> sqlx::Result<Db::QueryResult>;
@@ -34,6 +37,7 @@ pub trait EffectsManager<Db: Database> {
 
     async fn bet_limit<GamblingHandler: GamblingManager<Db>>(
         pool: &Pool<Db>,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         bet: i64,
         coins: i64,
@@ -48,7 +52,7 @@ pub trait EffectsManager<Db: Database> {
 
         let mut tx = pool.begin().await.unwrap();
 
-        match Self::get_effect(&mut *tx, user_id, ALL_INS.id)
+        match Self::get_effect(&mut *tx, economy, user_id, ALL_INS.id)
             .await
             .unwrap()
         {
@@ -56,7 +60,9 @@ pub trait EffectsManager<Db: Database> {
                 Self::remove_effect(&mut *tx, effect.id).await?;
             }
             None => {
-                let max = GamblingHandler::max_bet(&mut *tx, user_id).await.unwrap();
+                let max = GamblingHandler::max_bet(&mut *tx, economy, user_id)
+                    .await
+                    .unwrap();
                 if bet > max {
                     return Err(Error::MaximumBetAmount(max));
                 }
@@ -77,6 +83,7 @@ pub trait EffectsManager<Db: Database> {
 
     async fn payout(
         pool: &Pool<Db>,
+        economy: Economy,
         user_id: impl Into<UserId> + Send,
         bet: i64,
         mut payout: i64,
@@ -88,7 +95,7 @@ pub trait EffectsManager<Db: Database> {
         let user_id = user_id.into();
 
         let mut tx = pool.begin().await.unwrap();
-        let mut effects = Self::get_effects(&mut *tx, user_id).await.unwrap();
+        let mut effects = Self::get_effects(&mut *tx, economy, user_id).await.unwrap();
 
         {
             let lucky_chip = effects.remove(LUCKY_CHIP.id);
@@ -1,7 +1,7 @@
 use chrono::{NaiveDate, Utc};
 use serenity::all::UserId;
 use sqlx::FromRow;
-use zayden_core::FormatNum;
+use zayden_core::{FormatNum, t};
 
 use crate::goals::GOAL_REGISTRY;
 
@@ -52,7 +52,7 @@ impl GamblingGoalsRow {
         self.progress == self.target
     }
 
-    pub fn description(&self) -> String {
+    pub fn description(&self, locale: &str) -> String {
         let title = if let Some(goal) = GOAL_REGISTRY.get_definition(&self.goal_id) {
             (goal.description)(self.target)
         } else {
@@ -63,9 +63,17 @@ impl GamblingGoalsRow {
         let target_str = self.target.format();
 
         if self.is_complete() {
-            format!("~~**{title}**~~\nProgress: Done 🟢")
+            format!("~~**{title}**~~\n{}", t!(locale, "gambling.goals.done"))
         } else {
-            format!("**{title}**\nProgress: `{progress_str}/{target_str}`")
+            format!(
+                "**{title}**\n{}",
+                t!(
+                    locale,
+                    "gambling.goals.progress",
+                    progress = progress_str,
+                    target = target_str
+                )
+            )
         }
     }
 }
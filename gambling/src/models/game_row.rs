@@ -2,15 +2,31 @@ use async_trait::async_trait;
 use serenity::all::UserId;
 use sqlx::{Database, FromRow, Pool};
 
-use crate::Prestige;
+use crate::{Economy, Prestige};
 
 use super::{Coins, Gems, MaxBet};
 
 #[async_trait]
 pub trait GameManager<Db: Database> {
-    async fn row(pool: &Pool<Db>, id: impl Into<UserId> + Send) -> sqlx::Result<Option<GameRow>>;
+    async fn row(
+        pool: &Pool<Db>,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+    ) -> sqlx::Result<Option<GameRow>>;
 
-    async fn save(pool: &Pool<Db>, row: GameRow) -> sqlx::Result<Db::QueryResult>;
+    async fn save(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        row: GameRow,
+    ) -> sqlx::Result<Db::QueryResult>;
+
+    /// Returns the user's new balance, or `None` if they have never played.
+    async fn add_coins(
+        conn: &mut Db::Connection,
+        economy: Economy,
+        id: impl Into<UserId> + Send,
+        amount: i64,
+    ) -> sqlx::Result<Option<i64>>;
 }
 
 #[derive(FromRow)]
@@ -0,0 +1,452 @@
+use async_trait::async_trait;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ChannelId, ComponentInteraction, CreateEmbed, EditMessage, Http, MessageId, ModalInteraction,
+    UserId,
+};
+use sqlx::any::AnyQueryResult;
+use sqlx::types::Json;
+use sqlx::{Database, Pool};
+use zayden_core::CronJob;
+
+use crate::commands::blackjack::Blackjack;
+use crate::commands::crash::Crash;
+use crate::commands::higher_lower::HigherLower;
+use crate::commands::mines::Mines;
+use crate::commands::poker::Poker;
+use crate::commands::roulette::Roulette;
+use crate::commands::tictactoe::TicTacToe;
+use crate::{
+    Economy, EffectsManager, Error, GameManager, GoalsManager, Ledger, LedgerManager, Result,
+};
+
+/// How long a game waits for its next move before it is settled.
+pub const SESSION_TIMEOUT: TimeDelta = TimeDelta::minutes(2);
+
+/// The state of a game that is waiting on its players.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "game", rename_all = "snake_case")]
+pub enum Session {
+    Blackjack(Blackjack),
+    Crash(Crash),
+    HigherLower(HigherLower),
+    Mines(Mines),
+    Poker(Poker),
+    Roulette(Roulette),
+    TicTacToe(TicTacToe),
+}
+
+impl Session {
+    fn time_out(self) -> TimedOut {
+        match self {
+            Self::Blackjack(game) => game.time_out(),
+            Self::Crash(game) => game.time_out(),
+            Self::HigherLower(game) => game.time_out(),
+            Self::Mines(game) => game.time_out(),
+            Self::Poker(game) => game.time_out(),
+            Self::Roulette(game) => game.time_out(),
+            Self::TicTacToe(game) => game.time_out(),
+        }
+    }
+}
+
+/// How a game that timed out ends: the coins its players get back and the
+/// embed left on its message.
+pub(crate) struct TimedOut {
+    pub(crate) economy: Economy,
+    /// The ledger source the refunds are recorded under.
+    pub(crate) source: &'static str,
+    pub(crate) refunds: Vec<(UserId, i64)>,
+    pub(crate) embed: CreateEmbed,
+}
+
+/// A game stored by the message its buttons are on, so the buttons keep
+/// working after a restart.
+pub struct SessionRow {
+    pub message_id: i64,
+    pub channel_id: i64,
+    pub state: Json<Session>,
+    pub expires_at: DateTime<Utc>,
+    /// How many moves have been stored, which a move has to match to be
+    /// stored itself.
+    pub version: i64,
+}
+
+impl SessionRow {
+    pub fn new(
+        message_id: impl Into<MessageId>,
+        channel_id: impl Into<ChannelId>,
+        session: impl Into<Session>,
+    ) -> Self {
+        Self {
+            message_id: message_id.into().get() as i64,
+            channel_id: channel_id.into().get() as i64,
+            state: Json(session.into()),
+            expires_at: Utc::now() + SESSION_TIMEOUT,
+            version: 0,
+        }
+    }
+
+    pub fn message_id(&self) -> MessageId {
+        MessageId::new(self.message_id as u64)
+    }
+
+    pub fn channel_id(&self) -> ChannelId {
+        ChannelId::new(self.channel_id as u64)
+    }
+}
+
+#[async_trait]
+pub trait SessionManager<Db: Database> {
+    async fn row(
+        pool: &Pool<Db>,
+        message_id: impl Into<MessageId> + Send,
+    ) -> sqlx::Result<Option<SessionRow>>;
+
+    async fn insert(pool: &Pool<Db>, row: &SessionRow) -> sqlx::Result<AnyQueryResult>;
+
+    /// Stores the state of an existing session at `row.version`, bumping the
+    /// version. Returns `false` if another move was stored or the session
+    /// was settled in the meantime.
+    async fn update(conn: &mut Db::Connection, row: &SessionRow) -> sqlx::Result<bool>;
+
+    /// Removes the session at `version`, returning `false` if it moved on or
+    /// was already gone. Only the caller that removes a session settles it.
+    async fn delete(
+        conn: &mut Db::Connection,
+        message_id: impl Into<MessageId> + Send,
+        version: i64,
+    ) -> sqlx::Result<bool>;
+
+    async fn expired(pool: &Pool<Db>, now: DateTime<Utc>) -> sqlx::Result<Vec<SessionRow>>;
+}
+
+/// An interaction on the message a game is played on.
+pub(crate) trait GameInteraction {
+    /// The message holding the game, if the interaction came from one.
+    fn game_message(&self) -> Option<(MessageId, ChannelId)>;
+}
+
+impl GameInteraction for ComponentInteraction {
+    fn game_message(&self) -> Option<(MessageId, ChannelId)> {
+        Some((self.message.id, self.channel_id))
+    }
+}
+
+impl GameInteraction for ModalInteraction {
+    fn game_message(&self) -> Option<(MessageId, ChannelId)> {
+        self.message
+            .as_ref()
+            .map(|message| (message.id, message.channel_id))
+    }
+}
+
+pub struct Sessions;
+
+impl Sessions {
+    /// The game behind the buttons of `interaction` and the version it was
+    /// loaded at, unless it has already ended.
+    pub(crate) async fn get<Db: Database, Manager: SessionManager<Db>>(
+        pool: &Pool<Db>,
+        interaction: &impl GameInteraction,
+    ) -> Result<(Session, i64)> {
+        let (message_id, _) = interaction.game_message().ok_or(Error::GameEnded)?;
+
+        match Manager::row(pool, message_id).await? {
+            Some(row) if row.expires_at > Utc::now() => Ok((row.state.0, row.version)),
+            _ => Err(Error::GameEnded),
+        }
+    }
+
+    /// Stores the game after a move, giving the players another
+    /// [`SESSION_TIMEOUT`] for the next one. Fails if another move was
+    /// stored since the game was loaded at `version`, so only one of two
+    /// clicks at the same time goes through, and anything the move costs
+    /// should only be taken once this succeeds.
+    pub(crate) async fn update<Db: Database, Manager: SessionManager<Db>>(
+        pool: &Pool<Db>,
+        interaction: &impl GameInteraction,
+        version: i64,
+        session: impl Into<Session>,
+    ) -> Result<()> {
+        let mut conn = pool.acquire().await?;
+
+        Self::update_in::<Db, Manager>(&mut *conn, interaction, version, session).await
+    }
+
+    /// [`Sessions::update`] on a connection, so that a move can be stored in
+    /// the same transaction as the coins it costs.
+    pub(crate) async fn update_in<Db: Database, Manager: SessionManager<Db>>(
+        conn: &mut Db::Connection,
+        interaction: &impl GameInteraction,
+        version: i64,
+        session: impl Into<Session>,
+    ) -> Result<()> {
+        let (message_id, channel_id) = interaction.game_message().ok_or(Error::GameEnded)?;
+        let mut row = SessionRow::new(message_id, channel_id, session);
+        row.version = version;
+
+        match Manager::update(conn, &row).await? {
+            true => Ok(()),
+            false => Err(Error::GameEnded),
+        }
+    }
+
+    /// Ends the game behind the buttons of `interaction`, failing if it was
+    /// already settled or moved on since it was loaded at `version`.
+    pub(crate) async fn end<Db: Database, Manager: SessionManager<Db>>(
+        pool: &Pool<Db>,
+        interaction: &impl GameInteraction,
+        version: i64,
+    ) -> Result<()> {
+        let mut conn = pool.acquire().await?;
+
+        Self::end_in::<Db, Manager>(&mut *conn, interaction, version).await
+    }
+
+    /// [`Sessions::end`] on a connection, so that the last move can be taken
+    /// in the same transaction as the coins it costs.
+    pub(crate) async fn end_in<Db: Database, Manager: SessionManager<Db>>(
+        conn: &mut Db::Connection,
+        interaction: &impl GameInteraction,
+        version: i64,
+    ) -> Result<()> {
+        let (message_id, _) = interaction.game_message().ok_or(Error::GameEnded)?;
+
+        match Manager::delete(conn, message_id, version).await? {
+            true => Ok(()),
+            false => Err(Error::GameEnded),
+        }
+    }
+
+    /// Moves on every session that timed out the way each game treats a
+    /// player that stopped playing: blackjack stands, higher or lower and
+    /// mines cash out, a poker hand checks or folds for whoever's turn it
+    /// was, and the rest are called off. A session that fails to move on is
+    /// logged and left for the next run.
+    pub async fn expire<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db> + Send + Sync,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        http: &Http,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        for row in SessionHandler::expired(pool, Utc::now()).await? {
+            let message_id = row.message_id();
+
+            if let Err(e) = Self::time_out::<
+                Db,
+                GoalsHandler,
+                EffectsHandler,
+                GameHandler,
+                SessionHandler,
+                LedgerHandler,
+            >(http, pool, row)
+            .await
+            {
+                tracing::error!("Failed to end game session {message_id}: {e:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Ends every session that timed out while the bot was offline,
+    /// refunding whatever its players had staked, as they never got the
+    /// chance to finish. A session that fails to end is logged and left for
+    /// the next run.
+    pub async fn refund_expired<
+        Db: Database,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        http: &Http,
+        pool: &Pool<Db>,
+    ) -> Result<()> {
+        for row in SessionHandler::expired(pool, Utc::now()).await? {
+            let message_id = row.message_id();
+
+            if let Err(e) =
+                Self::refund::<Db, GameHandler, SessionHandler, LedgerHandler>(http, pool, row)
+                    .await
+            {
+                tracing::error!("Failed to end game session {message_id}: {e:?}");
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn time_out<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db> + Send + Sync,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        http: &Http,
+        pool: &Pool<Db>,
+        row: SessionRow,
+    ) -> Result<()> {
+        let (channel_id, message_id, version) = (row.channel_id(), row.message_id(), row.version);
+
+        let (embed, components) = match row.state.0 {
+            Session::Blackjack(mut game) => {
+                if !Self::remove::<Db, SessionHandler>(pool, message_id, version).await? {
+                    return Ok(());
+                }
+
+                let embed = game
+                    .settle::<Db, GoalsHandler, EffectsHandler, GameHandler, LedgerHandler>(
+                        pool, message_id,
+                    )
+                    .await?;
+                (embed, Vec::new())
+            }
+            Session::HigherLower(game) => {
+                if !Self::remove::<Db, SessionHandler>(pool, message_id, version).await? {
+                    return Ok(());
+                }
+
+                let embed = game
+                    .cash_out::<Db, GoalsHandler, GameHandler, LedgerHandler>(pool, message_id)
+                    .await?;
+                (embed, Vec::new())
+            }
+            Session::Mines(game) => {
+                if !Self::remove::<Db, SessionHandler>(pool, message_id, version).await? {
+                    return Ok(());
+                }
+
+                let board = game.board(true);
+                let embed = game
+                    .settle::<Db, GoalsHandler, EffectsHandler, GameHandler, LedgerHandler>(
+                        pool, message_id, false,
+                    )
+                    .await?;
+                (embed, board)
+            }
+            Session::Poker(game) if game.hand.is_some() => {
+                return game
+                    .skip_turn::<Db, GoalsHandler, GameHandler, SessionHandler, LedgerHandler>(
+                        http, pool, channel_id, message_id, version,
+                    )
+                    .await;
+            }
+            session => {
+                let row = SessionRow {
+                    state: Json(session),
+                    ..row
+                };
+                return Self::refund::<Db, GameHandler, SessionHandler, LedgerHandler>(
+                    http, pool, row,
+                )
+                .await;
+            }
+        };
+
+        // The game is settled even if the message was deleted
+        let _ = channel_id
+            .edit_message(
+                http,
+                message_id,
+                EditMessage::new().embed(embed).components(components),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    /// Removes a session that timed out at `version`, returning `false` if
+    /// a move got in first.
+    async fn remove<Db: Database, Manager: SessionManager<Db>>(
+        pool: &Pool<Db>,
+        message_id: MessageId,
+        version: i64,
+    ) -> Result<bool> {
+        let mut conn = pool.acquire().await?;
+
+        Ok(Manager::delete(&mut *conn, message_id, version).await?)
+    }
+
+    /// Removes the session and refunds its stakes in one transaction, so
+    /// the coins are returned exactly once.
+    pub(crate) async fn refund<
+        Db: Database,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >(
+        http: &Http,
+        pool: &Pool<Db>,
+        row: SessionRow,
+    ) -> Result<()> {
+        let (channel_id, message_id) = (row.channel_id(), row.message_id());
+        let timed_out = row.state.0.time_out();
+        let economy = timed_out.economy;
+
+        let mut tx: sqlx::Transaction<'static, Db> = pool.begin().await?;
+
+        if !SessionHandler::delete(&mut *tx, message_id, row.version).await? {
+            return Ok(());
+        }
+
+        for (user, stake) in timed_out.refunds {
+            if let Some(balance) = GameHandler::add_coins(&mut *tx, economy, user, stake).await? {
+                Ledger::new(economy, user, timed_out.source)
+                    .reference(message_id)
+                    .coins(balance - stake, balance)
+                    .record::<Db, LedgerHandler>(&mut *tx)
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        // The coins are refunded even if the message was deleted
+        let _ = channel_id
+            .edit_message(
+                http,
+                message_id,
+                EditMessage::new()
+                    .embed(timed_out.embed)
+                    .components(Vec::new()),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    pub fn cron_job<
+        Db: Database,
+        GoalsHandler: GoalsManager<Db> + Send + Sync,
+        EffectsHandler: EffectsManager<Db> + Send,
+        GameHandler: GameManager<Db>,
+        SessionHandler: SessionManager<Db>,
+        LedgerHandler: LedgerManager<Db>,
+    >() -> CronJob<Db> {
+        CronJob::new("game_sessions", "0 * * * * * *")
+            .expect("Schedule should be valid")
+            .set_action(|ctx, pool| async move {
+                if let Err(e) = Self::expire::<
+                    Db,
+                    GoalsHandler,
+                    EffectsHandler,
+                    GameHandler,
+                    SessionHandler,
+                    LedgerHandler,
+                >(&ctx.http, &pool)
+                .await
+                {
+                    tracing::error!("Failed to load expired game sessions: {e:?}");
+                }
+            })
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::HashMap;
+
+use rand::distr::weighted::WeightedIndex;
+use rand::rng;
+use rand_distr::Distribution;
+use serde::Deserialize;
+use zayden_core::ConfigError;
+
+use crate::config;
+
+/// The rows of each reel in view, with the reel's stop in the middle.
+pub const ROWS: usize = 3;
+
+/// The `[gambling.slots]` section, which describes the whole machine so it
+/// can be tuned without a release. Symbols are emoji names, like `coal`, or
+/// unicode emojis, like `💎`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SlotsConfig {
+    /// The reel strips from left to right, each read from top to bottom.
+    pub reels: Vec<Vec<String>>,
+    /// How likely a reel is to stop on each symbol, 1 for the ones not
+    /// listed.
+    #[serde(default)]
+    pub weights: HashMap<String, u32>,
+    /// The row read from each reel for every line, counted from 0 at the
+    /// top.
+    pub paylines: Vec<Vec<usize>>,
+    /// A symbol that stands in for any other on a line, apart from the free
+    /// spin symbol.
+    #[serde(default)]
+    pub wild: Option<String>,
+    pub paytable: Vec<Pay>,
+    #[serde(default)]
+    pub free_spins: Option<FreeSpins>,
+}
+
+impl Default for SlotsConfig {
+    /// The machine in the example config, which pays back about 94%.
+    fn default() -> Self {
+        let strip = |symbols: [&str; 18]| symbols.map(String::from).to_vec();
+        let pay = |symbol: &str, count, pays| Pay {
+            symbol: symbol.to_string(),
+            count,
+            pays,
+        };
+
+        Self {
+            reels: vec![
+                strip([
+                    "coal", "iron", "coal", "gold", "coal", "redstone", "iron", "lapis", "coal",
+                    "diamond", "iron", "💎", "coal", "gold", "emerald", "coin", "iron", "redstone",
+                ]),
+                strip([
+                    "coal", "iron", "gold", "coal", "redstone", "iron", "coal", "lapis", "diamond",
+                    "coal", "iron", "💎", "gold", "coal", "emerald", "iron", "coin", "redstone",
+                ]),
+                strip([
+                    "iron", "coal", "gold", "coal", "iron", "redstone", "coal", "lapis", "iron",
+                    "diamond", "coal", "💎", "gold", "iron", "emerald", "coal", "coin", "redstone",
+                ]),
+            ],
+            weights: [
+                ("coal", 4),
+                ("iron", 3),
+                ("gold", 2),
+                ("redstone", 2),
+                ("lapis", 2),
+            ]
+            .into_iter()
+            .map(|(symbol, weight)| (symbol.to_string(), weight))
+            .collect(),
+            paylines: vec![
+                vec![1, 1, 1],
+                vec![0, 0, 0],
+                vec![2, 2, 2],
+                vec![0, 1, 2],
+                vec![2, 1, 0],
+            ],
+            wild: Some(String::from("coin")),
+            paytable: vec![
+                pay("coal", 3, 1.0),
+                pay("iron", 3, 2.0),
+                pay("gold", 3, 4.0),
+                pay("redstone", 3, 5.0),
+                pay("lapis", 3, 5.0),
+                pay("diamond", 2, 1.0),
+                pay("diamond", 3, 20.0),
+                pay("emerald", 2, 2.0),
+                pay("emerald", 3, 30.0),
+                pay("coin", 3, 100.0),
+            ],
+            free_spins: Some(FreeSpins {
+                symbol: String::from("💎"),
+                count: 3,
+                spins: 5,
+                multiplier: 2.0,
+            }),
+        }
+    }
+}
+
+/// What a line pays for at least `count` of `symbol` from the left, as a
+/// multiple of the bet.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Pay {
+    pub symbol: String,
+    pub count: usize,
+    pub pays: f64,
+}
+
+/// The spins awarded for `count` of `symbol` anywhere in view. They are
+/// played straight away and their wins are multiplied by `multiplier`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FreeSpins {
+    pub symbol: String,
+    pub count: usize,
+    pub spins: u32,
+    pub multiplier: f64,
+}
+
+/// The symbols in view after a spin, by reel and then row.
+pub(crate) type Window<'a> = Vec<[&'a str; ROWS]>;
+
+/// A line that paid, numbered from 0 in the order of the paylines.
+pub(crate) struct LineWin<'a> {
+    pub line: usize,
+    pub symbol: &'a str,
+    pub count: usize,
+    pub pays: f64,
+}
+
+/// A paid spin and the free spins it won.
+pub(crate) struct Play<'a> {
+    pub window: Window<'a>,
+    pub wins: Vec<LineWin<'a>>,
+    /// The free spins and what they won together.
+    pub free_spins: Option<(&'a FreeSpins, i64)>,
+    /// Everything the spin returns, including the bet.
+    pub payout: i64,
+}
+
+impl SlotsConfig {
+    /// The share of the bets the machine pays back in the long run, worked
+    /// out from every way the reels can stop. Free spins don't win more free
+    /// spins, so each one is worth a paid spin's line wins times the
+    /// multiplier.
+    pub fn rtp(&self) -> f64 {
+        let totals = self
+            .reels
+            .iter()
+            .map(|strip| strip.iter().map(|symbol| self.weight(symbol)).sum::<f64>())
+            .collect::<Vec<_>>();
+
+        let mut stops = vec![0; self.reels.len()];
+        let (mut lines, mut triggers) = (0.0, 0.0);
+
+        loop {
+            let chance = stops
+                .iter()
+                .zip(&self.reels)
+                .zip(&totals)
+                .map(|((stop, strip), total)| self.weight(&strip[*stop]) / total)
+                .product::<f64>();
+
+            let window = self.stop_at(&stops);
+
+            lines += chance
+                * self
+                    .line_wins(&window)
+                    .iter()
+                    .map(|win| win.pays)
+                    .sum::<f64>();
+
+            if self.free_spins(&window).is_some() {
+                triggers += chance;
+            }
+
+            // Counts through the stops like an odometer
+            let Some(reel) = stops
+                .iter()
+                .zip(&self.reels)
+                .rposition(|(stop, strip)| stop + 1 < strip.len())
+            else {
+                break;
+            };
+            stops[reel] += 1;
+            stops[reel + 1..].fill(0);
+        }
+
+        let free = self
+            .free_spins
+            .as_ref()
+            .map_or(0.0, |free| f64::from(free.spins) * free.multiplier);
+
+        lines + triggers * free * lines
+    }
+
+    /// Checks that the machine can be played, with every symbol shown as an
+    /// emoji.
+    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+        let invalid = |reason: String| ConfigError::Invalid {
+            section: "gambling.slots",
+            reason,
+        };
+
+        if self.reels.is_empty() {
+            return Err(invalid(String::from("there are no reels")));
+        }
+
+        if let Some(reel) = self
+            .reels
+            .iter()
+            .position(|strip| strip.iter().all(|symbol| self.weight(symbol) == 0.0))
+        {
+            return Err(invalid(format!("reel {reel} can't stop on any symbol")));
+        }
+
+        if let Some(line) = self
+            .paylines
+            .iter()
+            .position(|rows| rows.len() != self.reels.len() || rows.iter().any(|row| *row >= ROWS))
+        {
+            return Err(invalid(format!(
+                "payline {line} needs a row from 0 to {} for each of the {} reels",
+                ROWS - 1,
+                self.reels.len()
+            )));
+        }
+
+        let missing = self
+            .reels
+            .iter()
+            .flatten()
+            .find(|symbol| symbol.is_ascii() && config::resolve(symbol).is_none());
+
+        match missing {
+            Some(symbol) => Err(ConfigError::MissingEmoji(symbol.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Spins the reels, and then any free spins that won. The paid spin
+    /// lands on `stops` when they are given, one for each reel.
+    pub(crate) fn play(&self, bet: i64, stops: Option<Vec<usize>>) -> Play<'_> {
+        let window = match stops {
+            Some(stops) => self.stop_at(&stops),
+            None => self.spin(),
+        };
+        let wins = self.line_wins(&window);
+        let mut payout = pays(bet, wins.iter().map(|win| win.pays).sum());
+
+        let free_spins = self.free_spins(&window).map(|free| {
+            let won = (0..free.spins)
+                .map(|_| {
+                    let pays_each = self
+                        .line_wins(&self.spin())
+                        .iter()
+                        .map(|win| win.pays)
+                        .sum::<f64>();
+                    pays(bet, pays_each * free.multiplier)
+                })
+                .sum::<i64>();
+
+            (free, won)
+        });
+
+        if let Some((_, won)) = free_spins {
+            payout += won;
+        }
+
+        Play {
+            window,
+            wins,
+            free_spins,
+            payout,
+        }
+    }
+
+    fn weight(&self, symbol: &str) -> f64 {
+        f64::from(self.weights.get(symbol).copied().unwrap_or(1))
+    }
+
+    fn spin(&self) -> Window<'_> {
+        self.reels
+            .iter()
+            .map(|strip| {
+                let dist =
+                    WeightedIndex::new(strip.iter().map(|symbol| self.weight(symbol))).unwrap();
+                view(strip, dist.sample(&mut rng()))
+            })
+            .collect()
+    }
+
+    fn stop_at(&self, stops: &[usize]) -> Window<'_> {
+        stops
+            .iter()
+            .zip(&self.reels)
+            .map(|(stop, strip)| view(strip, *stop))
+            .collect()
+    }
+
+    fn line_wins<'a>(&'a self, window: &Window<'a>) -> Vec<LineWin<'a>> {
+        self.paylines
+            .iter()
+            .enumerate()
+            .filter_map(|(line, rows)| {
+                let symbols = rows
+                    .iter()
+                    .zip(window)
+                    .map(|(row, reel)| reel[*row])
+                    .collect::<Vec<_>>();
+
+                self.line_pay(&symbols)
+                    .map(|(symbol, count, pays)| LineWin {
+                        line,
+                        symbol,
+                        count,
+                        pays,
+                    })
+            })
+            .collect()
+    }
+
+    /// The symbol a line pays for, how many of it there are from the left
+    /// and what that pays.
+    fn line_pay<'a>(&'a self, symbols: &[&'a str]) -> Option<(&'a str, usize, f64)> {
+        let wild = self.wild.as_deref();
+        let scatter = self.free_spins.as_ref().map(|free| free.symbol.as_str());
+
+        // A line of nothing but wilds pays as wilds
+        let symbol = symbols
+            .iter()
+            .copied()
+            .find(|symbol| Some(*symbol) != wild)
+            .or(wild)?;
+
+        let count = symbols
+            .iter()
+            .take_while(|s| **s == symbol || (Some(**s) == wild && Some(symbol) != scatter))
+            .count();
+
+        self.paytable
+            .iter()
+            .filter(|pay| pay.symbol == symbol && pay.count <= count)
+            .map(|pay| pay.pays)
+            .max_by(f64::total_cmp)
+            .filter(|pays| *pays > 0.0)
+            .map(|pays| (symbol, count, pays))
+    }
+
+    /// The free spins `window` wins, if it has enough of their symbol.
+    fn free_spins(&self, window: &Window<'_>) -> Option<&FreeSpins> {
+        let free = self.free_spins.as_ref()?;
+
+        let count = window
+            .iter()
+            .flatten()
+            .filter(|symbol| **symbol == free.symbol)
+            .count();
+
+        (count >= free.count).then_some(free)
+    }
+}
+
+/// The rows in view when `strip` stops at `stop`.
+fn view(strip: &[String], stop: usize) -> [&str; ROWS] {
+    std::array::from_fn(|row| strip[(stop + strip.len() + row - ROWS / 2) % strip.len()].as_str())
+}
+
+fn pays(bet: i64, multiple: f64) -> i64 {
+    (bet as f64 * multiple) as i64
+}
+
+/// How a symbol is shown on the reels.
+pub(crate) fn emoji(symbol: &str) -> String {
+    if symbol.is_ascii() {
+        format!("<:{symbol}:{}>", config::emoji(symbol))
+    } else {
+        String::from(symbol)
+    }
+}
@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use sqlx::{Database, Pool, any::AnyQueryResult};
-use zayden_core::CronJob;
+use zayden_core::{CatchUp, CronJob};
 
 #[async_trait]
 pub trait StaminaManager<Db: Database> {
@@ -11,8 +11,11 @@ pub struct StaminaCron;
 
 impl StaminaCron {
     pub fn cron_job<Db: Database, Manager: StaminaManager<Db>>() -> CronJob<Db> {
-        CronJob::new("stamina", "0 */10 * * * * *").set_action(|_ctx, pool| async move {
-            Manager::update(&pool).await.unwrap();
-        })
+        CronJob::new("stamina", "0 */10 * * * * *")
+            .expect("Schedule should be valid")
+            .catch_up(CatchUp::All)
+            .set_action(|_ctx, pool| async move {
+                Manager::update(&pool).await.unwrap();
+            })
     }
 }
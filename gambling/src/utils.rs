@@ -1,14 +1,14 @@
 use std::fmt::Display;
 
-use serenity::all::{Colour, CreateEmbed, EmojiId};
-use zayden_core::FormatNum;
+use serenity::all::{Colour, CreateEmbed};
+use zayden_core::{FormatNum, t};
 
-use crate::COIN;
+use crate::{COIN, ConfigEmoji};
 
 #[derive(Clone, Copy)]
 pub enum Emoji<'a> {
     Str(&'a str),
-    Id(EmojiId),
+    Id(ConfigEmoji),
     None,
 }
 
@@ -34,7 +34,7 @@ impl<'a> GameResult<'a> {
 }
 
 impl GameResult<'_> {
-    pub fn new_with_id(name: impl Into<String>, emoji: EmojiId) -> Self {
+    pub fn new_with_id(name: impl Into<String>, emoji: ConfigEmoji) -> Self {
         Self {
             name: name.into(),
             emoji: Emoji::Id(emoji),
@@ -62,7 +62,14 @@ impl PartialEq for GameResult<'_> {
     }
 }
 
+/// An amount of coins with the coin emoji, e.g. `1,000 <:coin:…>`.
+pub(crate) fn coins_str(amount: i64) -> String {
+    format!("{} <:coin:{COIN}>", amount.format())
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn game_embed<'a>(
+    locale: &str,
     title: impl Into<String>,
     prediction: impl Into<GameResult<'a>>,
     outcome_text: &str,
@@ -76,22 +83,21 @@ pub fn game_embed<'a>(
 
     let win = prediction == outcome;
 
-    let result = format!("Payout: {} ({})", payout.format(), (payout - bet).format());
-
     let colour = if win { Colour::DARK_GREEN } else { Colour::RED };
 
     let desc = format!(
-        "Your bet: {} <:coin:{COIN}>
-        
-        **You bet on:** {} ({prediction})
-        **{outcome_text}:** {} ({outcome})
-        
-        {result}
-        Your coins: {}",
-        bet.format(),
+        "{}\n\n**{}:** {} ({prediction})\n**{outcome_text}:** {} ({outcome})\n\n{}\n{}",
+        t!(locale, "gambling.your_bet", bet = coins_str(bet)),
+        t!(locale, "gambling.you_bet_on"),
         prediction.emoji(),
         outcome.emoji(),
-        coins.format()
+        t!(
+            locale,
+            "gambling.payout",
+            payout = payout.format(),
+            profit = (payout - bet).format()
+        ),
+        t!(locale, "gambling.your_coins", coins = coins.format()),
     );
 
     CreateEmbed::new()
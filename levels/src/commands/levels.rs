@@ -1,11 +1,6 @@
-use std::time::Duration;
-
-use futures::StreamExt;
-use serenity::all::{
-    CommandInteraction, ComponentInteraction, Context, CreateButton, CreateEmbed,
-    CreateEmbedFooter, EditInteractionResponse, GuildId, Mentionable,
-};
+use serenity::all::{CommandInteraction, Context, CreateEmbed, Mentionable};
 use sqlx::{Database, Pool};
+use zayden_core::Paginator;
 use zayden_core::cache::GuildMembersCache;
 
 use crate::{LeaderboardRow, LevelsManager, LevelsRow};
@@ -20,119 +15,50 @@ impl Commands {
     ) {
         interaction.defer(ctx).await.unwrap();
 
-        let embed = create_embed::<Db, Manager>(ctx, pool, interaction.guild_id.unwrap(), 1).await;
-
-        let msg = interaction
-            .edit_response(
-                &ctx,
-                EditInteractionResponse::new()
-                    .embed(embed)
-                    .button(CreateButton::new("previous").label("<"))
-                    .button(CreateButton::new("user").emoji('🎯'))
-                    .button(CreateButton::new("next").label(">")),
-            )
-            .await
-            .unwrap();
-
-        let mut stream = msg
-            .await_component_interactions(ctx)
-            .timeout(Duration::from_secs(120))
-            .stream();
-
-        while let Some(component) = stream.next().await {
-            run_components::<Db, Manager>(ctx, component, pool).await
-        }
-    }
-}
-
-async fn run_components<Db: Database, Manager: LevelsManager<Db>>(
-    ctx: &Context,
-    mut interaction: ComponentInteraction,
-    pool: &Pool<Db>,
-) {
-    interaction.defer(ctx).await.unwrap();
-
-    let Some(embed) = interaction.message.embeds.pop() else {
-        unreachable!("Embed must be present")
-    };
-
-    let page_number = match interaction.data.custom_id.as_str() {
-        "previous" => {
-            embed
-                .footer
-                .unwrap()
-                .text
-                .strip_prefix("Page ")
-                .unwrap()
-                .parse::<i64>()
-                .unwrap()
-                - 1
-        }
-        "user" => {
-            let Some(row_number) = Manager::user_rank(pool, interaction.user.id).await.unwrap()
-            else {
-                return;
-            };
-
-            row_number / 10 + 1
-        }
-        "next" => {
-            embed
-                .footer
-                .unwrap()
-                .text
-                .strip_prefix("Page ")
+        let users = {
+            let data = ctx.data.read().await;
+            let cache = data.get::<GuildMembersCache>().unwrap();
+            cache
+                .get(&interaction.guild_id.unwrap())
                 .unwrap()
-                .parse::<i64>()
-                .unwrap()
-                + 1
-        }
-        _ => unreachable!(),
-    }
-    .max(1);
-
-    let embed =
-        create_embed::<Db, Manager>(ctx, pool, interaction.guild_id.unwrap(), page_number + 1)
-            .await;
+                .iter()
+                .map(|id| id.get() as i64)
+                .collect::<Vec<_>>()
+        };
 
-    interaction
-        .edit_response(ctx, EditInteractionResponse::new().embed(embed))
+        let user_page = Manager::user_rank(pool, interaction.user.id)
+            .await
+            .unwrap()
+            .map(|row_number| (row_number as usize).div_ceil(10));
+
+        let users = &users;
+        Paginator::new(users.len().div_ceil(10), |page| async move {
+            Ok::<_, serenity::Error>(create_embed::<Db, Manager>(pool, users, page).await)
+        })
+        .user_page(user_page)
+        .run(ctx, interaction)
         .await
         .unwrap();
+    }
 }
 
 async fn create_embed<Db: Database, Manager: LevelsManager<Db>>(
-    ctx: &Context,
     pool: &Pool<Db>,
-    guild_id: GuildId,
-    page_number: i64,
+    users: &[i64],
+    page: usize,
 ) -> CreateEmbed {
-    let users = {
-        let data = ctx.data.read().await;
-        let cache = data.get::<GuildMembersCache>().unwrap();
-        cache
-            .get(&guild_id)
-            .unwrap()
-            .iter()
-            .map(|id| id.get() as i64)
-            .collect::<Vec<_>>()
-    };
-
-    let rows = Manager::leaderboard(pool, &users, page_number)
+    let rows = Manager::leaderboard(pool, users, page as i64)
         .await
         .unwrap();
 
     let desc = rows
         .into_iter()
         .enumerate()
-        .map(|(i, row)| row_as_desc(&row, i + (page_number as usize - 1) * 10))
+        .map(|(i, row)| row_as_desc(&row, i + (page - 1) * 10))
         .collect::<Vec<_>>()
         .join("\n\n");
 
-    CreateEmbed::new()
-        .title("Leaderboard")
-        .description(desc)
-        .footer(CreateEmbedFooter::new(format!("Page {}", page_number)))
+    CreateEmbed::new().title("Leaderboard").description(desc)
 }
 
 fn row_as_desc(row: &LeaderboardRow, i: usize) -> String {
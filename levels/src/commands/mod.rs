@@ -8,9 +8,19 @@ pub struct Commands;
 
 impl Commands {
     pub fn register() -> [CreateCommand; 3] {
-        let levels = CreateCommand::new("levels").description("Get the leaderboard");
+        [
+            Self::register_levels(),
+            Self::register_rank(),
+            Self::register_xp(),
+        ]
+    }
+
+    pub fn register_levels() -> CreateCommand {
+        CreateCommand::new("levels").description("Get the leaderboard")
+    }
 
-        let rank = CreateCommand::new("rank")
+    pub fn register_rank() -> CreateCommand {
+        CreateCommand::new("rank")
             .description("Get your rank or another member's rank")
             .add_option(CreateCommandOption::new(
                 CommandOptionType::User,
@@ -21,16 +31,16 @@ impl Commands {
                 CommandOptionType::Boolean,
                 "ephemeral",
                 "Whether the response should be ephemeral",
-            ));
+            ))
+    }
 
-        let xp = CreateCommand::new("xp")
+    pub fn register_xp() -> CreateCommand {
+        CreateCommand::new("xp")
             .description("Get your current xp")
             .add_option(CreateCommandOption::new(
                 CommandOptionType::Boolean,
                 "ephemeral",
                 "Whether the response should be ephemeral",
-            ));
-
-        [levels, rank, xp]
+            ))
     }
 }
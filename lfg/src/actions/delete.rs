@@ -1,9 +1,11 @@
 use serenity::all::{ChannelId, Context, DiscordJsonError, ErrorResponse, HttpError};
 use sqlx::{Database, Pool};
+use zayden_core::CronJobManager;
 
+use crate::cron::delete_reminders;
 use crate::{PostManager, Result, templates::TemplateInfo};
 
-pub async fn delete<Db: Database, Manager: PostManager<Db>>(
+pub async fn delete<Db: Database, Manager: PostManager<Db>, CronManager: CronJobManager<Db>>(
     ctx: &Context,
     channel: ChannelId,
     pool: &Pool<Db>,
@@ -34,6 +36,8 @@ pub async fn delete<Db: Database, Manager: PostManager<Db>>(
         }
     }
 
+    delete_reminders::<Db, CronManager>(ctx, pool, channel).await;
+
     Manager::delete(pool, channel).await.unwrap();
 
     Ok(())
@@ -3,7 +3,7 @@ use serenity::all::{
     UserId,
 };
 use sqlx::{Database, Pool};
-use zayden_core::parse_options;
+use zayden_core::{i18n, parse_options, t};
 
 use crate::models::Savable;
 use crate::templates::DefaultTemplate;
@@ -13,6 +13,8 @@ use crate::{Join, PostManager, PostRow, Result};
 pub struct JoinInteraction {
     thread: ChannelId,
     user: UserId,
+    locale: String,
+    guild_locale: String,
 }
 
 impl From<&ComponentInteraction> for JoinInteraction {
@@ -20,6 +22,8 @@ impl From<&ComponentInteraction> for JoinInteraction {
         Self {
             thread: value.channel_id,
             user: value.user.id,
+            locale: i18n::locale(&value.locale, value.guild_locale.as_deref()).to_string(),
+            guild_locale: i18n::guild_locale(value.guild_locale.as_deref()).to_string(),
         }
     }
 }
@@ -41,7 +45,12 @@ impl From<&CommandInteraction> for JoinInteraction {
             _ => value.user.id,
         };
 
-        Self { thread, user }
+        Self {
+            thread,
+            user,
+            locale: i18n::locale(&value.locale, value.guild_locale.as_deref()).to_string(),
+            guild_locale: i18n::guild_locale(value.guild_locale.as_deref()).to_string(),
+        }
     }
 }
 
@@ -58,15 +67,26 @@ pub async fn join<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>>
 
     let owner = row.owner().to_user(ctx).await.unwrap();
 
-    update_embeds::<DefaultTemplate>(ctx, &row, owner.display_name(), interaction.thread).await;
+    update_embeds::<DefaultTemplate>(
+        ctx,
+        &row,
+        owner.display_name(),
+        interaction.thread,
+        &interaction.guild_locale,
+    )
+    .await;
     Announcement::Joined {
         user: interaction.user,
         alternative,
     }
-    .send(ctx, interaction.thread)
+    .send(ctx, interaction.thread, &interaction.guild_locale)
     .await;
 
     Manager::save(pool, row).await.unwrap();
 
-    Ok(format!("You have joined {}", interaction.thread.mention()))
+    Ok(t!(
+        &interaction.locale,
+        "lfg.response.joined",
+        thread = interaction.thread.mention()
+    ))
 }
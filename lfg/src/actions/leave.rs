@@ -3,7 +3,7 @@ use serenity::all::{
     Mentionable, ResolvedValue, UserId,
 };
 use sqlx::{Database, Pool};
-use zayden_core::parse_options;
+use zayden_core::{i18n, parse_options, t};
 
 use crate::{
     Leave, PostManager, PostRow, Result,
@@ -16,6 +16,8 @@ pub struct LeaveInteraction {
     thread: ChannelId,
     author: UserId,
     user: UserId,
+    locale: String,
+    guild_locale: String,
 }
 
 impl From<&CommandInteraction> for LeaveInteraction {
@@ -39,6 +41,8 @@ impl From<&CommandInteraction> for LeaveInteraction {
             thread,
             author: value.user.id,
             user,
+            locale: i18n::locale(&value.locale, value.guild_locale.as_deref()).to_string(),
+            guild_locale: i18n::guild_locale(value.guild_locale.as_deref()).to_string(),
         }
     }
 }
@@ -54,6 +58,8 @@ impl From<&ComponentInteraction> for LeaveInteraction {
             thread: value.channel_id,
             author: value.user.id,
             user,
+            locale: i18n::locale(&value.locale, value.guild_locale.as_deref()).to_string(),
+            guild_locale: i18n::guild_locale(value.guild_locale.as_deref()).to_string(),
         }
     }
 }
@@ -70,20 +76,32 @@ pub async fn leave<Db: Database, Manager: PostManager<Db> + Savable<Db, PostRow>
 
     let owner = row.owner().to_user(ctx).await.unwrap();
 
-    update_embeds::<DefaultTemplate>(ctx, &row, owner.display_name(), interaction.thread).await;
+    update_embeds::<DefaultTemplate>(
+        ctx,
+        &row,
+        owner.display_name(),
+        interaction.thread,
+        &interaction.guild_locale,
+    )
+    .await;
     Announcement::Left(interaction.user)
-        .send(ctx, interaction.thread)
+        .send(ctx, interaction.thread, &interaction.guild_locale)
         .await;
 
     Manager::save(pool, row).await.unwrap();
 
     let content = if interaction.author == interaction.user {
-        format!("You have left {}", interaction.thread.mention())
+        t!(
+            &interaction.locale,
+            "lfg.response.left",
+            thread = interaction.thread.mention()
+        )
     } else {
-        format!(
-            "{} have left {}",
-            interaction.user.mention(),
-            interaction.thread.mention()
+        t!(
+            &interaction.locale,
+            "lfg.response.removed",
+            user = interaction.user.mention(),
+            thread = interaction.thread.mention()
         )
     };
 
@@ -1,12 +1,13 @@
 use serenity::all::{ComponentInteraction, Context, CreateInteractionResponse};
 use sqlx::{Database, Pool};
+use zayden_core::CronJobManager;
 
 use crate::{Error, PostManager, Result, actions};
 
 use super::Components;
 
 impl Components {
-    pub async fn delete<Db: Database, Manager: PostManager<Db>>(
+    pub async fn delete<Db: Database, Manager: PostManager<Db>, CronManager: CronJobManager<Db>>(
         ctx: &Context,
         interaction: &ComponentInteraction,
         pool: &Pool<Db>,
@@ -17,7 +18,7 @@ impl Components {
             return Err(Error::PermissionDenied(owner));
         }
 
-        actions::delete::<Db, Manager>(ctx, interaction.channel_id, pool)
+        actions::delete::<Db, Manager, CronManager>(ctx, interaction.channel_id, pool)
             .await
             .unwrap();
 
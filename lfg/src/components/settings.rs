@@ -2,6 +2,7 @@ use serenity::all::{
     ComponentInteraction, Context, CreateInteractionResponse, CreateInteractionResponseMessage,
 };
 use sqlx::{Database, Pool};
+use zayden_core::i18n;
 
 use crate::templates::{DefaultTemplate, Template};
 use crate::{Error, PostManager, Result};
@@ -25,7 +26,8 @@ impl Components {
         }
 
         let main_row = DefaultTemplate::main_row();
-        let settings_row = DefaultTemplate::settings_row();
+        let settings_row =
+            DefaultTemplate::settings_row(i18n::guild_locale(interaction.guild_locale.as_deref()));
 
         interaction
             .create_response(
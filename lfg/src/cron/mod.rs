@@ -1,3 +1,3 @@
 pub mod reminders;
 
-pub use reminders::create_reminders;
+pub use reminders::{create_reminders, delete_reminders, reschedule_reminders, restore_reminder};
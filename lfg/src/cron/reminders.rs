@@ -1,86 +1,137 @@
-use chrono::{Datelike, Duration, Timelike};
+use chrono::{DateTime, Duration, Utc};
 use futures::future;
 use serenity::all::{ChannelId, Colour, Context, CreateEmbed, CreateMessage, Mentionable};
 use sqlx::{Database, Pool};
-use zayden_core::{CronJob, cron::CronJobs};
+use zayden_core::{CatchUp, CronJob, CronJobManager, CronJobRow, cron::CronJobs};
 
-use crate::{Join, PostManager, PostRow};
+use crate::{Join, PostManager, PostRow, Result};
 
-pub async fn create_reminders<Db: Database, Manager: PostManager<Db>>(
+pub async fn create_reminders<
+    Db: Database,
+    Manager: PostManager<Db>,
+    CronManager: CronJobManager<Db>,
+>(
     ctx: &Context,
+    pool: &Pool<Db>,
     row: &PostRow,
 ) {
     let post_id = row.channel();
-
-    let week = row.start_time - Duration::days(7);
-    let day = row.start_time - Duration::hours(24);
-    let mins_30 = row.start_time - Duration::minutes(30);
-
-    let week_job = CronJob::<Db>::new(
-        format!("lfg_{}", post_id),
-        &format!(
-            "0 {} {} {} {} * {}",
-            week.minute(),
-            week.hour(),
-            week.day(),
-            week.month(),
-            week.year()
-        ),
-    )
-    .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
-    });
-
-    let day_job = CronJob::<Db>::new(
-        format!("lfg_{}", post_id),
-        &format!(
-            "0 {} {} {} {} * {}",
-            day.minute(),
-            day.hour(),
-            day.day(),
-            day.month(),
-            day.year()
-        ),
-    )
-    .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
-    });
-
-    let mins_30_job = CronJob::<Db>::new(
-        format!("lfg_{}", post_id),
-        &format!(
-            "0 {} {} {} {} * {}",
-            mins_30.minute(),
-            mins_30.hour(),
-            mins_30.day(),
-            mins_30.month(),
-            mins_30.year()
-        ),
-    )
-    .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
-    });
-
-    let now_job = CronJob::<Db>::new(
-        format!("lfg_{}", post_id),
-        &format!(
-            "0 {} {} {} {} * {}",
-            row.start_time.minute(),
-            row.start_time.hour(),
-            row.start_time.day(),
-            row.start_time.month(),
-            row.start_time.year()
-        ),
-    )
-    .set_action(move |ctx, pool| async move {
-        reminder::<Db, Manager>(ctx, pool, post_id).await;
-    });
+    let id = format!("lfg_{}", post_id);
 
     let mut data = ctx.data.write().await;
     let jobs = data.entry::<CronJobs<Db>>().or_insert(Vec::new());
 
-    jobs.retain(|job| job.id != format!("lfg_{}", post_id));
-    jobs.extend([week_job, day_job, mins_30_job, now_job]);
+    // Times too far out for a schedule don't get a reminder
+    let mut reminders = reminder_times(row.start_time)
+        .into_iter()
+        .filter_map(|time| CronJob::once(&id, time).ok())
+        .map(|job| reminder_job::<Db, Manager>(job.catch_up(CatchUp::Once), post_id))
+        .collect::<Vec<_>>();
+
+    // Keep the progress of reminders that are unchanged so a restart doesn't
+    // forget which of them were already missed.
+    for reminder in reminders.iter_mut() {
+        reminder.last_run = jobs
+            .iter()
+            .find(|job| job.id == reminder.id && job.source() == reminder.source())
+            .and_then(|job| job.last_run)
+            .or(Some(Utc::now()));
+    }
+
+    jobs.retain(|job| job.id != id);
+    jobs.extend(reminders.iter().cloned());
+
+    drop(data);
+
+    CronManager::delete(pool, &id).await.unwrap();
+    for reminder in reminders {
+        CronManager::save(pool, reminder.to_row()).await.unwrap();
+    }
+}
+
+/// Moves each of the post's reminders from the old start time to the new
+/// one, sending a reminder again if it already went out and the post moved
+/// later.
+pub async fn reschedule_reminders<
+    Db: Database,
+    Manager: PostManager<Db>,
+    CronManager: CronJobManager<Db>,
+>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    from: DateTime<Utc>,
+    row: &PostRow,
+) -> Result<()> {
+    let post_id = row.channel();
+    let id = format!("lfg_{}", post_id);
+
+    let times = reminder_times(from)
+        .into_iter()
+        .zip(reminder_times(row.start_time));
+
+    for (old, new) in times {
+        let (Ok(old), Ok(new)) = (CronJob::<Db>::once(&id, old), CronJob::once(&id, new)) else {
+            continue;
+        };
+        let new = reminder_job::<Db, Manager>(new.catch_up(CatchUp::Once), post_id);
+
+        let moved = CronJobs::<Db>::reschedule::<CronManager>(
+            ctx,
+            pool,
+            &id,
+            old.source(),
+            new.schedule.clone(),
+        )
+        .await?;
+
+        if !moved && new.next_run().is_some() {
+            CronJobs::add::<CronManager>(ctx, pool, new).await?;
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn delete_reminders<Db: Database, CronManager: CronJobManager<Db>>(
+    ctx: &Context,
+    pool: &Pool<Db>,
+    post_id: ChannelId,
+) {
+    CronJobs::<Db>::cancel::<CronManager>(ctx, pool, &format!("lfg_{}", post_id))
+        .await
+        .unwrap();
+}
+
+/// Rebuilds a stored LFG reminder, returning `None` if the row belongs to
+/// another job or its schedule can't be parsed.
+pub fn restore_reminder<Db: Database, Manager: PostManager<Db>>(
+    row: &CronJobRow,
+) -> Option<CronJob<Db>> {
+    let post_id = row.id.strip_prefix("lfg_")?.parse::<u64>().ok()?;
+
+    Some(reminder_job::<Db, Manager>(
+        row.to_job().ok()?,
+        ChannelId::new(post_id),
+    ))
+}
+
+/// A week, a day and half an hour before the start, and the start itself.
+fn reminder_times(start: DateTime<Utc>) -> [DateTime<Utc>; 4] {
+    [
+        start - Duration::days(7),
+        start - Duration::hours(24),
+        start - Duration::minutes(30),
+        start,
+    ]
+}
+
+fn reminder_job<Db: Database, Manager: PostManager<Db>>(
+    job: CronJob<Db>,
+    post_id: ChannelId,
+) -> CronJob<Db> {
+    job.kind("lfg").set_action(move |ctx, pool| async move {
+        reminder::<Db, Manager>(ctx, pool, post_id).await;
+    })
 }
 
 async fn reminder<Db: Database, Manager: PostManager<Db>>(
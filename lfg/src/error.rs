@@ -1,5 +1,6 @@
 use serenity::all::{Mentionable, UserId};
-use zayden_core::Error as ZaydenError;
+use zayden_core::i18n::DEFAULT_LOCALE;
+use zayden_core::{Error as ZaydenError, Localize, t};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -18,38 +19,32 @@ pub enum Error {
     Sqlx(sqlx::Error),
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Localize for Error {
+    fn localize(&self, locale: &str) -> String {
         match self {
-            Self::MissingGuildId => ZaydenError::MissingGuildId.fmt(f),
-            Self::MissingSetup => {
-                write!(
-                    f,
-                    "Missing setup. If you are the owner, please run `/lfg setup` to set up the bot."
-                )
+            Self::MissingGuildId => ZaydenError::MissingGuildId.localize(locale),
+            Self::MissingSetup => t!(locale, "lfg.error.missing_setup"),
+            Self::FireteamFull => t!(locale, "lfg.error.fireteam_full"),
+            Self::PermissionDenied(id) => {
+                t!(locale, "lfg.error.permission_denied", owner = id.mention())
             }
-            Self::FireteamFull => write!(f, "Unable to join. Fireteam is full."),
-            Self::PermissionDenied(id) => write!(
-                f,
-                "Permission denied. Only the owner ({}) can use this action.",
-                id.mention()
-            ),
             Self::InvalidDateTime(format) => {
-                write!(f, "Invalid date time. Expected format: {}", format)
+                t!(locale, "lfg.error.invalid_date_time", format = format)
             }
-            Self::TagRequired => {
-                write!(
-                    f,
-                    "Unable to parse Activity and apply necessary tags. Please fix the Activity field and use the edit button to update after creating the post."
-                )
-            }
-            Self::AlreadyJoined => write!(f, "You have already joined this LFG."),
-            Self::InvalidChannel => write!(f, "Invalid LFG channel."),
-            _ => write!(f, "Unhandled error"),
+            Self::TagRequired => t!(locale, "lfg.error.tag_required"),
+            Self::AlreadyJoined => t!(locale, "lfg.error.already_joined"),
+            Self::InvalidChannel => t!(locale, "lfg.error.invalid_channel"),
+            _ => t!(locale, "lfg.error.unhandled"),
         }
     }
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.localize(DEFAULT_LOCALE))
+    }
+}
+
 impl std::error::Error for Error {}
 
 impl From<serenity::Error> for Error {
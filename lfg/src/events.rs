@@ -3,16 +3,21 @@ use serenity::all::{
     Context, DiscordJsonError, EditThread, ErrorResponse, Guild, HttpError, PartialGuildChannel,
 };
 use sqlx::{Database, Pool};
+use zayden_core::CronJobManager;
 
 use crate::{GuildManager, PostManager, actions, cron::create_reminders, templates::TemplateInfo};
 
-pub async fn thread_delete<Db: Database, Manager: PostManager<Db>>(
+pub async fn thread_delete<
+    Db: Database,
+    Manager: PostManager<Db>,
+    CronManager: CronJobManager<Db>,
+>(
     ctx: &Context,
     thread: &PartialGuildChannel,
     pool: &Pool<Db>,
 ) {
     if Manager::exists(pool, thread.id).await.unwrap() {
-        actions::delete::<Db, Manager>(ctx, thread.id, pool)
+        actions::delete::<Db, Manager, CronManager>(ctx, thread.id, pool)
             .await
             .unwrap();
     }
@@ -22,6 +27,7 @@ pub async fn guild_create<
     Db: Database,
     GuildHandler: GuildManager<Db>,
     PostHandler: PostManager<Db>,
+    CronHandler: CronJobManager<Db>,
 >(
     ctx: &Context,
     guild: &Guild,
@@ -77,7 +83,7 @@ pub async fn guild_create<
         };
 
         if post.start_time > now {
-            create_reminders::<Db, PostHandler>(ctx, &post).await;
+            create_reminders::<Db, PostHandler, CronHandler>(ctx, pool, &post).await;
         }
 
         if post.start_time < now {
@@ -106,7 +112,7 @@ pub async fn guild_create<
                 let thread = post.channel();
 
                 if !threads.contain(thread) {
-                    actions::delete::<Db, PostHandler>(ctx, thread, pool)
+                    actions::delete::<Db, PostHandler, CronHandler>(ctx, thread, pool)
                             .await
                             .unwrap();
                 }
@@ -6,7 +6,7 @@ use serenity::all::{
 };
 use sqlx::prelude::FromRow;
 use sqlx::{Database, Pool};
-use zayden_core::parse_modal_data;
+use zayden_core::{CronJobManager, i18n, parse_modal_data};
 
 use crate::cron::create_reminders;
 use crate::templates::{DefaultTemplate, Template};
@@ -44,6 +44,7 @@ impl Create {
         GuildHandler: GuildManager<Db>,
         PostHandler: PostManager<Db> + Savable<Db, PostRow>,
         TzManager: TimezoneManager<Db>,
+        CronManager: CronJobManager<Db>,
     >(
         ctx: &Context,
         interaction: &ModalInteraction,
@@ -83,7 +84,9 @@ impl Create {
             fireteam_size as i16,
         );
 
-        let embed = DefaultTemplate::thread_embed(&post, interaction.user.display_name());
+        let locale = i18n::guild_locale(interaction.guild_locale.as_deref());
+
+        let embed = DefaultTemplate::thread_embed(&post, interaction.user.display_name(), locale);
         let row = DefaultTemplate::main_row();
 
         let lfg_guild = GuildHandler::row(pool, guild_id)
@@ -145,8 +148,12 @@ impl Create {
             .unwrap();
 
         if let Some(thread_id) = lfg_guild.scheduled_thread_id() {
-            let embed =
-                DefaultTemplate::message_embed(&post, interaction.user.display_name(), thread.id);
+            let embed = DefaultTemplate::message_embed(
+                &post,
+                interaction.user.display_name(),
+                thread.id,
+                locale,
+            );
 
             let msg = thread_id
                 .send_message(ctx, CreateMessage::new().embed(embed))
@@ -158,7 +165,7 @@ impl Create {
 
         let post = post.id(thread.id).build();
 
-        create_reminders::<Db, PostHandler>(ctx, &post).await;
+        create_reminders::<Db, PostHandler, CronManager>(ctx, pool, &post).await;
 
         PostHandler::save(pool, post).await.unwrap();
 
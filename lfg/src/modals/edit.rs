@@ -1,8 +1,8 @@
 use serenity::all::{Context, CreateInteractionResponse, EditThread, ModalInteraction};
 use sqlx::{Database, Pool};
-use zayden_core::parse_modal_data;
+use zayden_core::{CronJobManager, i18n, parse_modal_data};
 
-use crate::cron::create_reminders;
+use crate::cron::reschedule_reminders;
 use crate::templates::DefaultTemplate;
 use crate::utils::update_embeds;
 use crate::{PostBuilder, PostManager, PostRow, Result, Savable, TimezoneManager};
@@ -16,6 +16,7 @@ impl Edit {
         Db: Database,
         Manager: PostManager<Db> + Savable<Db, PostRow>,
         TzManager: TimezoneManager<Db>,
+        CronManager: CronJobManager<Db>,
     >(
         ctx: &Context,
         interaction: &ModalInteraction,
@@ -45,7 +46,10 @@ impl Edit {
 
         let start_time = start_time(timezone, start_time_str)?;
 
-        let post = PostBuilder::from(Manager::row(pool, interaction.channel_id).await.unwrap())
+        let row = Manager::row(pool, interaction.channel_id).await.unwrap();
+        let from = row.start_time;
+
+        let post = PostBuilder::from(row)
             .activity(activity)
             .fireteam_size(fireteam_size)
             .description(description)
@@ -69,12 +73,13 @@ impl Edit {
             &post,
             interaction.user.display_name(),
             interaction.channel_id,
+            i18n::guild_locale(interaction.guild_locale.as_deref()),
         )
         .await;
 
         let post = post.build();
 
-        create_reminders::<Db, Manager>(ctx, &post).await;
+        reschedule_reminders::<Db, Manager, CronManager>(ctx, pool, from, &post).await?;
         Manager::save(pool, post).await.unwrap();
 
         interaction
@@ -2,6 +2,7 @@ use serenity::all::{
     ButtonStyle, ChannelId, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter,
     Mentionable, MessageId, UserId,
 };
+use zayden_core::t;
 
 pub trait TemplateInfo {
     fn activity(&self) -> &str;
@@ -22,25 +23,30 @@ pub trait TemplateInfo {
 }
 
 pub trait Template {
-    fn thread_embed(post: &impl TemplateInfo, owner_name: &str) -> CreateEmbed;
+    fn thread_embed(post: &impl TemplateInfo, owner_name: &str, locale: &str) -> CreateEmbed;
 
-    fn message_embed(post: &impl TemplateInfo, owner_name: &str, thread: ChannelId) -> CreateEmbed;
+    fn message_embed(
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed;
 
     fn main_row() -> CreateActionRow;
 
-    fn settings_row() -> CreateActionRow {
+    fn settings_row(locale: &str) -> CreateActionRow {
         CreateActionRow::Buttons(vec![
             CreateButton::new("lfg_edit")
-                .label("Edit")
+                .label(t!(locale, "lfg.button.edit"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("lfg_copy")
-                .label("Copy")
+                .label(t!(locale, "lfg.button.copy"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("lfg_kick")
-                .label("Kick")
+                .label(t!(locale, "lfg.button.kick"))
                 .style(ButtonStyle::Secondary),
             CreateButton::new("lfg_delete")
-                .label("Delete")
+                .label(t!(locale, "lfg.button.delete"))
                 .style(ButtonStyle::Danger),
         ])
     }
@@ -49,12 +55,17 @@ pub trait Template {
 pub struct DefaultTemplate;
 
 impl Template for DefaultTemplate {
-    fn thread_embed(post: &impl TemplateInfo, owner_name: &str) -> CreateEmbed {
-        embed(post, owner_name, None)
+    fn thread_embed(post: &impl TemplateInfo, owner_name: &str, locale: &str) -> CreateEmbed {
+        embed(post, owner_name, None, locale)
     }
 
-    fn message_embed(post: &impl TemplateInfo, owner_name: &str, thread: ChannelId) -> CreateEmbed {
-        embed(post, owner_name, Some(thread))
+    fn message_embed(
+        post: &impl TemplateInfo,
+        owner_name: &str,
+        thread: ChannelId,
+        locale: &str,
+    ) -> CreateEmbed {
+        embed(post, owner_name, Some(thread), locale)
     }
 
     fn main_row() -> CreateActionRow {
@@ -75,7 +86,12 @@ impl Template for DefaultTemplate {
     }
 }
 
-fn embed(post: &impl TemplateInfo, owner_name: &str, thread: Option<ChannelId>) -> CreateEmbed {
+fn embed(
+    post: &impl TemplateInfo,
+    owner_name: &str,
+    thread: Option<ChannelId>,
+    locale: &str,
+) -> CreateEmbed {
     let timestamp = post.timestamp();
 
     let fireteam = post
@@ -92,27 +108,52 @@ fn embed(post: &impl TemplateInfo, owner_name: &str, thread: Option<ChannelId>)
 
     let mut embed = CreateEmbed::new()
         .title(format!("{} - <t:{}>", post.activity(), timestamp))
-        .field("Activity", post.activity(), true)
-        .field("Start Time", format!("<t:{}:R>", timestamp), true);
+        .field(t!(locale, "lfg.embed.activity"), post.activity(), true)
+        .field(
+            t!(locale, "lfg.embed.start_time"),
+            format!("<t:{}:R>", timestamp),
+            true,
+        );
 
     if let Some(thread) = thread {
-        embed = embed.field("Event Thread", thread.mention().to_string(), true);
+        embed = embed.field(
+            t!(locale, "lfg.embed.event_thread"),
+            thread.mention().to_string(),
+            true,
+        );
     }
 
     if !post.description().is_empty() {
-        embed = embed.field("Description", post.description(), false)
+        embed = embed.field(
+            t!(locale, "lfg.embed.description"),
+            post.description(),
+            false,
+        )
     }
 
     embed = embed
         .field(
-            format!("Joined: {}/{}", fireteam.len(), post.fireteam_size()),
+            t!(
+                locale,
+                "lfg.embed.joined",
+                count = fireteam.len(),
+                size = post.fireteam_size()
+            ),
             fireteam_str,
             false,
         )
-        .footer(CreateEmbedFooter::new(format!("Posted by {}", owner_name)));
+        .footer(CreateEmbedFooter::new(t!(
+            locale,
+            "lfg.embed.posted_by",
+            owner = owner_name
+        )));
 
     if !alternatives.is_empty() {
-        embed = embed.field("Alternatives", alternatives.join("\n"), true);
+        embed = embed.field(
+            t!(locale, "lfg.embed.alternatives"),
+            alternatives.join("\n"),
+            true,
+        );
     }
 
     embed
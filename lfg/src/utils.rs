@@ -5,6 +5,9 @@ use serenity::all::{
     Mentionable, UserId,
 };
 
+use zayden_core::i18n::DEFAULT_LOCALE;
+use zayden_core::{Localize, t};
+
 use crate::templates::{Template, TemplateInfo};
 
 pub async fn update_embeds<T: Template>(
@@ -12,10 +15,11 @@ pub async fn update_embeds<T: Template>(
     row: &impl TemplateInfo,
     owner_name: &str,
     thread: impl Into<ChannelId>,
+    locale: &str,
 ) {
     let thread = thread.into();
 
-    let embed = T::thread_embed(row, owner_name);
+    let embed = T::thread_embed(row, owner_name, locale);
 
     thread
         .edit_message(ctx, thread.get(), EditMessage::new().embed(embed))
@@ -23,7 +27,7 @@ pub async fn update_embeds<T: Template>(
         .unwrap();
 
     if let (Some(channel), Some(message)) = (row.alt_channel(), row.alt_message()) {
-        let embed = T::message_embed(row, owner_name, thread);
+        let embed = T::message_embed(row, owner_name, thread, locale);
 
         match channel
             .edit_message(ctx, message, EditMessage::new().embed(embed))
@@ -46,24 +50,34 @@ pub enum Announcement {
 }
 
 impl Announcement {
-    pub async fn send(&self, ctx: &Context, channel: ChannelId) {
+    pub async fn send(&self, ctx: &Context, channel: ChannelId, locale: &str) {
         channel
-            .send_message(ctx, CreateMessage::new().content(format!("{self}")))
+            .send_message(ctx, CreateMessage::new().content(self.localize(locale)))
             .await
             .unwrap();
     }
 }
 
-impl Display for Announcement {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl Localize for Announcement {
+    fn localize(&self, locale: &str) -> String {
         match self {
-            Announcement::Joined { user, alternative } if *alternative => {
-                write!(f, "{} joined as an alternative", user.mention())
-            }
+            Announcement::Joined { user, alternative } if *alternative => t!(
+                locale,
+                "lfg.announcement.joined_alternative",
+                user = user.mention()
+            ),
             Announcement::Joined { user, .. } => {
-                write!(f, "{} joined the fireteam", user.mention())
+                t!(locale, "lfg.announcement.joined", user = user.mention())
+            }
+            Announcement::Left(user) => {
+                t!(locale, "lfg.announcement.left", user = user.mention())
             }
-            Announcement::Left(user) => write!(f, "{} left the fireteam", user.mention()),
         }
     }
 }
+
+impl Display for Announcement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.localize(DEFAULT_LOCALE))
+    }
+}
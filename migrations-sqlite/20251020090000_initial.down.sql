@@ -0,0 +1,21 @@
+-- Add down migration script here
+DROP TABLE voice_channels;
+DROP TABLE tickets;
+DROP TABLE reaction_roles;
+DROP TABLE lfg_users;
+DROP TABLE lfg_messages;
+DROP TABLE lfg_alternatives;
+DROP TABLE lfg_fireteam;
+DROP TABLE lfg_posts;
+DROP TABLE lfg_guilds;
+DROP TABLE gambling_mine;
+DROP TABLE gambling_inventory;
+DROP TABLE gambling_goals;
+DROP TABLE gambling_effects;
+DROP TABLE gambling;
+DROP TABLE levels;
+DROP TABLE guilds;
+DROP TABLE cron_jobs;
+DROP TABLE guild_command_permissions;
+DROP TABLE guild_permission_roles;
+DROP TABLE bot_owners;
\ No newline at end of file
@@ -0,0 +1,187 @@
+-- Add up migration script here
+-- SQLite schema for self-hosted instances, equivalent to the Postgres
+-- migrations up to 20251019120000. Arrays are stored as JSON text.
+--
+-- Start the bot with DATABASE_URL=sqlite://zayden.db?mode=rwc to create the
+-- file on first run; these migrations are applied automatically.
+--
+-- Add yourself as bot owner with:
+--   INSERT INTO bot_owners (user_id) VALUES (<your user id>);
+
+CREATE TABLE bot_owners (
+    user_id INTEGER PRIMARY KEY
+);
+
+CREATE TABLE guild_permission_roles (
+    guild_id INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    level TEXT NOT NULL,
+    PRIMARY KEY (guild_id, role_id)
+);
+
+CREATE TABLE guild_command_permissions (
+    guild_id INTEGER NOT NULL,
+    command TEXT NOT NULL,
+    level TEXT NOT NULL,
+    PRIMARY KEY (guild_id, command)
+);
+
+CREATE TABLE cron_jobs (
+    id TEXT NOT NULL,
+    schedule TEXT NOT NULL,
+    catch_up TEXT NOT NULL DEFAULT 'skip',
+    last_run DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id, schedule)
+);
+
+CREATE TABLE guilds (
+    id INTEGER PRIMARY KEY,
+    temp_voice_category INTEGER,
+    temp_voice_creator_channel INTEGER,
+    support_role_ids TEXT NOT NULL DEFAULT '[]',
+    thread_id INTEGER NOT NULL DEFAULT 0,
+    support_channel_id INTEGER,
+    faq_channel_id INTEGER,
+    suggestions_channel_id INTEGER,
+    review_channel_id INTEGER,
+    xp_blocked_channels TEXT NOT NULL DEFAULT '[]',
+    disabled_modules TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE TABLE levels (
+    id INTEGER PRIMARY KEY,
+    total_xp INTEGER NOT NULL DEFAULT 0,
+    last_xp DATETIME NOT NULL DEFAULT '1970-01-01 00:00:00',
+    xp INTEGER NOT NULL DEFAULT 0,
+    level INTEGER NOT NULL DEFAULT 0,
+    message_count INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE gambling (
+    id INTEGER PRIMARY KEY,
+    coins INTEGER NOT NULL DEFAULT 1000 CHECK (coins >= 0),
+    daily DATE NOT NULL DEFAULT '1970-01-01',
+    stamina INTEGER NOT NULL DEFAULT 1,
+    gift DATE NOT NULL DEFAULT '1970-01-01',
+    gems INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE gambling_effects (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    item_id TEXT NOT NULL,
+    expiry DATETIME,
+    UNIQUE (user_id, item_id)
+);
+
+CREATE TABLE gambling_goals (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    goal_id TEXT NOT NULL,
+    day DATE NOT NULL DEFAULT '1970-01-01',
+    progress INTEGER NOT NULL DEFAULT 0,
+    target INTEGER NOT NULL DEFAULT 1
+);
+
+CREATE INDEX idx_gambling_goals_user_id ON gambling_goals (user_id);
+
+CREATE TABLE gambling_inventory (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES gambling (id) ON DELETE CASCADE,
+    item_id TEXT NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 0,
+    UNIQUE (user_id, item_id)
+);
+
+CREATE INDEX idx_gambling_inventory_user_id ON gambling_inventory (user_id);
+
+CREATE TABLE gambling_mine (
+    id INTEGER PRIMARY KEY REFERENCES gambling (id),
+    miners INTEGER NOT NULL DEFAULT 0,
+    mines INTEGER NOT NULL DEFAULT 0,
+    land INTEGER NOT NULL DEFAULT 0,
+    countries INTEGER NOT NULL DEFAULT 0,
+    continents INTEGER NOT NULL DEFAULT 0,
+    planets INTEGER NOT NULL DEFAULT 0,
+    solar_systems INTEGER NOT NULL DEFAULT 0,
+    galaxies INTEGER NOT NULL DEFAULT 0,
+    universes INTEGER NOT NULL DEFAULT 0,
+    prestige INTEGER NOT NULL DEFAULT 0,
+    coal INTEGER NOT NULL DEFAULT 0,
+    iron INTEGER NOT NULL DEFAULT 0,
+    gold INTEGER NOT NULL DEFAULT 0,
+    redstone INTEGER NOT NULL DEFAULT 0,
+    lapis INTEGER NOT NULL DEFAULT 0,
+    diamonds INTEGER NOT NULL DEFAULT 0,
+    emeralds INTEGER NOT NULL DEFAULT 0,
+    tech INTEGER NOT NULL DEFAULT 0,
+    utility INTEGER NOT NULL DEFAULT 0,
+    production INTEGER NOT NULL DEFAULT 0,
+    mine_activity DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE lfg_guilds (
+    id INTEGER PRIMARY KEY,
+    channel_id INTEGER NOT NULL,
+    role_id INTEGER,
+    scheduled_thread_id INTEGER
+);
+
+CREATE TABLE lfg_posts (
+    id INTEGER PRIMARY KEY,
+    owner INTEGER NOT NULL,
+    activity TEXT NOT NULL,
+    start_time DATETIME NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    fireteam_size INTEGER NOT NULL
+);
+
+CREATE INDEX idx_lfg_posts_owner_id ON lfg_posts (owner);
+
+CREATE TABLE lfg_fireteam (
+    post INTEGER NOT NULL REFERENCES lfg_posts (id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL,
+    PRIMARY KEY (post, user_id)
+);
+
+CREATE TABLE lfg_alternatives (
+    post INTEGER NOT NULL REFERENCES lfg_posts (id) ON DELETE CASCADE,
+    user_id INTEGER NOT NULL,
+    PRIMARY KEY (post, user_id)
+);
+
+CREATE TABLE lfg_messages (
+    id INTEGER PRIMARY KEY REFERENCES lfg_posts (id) ON DELETE CASCADE,
+    message INTEGER NOT NULL,
+    channel INTEGER NOT NULL
+);
+
+CREATE TABLE lfg_users (
+    id INTEGER PRIMARY KEY,
+    timezone TEXT NOT NULL
+);
+
+CREATE TABLE reaction_roles (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL,
+    channel_id INTEGER NOT NULL,
+    message_id INTEGER NOT NULL,
+    role_id INTEGER NOT NULL,
+    emoji TEXT NOT NULL
+);
+
+CREATE TABLE tickets (
+    id INTEGER PRIMARY KEY,
+    role_ids TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE TABLE voice_channels (
+    id INTEGER PRIMARY KEY,
+    owner_id INTEGER NOT NULL,
+    trusted_ids TEXT NOT NULL DEFAULT '[]',
+    password TEXT,
+    persistent BOOLEAN NOT NULL DEFAULT FALSE,
+    invites TEXT NOT NULL DEFAULT '[]',
+    mode TEXT NOT NULL DEFAULT 'open'
+        CHECK (mode IN ('open', 'spectator', 'locked', 'invisible'))
+);
\ No newline at end of file
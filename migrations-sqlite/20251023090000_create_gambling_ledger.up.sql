@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE gambling_ledger (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    amount INTEGER NOT NULL,
+    currency TEXT NOT NULL,
+    source TEXT NOT NULL,
+    reference_id INTEGER,
+    balance INTEGER NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (user_id, id);
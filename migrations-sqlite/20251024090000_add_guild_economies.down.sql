@@ -0,0 +1,97 @@
+-- Add down migration script here
+DROP INDEX idx_gambling_ledger_user_id;
+DELETE FROM gambling_ledger WHERE guild_id <> 0;
+ALTER TABLE gambling_ledger DROP COLUMN guild_id;
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (user_id, id);
+
+DELETE FROM gambling_goals WHERE guild_id <> 0;
+ALTER TABLE gambling_goals DROP COLUMN guild_id;
+
+CREATE TABLE gambling_old AS SELECT * FROM gambling WHERE guild_id = 0;
+CREATE TABLE gambling_effects_old AS SELECT * FROM gambling_effects WHERE guild_id = 0;
+CREATE TABLE gambling_inventory_old AS SELECT * FROM gambling_inventory WHERE guild_id = 0;
+CREATE TABLE gambling_mine_old AS SELECT * FROM gambling_mine WHERE guild_id = 0;
+
+DROP TABLE gambling_mine;
+DROP TABLE gambling_inventory;
+DROP TABLE gambling_effects;
+DROP TABLE gambling;
+
+CREATE TABLE gambling (
+    id INTEGER PRIMARY KEY,
+    coins INTEGER NOT NULL DEFAULT 1000 CHECK (coins >= 0),
+    daily DATE NOT NULL DEFAULT '1970-01-01',
+    stamina INTEGER NOT NULL DEFAULT 1,
+    gift DATE NOT NULL DEFAULT '1970-01-01',
+    gems INTEGER NOT NULL DEFAULT 0
+);
+
+CREATE TABLE gambling_effects (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL,
+    item_id TEXT NOT NULL,
+    expiry DATETIME,
+    UNIQUE (user_id, item_id)
+);
+
+CREATE TABLE gambling_inventory (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL REFERENCES gambling (id) ON DELETE CASCADE,
+    item_id TEXT NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 0,
+    UNIQUE (user_id, item_id)
+);
+
+CREATE TABLE gambling_mine (
+    id INTEGER PRIMARY KEY REFERENCES gambling (id),
+    miners INTEGER NOT NULL DEFAULT 0,
+    mines INTEGER NOT NULL DEFAULT 0,
+    land INTEGER NOT NULL DEFAULT 0,
+    countries INTEGER NOT NULL DEFAULT 0,
+    continents INTEGER NOT NULL DEFAULT 0,
+    planets INTEGER NOT NULL DEFAULT 0,
+    solar_systems INTEGER NOT NULL DEFAULT 0,
+    galaxies INTEGER NOT NULL DEFAULT 0,
+    universes INTEGER NOT NULL DEFAULT 0,
+    prestige INTEGER NOT NULL DEFAULT 0,
+    coal INTEGER NOT NULL DEFAULT 0,
+    iron INTEGER NOT NULL DEFAULT 0,
+    gold INTEGER NOT NULL DEFAULT 0,
+    redstone INTEGER NOT NULL DEFAULT 0,
+    lapis INTEGER NOT NULL DEFAULT 0,
+    diamonds INTEGER NOT NULL DEFAULT 0,
+    emeralds INTEGER NOT NULL DEFAULT 0,
+    tech INTEGER NOT NULL DEFAULT 0,
+    utility INTEGER NOT NULL DEFAULT 0,
+    production INTEGER NOT NULL DEFAULT 0,
+    mine_activity DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO gambling (id, coins, daily, stamina, gift, gems)
+SELECT id, coins, daily, stamina, gift, gems FROM gambling_old;
+
+INSERT INTO gambling_effects (id, user_id, item_id, expiry)
+SELECT id, user_id, item_id, expiry FROM gambling_effects_old;
+
+INSERT INTO gambling_inventory (id, user_id, item_id, quantity)
+SELECT id, user_id, item_id, quantity FROM gambling_inventory_old;
+
+INSERT INTO gambling_mine (
+    id, miners, mines, land, countries, continents, planets, solar_systems, galaxies,
+    universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech,
+    utility, production, mine_activity
+)
+SELECT
+    id, miners, mines, land, countries, continents, planets, solar_systems, galaxies,
+    universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech,
+    utility, production, mine_activity
+FROM gambling_mine_old;
+
+DROP TABLE gambling_mine_old;
+DROP TABLE gambling_inventory_old;
+DROP TABLE gambling_effects_old;
+DROP TABLE gambling_old;
+
+CREATE INDEX idx_gambling_inventory_user_id ON gambling_inventory (user_id);
+
+DROP TABLE gambling_economies;
@@ -0,0 +1,108 @@
+-- Add up migration script here
+CREATE TABLE gambling_economies (
+    guild_id INTEGER PRIMARY KEY,
+    started_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- SQLite can't change a primary key or constraint in place, so the tables
+-- keyed by user are rebuilt with guild_id, where 0 is the global economy
+CREATE TABLE gambling_old AS SELECT * FROM gambling;
+CREATE TABLE gambling_effects_old AS SELECT * FROM gambling_effects;
+CREATE TABLE gambling_inventory_old AS SELECT * FROM gambling_inventory;
+CREATE TABLE gambling_mine_old AS SELECT * FROM gambling_mine;
+
+DROP TABLE gambling_mine;
+DROP TABLE gambling_inventory;
+DROP TABLE gambling_effects;
+DROP TABLE gambling;
+
+CREATE TABLE gambling (
+    guild_id INTEGER NOT NULL DEFAULT 0,
+    id INTEGER NOT NULL,
+    coins INTEGER NOT NULL DEFAULT 1000 CHECK (coins >= 0),
+    daily DATE NOT NULL DEFAULT '1970-01-01',
+    stamina INTEGER NOT NULL DEFAULT 1,
+    gift DATE NOT NULL DEFAULT '1970-01-01',
+    gems INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (guild_id, id)
+);
+
+CREATE TABLE gambling_effects (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL DEFAULT 0,
+    user_id INTEGER NOT NULL,
+    item_id TEXT NOT NULL,
+    expiry DATETIME,
+    UNIQUE (guild_id, user_id, item_id)
+);
+
+CREATE TABLE gambling_inventory (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    guild_id INTEGER NOT NULL DEFAULT 0,
+    user_id INTEGER NOT NULL,
+    item_id TEXT NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 0,
+    UNIQUE (guild_id, user_id, item_id),
+    FOREIGN KEY (guild_id, user_id) REFERENCES gambling (guild_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE gambling_mine (
+    guild_id INTEGER NOT NULL DEFAULT 0,
+    id INTEGER NOT NULL,
+    miners INTEGER NOT NULL DEFAULT 0,
+    mines INTEGER NOT NULL DEFAULT 0,
+    land INTEGER NOT NULL DEFAULT 0,
+    countries INTEGER NOT NULL DEFAULT 0,
+    continents INTEGER NOT NULL DEFAULT 0,
+    planets INTEGER NOT NULL DEFAULT 0,
+    solar_systems INTEGER NOT NULL DEFAULT 0,
+    galaxies INTEGER NOT NULL DEFAULT 0,
+    universes INTEGER NOT NULL DEFAULT 0,
+    prestige INTEGER NOT NULL DEFAULT 0,
+    coal INTEGER NOT NULL DEFAULT 0,
+    iron INTEGER NOT NULL DEFAULT 0,
+    gold INTEGER NOT NULL DEFAULT 0,
+    redstone INTEGER NOT NULL DEFAULT 0,
+    lapis INTEGER NOT NULL DEFAULT 0,
+    diamonds INTEGER NOT NULL DEFAULT 0,
+    emeralds INTEGER NOT NULL DEFAULT 0,
+    tech INTEGER NOT NULL DEFAULT 0,
+    utility INTEGER NOT NULL DEFAULT 0,
+    production INTEGER NOT NULL DEFAULT 0,
+    mine_activity DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (guild_id, id),
+    FOREIGN KEY (guild_id, id) REFERENCES gambling (guild_id, id)
+);
+
+INSERT INTO gambling (id, coins, daily, stamina, gift, gems)
+SELECT id, coins, daily, stamina, gift, gems FROM gambling_old;
+
+INSERT INTO gambling_effects (id, user_id, item_id, expiry)
+SELECT id, user_id, item_id, expiry FROM gambling_effects_old;
+
+INSERT INTO gambling_inventory (id, user_id, item_id, quantity)
+SELECT id, user_id, item_id, quantity FROM gambling_inventory_old;
+
+INSERT INTO gambling_mine (
+    id, miners, mines, land, countries, continents, planets, solar_systems, galaxies,
+    universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech,
+    utility, production, mine_activity
+)
+SELECT
+    id, miners, mines, land, countries, continents, planets, solar_systems, galaxies,
+    universes, prestige, coal, iron, gold, redstone, lapis, diamonds, emeralds, tech,
+    utility, production, mine_activity
+FROM gambling_mine_old;
+
+DROP TABLE gambling_mine_old;
+DROP TABLE gambling_inventory_old;
+DROP TABLE gambling_effects_old;
+DROP TABLE gambling_old;
+
+CREATE INDEX idx_gambling_inventory_user_id ON gambling_inventory (user_id);
+
+ALTER TABLE gambling_goals ADD COLUMN guild_id INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE gambling_ledger ADD COLUMN guild_id INTEGER NOT NULL DEFAULT 0;
+
+DROP INDEX idx_gambling_ledger_user_id;
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (guild_id, user_id, id);
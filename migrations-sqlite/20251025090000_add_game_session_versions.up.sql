@@ -0,0 +1,3 @@
+-- Add up migration script here
+-- Bumped on every move, so a move is only stored over the state it was made on
+ALTER TABLE game_sessions ADD COLUMN version BIGINT NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE destiny_perks;
+DROP TABLE destiny_weapons;
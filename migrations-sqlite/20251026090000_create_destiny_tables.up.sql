@@ -0,0 +1,22 @@
+-- Add up migration script here
+-- Filled from the Bungie manifest when a release build starts. Perk hashes are
+-- stored as JSON arrays.
+CREATE TABLE destiny_weapons (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    icon TEXT NOT NULL DEFAULT '',
+    column_1 TEXT NOT NULL DEFAULT '[]',
+    column_2 TEXT NOT NULL DEFAULT '[]',
+    perk_1 TEXT NOT NULL DEFAULT '[]',
+    perk_2 TEXT NOT NULL DEFAULT '[]'
+);
+
+CREATE INDEX idx_destiny_weapons_name ON destiny_weapons (name);
+
+CREATE TABLE destiny_perks (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT ''
+);
+
+CREATE INDEX idx_destiny_perks_name ON destiny_perks (name);
@@ -0,0 +1 @@
+-- Nothing to undo, the column is added back by the previous migration
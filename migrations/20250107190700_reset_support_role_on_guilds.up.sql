@@ -0,0 +1,12 @@
+-- The ticket columns below add support_role_ids again, so a database built
+-- from scratch drops the nullable column first. Databases that already have
+-- the ticket columns are left alone.
+DO $$
+BEGIN
+    IF NOT EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_name = 'guilds' AND column_name = 'thread_id'
+    ) THEN
+        ALTER TABLE guilds DROP COLUMN IF EXISTS support_role_ids;
+    END IF;
+END $$;
@@ -0,0 +1 @@
+-- Nothing to undo, the old tables were replaced by the next migration
@@ -0,0 +1,13 @@
+-- The LFG tables are recreated below, so a database built from scratch
+-- drops the old ones first. Databases that already have the new tables are
+-- left alone.
+DO $$
+BEGIN
+    IF EXISTS (
+        SELECT 1 FROM information_schema.columns
+        WHERE table_name = 'lfg_posts' AND column_name = 'timezone'
+    ) THEN
+        DROP TABLE lfg_posts;
+        DROP TABLE IF EXISTS lfg_messages;
+    END IF;
+END $$;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+DROP TABLE cron_jobs;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE cron_jobs (
+    id TEXT NOT NULL,
+    schedule TEXT NOT NULL,
+    catch_up TEXT NOT NULL DEFAULT 'skip',
+    last_run TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (id, schedule)
+);
\ No newline at end of file
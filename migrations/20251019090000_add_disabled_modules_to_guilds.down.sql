@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE guilds
+DROP COLUMN disabled_modules;
\ No newline at end of file
@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE guilds
+ADD COLUMN disabled_modules TEXT[] NOT NULL DEFAULT '{}';
\ No newline at end of file
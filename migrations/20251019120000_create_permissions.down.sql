@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP TABLE guild_command_permissions;
+DROP TABLE guild_permission_roles;
+DROP TABLE bot_owners;
\ No newline at end of file
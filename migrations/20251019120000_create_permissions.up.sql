@@ -0,0 +1,20 @@
+-- Add up migration script here
+CREATE TABLE bot_owners (
+    user_id BIGINT PRIMARY KEY
+);
+
+INSERT INTO bot_owners (user_id) VALUES (211486447369322506);
+
+CREATE TABLE guild_permission_roles (
+    guild_id BIGINT NOT NULL,
+    role_id BIGINT NOT NULL,
+    level TEXT NOT NULL,
+    PRIMARY KEY (guild_id, role_id)
+);
+
+CREATE TABLE guild_command_permissions (
+    guild_id BIGINT NOT NULL,
+    command TEXT NOT NULL,
+    level TEXT NOT NULL,
+    PRIMARY KEY (guild_id, command)
+);
\ No newline at end of file
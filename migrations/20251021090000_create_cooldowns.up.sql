@@ -0,0 +1,8 @@
+-- Add up migration script here
+CREATE TABLE cooldowns (
+    name TEXT NOT NULL,
+    bucket_id BIGINT NOT NULL,
+    used_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_cooldowns_name_bucket_id ON cooldowns (name, bucket_id);
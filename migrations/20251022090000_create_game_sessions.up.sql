@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE game_sessions (
+    message_id BIGINT PRIMARY KEY,
+    channel_id BIGINT NOT NULL,
+    state JSONB NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_game_sessions_expires_at ON game_sessions (expires_at);
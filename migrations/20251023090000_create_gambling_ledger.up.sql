@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE gambling_ledger (
+    id BIGSERIAL PRIMARY KEY,
+    user_id BIGINT NOT NULL,
+    amount BIGINT NOT NULL,
+    currency TEXT NOT NULL,
+    source TEXT NOT NULL,
+    reference_id BIGINT,
+    balance BIGINT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (user_id, id);
@@ -0,0 +1,38 @@
+-- Add down migration script here
+DELETE FROM gambling_ledger WHERE guild_id <> 0;
+DELETE FROM gambling_effects WHERE guild_id <> 0;
+DELETE FROM gambling_goals WHERE guild_id <> 0;
+DELETE FROM gambling_mine WHERE guild_id <> 0;
+DELETE FROM gambling WHERE guild_id <> 0;
+
+DROP INDEX idx_gambling_ledger_user_id;
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (user_id, id);
+
+ALTER TABLE gambling_effects DROP CONSTRAINT unique_user_item;
+ALTER TABLE gambling_effects ADD CONSTRAINT unique_user_item UNIQUE (user_id, item_id);
+
+ALTER TABLE gambling_inventory DROP CONSTRAINT fk_inventory_user;
+ALTER TABLE gambling_inventory DROP CONSTRAINT uq_gambling_inventory_user_item;
+ALTER TABLE gambling_inventory
+    ADD CONSTRAINT uq_gambling_inventory_user_item UNIQUE (user_id, item_id);
+
+ALTER TABLE gambling_mine DROP CONSTRAINT gambling;
+ALTER TABLE gambling_mine DROP CONSTRAINT gambling_mine_pkey;
+ALTER TABLE gambling_mine ADD CONSTRAINT gambling_mine_pkey PRIMARY KEY (id);
+
+ALTER TABLE gambling DROP CONSTRAINT gambling_pkey;
+ALTER TABLE gambling ADD CONSTRAINT gambling_pkey PRIMARY KEY (id);
+
+ALTER TABLE gambling_mine ADD CONSTRAINT gambling FOREIGN KEY (id) REFERENCES gambling (id);
+ALTER TABLE gambling_inventory
+    ADD CONSTRAINT fk_inventory_user FOREIGN KEY (user_id)
+        REFERENCES gambling (id) ON DELETE CASCADE;
+
+ALTER TABLE gambling_ledger DROP COLUMN guild_id;
+ALTER TABLE gambling_effects DROP COLUMN guild_id;
+ALTER TABLE gambling_goals DROP COLUMN guild_id;
+ALTER TABLE gambling_mine DROP COLUMN guild_id;
+ALTER TABLE gambling_inventory DROP COLUMN guild_id;
+ALTER TABLE gambling DROP COLUMN guild_id;
+
+DROP TABLE gambling_economies;
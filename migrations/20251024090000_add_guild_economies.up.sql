@@ -0,0 +1,37 @@
+-- Add up migration script here
+CREATE TABLE gambling_economies (
+    guild_id BIGINT PRIMARY KEY,
+    started_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+
+-- 0 is the global economy
+ALTER TABLE gambling ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE gambling_inventory ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE gambling_mine ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE gambling_goals ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE gambling_effects ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+ALTER TABLE gambling_ledger ADD COLUMN guild_id BIGINT NOT NULL DEFAULT 0;
+
+ALTER TABLE gambling_mine DROP CONSTRAINT gambling;
+ALTER TABLE gambling_inventory DROP CONSTRAINT fk_inventory_user;
+
+ALTER TABLE gambling DROP CONSTRAINT gambling_pkey;
+ALTER TABLE gambling ADD CONSTRAINT gambling_pkey PRIMARY KEY (guild_id, id);
+
+ALTER TABLE gambling_mine DROP CONSTRAINT gambling_mine_pkey;
+ALTER TABLE gambling_mine ADD CONSTRAINT gambling_mine_pkey PRIMARY KEY (guild_id, id);
+ALTER TABLE gambling_mine
+    ADD CONSTRAINT gambling FOREIGN KEY (guild_id, id) REFERENCES gambling (guild_id, id);
+
+ALTER TABLE gambling_inventory DROP CONSTRAINT uq_gambling_inventory_user_item;
+ALTER TABLE gambling_inventory
+    ADD CONSTRAINT uq_gambling_inventory_user_item UNIQUE (guild_id, user_id, item_id);
+ALTER TABLE gambling_inventory
+    ADD CONSTRAINT fk_inventory_user FOREIGN KEY (guild_id, user_id)
+        REFERENCES gambling (guild_id, id) ON DELETE CASCADE;
+
+ALTER TABLE gambling_effects DROP CONSTRAINT unique_user_item;
+ALTER TABLE gambling_effects ADD CONSTRAINT unique_user_item UNIQUE (guild_id, user_id, item_id);
+
+DROP INDEX idx_gambling_ledger_user_id;
+CREATE INDEX idx_gambling_ledger_user_id ON gambling_ledger (guild_id, user_id, id);
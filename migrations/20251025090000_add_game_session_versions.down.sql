@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE game_sessions DROP COLUMN version;
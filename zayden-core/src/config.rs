@@ -0,0 +1,77 @@
+use std::path::{Path, PathBuf};
+use std::{env, fmt, fs, io};
+
+use serde::de::DeserializeOwned;
+
+/// The config file, from `CONFIG_PATH` or `config.toml` in the working
+/// directory.
+pub fn path() -> PathBuf {
+    env::var_os("CONFIG_PATH")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("config.toml"))
+}
+
+/// Reads and parses the TOML file at `path`.
+///
+/// Unknown keys are only rejected if `T` uses `#[serde(deny_unknown_fields)]`.
+pub fn load<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T, ConfigError> {
+    let path = path.as_ref();
+
+    let contents = fs::read_to_string(path).map_err(|source| ConfigError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    toml::from_str(&contents).map_err(|source| ConfigError::Parse {
+        path: path.to_path_buf(),
+        source: Box::new(source),
+    })
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Parse {
+        path: PathBuf,
+        source: Box<toml::de::Error>,
+    },
+    /// An emoji that is neither an application emoji nor set in the file.
+    MissingEmoji(String),
+    /// A section that parsed but can't be used, e.g. a reference to
+    /// something the section doesn't define.
+    Invalid {
+        section: &'static str,
+        reason: String,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read { path, source } => {
+                write!(f, "failed to read config file {}: {source}", path.display())
+            }
+            Self::Parse { path, source } => {
+                write!(f, "invalid config file {}: {source}", path.display())
+            }
+            Self::MissingEmoji(name) => write!(
+                f,
+                "missing emoji `{name}`, add `{name}.png` to the emoji folder or set its id in the config file"
+            ),
+            Self::Invalid { section, reason } => write!(f, "invalid [{section}] section: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Read { source, .. } => Some(source),
+            Self::Parse { source, .. } => Some(source.as_ref()),
+            Self::MissingEmoji(_) | Self::Invalid { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::{HashMap, VecDeque};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use serenity::all::{
+    ChannelId, CommandInteraction, ComponentInteraction, Context, GuildId, Message,
+    ModalInteraction, UserId,
+};
+use serenity::prelude::{RwLock, TypeMap, TypeMapKey};
+use sqlx::any::AnyQueryResult;
+use sqlx::{Database, Pool};
+
+use crate::{CronJob, Error};
+
+/// Who shares the uses of a cooldown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Bucket {
+    #[default]
+    User,
+    /// Falls back to the channel outside of servers.
+    Guild,
+    Channel,
+    Global,
+}
+
+/// Allows `burst` uses within any `duration` window.
+#[derive(Debug, Clone, Copy)]
+pub struct Cooldown {
+    pub duration: Duration,
+    pub burst: usize,
+    pub bucket: Bucket,
+    pub persistent: bool,
+}
+
+impl Cooldown {
+    pub fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            burst: 1,
+            bucket: Bucket::default(),
+            persistent: false,
+        }
+    }
+
+    pub fn seconds(seconds: i64) -> Self {
+        Self::new(Duration::seconds(seconds))
+    }
+
+    pub fn bucket(mut self, bucket: Bucket) -> Self {
+        self.bucket = bucket;
+        self
+    }
+
+    pub fn burst(mut self, burst: usize) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Keeps the uses across restarts, see [`Cooldowns::cron_job`].
+    pub fn persistent(mut self) -> Self {
+        self.persistent = true;
+        self
+    }
+}
+
+/// The ids a use is counted against.
+#[derive(Debug, Clone, Copy)]
+pub struct Target {
+    pub user: UserId,
+    pub guild: Option<GuildId>,
+    pub channel: ChannelId,
+}
+
+impl Target {
+    pub fn new(user: UserId, guild: Option<GuildId>, channel: ChannelId) -> Self {
+        Self {
+            user,
+            guild,
+            channel,
+        }
+    }
+
+    fn key(&self, bucket: Bucket) -> u64 {
+        match bucket {
+            Bucket::User => self.user.get(),
+            Bucket::Guild => self
+                .guild
+                .map(|guild| guild.get())
+                .unwrap_or(self.channel.get()),
+            Bucket::Channel => self.channel.get(),
+            Bucket::Global => 0,
+        }
+    }
+}
+
+impl From<&CommandInteraction> for Target {
+    fn from(interaction: &CommandInteraction) -> Self {
+        Self::new(
+            interaction.user.id,
+            interaction.guild_id,
+            interaction.channel_id,
+        )
+    }
+}
+
+impl From<&ComponentInteraction> for Target {
+    fn from(interaction: &ComponentInteraction) -> Self {
+        Self::new(
+            interaction.user.id,
+            interaction.guild_id,
+            interaction.channel_id,
+        )
+    }
+}
+
+impl From<&ModalInteraction> for Target {
+    fn from(interaction: &ModalInteraction) -> Self {
+        Self::new(
+            interaction.user.id,
+            interaction.guild_id,
+            interaction.channel_id,
+        )
+    }
+}
+
+impl From<&Message> for Target {
+    fn from(message: &Message) -> Self {
+        Self::new(message.author.id, message.guild_id, message.channel_id)
+    }
+}
+
+/// Named limits and the recent uses counted against them.
+///
+/// Names without a registered [`Cooldown`] are never limited, so commands can
+/// check their own name without opting in.
+#[derive(Debug, Default)]
+pub struct Cooldowns {
+    limits: HashMap<String, Cooldown>,
+    uses: HashMap<(String, u64), VecDeque<DateTime<Utc>>>,
+}
+
+impl Cooldowns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn limit(mut self, name: impl Into<String>, cooldown: Cooldown) -> Self {
+        self.limits.insert(name.into(), cooldown);
+        self
+    }
+
+    /// Errors with the time of the next allowed use if `name` has no uses
+    /// left for `target`.
+    pub async fn check(ctx: &Context, name: &str, target: impl Into<Target>) -> Result<(), Error> {
+        let target = target.into();
+
+        let mut data = ctx.data.write().await;
+        let Some(cooldowns) = data.get_mut::<Self>() else {
+            return Ok(());
+        };
+
+        match cooldowns.available_at(name, &target, Utc::now()) {
+            Some(time) => Err(Error::Cooldown(time.timestamp())),
+            None => Ok(()),
+        }
+    }
+
+    /// Counts a use of `name` against `target`.
+    pub async fn trigger(ctx: &Context, name: &str, target: impl Into<Target>) {
+        let target = target.into();
+
+        let mut data = ctx.data.write().await;
+        if let Some(cooldowns) = data.get_mut::<Self>() {
+            cooldowns.record(name, &target, Utc::now());
+        }
+    }
+
+    /// Checks and counts a use in one go, for limits that apply whether or
+    /// not the action succeeds.
+    pub async fn take(ctx: &Context, name: &str, target: impl Into<Target>) -> Result<(), Error> {
+        let target = target.into();
+
+        let mut data = ctx.data.write().await;
+        let Some(cooldowns) = data.get_mut::<Self>() else {
+            return Ok(());
+        };
+
+        let now = Utc::now();
+        if let Some(time) = cooldowns.available_at(name, &target, now) {
+            return Err(Error::Cooldown(time.timestamp()));
+        }
+        cooldowns.record(name, &target, now);
+
+        Ok(())
+    }
+
+    fn available_at(
+        &mut self,
+        name: &str,
+        target: &Target,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        let cooldown = self.limits.get(name)?;
+        let uses = self
+            .uses
+            .get_mut(&(name.to_string(), target.key(cooldown.bucket)))?;
+
+        while uses
+            .front()
+            .is_some_and(|used_at| *used_at + cooldown.duration <= now)
+        {
+            uses.pop_front();
+        }
+
+        if uses.len() < cooldown.burst {
+            return None;
+        }
+
+        uses.front().map(|used_at| *used_at + cooldown.duration)
+    }
+
+    fn record(&mut self, name: &str, target: &Target, now: DateTime<Utc>) {
+        let Some(cooldown) = self.limits.get(name) else {
+            return;
+        };
+
+        let uses = self
+            .uses
+            .entry((name.to_string(), target.key(cooldown.bucket)))
+            .or_default();
+
+        uses.push_back(now);
+        while uses.len() > cooldown.burst {
+            uses.pop_front();
+        }
+    }
+
+    fn rows(&self, now: DateTime<Utc>) -> Vec<CooldownRow> {
+        self.uses
+            .iter()
+            .filter_map(|((name, key), uses)| {
+                let cooldown = self
+                    .limits
+                    .get(name)
+                    .filter(|cooldown| cooldown.persistent)?;
+                Some(
+                    uses.iter()
+                        .filter(move |used_at| **used_at + cooldown.duration > now)
+                        .map(move |used_at| CooldownRow {
+                            name: name.clone(),
+                            bucket_id: *key as i64,
+                            used_at: *used_at,
+                        }),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+
+    fn restore(&mut self, rows: Vec<CooldownRow>) {
+        for row in rows {
+            if !self.limits.contains_key(&row.name) {
+                continue;
+            }
+
+            let uses = self
+                .uses
+                .entry((row.name, row.bucket_id as u64))
+                .or_default();
+
+            // Uses already in memory, e.g. when loading twice, aren't counted again
+            if !uses.contains(&row.used_at) {
+                uses.push_back(row.used_at);
+                uses.make_contiguous().sort();
+            }
+        }
+    }
+
+    /// Restores the stored uses of persistent cooldowns.
+    pub async fn load<Db: Database, Manager: CooldownManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+    ) -> sqlx::Result<()> {
+        let rows = Manager::rows(pool).await?;
+
+        let mut data = ctx.data.write().await;
+        if let Some(cooldowns) = data.get_mut::<Self>() {
+            cooldowns.restore(rows);
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the stored uses with the ones still counting against
+    /// persistent cooldowns.
+    pub async fn save<Db: Database, Manager: CooldownManager<Db>>(
+        data: &RwLock<TypeMap>,
+        pool: &Pool<Db>,
+    ) -> sqlx::Result<()> {
+        let rows = {
+            let data = data.read().await;
+            match data.get::<Self>() {
+                Some(cooldowns) => cooldowns.rows(Utc::now()),
+                None => return Ok(()),
+            }
+        };
+
+        Manager::replace(pool, rows).await?;
+
+        Ok(())
+    }
+
+    pub fn cron_job<Db: Database, Manager: CooldownManager<Db>>() -> CronJob<Db> {
+        CronJob::new("cooldowns", "0 * * * * * *")
+            .expect("Schedule should be valid")
+            .set_action(|ctx, pool| async move {
+                if let Err(e) = Self::save::<Db, Manager>(&ctx.data, &pool).await {
+                    tracing::error!(error = ?e, "failed to save cooldowns");
+                }
+            })
+    }
+}
+
+impl TypeMapKey for Cooldowns {
+    type Value = Cooldowns;
+}
+
+pub struct CooldownRow {
+    pub name: String,
+    pub bucket_id: i64,
+    pub used_at: DateTime<Utc>,
+}
+
+#[async_trait]
+pub trait CooldownManager<Db: Database> {
+    async fn rows(pool: &Pool<Db>) -> sqlx::Result<Vec<CooldownRow>>;
+
+    async fn replace(pool: &Pool<Db>, rows: Vec<CooldownRow>) -> sqlx::Result<AnyQueryResult>;
+}
@@ -3,30 +3,193 @@ use std::str::FromStr;
 use std::sync::Arc;
 use std::{marker::PhantomData, pin::Pin};
 
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Timelike, Utc};
 use cron::Schedule;
 use serenity::all::Context;
 use serenity::prelude::TypeMapKey;
+use sqlx::any::AnyQueryResult;
 use sqlx::{Database, Pool};
 
+use crate::METRICS;
+
 pub type ActionFn<Db> =
     Arc<dyn Fn(Context, Pool<Db>) -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>;
 
-#[derive(Clone)]
+/// What to do with runs that were missed while the bot was offline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatchUp {
+    /// Run the job a single time, however many runs were missed.
+    Once,
+    /// Drop the missed runs and wait for the next scheduled time.
+    #[default]
+    Skip,
+    /// Run the job once for every missed run.
+    All,
+}
+
+impl CatchUp {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Once => "once",
+            Self::Skip => "skip",
+            Self::All => "all",
+        }
+    }
+
+    pub fn runs(&self, missed: usize) -> usize {
+        match self {
+            Self::Once => missed.min(1),
+            Self::Skip => 0,
+            Self::All => missed,
+        }
+    }
+}
+
+impl FromStr for CatchUp {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "once" => Ok(Self::Once),
+            "skip" => Ok(Self::Skip),
+            "all" => Ok(Self::All),
+            _ => Err(()),
+        }
+    }
+}
+
 pub struct CronJob<Db: Database> {
     pub id: String,
+    /// What the job does, which its runs are recorded under in the metrics.
+    /// It is the id unless set, so jobs made for each of something, like the
+    /// reminders of every LFG post, can share one.
+    pub kind: String,
     pub schedule: Schedule,
+    pub catch_up: CatchUp,
+    pub last_run: Option<DateTime<Utc>>,
     pub action_fn: ActionFn<Db>,
 }
 
 impl<Db: Database> CronJob<Db> {
-    pub fn new(id: impl Into<String>, source: &str) -> Self {
-        Self {
-            id: id.into(),
-            schedule: Schedule::from_str(source).unwrap(),
+    pub fn new(id: impl Into<String>, source: &str) -> Result<Self, cron::error::Error> {
+        let id = id.into();
+
+        Ok(Self {
+            kind: id.clone(),
+            id,
+            schedule: Schedule::from_str(source)?,
+            catch_up: CatchUp::default(),
+            last_run: None,
             action_fn: Self::action_fn(|_, _| async {}),
+        })
+    }
+
+    /// Creates a job that runs a single time, at the start of the minute of `time`.
+    pub fn once(id: impl Into<String>, time: DateTime<Utc>) -> Result<Self, cron::error::Error> {
+        Self::new(
+            id,
+            &format!(
+                "0 {} {} {} {} * {}",
+                time.minute(),
+                time.hour(),
+                time.day(),
+                time.month(),
+                time.year()
+            ),
+        )
+    }
+
+    pub fn source(&self) -> &str {
+        self.schedule.source()
+    }
+
+    pub fn next_run(&self) -> Option<DateTime<Utc>> {
+        self.schedule.upcoming(Utc).next()
+    }
+
+    /// The first run after `last_run`, which is already in the past if it
+    /// came up since, or the next upcoming run for a job that never ran.
+    pub fn next_due(&self) -> Option<DateTime<Utc>> {
+        match self.last_run {
+            Some(last_run) => self.schedule.after(&last_run).next(),
+            None => self.next_run(),
         }
     }
 
+    pub fn kind(mut self, kind: impl Into<String>) -> Self {
+        self.kind = kind.into();
+        self
+    }
+
+    pub fn catch_up(mut self, catch_up: CatchUp) -> Self {
+        self.catch_up = catch_up;
+        self
+    }
+
+    pub fn last_run(mut self, last_run: DateTime<Utc>) -> Self {
+        self.last_run = Some(last_run);
+        self
+    }
+
+    /// Number of times the job should run to make up for runs scheduled
+    /// between `last_run` and `now`, according to its [`CatchUp`] policy.
+    pub fn missed_runs(&self, now: DateTime<Utc>) -> usize {
+        let Some(last_run) = self.last_run else {
+            return 0;
+        };
+
+        let missed = self
+            .schedule
+            .after(&last_run)
+            .take_while(|run_time| *run_time <= now)
+            .count();
+
+        self.catch_up.runs(missed)
+    }
+
+    /// The latest run scheduled between `last_run` and `now`, if any.
+    pub fn last_missed(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let last_run = self.last_run?;
+
+        self.schedule
+            .after(&last_run)
+            .take_while(|run_time| *run_time <= now)
+            .last()
+    }
+
+    /// Whether the schedule has no runs left after `time`.
+    pub fn is_finished(&self, time: DateTime<Utc>) -> bool {
+        self.schedule.after(&time).next().is_none()
+    }
+
+    pub fn matches(&self, row: &CronJobRow) -> bool {
+        self.id == row.id && self.source() == row.schedule
+    }
+
+    pub fn to_row(&self) -> CronJobRow {
+        CronJobRow {
+            id: self.id.clone(),
+            schedule: self.source().to_string(),
+            catch_up: self.catch_up.as_str().to_string(),
+            last_run: self.last_run.unwrap_or_else(Utc::now),
+        }
+    }
+
+    /// Runs the action, recording it under the job's kind.
+    pub async fn run(&self, ctx: Context, pool: Pool<Db>) {
+        let name = self.kind.as_str();
+
+        let timer = METRICS
+            .cron_duration
+            .with_label_values(&[name])
+            .start_timer();
+        (self.action_fn)(ctx, pool).await;
+        timer.observe_duration();
+
+        METRICS.cron_runs.with_label_values(&[name]).inc();
+    }
+
     fn action_fn<F, Fut>(f: F) -> ActionFn<Db>
     where
         F: Fn(Context, Pool<Db>) -> Fut + Send + Sync + 'static,
@@ -55,17 +218,193 @@ impl<Db: Database> CronJob<Db> {
     }
 }
 
+impl<Db: Database> Clone for CronJob<Db> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            kind: self.kind.clone(),
+            schedule: self.schedule.clone(),
+            catch_up: self.catch_up,
+            last_run: self.last_run,
+            action_fn: self.action_fn.clone(),
+        }
+    }
+}
+
 impl<Db: Database> Debug for CronJob<Db> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CronJob")
             .field("id", &self.id)
+            .field("kind", &self.kind)
             .field("schedule", &self.schedule)
+            .field("catch_up", &self.catch_up)
+            .field("last_run", &self.last_run)
             .finish()
     }
 }
 
 pub struct CronJobs<Db: Database>(PhantomData<Db>);
 
+impl<Db: Database> CronJobs<Db> {
+    /// Registers `job` and stores it, replacing any job with the same id and schedule.
+    pub async fn add<Manager: CronJobManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+        mut job: CronJob<Db>,
+    ) -> sqlx::Result<()> {
+        job.last_run.get_or_insert_with(Utc::now);
+
+        Manager::save(pool, job.to_row()).await?;
+
+        let mut data = ctx.data.write().await;
+        let jobs = data.entry::<Self>().or_insert(Vec::new());
+        jobs.retain(|existing| existing.id != job.id || existing.source() != job.source());
+        jobs.push(job);
+
+        Ok(())
+    }
+
+    /// Removes every job registered under `id`, returning how many were removed.
+    pub async fn cancel<Manager: CronJobManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+        id: &str,
+    ) -> sqlx::Result<usize> {
+        Manager::delete(pool, id).await?;
+
+        let mut data = ctx.data.write().await;
+        let Some(jobs) = data.get_mut::<Self>() else {
+            return Ok(0);
+        };
+
+        let len = jobs.len();
+        jobs.retain(|job| job.id != id);
+
+        Ok(len - jobs.len())
+    }
+
+    /// Moves the job registered under `id` with the schedule in `from` to
+    /// the schedule `to`, returning whether there was such a job.
+    ///
+    /// Only that one schedule moves, as an id like `lfg_<post id>` has a job
+    /// for each of its reminders.
+    pub async fn reschedule<Manager: CronJobManager<Db>>(
+        ctx: &Context,
+        pool: &Pool<Db>,
+        id: &str,
+        from: &str,
+        to: Schedule,
+    ) -> sqlx::Result<bool> {
+        let row = {
+            let mut data = ctx.data.write().await;
+            let Some(jobs) = data.get_mut::<Self>() else {
+                return Ok(false);
+            };
+
+            if !jobs.iter().any(|job| job.id == id && job.source() == from) {
+                return Ok(false);
+            }
+
+            // Another job of the id may already be on the new schedule
+            jobs.retain(|job| job.id != id || job.source() == from || job.source() != to.source());
+
+            let job = jobs
+                .iter_mut()
+                .find(|job| job.id == id && job.source() == from)
+                .expect("Job should still be registered");
+
+            job.schedule = to;
+            job.to_row()
+        };
+
+        Manager::delete_schedule(pool, id, from).await?;
+        Manager::save(pool, row).await?;
+
+        Ok(true)
+    }
+
+    /// Records that the job with the same id and schedule as `job` ran at
+    /// `time`, so its next run is worked out from there.
+    pub async fn ran(ctx: &Context, job: &CronJob<Db>, time: DateTime<Utc>) {
+        let mut data = ctx.data.write().await;
+
+        let existing = data.get_mut::<Self>().and_then(|jobs| {
+            jobs.iter_mut()
+                .find(|existing| existing.id == job.id && existing.source() == job.source())
+        });
+
+        if let Some(existing) = existing {
+            existing.last_run = Some(time);
+        }
+    }
+
+    /// Lists the ids of the registered jobs with their next run time, soonest first.
+    pub async fn pending(ctx: &Context) -> Vec<(String, DateTime<Utc>)> {
+        let data = ctx.data.read().await;
+
+        let mut pending = data
+            .get::<Self>()
+            .map(|jobs| {
+                jobs.iter()
+                    .filter_map(|job| job.next_run().map(|time| (job.id.clone(), time)))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        pending.sort_by_key(|(_, time)| *time);
+
+        pending
+    }
+
+    /// Whether a job with the same id and schedule as `job` is still registered.
+    pub async fn contains(ctx: &Context, job: &CronJob<Db>) -> bool {
+        let data = ctx.data.read().await;
+
+        data.get::<Self>().is_some_and(|jobs| {
+            jobs.iter()
+                .any(|existing| existing.id == job.id && existing.source() == job.source())
+        })
+    }
+}
+
 impl<Db: Database> TypeMapKey for CronJobs<Db> {
     type Value = Vec<CronJob<Db>>;
 }
+
+pub struct CronJobRow {
+    pub id: String,
+    pub schedule: String,
+    pub catch_up: String,
+    pub last_run: DateTime<Utc>,
+}
+
+impl CronJobRow {
+    pub fn catch_up(&self) -> CatchUp {
+        self.catch_up.parse().unwrap_or_default()
+    }
+
+    /// Rebuilds a job from its stored row, leaving the action to the caller
+    /// as closures can't be persisted.
+    pub fn to_job<Db: Database>(&self) -> Result<CronJob<Db>, cron::error::Error> {
+        let job = CronJob::new(&self.id, &self.schedule)?
+            .catch_up(self.catch_up())
+            .last_run(self.last_run);
+
+        Ok(job)
+    }
+}
+
+#[async_trait]
+pub trait CronJobManager<Db: Database> {
+    async fn rows(pool: &Pool<Db>) -> sqlx::Result<Vec<CronJobRow>>;
+
+    async fn save(pool: &Pool<Db>, row: CronJobRow) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete(pool: &Pool<Db>, id: &str) -> sqlx::Result<AnyQueryResult>;
+
+    async fn delete_schedule(
+        pool: &Pool<Db>,
+        id: &str,
+        schedule: &str,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serenity::all::{CreateAttachment, EmojiId, Http};
+
+/// The application's emojis by name, set by [`sync`].
+static EMOJIS: OnceLock<HashMap<String, EmojiId>> = OnceLock::new();
+
+/// Uploads every PNG in `dir` that the application has no emoji for yet,
+/// named after the file, then remembers the ids of all application emojis.
+///
+/// A missing `dir` only skips the upload. Only the first call sets the ids.
+pub async fn sync(http: &Http, dir: impl AsRef<Path>) -> serenity::Result<()> {
+    let mut emojis = http
+        .get_application_emojis()
+        .await?
+        .into_iter()
+        .map(|emoji| (emoji.name, emoji.id))
+        .collect::<HashMap<_, _>>();
+
+    for (name, path) in assets(dir.as_ref())? {
+        if emojis.contains_key(&name) {
+            continue;
+        }
+
+        let image = CreateAttachment::path(&path).await?.to_base64();
+        let emoji = http
+            .create_application_emoji(&HashMap::from([
+                ("name", name.as_str()),
+                ("image", image.as_str()),
+            ]))
+            .await?;
+
+        tracing::info!(name = emoji.name, "uploaded application emoji");
+        emojis.insert(emoji.name, emoji.id);
+    }
+
+    let _ = EMOJIS.set(emojis);
+
+    Ok(())
+}
+
+/// The application emoji called `name`, if [`sync`] found or uploaded one.
+pub fn get(name: &str) -> Option<EmojiId> {
+    EMOJIS.get()?.get(name).copied()
+}
+
+/// The PNGs in `dir` by file stem, skipping names Discord doesn't allow.
+fn assets(dir: &Path) -> std::io::Result<Vec<(String, PathBuf)>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            tracing::warn!(dir = %dir.display(), "emoji folder not found, skipping upload");
+            return Ok(Vec::new());
+        }
+        Err(e) => return Err(e),
+    };
+
+    let mut assets = Vec::new();
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_none_or(|ext| ext != "png") {
+            continue;
+        }
+
+        let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        if !(2..=32).contains(&name.len())
+            || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            tracing::warn!(path = %path.display(), "invalid emoji name, skipping upload");
+            continue;
+        }
+
+        assets.push((name.to_string(), path));
+    }
+
+    Ok(assets)
+}
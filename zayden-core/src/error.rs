@@ -1,7 +1,11 @@
+use crate::i18n::{self, DEFAULT_LOCALE, Localize};
+
 #[derive(Debug)]
 pub enum Error {
     MissingGuildId,
     NotInteractionAuthor,
+    Cooldown(i64),
+    ShuttingDown,
 
     MessageConflict,
     //region: Serenity
@@ -13,24 +17,34 @@ pub enum Error {
     //endregion
 }
 
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl Error {
+    pub fn key(&self) -> &'static str {
         match self {
-            Error::MissingGuildId => write!(f, "This command can only be used within a server."),
-            Error::NotInteractionAuthor => write!(f, "You are not the author of this interaction."),
-            Error::MessageConflict => write!(
-                f,
-                "Command is already awaiting interaction. Please respond to previous command first."
-            ),
-            Error::UnknownInteraction => write!(
-                f,
-                "An error occurred while processing the interaction. Please try again."
-            ),
-            Error::ChannelDeleted => write!(f, "Channel already deleted"),
-            Error::PoolTimedOut => write!(
-                f,
-                "An internal error occurred while accessing data. Please try again shortly."
-            ),
+            Error::MissingGuildId => "core.error.missing_guild_id",
+            Error::NotInteractionAuthor => "core.error.not_interaction_author",
+            Error::Cooldown(_) => "core.error.cooldown",
+            Error::ShuttingDown => "core.error.shutting_down",
+            Error::MessageConflict => "core.error.message_conflict",
+            Error::UnknownInteraction => "core.error.unknown_interaction",
+            Error::ChannelDeleted => "core.error.channel_deleted",
+            Error::PoolTimedOut => "core.error.pool_timed_out",
         }
     }
 }
+
+impl Localize for Error {
+    fn localize(&self, locale: &str) -> String {
+        match self {
+            Error::Cooldown(timestamp) => {
+                i18n::translate(locale, self.key(), &[("timestamp", timestamp)])
+            }
+            _ => i18n::translate(locale, self.key(), &[]),
+        }
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.localize(DEFAULT_LOCALE))
+    }
+}
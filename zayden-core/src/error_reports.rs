@@ -0,0 +1,237 @@
+use std::collections::HashSet;
+use std::fmt::Debug;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serenity::all::{
+    ChannelId, Colour, Context, CreateEmbed, CreateMessage, EditMessage, GuildId, Mentionable,
+    MessageId, UserId,
+};
+use serenity::prelude::TypeMapKey;
+
+/// Identical errors reported within this window of each other are grouped.
+const WINDOW: Duration = Duration::from_secs(60 * 60);
+const MAX_GROUPS: usize = 100;
+const MAX_ERROR_LEN: usize = 3500;
+/// Embed field values are limited to 1024 characters, which has to leave
+/// room for the backticks and the ellipsis.
+const MAX_OPTIONS_LEN: usize = 1000;
+
+#[derive(Clone, Debug)]
+pub struct ErrorReport {
+    pub event: String,
+    pub interaction: Option<String>,
+    pub options: Option<String>,
+    pub guild: Option<GuildId>,
+    pub user: Option<UserId>,
+    pub error: String,
+}
+
+impl ErrorReport {
+    pub fn new(event: impl Into<String>, error: &impl Debug) -> Self {
+        Self {
+            event: event.into(),
+            interaction: None,
+            options: None,
+            guild: None,
+            user: None,
+            error: format!("{error:?}"),
+        }
+    }
+
+    /// The command or custom id that failed, with the options it was given.
+    pub fn interaction(mut self, name: impl Into<String>, options: impl Into<String>) -> Self {
+        self.interaction = Some(name.into());
+        self.options = Some(options.into());
+        self
+    }
+
+    pub fn guild(mut self, guild: Option<GuildId>) -> Self {
+        self.guild = guild;
+        self
+    }
+
+    pub fn user(mut self, user: UserId) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    fn same_error(&self, other: &ErrorReport) -> bool {
+        self.event == other.event
+            && self.interaction == other.interaction
+            && self.error == other.error
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ErrorGroup {
+    pub report: ErrorReport,
+    pub count: u32,
+    pub users: HashSet<UserId>,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+    pub message: Option<(ChannelId, MessageId)>,
+}
+
+impl ErrorGroup {
+    fn new(report: ErrorReport, now: DateTime<Utc>) -> Self {
+        Self {
+            users: report.user.into_iter().collect(),
+            report,
+            count: 1,
+            first_seen: now,
+            last_seen: now,
+            message: None,
+        }
+    }
+
+    pub fn title(&self) -> String {
+        match &self.report.interaction {
+            Some(interaction) => format!("Error in {interaction}"),
+            None => format!("Error in {}", self.report.event),
+        }
+    }
+
+    pub fn embed(&self) -> CreateEmbed {
+        let mut error = self
+            .report
+            .error
+            .chars()
+            .take(MAX_ERROR_LEN)
+            .collect::<String>();
+        if error.len() < self.report.error.len() {
+            error.push_str("...");
+        }
+
+        let mut embed = CreateEmbed::new()
+            .title(self.title())
+            .colour(Colour::RED)
+            .description(format!("```\n{error}\n```"))
+            .field("Event", &self.report.event, true)
+            .field("Count", self.count.to_string(), true)
+            .field("Users", self.users.len().to_string(), true);
+
+        if let Some(options) = self
+            .report
+            .options
+            .as_deref()
+            .filter(|options| !options.is_empty())
+        {
+            let mut preview = options.chars().take(MAX_OPTIONS_LEN).collect::<String>();
+            if preview.len() < options.len() {
+                preview.push_str("...");
+            }
+
+            embed = embed.field("Options", format!("`{preview}`"), false);
+        }
+
+        if let Some(guild) = self.report.guild {
+            embed = embed.field("Guild", guild.to_string(), true);
+        }
+
+        if let Some(user) = self.report.user {
+            embed = embed.field("Last User", user.mention().to_string(), true);
+        }
+
+        embed.field(
+            "Seen",
+            format!(
+                "First <t:{}:R>, last <t:{}:R>",
+                self.first_seen.timestamp(),
+                self.last_seen.timestamp()
+            ),
+            false,
+        )
+    }
+
+    fn is_recent(&self, now: DateTime<Utc>) -> bool {
+        (now - self.last_seen)
+            .to_std()
+            .is_ok_and(|elapsed| elapsed < WINDOW)
+    }
+}
+
+pub struct ErrorReports;
+
+impl ErrorReports {
+    /// Records `report` and posts it to `channel`. Repeats of a recent error
+    /// update the existing message's counter instead of posting again.
+    pub async fn report(ctx: &Context, channel: Option<ChannelId>, report: ErrorReport) {
+        let now = Utc::now();
+
+        let (group, existing) = {
+            let mut data = ctx.data.write().await;
+            let groups = data.entry::<ErrorReports>().or_insert_with(Vec::new);
+
+            match groups
+                .iter_mut()
+                .find(|group| group.report.same_error(&report) && group.is_recent(now))
+            {
+                Some(group) => {
+                    group.count += 1;
+                    group.last_seen = now;
+                    group.users.extend(report.user);
+                    group.report = report;
+                    (group.clone(), true)
+                }
+                None => {
+                    let group = ErrorGroup::new(report, now);
+                    groups.push(group.clone());
+
+                    if groups.len() > MAX_GROUPS {
+                        groups.remove(0);
+                    }
+
+                    (group, false)
+                }
+            }
+        };
+
+        let Some(channel) = channel else {
+            return;
+        };
+
+        let message = match (existing, group.message) {
+            (true, Some((channel_id, message_id))) => channel_id
+                .edit_message(ctx, message_id, EditMessage::new().embed(group.embed()))
+                .await
+                .map(|_| (channel_id, message_id)),
+            // The first report is still being sent
+            (true, None) => return,
+            (false, _) => channel
+                .send_message(ctx, CreateMessage::new().embed(group.embed()))
+                .await
+                .map(|message| (channel, message.id)),
+        };
+
+        match message {
+            Ok(message) => {
+                let mut data = ctx.data.write().await;
+                if let Some(stored) = data.get_mut::<ErrorReports>().and_then(|groups| {
+                    groups.iter_mut().find(|stored| {
+                        stored.first_seen == group.first_seen
+                            && stored.report.same_error(&group.report)
+                    })
+                }) {
+                    stored.message = Some(message);
+                }
+            }
+            Err(e) => tracing::error!(error = ?e, "failed to send error report"),
+        }
+    }
+
+    /// The most recently seen error groups, newest first.
+    pub async fn recent(ctx: &Context, limit: usize) -> Vec<ErrorGroup> {
+        let data = ctx.data.read().await;
+
+        let mut groups = data.get::<ErrorReports>().cloned().unwrap_or_default();
+        groups.sort_by_key(|group| std::cmp::Reverse(group.last_seen));
+        groups.truncate(limit);
+
+        groups
+    }
+}
+
+impl TypeMapKey for ErrorReports {
+    type Value = Vec<ErrorGroup>;
+}
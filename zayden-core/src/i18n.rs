@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::LazyLock;
+
+use serenity::all::CreateCommand;
+
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Message catalogs keyed by Discord locale. Each file is TOML whose
+/// tables are flattened into dotted keys, e.g. `bot.error.generic`.
+static CATALOGS: LazyLock<Vec<(&'static str, HashMap<String, String>)>> = LazyLock::new(|| {
+    vec![
+        ("en-US", parse(include_str!("../locales/en-US.toml"))),
+        ("de", parse(include_str!("../locales/de.toml"))),
+        ("pt-BR", parse(include_str!("../locales/pt-BR.toml"))),
+    ]
+});
+
+pub trait Localize {
+    fn localize(&self, locale: &str) -> String;
+}
+
+/// Looks up `key`, falling back to another locale of the same language, then
+/// to [`DEFAULT_LOCALE`].
+pub fn get(locale: &str, key: &str) -> Option<&'static str> {
+    let language = language(locale);
+
+    let exact = CATALOGS.iter().filter(|(name, _)| *name == locale);
+    let same_language = CATALOGS
+        .iter()
+        .filter(|(name, _)| *name != locale && self::language(name) == language);
+    let default = CATALOGS.iter().filter(|(name, _)| *name == DEFAULT_LOCALE);
+
+    exact
+        .chain(same_language)
+        .chain(default)
+        .find_map(|(_, messages)| messages.get(key))
+        .map(String::as_str)
+}
+
+/// Formats `key` for `locale`, replacing each `{name}` with its argument.
+/// Unknown keys are returned as is.
+pub fn translate(locale: &str, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut message = get(locale, key).unwrap_or(key).to_string();
+
+    for (name, value) in args {
+        message = message.replace(&format!("{{{name}}}"), &value.to_string());
+    }
+
+    message
+}
+
+/// Picks the user's locale when there are messages for it, then the guild's
+/// preferred locale, then [`DEFAULT_LOCALE`].
+pub fn locale<'a>(user: &'a str, guild: Option<&'a str>) -> &'a str {
+    if is_supported(user) {
+        return user;
+    }
+
+    match guild {
+        Some(guild) if is_supported(guild) => guild,
+        _ => DEFAULT_LOCALE,
+    }
+}
+
+/// The locale for messages everyone in the guild sees, such as shared posts.
+pub fn guild_locale(guild: Option<&str>) -> &str {
+    guild
+        .filter(|guild| is_supported(guild))
+        .unwrap_or(DEFAULT_LOCALE)
+}
+
+pub fn is_supported(locale: &str) -> bool {
+    let language = language(locale);
+    CATALOGS
+        .iter()
+        .any(|(name, _)| self::language(name) == language)
+}
+
+/// Adds the translated descriptions of `commands.<name>.description`.
+pub fn localize_command(mut command: CreateCommand, name: &str) -> CreateCommand {
+    let key = format!("commands.{name}.description");
+
+    for (locale, messages) in CATALOGS.iter() {
+        if *locale == DEFAULT_LOCALE {
+            continue;
+        }
+
+        if let Some(description) = messages.get(&key) {
+            command = command.description_localized(*locale, description);
+        }
+    }
+
+    command
+}
+
+fn language(locale: &str) -> &str {
+    locale.split('-').next().unwrap_or(locale)
+}
+
+fn parse(source: &str) -> HashMap<String, String> {
+    let table = toml::from_str(source).expect("the bundled locales are valid TOML");
+
+    let mut messages = HashMap::new();
+    flatten(String::new(), table, &mut messages);
+    messages
+}
+
+/// Adds every string in `table` to `messages`, keyed by its dotted path.
+fn flatten(prefix: String, table: toml::Table, messages: &mut HashMap<String, String>) {
+    for (key, value) in table {
+        let key = format!("{prefix}{key}");
+
+        match value {
+            toml::Value::String(message) => {
+                messages.insert(key, message);
+            }
+            toml::Value::Table(table) => flatten(format!("{key}."), table, messages),
+            _ => {}
+        }
+    }
+}
+
+/// Translates a catalog key, e.g. `t!(locale, "lfg.fireteam_full")` or
+/// `t!(locale, "gambling.cooldown", timestamp = 1700000000)`.
+#[macro_export]
+macro_rules! t {
+    ($locale:expr, $key:expr) => {
+        $crate::i18n::translate($locale, $key, &[])
+    };
+    ($locale:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate(
+            $locale,
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
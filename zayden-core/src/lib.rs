@@ -9,19 +9,53 @@ use sqlx::{Database, Pool};
 
 pub mod cache;
 
+pub mod config;
+pub use config::ConfigError;
+
+pub mod cooldown;
+pub use cooldown::{Bucket, Cooldown, CooldownManager, CooldownRow, Cooldowns};
+
 pub mod cron;
-pub use cron::{ActionFn, CronJob, CronJobs};
+pub use cron::{ActionFn, CatchUp, CronJob, CronJobManager, CronJobRow, CronJobs};
 
 mod error;
 pub use error::Error;
 
+pub mod error_reports;
+pub use error_reports::{ErrorGroup, ErrorReport, ErrorReports};
+
+pub mod emojis;
 pub mod events;
 pub mod format_num;
 pub use format_num::FormatNum;
 
+pub mod i18n;
+pub use i18n::Localize;
+
+pub mod logging;
+pub use logging::LogFormat;
+
+pub mod metrics;
+pub use metrics::METRICS;
+
+pub mod paginator;
+pub use paginator::Paginator;
+
+pub mod permissions;
+pub use permissions::{PermissionLevel, PermissionsManager};
+
+pub mod registry;
+pub use registry::{Registry, Route};
+
+pub mod shutdown;
+pub use shutdown::Shutdown;
+
 pub mod sqlx_lib;
 pub use sqlx_lib::TableRow;
 
+#[cfg(feature = "testing")]
+pub mod testing;
+
 #[async_trait]
 pub trait SlashCommand<E: std::error::Error, Db: Database> {
     async fn run(
@@ -0,0 +1,105 @@
+use std::str::FromStr;
+use std::time::Instant;
+
+use serenity::all::{CommandInteraction, ComponentInteraction, ModalInteraction, ResolvedOption};
+use tracing::{Instrument, Span, field};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::get_option_str;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    #[default]
+    Pretty,
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pretty" => Ok(Self::Pretty),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Unknown log format: {s}")),
+        }
+    }
+}
+
+/// Installs the global subscriber. Filtering follows `RUST_LOG` and defaults
+/// to `info`, which hides the per-event spans but keeps every interaction.
+///
+/// Spans are logged when they close so each line carries its duration.
+pub fn init(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_span_events(FmtSpan::CLOSE);
+
+    match format {
+        LogFormat::Pretty => builder.pretty().init(),
+        LogFormat::Json => builder.json().with_span_list(false).init(),
+    }
+}
+
+pub fn event_span(name: &str) -> Span {
+    tracing::debug_span!("event", name, duration_ms = field::Empty)
+}
+
+pub fn command_span(interaction: &CommandInteraction, options: &[ResolvedOption<'_>]) -> Span {
+    tracing::info_span!(
+        "command",
+        guild = interaction.guild_id.map(|id| id.get()),
+        user = interaction.user.id.get(),
+        user_name = %interaction.user.name,
+        command = %interaction.data.name,
+        options = %get_option_str(options).trim_start(),
+        duration_ms = field::Empty,
+    )
+}
+
+pub fn autocomplete_span(interaction: &CommandInteraction) -> Span {
+    tracing::debug_span!(
+        "autocomplete",
+        guild = interaction.guild_id.map(|id| id.get()),
+        user = interaction.user.id.get(),
+        user_name = %interaction.user.name,
+        command = %interaction.data.name,
+        duration_ms = field::Empty,
+    )
+}
+
+pub fn component_span(interaction: &ComponentInteraction) -> Span {
+    tracing::info_span!(
+        "component",
+        guild = interaction.guild_id.map(|id| id.get()),
+        user = interaction.user.id.get(),
+        user_name = %interaction.user.name,
+        custom_id = %interaction.data.custom_id,
+        message = interaction.message.id.get(),
+        duration_ms = field::Empty,
+    )
+}
+
+pub fn modal_span(interaction: &ModalInteraction) -> Span {
+    tracing::info_span!(
+        "modal",
+        guild = interaction.guild_id.map(|id| id.get()),
+        user = interaction.user.id.get(),
+        user_name = %interaction.user.name,
+        custom_id = %interaction.data.custom_id,
+        duration_ms = field::Empty,
+    )
+}
+
+/// Runs `future` inside `span` and records how long it took.
+pub async fn timed<F: Future>(span: &Span, future: F) -> F::Output {
+    let start = Instant::now();
+    let output = future.instrument(span.clone()).await;
+
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+
+    output
+}
@@ -0,0 +1,182 @@
+use std::net::SocketAddr;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+
+use prometheus::{
+    Encoder, GaugeVec, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+/// How long a client gets to send its request before the connection is
+/// dropped.
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Every metric the bot exports, registered together so they all show up
+/// from the first scrape.
+pub struct Metrics {
+    registry: Registry,
+    pub commands: IntCounterVec,
+    pub command_errors: IntCounterVec,
+    pub event_duration: HistogramVec,
+    pub last_interaction: IntGauge,
+    pub gateway_latency: GaugeVec,
+    pub db_connections: IntGaugeVec,
+    pub cron_runs: IntCounterVec,
+    pub cron_duration: HistogramVec,
+    pub temp_voice_channels: IntGauge,
+}
+
+pub static METRICS: LazyLock<Metrics> = LazyLock::new(Metrics::new);
+
+impl Metrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some(String::from("zayden")), None).unwrap();
+
+        let metrics = Self {
+            commands: IntCounterVec::new(
+                Opts::new("commands_total", "Slash commands run"),
+                &["command"],
+            )
+            .unwrap(),
+            command_errors: IntCounterVec::new(
+                Opts::new("command_errors_total", "Slash commands that failed"),
+                &["command"],
+            )
+            .unwrap(),
+            event_duration: HistogramVec::new(
+                HistogramOpts::new(
+                    "event_duration_seconds",
+                    "Time spent handling gateway events, by slash command for interactions",
+                ),
+                &["event", "command"],
+            )
+            .unwrap(),
+            last_interaction: IntGauge::new(
+                "last_interaction_timestamp_seconds",
+                "When the last interaction was received",
+            )
+            .unwrap(),
+            gateway_latency: GaugeVec::new(
+                Opts::new("gateway_latency_seconds", "Heartbeat latency by shard"),
+                &["shard"],
+            )
+            .unwrap(),
+            db_connections: IntGaugeVec::new(
+                Opts::new("db_connections", "Database pool connections by state"),
+                &["state"],
+            )
+            .unwrap(),
+            cron_runs: IntCounterVec::new(Opts::new("cron_runs_total", "Cron job runs"), &["job"])
+                .unwrap(),
+            cron_duration: HistogramVec::new(
+                HistogramOpts::new("cron_duration_seconds", "Time spent running cron jobs"),
+                &["job"],
+            )
+            .unwrap(),
+            temp_voice_channels: IntGauge::new(
+                "temp_voice_channels",
+                "Temporary voice channels that exist",
+            )
+            .unwrap(),
+            registry,
+        };
+
+        metrics.register();
+        metrics
+    }
+
+    fn register(&self) {
+        let collectors: [Box<dyn prometheus::core::Collector>; 9] = [
+            Box::new(self.commands.clone()),
+            Box::new(self.command_errors.clone()),
+            Box::new(self.event_duration.clone()),
+            Box::new(self.last_interaction.clone()),
+            Box::new(self.gateway_latency.clone()),
+            Box::new(self.db_connections.clone()),
+            Box::new(self.cron_runs.clone()),
+            Box::new(self.cron_duration.clone()),
+            Box::new(self.temp_voice_channels.clone()),
+        ];
+
+        for collector in collectors {
+            self.registry.register(collector).unwrap();
+        }
+    }
+
+    /// The metrics in the Prometheus text format.
+    pub fn encode(&self) -> String {
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .unwrap();
+
+        String::from_utf8(buffer).unwrap()
+    }
+}
+
+/// Serves `/metrics` and `/healthz` on `addr` until the listener fails.
+///
+/// `refresh` runs before every response to update the gauges that are read
+/// rather than counted, and returns whether the bot is healthy. Each
+/// connection is answered on its own task, so a slow client can't hold up
+/// the next scrape.
+pub async fn serve<F, Fut>(addr: SocketAddr, refresh: F) -> std::io::Result<()>
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = bool> + Send,
+{
+    let listener = TcpListener::bind(addr).await?;
+    tracing::info!(%addr, "serving metrics");
+
+    let refresh = Arc::new(refresh);
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let refresh = refresh.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = respond(stream, &*refresh).await {
+                tracing::debug!(error = ?e, "failed to answer metrics request");
+            }
+        });
+    }
+}
+
+async fn respond<F, Fut>(mut stream: TcpStream, refresh: &F) -> std::io::Result<()>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = bool>,
+{
+    let mut buffer = [0; 1024];
+    let read = timeout(READ_TIMEOUT, stream.read(&mut buffer))
+        .await
+        .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))??;
+    let request = String::from_utf8_lossy(&buffer[..read]);
+
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or_default();
+
+    let (status, body) = match path {
+        "/metrics" => {
+            refresh().await;
+            ("200 OK", METRICS.encode())
+        }
+        "/healthz" if refresh().await => ("200 OK", String::from("ok\n")),
+        "/healthz" => ("503 Service Unavailable", String::from("unhealthy\n")),
+        _ => ("404 Not Found", String::new()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
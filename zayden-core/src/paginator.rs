@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use futures::StreamExt;
+use serenity::all::{
+    ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateActionRow, CreateButton,
+    CreateEmbed, CreateEmbedFooter, CreateInputText, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateModal, EditInteractionResponse, InputTextStyle,
+    ModalInteractionCollector,
+};
+
+use crate::i18n::{self, Localize};
+use crate::{Error, Shutdown, parse_modal_data, t};
+
+const FIRST: &str = "paginator_first";
+const PREVIOUS: &str = "paginator_previous";
+const JUMP: &str = "paginator_jump";
+const USER: &str = "paginator_user";
+const NEXT: &str = "paginator_next";
+const LAST: &str = "paginator_last";
+
+/// Shows the pages returned by `fetch` in the response to a deferred command,
+/// with buttons to move between them until `timeout` passes without a click.
+///
+/// Pages are numbered from 1, and their footer is replaced with the page
+/// number. Only the user who ran the command can use the buttons.
+pub struct Paginator<F> {
+    fetch: F,
+    pages: usize,
+    start: usize,
+    user_page: Option<usize>,
+    timeout: Duration,
+}
+
+impl<F, Fut, E> Paginator<F>
+where
+    F: Fn(usize) -> Fut,
+    Fut: Future<Output = Result<CreateEmbed, E>>,
+    E: From<serenity::Error>,
+{
+    pub fn new(pages: usize, fetch: F) -> Self {
+        Self {
+            fetch,
+            pages: pages.max(1),
+            start: 1,
+            user_page: None,
+            timeout: Duration::from_secs(120),
+        }
+    }
+
+    /// The page shown first, instead of page 1.
+    pub fn start(mut self, page: usize) -> Self {
+        self.start = page.clamp(1, self.pages);
+        self
+    }
+
+    /// Adds a 🎯 button jumping to `page`, where the user appears.
+    pub fn user_page(mut self, page: Option<usize>) -> Self {
+        self.user_page = page.map(|page| page.clamp(1, self.pages));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub async fn run(self, ctx: &Context, interaction: &CommandInteraction) -> Result<(), E> {
+        let locale = i18n::locale(&interaction.locale, interaction.guild_locale.as_deref());
+        let mut page = self.start;
+
+        let msg = interaction
+            .edit_response(
+                ctx,
+                EditInteractionResponse::new()
+                    .embed(self.render(locale, page).await?)
+                    .components(self.buttons(page)),
+            )
+            .await?;
+
+        if self.pages == 1 && self.user_page.is_none() {
+            return Ok(());
+        }
+
+        let mut stream = msg
+            .await_component_interactions(ctx)
+            .timeout(self.timeout)
+            .stream()
+            .take_until(Shutdown::signal(ctx));
+
+        while let Some(component) = stream.next().await {
+            if component.user.id != interaction.user.id {
+                let locale = i18n::locale(&component.locale, component.guild_locale.as_deref());
+
+                component
+                    .create_response(
+                        ctx,
+                        CreateInteractionResponse::Message(
+                            CreateInteractionResponseMessage::new()
+                                .content(Error::NotInteractionAuthor.localize(locale))
+                                .ephemeral(true),
+                        ),
+                    )
+                    .await?;
+                continue;
+            }
+
+            page = match component.data.custom_id.as_str() {
+                FIRST => 1,
+                PREVIOUS => page.saturating_sub(1).max(1),
+                USER => self.user_page.unwrap_or(page),
+                NEXT => (page + 1).min(self.pages),
+                LAST => self.pages,
+                JUMP => {
+                    if let Some(jump) = self.jump(ctx, &component, locale).await? {
+                        page = jump;
+                    }
+                    continue;
+                }
+                _ => continue,
+            };
+
+            component
+                .create_response(
+                    ctx,
+                    CreateInteractionResponse::UpdateMessage(
+                        CreateInteractionResponseMessage::new()
+                            .embed(self.render(locale, page).await?)
+                            .components(self.buttons(page)),
+                    ),
+                )
+                .await?;
+        }
+
+        interaction
+            .edit_response(ctx, EditInteractionResponse::new().components(Vec::new()))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn render(&self, locale: &str, page: usize) -> Result<CreateEmbed, E> {
+        let embed = (self.fetch)(page).await?;
+
+        Ok(embed.footer(CreateEmbedFooter::new(t!(
+            locale,
+            "core.paginator.footer",
+            page = page,
+            pages = self.pages
+        ))))
+    }
+
+    fn buttons(&self, page: usize) -> Vec<CreateActionRow> {
+        let mut buttons = vec![
+            CreateButton::new(FIRST).label("«").disabled(page == 1),
+            CreateButton::new(PREVIOUS).label("‹").disabled(page == 1),
+            CreateButton::new(JUMP)
+                .label(format!("{page}/{}", self.pages))
+                .style(ButtonStyle::Secondary)
+                .disabled(self.pages == 1),
+        ];
+
+        if self.user_page.is_some() {
+            buttons.push(
+                CreateButton::new(USER)
+                    .emoji('🎯')
+                    .style(ButtonStyle::Secondary),
+            );
+        }
+
+        buttons.extend([
+            CreateButton::new(NEXT)
+                .label("›")
+                .disabled(page == self.pages),
+            CreateButton::new(LAST)
+                .label("»")
+                .disabled(page == self.pages),
+        ]);
+
+        vec![CreateActionRow::Buttons(buttons)]
+    }
+
+    /// Asks for a page number and shows that page, returning it unless the
+    /// modal was dismissed or the input wasn't a number.
+    async fn jump(
+        &self,
+        ctx: &Context,
+        component: &ComponentInteraction,
+        locale: &str,
+    ) -> Result<Option<usize>, E> {
+        let custom_id = format!("{JUMP}_{}", component.id);
+
+        let input = CreateInputText::new(
+            InputTextStyle::Short,
+            t!(locale, "core.paginator.page"),
+            "page",
+        )
+        .placeholder(format!("1-{}", self.pages))
+        .required(true);
+
+        component
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Modal(
+                    CreateModal::new(&custom_id, t!(locale, "core.paginator.jump"))
+                        .components(vec![CreateActionRow::InputText(input)]),
+                ),
+            )
+            .await?;
+
+        let Some(modal) = ModalInteractionCollector::new(ctx)
+            .author_id(component.user.id)
+            .custom_ids(vec![custom_id])
+            .timeout(self.timeout)
+            .next()
+            .await
+        else {
+            return Ok(None);
+        };
+
+        let Some(page) = parse_modal_data(&modal.data.components)
+            .get("page")
+            .and_then(|page| page.trim().parse::<usize>().ok())
+            .map(|page| page.clamp(1, self.pages))
+        else {
+            modal
+                .create_response(ctx, CreateInteractionResponse::Acknowledge)
+                .await?;
+            return Ok(None);
+        };
+
+        modal
+            .create_response(
+                ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new()
+                        .embed(self.render(locale, page).await?)
+                        .components(self.buttons(page)),
+                ),
+            )
+            .await?;
+
+        Ok(Some(page))
+    }
+}
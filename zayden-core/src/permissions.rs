@@ -0,0 +1,186 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use serenity::all::{GuildId, Member, RoleId, UserId};
+use sqlx::any::AnyQueryResult;
+use sqlx::{Database, Pool};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PermissionLevel {
+    #[default]
+    Everyone,
+    Moderator,
+    Admin,
+    Owner,
+}
+
+impl PermissionLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Everyone => "everyone",
+            Self::Moderator => "moderator",
+            Self::Admin => "admin",
+            Self::Owner => "owner",
+        }
+    }
+}
+
+impl FromStr for PermissionLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "everyone" => Ok(Self::Everyone),
+            "moderator" => Ok(Self::Moderator),
+            "admin" => Ok(Self::Admin),
+            "owner" => Ok(Self::Owner),
+            _ => Err(format!("Unknown permission level: {s}")),
+        }
+    }
+}
+
+impl Display for PermissionLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+pub struct PermissionRoleRow {
+    pub role_id: i64,
+    pub level: String,
+}
+
+impl PermissionRoleRow {
+    pub fn role_id(&self) -> RoleId {
+        RoleId::new(self.role_id as u64)
+    }
+
+    pub fn level(&self) -> PermissionLevel {
+        self.level.parse().unwrap_or_default()
+    }
+}
+
+pub struct CommandPermissionRow {
+    pub command: String,
+    pub level: String,
+}
+
+impl CommandPermissionRow {
+    pub fn level(&self) -> PermissionLevel {
+        self.level.parse().unwrap_or_default()
+    }
+}
+
+#[async_trait]
+pub trait PermissionsManager<Db: Database> {
+    async fn owners(pool: &Pool<Db>) -> sqlx::Result<Vec<UserId>>;
+
+    async fn is_owner(pool: &Pool<Db>, user: UserId) -> sqlx::Result<bool>;
+
+    async fn add_owner(pool: &Pool<Db>, user: UserId) -> sqlx::Result<AnyQueryResult>;
+
+    async fn remove_owner(pool: &Pool<Db>, user: UserId) -> sqlx::Result<AnyQueryResult>;
+
+    async fn roles(pool: &Pool<Db>, guild: GuildId) -> sqlx::Result<Vec<PermissionRoleRow>>;
+
+    async fn set_role(
+        pool: &Pool<Db>,
+        guild: GuildId,
+        role: RoleId,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn remove_role(
+        pool: &Pool<Db>,
+        guild: GuildId,
+        role: RoleId,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn commands(pool: &Pool<Db>, guild: GuildId) -> sqlx::Result<Vec<CommandPermissionRow>>;
+
+    async fn command(
+        pool: &Pool<Db>,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<Option<CommandPermissionRow>>;
+
+    async fn set_command(
+        pool: &Pool<Db>,
+        guild: GuildId,
+        command: &str,
+        level: PermissionLevel,
+    ) -> sqlx::Result<AnyQueryResult>;
+
+    async fn remove_command(
+        pool: &Pool<Db>,
+        guild: GuildId,
+        command: &str,
+    ) -> sqlx::Result<AnyQueryResult>;
+}
+
+/// The highest level `user` has. Guild levels come from `member`'s roles, and
+/// members with the Administrator permission are always admins.
+pub async fn level<Db: Database, Manager: PermissionsManager<Db>>(
+    pool: &Pool<Db>,
+    user: UserId,
+    member: Option<&Member>,
+) -> sqlx::Result<PermissionLevel> {
+    if Manager::is_owner(pool, user).await? {
+        return Ok(PermissionLevel::Owner);
+    }
+
+    let Some(member) = member else {
+        return Ok(PermissionLevel::Everyone);
+    };
+
+    if member
+        .permissions
+        .is_some_and(|permissions| permissions.administrator())
+    {
+        return Ok(PermissionLevel::Admin);
+    }
+
+    let level = Manager::roles(pool, member.guild_id)
+        .await?
+        .iter()
+        .filter(|row| member.roles.contains(&row.role_id()))
+        .map(|row| row.level())
+        .max()
+        .unwrap_or_default();
+
+    Ok(level)
+}
+
+/// Whether `user` has at least `required`.
+pub async fn has_level<Db: Database, Manager: PermissionsManager<Db>>(
+    pool: &Pool<Db>,
+    user: UserId,
+    member: Option<&Member>,
+    required: PermissionLevel,
+) -> sqlx::Result<bool> {
+    if required == PermissionLevel::Everyone {
+        return Ok(true);
+    }
+
+    Ok(level::<Db, Manager>(pool, user, member).await? >= required)
+}
+
+/// The level needed to run `command` in `guild`, falling back to `default`
+/// when the guild has no override.
+pub async fn command_level<Db: Database, Manager: PermissionsManager<Db>>(
+    pool: &Pool<Db>,
+    guild: Option<GuildId>,
+    command: &str,
+    default: PermissionLevel,
+) -> sqlx::Result<PermissionLevel> {
+    let Some(guild) = guild else {
+        return Ok(default);
+    };
+
+    let level = Manager::command(pool, guild, command)
+        .await?
+        .map_or(default, |row| row.level());
+
+    Ok(level)
+}
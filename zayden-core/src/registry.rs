@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::pin::Pin;
+
+use serenity::all::{
+    ActionRow, AutocompleteOption, CommandInteraction, ComponentInteraction, Context,
+    CreateCommand, GuildId, ModalInteraction, ResolvedOption,
+};
+use sqlx::{Database, Pool};
+
+use crate::{Autocomplete, Component, Modal, SlashCommand, i18n};
+
+pub type HandlerFuture<'a, E> = Pin<Box<dyn Future<Output = Result<(), E>> + Send + 'a>>;
+
+pub type RegisterFn<E> = fn(&Context) -> Result<CreateCommand, E>;
+
+pub type CommandFn<E, Db> = for<'a> fn(
+    &'a Context,
+    &'a CommandInteraction,
+    Vec<ResolvedOption<'a>>,
+    &'a Pool<Db>,
+) -> HandlerFuture<'a, E>;
+
+pub type AutocompleteFn<E, Db> = for<'a> fn(
+    &'a Context,
+    &'a CommandInteraction,
+    AutocompleteOption<'a>,
+    &'a Pool<Db>,
+) -> HandlerFuture<'a, E>;
+
+pub type ComponentFn<E, Db> =
+    for<'a> fn(&'a Context, &'a ComponentInteraction, &'a Pool<Db>) -> HandlerFuture<'a, E>;
+
+pub type ModalFn<E, Db> = for<'a> fn(
+    &'a Context,
+    &'a ModalInteraction,
+    &'a [ActionRow],
+    &'a Pool<Db>,
+) -> HandlerFuture<'a, E>;
+
+/// A registered handler and the module it belongs to.
+#[derive(Clone, Copy)]
+pub struct Route<F> {
+    pub module: Option<&'static str>,
+    pub run: F,
+}
+
+struct CommandEntry<E, Db: Database> {
+    guild: Option<GuildId>,
+    register: RegisterFn<E>,
+    route: Route<CommandFn<E, Db>>,
+}
+
+/// Single source for routing interactions and registering commands with Discord.
+///
+/// Commands and autocompletes are matched on the command name. Components and
+/// modals are matched on the longest registered prefix of their custom id
+/// that ends at a `_`, or on the whole id.
+///
+/// Handlers added inside [`Registry::module`] are tagged with that module so
+/// guilds can opt out of it.
+pub struct Registry<E, Db: Database> {
+    module: Option<&'static str>,
+    commands: HashMap<&'static str, CommandEntry<E, Db>>,
+    autocompletes: HashMap<&'static str, Route<AutocompleteFn<E, Db>>>,
+    components: HashMap<&'static str, Route<ComponentFn<E, Db>>>,
+    modals: HashMap<&'static str, Route<ModalFn<E, Db>>>,
+}
+
+impl<E: std::error::Error + 'static, Db: Database> Registry<E, Db> {
+    pub fn new() -> Self {
+        Self {
+            module: None,
+            commands: HashMap::new(),
+            autocompletes: HashMap::new(),
+            components: HashMap::new(),
+            modals: HashMap::new(),
+        }
+    }
+
+    /// Tags everything added by `register` as part of `module`.
+    pub fn module(mut self, module: &'static str, register: fn(Self) -> Self) -> Self {
+        let parent = self.module.replace(module);
+        let mut registry = register(self);
+        registry.module = parent;
+        registry
+    }
+
+    pub fn command<T: SlashCommand<E, Db> + 'static>(mut self, name: &'static str) -> Self {
+        self.insert_command::<T>(name, None);
+        self
+    }
+
+    /// Registers a command that is only created in `guild`.
+    pub fn guild_command<T: SlashCommand<E, Db> + 'static>(
+        mut self,
+        name: &'static str,
+        guild: impl Into<GuildId>,
+    ) -> Self {
+        self.insert_command::<T>(name, Some(guild.into()));
+        self
+    }
+
+    pub fn autocomplete<T: Autocomplete<E, Db> + 'static>(mut self, name: &'static str) -> Self {
+        let route = self.route(run_autocomplete::<E, Db, T>);
+
+        if self.autocompletes.insert(name, route).is_some() {
+            panic!("Autocomplete '{name}' is registered more than once");
+        }
+
+        self
+    }
+
+    pub fn component<T: Component<E, Db> + 'static>(mut self, prefix: &'static str) -> Self {
+        let route = self.route(run_component::<E, Db, T>);
+
+        if self.components.insert(prefix, route).is_some() {
+            panic!("Component prefix '{prefix}' is registered more than once");
+        }
+
+        self
+    }
+
+    pub fn modal<T: Modal<E, Db> + 'static>(mut self, prefix: &'static str) -> Self {
+        let route = self.route(run_modal::<E, Db, T>);
+
+        if self.modals.insert(prefix, route).is_some() {
+            panic!("Modal prefix '{prefix}' is registered more than once");
+        }
+
+        self
+    }
+
+    fn insert_command<T: SlashCommand<E, Db> + 'static>(
+        &mut self,
+        name: &'static str,
+        guild: Option<GuildId>,
+    ) {
+        let entry = CommandEntry {
+            guild,
+            register: T::register,
+            route: self.route(run_command::<E, Db, T>),
+        };
+
+        if self.commands.insert(name, entry).is_some() {
+            panic!("Command '{name}' is registered more than once");
+        }
+    }
+
+    fn route<F>(&self, run: F) -> Route<F> {
+        Route {
+            module: self.module,
+            run,
+        }
+    }
+
+    /// Builds every command that should be created in `guild`, skipping the
+    /// ones from `disabled` modules. Descriptions are translated from the
+    /// `commands.<name>.description` catalog keys.
+    pub fn register(
+        &self,
+        ctx: &Context,
+        guild: GuildId,
+        disabled: &[String],
+    ) -> Result<Vec<CreateCommand>, E> {
+        self.commands
+            .iter()
+            .filter(|(_, entry)| entry.guild.is_none_or(|id| id == guild))
+            .filter(|(_, entry)| {
+                entry
+                    .route
+                    .module
+                    .is_none_or(|module| !disabled.iter().any(|name| name == module))
+            })
+            .map(|(name, entry)| Ok(i18n::localize_command((entry.register)(ctx)?, name)))
+            .collect()
+    }
+
+    /// The commands registered under a different name than the one their
+    /// `register` creates, which could never be routed.
+    pub fn misnamed(&self, ctx: &Context) -> Result<Vec<&'static str>, E> {
+        let mut misnamed = Vec::new();
+
+        for (name, entry) in &self.commands {
+            let command = (entry.register)(ctx)?;
+
+            if command_name(&command).as_deref() != Some(*name) {
+                misnamed.push(*name);
+            }
+        }
+
+        Ok(misnamed)
+    }
+
+    pub fn get_command(&self, name: &str) -> Option<Route<CommandFn<E, Db>>> {
+        self.commands.get(name).map(|entry| entry.route)
+    }
+
+    pub fn get_autocomplete(&self, name: &str) -> Option<Route<AutocompleteFn<E, Db>>> {
+        self.autocompletes.get(name).copied()
+    }
+
+    pub fn get_component(&self, custom_id: &str) -> Option<Route<ComponentFn<E, Db>>> {
+        find_prefix(&self.components, custom_id)
+    }
+
+    pub fn get_modal(&self, custom_id: &str) -> Option<Route<ModalFn<E, Db>>> {
+        find_prefix(&self.modals, custom_id)
+    }
+}
+
+impl<E: std::error::Error + 'static, Db: Database> Default for Registry<E, Db> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The name `command` is created with, which the builder doesn't expose.
+fn command_name(command: &CreateCommand) -> Option<String> {
+    serde_json::to_value(command)
+        .ok()?
+        .get("name")?
+        .as_str()
+        .map(String::from)
+}
+
+/// The handler for the whole custom id, or else for its longest prefix
+/// that ends at a `_`, with or without the `_` itself. Prefixes are only
+/// looked up at those boundaries rather than scanning every handler.
+fn find_prefix<T: Copy>(handlers: &HashMap<&'static str, T>, custom_id: &str) -> Option<T> {
+    if let Some(handler) = handlers.get(custom_id) {
+        return Some(*handler);
+    }
+
+    custom_id.rmatch_indices('_').find_map(|(i, _)| {
+        handlers
+            .get(&custom_id[..=i])
+            .or_else(|| handlers.get(&custom_id[..i]))
+            .copied()
+    })
+}
+
+fn run_command<'a, E, Db: Database, T: SlashCommand<E, Db> + 'static>(
+    ctx: &'a Context,
+    interaction: &'a CommandInteraction,
+    options: Vec<ResolvedOption<'a>>,
+    pool: &'a Pool<Db>,
+) -> HandlerFuture<'a, E>
+where
+    E: std::error::Error + 'static,
+{
+    T::run(ctx, interaction, options, pool)
+}
+
+fn run_autocomplete<'a, E, Db: Database, T: Autocomplete<E, Db> + 'static>(
+    ctx: &'a Context,
+    interaction: &'a CommandInteraction,
+    option: AutocompleteOption<'a>,
+    pool: &'a Pool<Db>,
+) -> HandlerFuture<'a, E>
+where
+    E: std::error::Error + 'static,
+{
+    T::autocomplete(ctx, interaction, option, pool)
+}
+
+fn run_component<'a, E, Db: Database, T: Component<E, Db> + 'static>(
+    ctx: &'a Context,
+    interaction: &'a ComponentInteraction,
+    pool: &'a Pool<Db>,
+) -> HandlerFuture<'a, E>
+where
+    E: std::error::Error + 'static,
+{
+    T::run(ctx, interaction, pool)
+}
+
+fn run_modal<'a, E, Db: Database, T: Modal<E, Db> + 'static>(
+    ctx: &'a Context,
+    interaction: &'a ModalInteraction,
+    components: &'a [ActionRow],
+    pool: &'a Pool<Db>,
+) -> HandlerFuture<'a, E>
+where
+    E: std::error::Error + 'static,
+{
+    T::run(ctx, interaction, components, pool)
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use serenity::all::Context;
+use serenity::prelude::TypeMapKey;
+use tokio::sync::{Notify, watch};
+
+/// Coordinates a graceful shutdown: once started, new commands are refused
+/// and sessions waiting on [`Shutdown::signal`] wrap up, while the running
+/// handlers are counted so the caller can wait for them.
+#[derive(Clone)]
+pub struct Shutdown(Arc<Inner>);
+
+struct Inner {
+    started: watch::Sender<bool>,
+    running: AtomicUsize,
+    idle: Notify,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self(Arc::new(Inner {
+            started: watch::Sender::new(false),
+            running: AtomicUsize::new(0),
+            idle: Notify::new(),
+        }))
+    }
+
+    pub fn start(&self) {
+        self.0.started.send_replace(true);
+    }
+
+    pub fn is_started(&self) -> bool {
+        *self.0.started.borrow()
+    }
+
+    /// Counts a handler as running until the guard is dropped.
+    pub fn track(&self) -> Guard {
+        self.0.running.fetch_add(1, Ordering::SeqCst);
+        Guard(self.0.clone())
+    }
+
+    pub fn running(&self) -> usize {
+        self.0.running.load(Ordering::SeqCst)
+    }
+
+    /// Waits up to `grace` for the tracked handlers to finish, returning
+    /// whether they all did.
+    pub async fn idle(&self, grace: Duration) -> bool {
+        let wait = async {
+            loop {
+                let notified = self.0.idle.notified();
+                tokio::pin!(notified);
+                notified.as_mut().enable();
+
+                if self.running() == 0 {
+                    return;
+                }
+
+                notified.await;
+            }
+        };
+
+        tokio::time::timeout(grace, wait).await.is_ok()
+    }
+
+    async fn get(ctx: &Context) -> Option<Self> {
+        ctx.data.read().await.get::<Self>().cloned()
+    }
+
+    /// Whether a shutdown has started, `false` without a [`Shutdown`] in the
+    /// type map.
+    pub async fn started(ctx: &Context) -> bool {
+        Self::get(ctx)
+            .await
+            .is_some_and(|shutdown| shutdown.is_started())
+    }
+
+    /// Resolves once a shutdown starts, for ending collectors with
+    /// `take_until` so open menus close before the bot exits.
+    pub fn signal(ctx: &Context) -> BoxFuture<'_, ()> {
+        Box::pin(async move {
+            let Some(shutdown) = Self::get(ctx).await else {
+                return std::future::pending().await;
+            };
+
+            let mut started = shutdown.0.started.subscribe();
+            let _ = started.wait_for(|started| *started).await;
+        })
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TypeMapKey for Shutdown {
+    type Value = Shutdown;
+}
+
+pub struct Guard(Arc<Inner>);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        if self.0.running.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.idle.notify_waiters();
+        }
+    }
+}
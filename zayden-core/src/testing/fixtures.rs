@@ -0,0 +1,299 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde_json::{Value, json};
+use serenity::all::{
+    ApplicationId, ChannelId, CommandInteraction, ComponentInteraction, GuildId, MessageId,
+    ModalInteraction, UserId,
+};
+
+pub const APPLICATION_ID: ApplicationId = ApplicationId::new(100_000_000_000_000_001);
+pub const BOT_ID: UserId = UserId::new(100_000_000_000_000_001);
+pub const GUILD_ID: GuildId = GuildId::new(100_000_000_000_000_002);
+pub const CHANNEL_ID: ChannelId = ChannelId::new(100_000_000_000_000_003);
+pub const USER_ID: UserId = UserId::new(100_000_000_000_000_004);
+
+pub(super) const TIMESTAMP: &str = "2025-01-01T00:00:00.000000+00:00";
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(200_000_000_000_000_000);
+
+/// A fresh snowflake, unique within the test binary.
+pub fn snowflake() -> u64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+pub fn user(id: impl Into<UserId>) -> Value {
+    let id = id.into();
+
+    json!({
+        "id": id.to_string(),
+        "username": format!("user{id}"),
+        "discriminator": "0",
+        "global_name": null,
+        "avatar": null,
+        "bot": id == BOT_ID,
+    })
+}
+
+pub fn member(guild: impl Into<GuildId>, id: impl Into<UserId>) -> Value {
+    json!({
+        "guild_id": guild.into().to_string(),
+        "user": user(id),
+        "roles": [],
+        "joined_at": TIMESTAMP,
+        "deaf": false,
+        "mute": false,
+        "flags": 0,
+        "permissions": "0",
+    })
+}
+
+pub fn channel(guild: impl Into<GuildId>, id: impl Into<ChannelId>) -> Value {
+    let id = id.into();
+
+    json!({
+        "id": id.to_string(),
+        "guild_id": guild.into().to_string(),
+        "type": 0,
+        "name": format!("channel-{id}"),
+        "position": 0,
+        "permission_overwrites": [],
+    })
+}
+
+pub fn message(channel: impl Into<ChannelId>, id: impl Into<MessageId>, author: Value) -> Value {
+    json!({
+        "id": id.into().to_string(),
+        "channel_id": channel.into().to_string(),
+        "author": author,
+        "content": "",
+        "timestamp": TIMESTAMP,
+        "edited_timestamp": null,
+        "tts": false,
+        "mention_everyone": false,
+        "mentions": [],
+        "mention_roles": [],
+        "attachments": [],
+        "embeds": [],
+        "components": [],
+        "pinned": false,
+        "type": 0,
+        "flags": 0,
+    })
+}
+
+/// Builds the interaction payloads Discord would send, for the default guild,
+/// channel and user unless told otherwise.
+///
+/// Every interaction gets its own id and the token `token-<id>`, so requests
+/// made in response to it can be told apart.
+#[derive(Clone, Debug)]
+pub struct InteractionBuilder {
+    id: u64,
+    user: UserId,
+    guild: Option<GuildId>,
+    channel: ChannelId,
+    locale: String,
+    guild_locale: Option<String>,
+    resolved: Value,
+}
+
+impl InteractionBuilder {
+    pub fn new() -> Self {
+        Self {
+            id: snowflake(),
+            user: USER_ID,
+            guild: Some(GUILD_ID),
+            channel: CHANNEL_ID,
+            locale: String::from("en-US"),
+            guild_locale: Some(String::from("en-US")),
+            resolved: json!({}),
+        }
+    }
+
+    pub fn user(mut self, user: impl Into<UserId>) -> Self {
+        self.user = user.into();
+        self
+    }
+
+    pub fn guild(mut self, guild: impl Into<GuildId>) -> Self {
+        self.guild = Some(guild.into());
+        self
+    }
+
+    /// Sends the interaction from a direct message instead of a guild.
+    pub fn dm(mut self) -> Self {
+        self.guild = None;
+        self.guild_locale = None;
+        self
+    }
+
+    pub fn channel(mut self, channel: impl Into<ChannelId>) -> Self {
+        self.channel = channel.into();
+        self
+    }
+
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    pub fn guild_locale(mut self, locale: impl Into<String>) -> Self {
+        self.guild_locale = Some(locale.into());
+        self
+    }
+
+    /// The `resolved` data for user, role or channel options.
+    pub fn resolved(mut self, resolved: Value) -> Self {
+        self.resolved = resolved;
+        self
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn token(&self) -> String {
+        format!("token-{}", self.id)
+    }
+
+    pub fn command_json(&self, name: &str, options: Value) -> Value {
+        self.json(
+            2,
+            json!({
+                "id": snowflake().to_string(),
+                "name": name,
+                "type": 1,
+                "options": options,
+                "resolved": self.resolved,
+            }),
+            None,
+        )
+    }
+
+    pub fn component_json(&self, custom_id: &str, message: impl Into<MessageId>) -> Value {
+        self.json(
+            3,
+            json!({
+                "custom_id": custom_id,
+                "component_type": 2,
+            }),
+            Some(message.into()),
+        )
+    }
+
+    pub fn select_json(
+        &self,
+        custom_id: &str,
+        message: impl Into<MessageId>,
+        values: &[&str],
+    ) -> Value {
+        self.json(
+            3,
+            json!({
+                "custom_id": custom_id,
+                "component_type": 3,
+                "values": values,
+            }),
+            Some(message.into()),
+        )
+    }
+
+    pub fn modal_json(&self, custom_id: &str, fields: &[(&str, &str)]) -> Value {
+        self.json(5, modal_data(custom_id, fields), None)
+    }
+
+    /// A modal opened from a component on `message`, which Discord sends
+    /// along with the submission.
+    pub fn message_modal_json(
+        &self,
+        custom_id: &str,
+        message: impl Into<MessageId>,
+        fields: &[(&str, &str)],
+    ) -> Value {
+        self.json(5, modal_data(custom_id, fields), Some(message.into()))
+    }
+
+    pub fn command(&self, name: &str, options: Value) -> CommandInteraction {
+        serde_json::from_value(self.command_json(name, options)).unwrap()
+    }
+
+    pub fn component(
+        &self,
+        custom_id: &str,
+        message: impl Into<MessageId>,
+    ) -> ComponentInteraction {
+        serde_json::from_value(self.component_json(custom_id, message)).unwrap()
+    }
+
+    pub fn select(
+        &self,
+        custom_id: &str,
+        message: impl Into<MessageId>,
+        values: &[&str],
+    ) -> ComponentInteraction {
+        serde_json::from_value(self.select_json(custom_id, message, values)).unwrap()
+    }
+
+    pub fn modal(&self, custom_id: &str, fields: &[(&str, &str)]) -> ModalInteraction {
+        serde_json::from_value(self.modal_json(custom_id, fields)).unwrap()
+    }
+
+    fn json(&self, kind: u8, data: Value, message: Option<MessageId>) -> Value {
+        let mut interaction = json!({
+            "id": self.id.to_string(),
+            "application_id": APPLICATION_ID.to_string(),
+            "type": kind,
+            "data": data,
+            "channel_id": self.channel.to_string(),
+            "token": self.token(),
+            "version": 1,
+            "locale": self.locale,
+            "app_permissions": "0",
+            "entitlements": [],
+            "authorizing_integration_owners": {},
+        });
+
+        match self.guild {
+            Some(guild) => {
+                interaction["guild_id"] = json!(guild.to_string());
+                interaction["member"] = member(guild, self.user);
+                interaction["guild_locale"] = json!(self.guild_locale);
+            }
+            None => interaction["user"] = user(self.user),
+        }
+
+        if let Some(message) = message {
+            interaction["message"] = self::message(self.channel, message, user(BOT_ID));
+        }
+
+        interaction
+    }
+}
+
+impl Default for InteractionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn modal_data(custom_id: &str, fields: &[(&str, &str)]) -> Value {
+    let components = fields
+        .iter()
+        .map(|(custom_id, value)| {
+            json!({
+                "type": 1,
+                "components": [{
+                    "type": 4,
+                    "custom_id": custom_id,
+                    "style": 1,
+                    "value": value,
+                }],
+            })
+        })
+        .collect::<Vec<_>>();
+
+    json!({
+        "custom_id": custom_id,
+        "components": components,
+    })
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use futures::{SinkExt, StreamExt};
+use serde_json::{Value, json};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{Mutex, mpsc};
+use tokio_tungstenite::tungstenite::Message;
+
+use super::fixtures::{self, APPLICATION_ID, BOT_ID};
+
+pub(super) type Dispatch = (String, Value);
+
+/// Accepts shard connections, completes the identify handshake and forwards
+/// dispatched events. Reconnecting shards keep reading from the same queue.
+pub(super) async fn serve(listener: TcpListener, events: mpsc::UnboundedReceiver<Dispatch>) {
+    let events = Arc::new(Mutex::new(events));
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(connection(stream, events.clone(), url.clone()));
+    }
+}
+
+async fn connection(
+    stream: TcpStream,
+    events: Arc<Mutex<mpsc::UnboundedReceiver<Dispatch>>>,
+    url: String,
+) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    let mut ws = tokio_tungstenite::accept_async(stream).await?;
+
+    send(
+        &mut ws,
+        json!({ "op": 10, "d": { "heartbeat_interval": 45000 } }),
+    )
+    .await?;
+
+    let mut events = events.lock().await;
+    let mut seq = 0;
+
+    loop {
+        tokio::select! {
+            message = ws.next() => {
+                let text = match message {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                };
+
+                let payload = serde_json::from_str::<Value>(&text).unwrap_or_default();
+
+                match payload["op"].as_u64() {
+                    // Heartbeat
+                    Some(1) => send(&mut ws, json!({ "op": 11 })).await?,
+                    // Identify or resume
+                    Some(2) | Some(6) => {
+                        seq += 1;
+                        send(&mut ws, dispatch(seq, "READY", ready(&url))).await?;
+                    }
+                    _ => {}
+                }
+            }
+            event = events.recv() => {
+                let Some((name, data)) = event else {
+                    return Ok(());
+                };
+
+                seq += 1;
+                send(&mut ws, dispatch(seq, &name, data)).await?;
+            }
+        }
+    }
+}
+
+async fn send(
+    ws: &mut tokio_tungstenite::WebSocketStream<TcpStream>,
+    payload: Value,
+) -> Result<(), tokio_tungstenite::tungstenite::Error> {
+    ws.send(Message::Text(payload.to_string())).await
+}
+
+fn dispatch(seq: u64, name: &str, data: Value) -> Value {
+    json!({ "op": 0, "s": seq, "t": name, "d": data })
+}
+
+fn ready(url: &str) -> Value {
+    json!({
+        "v": 10,
+        "user": fixtures::user(BOT_ID),
+        "guilds": [],
+        "session_id": "test-session",
+        "resume_gateway_url": url,
+        "shard": [0, 1],
+        "application": { "id": APPLICATION_ID.to_string(), "flags": 0 },
+    })
+}
@@ -0,0 +1,220 @@
+//! An offline stand-in for Discord to run handlers against in tests.
+//!
+//! [`TestDiscord`] serves the REST API and a gateway on localhost and connects
+//! a real serenity client to them, so commands, components, modals and
+//! collectors run unchanged. Requests the handlers make are recorded for
+//! assertions, and interactions can be pushed through the gateway to exercise
+//! the full event handler.
+//!
+//! Use `#[sqlx::test]` for a disposable database alongside it.
+
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde_json::Value;
+use serenity::all::{ClientBuilder, Context, Event, GatewayIntents, HttpBuilder, RawEventHandler};
+use serenity::async_trait;
+use serenity::prelude::TypeMap;
+use tokio::net::TcpListener;
+use tokio::sync::{mpsc, oneshot};
+
+pub mod fixtures;
+mod gateway;
+mod rest;
+
+pub use fixtures::InteractionBuilder;
+pub use rest::Request;
+
+/// How long to wait for the client to connect or for an expected request.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct TestDiscord {
+    ctx: Context,
+    rest: Arc<rest::State>,
+    events: mpsc::UnboundedSender<gateway::Dispatch>,
+    seen: Mutex<HashSet<usize>>,
+}
+
+impl TestDiscord {
+    pub fn builder() -> TestDiscordBuilder {
+        TestDiscordBuilder::default()
+    }
+
+    pub async fn start() -> Self {
+        Self::builder().start().await
+    }
+
+    /// The context of the connected client, for calling handlers directly.
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// Sends a gateway event to the client, e.g. `MESSAGE_CREATE`.
+    pub fn dispatch(&self, event: &str, data: Value) {
+        self.events
+            .send((event.to_string(), data))
+            .expect("gateway is running");
+    }
+
+    /// Sends an interaction to the client, as built by [`InteractionBuilder`].
+    pub fn interaction(&self, interaction: Value) {
+        self.dispatch("INTERACTION_CREATE", interaction);
+    }
+
+    /// Overrides the response for `method` and `pattern`, where `*` matches
+    /// any path segment. Later stubs take precedence.
+    pub fn stub(&self, method: &str, pattern: &str, status: u16, body: Value) {
+        self.rest.stub(method, pattern, status, body);
+    }
+
+    /// Every request made so far, in order.
+    pub fn requests(&self) -> Vec<Request> {
+        self.rest.requests.lock().unwrap().clone()
+    }
+
+    /// Waits for the next request matching `method` and `pattern` that has
+    /// not been returned by a previous call.
+    ///
+    /// # Panics
+    ///
+    /// If no such request is made within five seconds.
+    pub async fn wait_for(&self, method: &str, pattern: &str) -> Request {
+        let wait = async {
+            loop {
+                let notified = self.rest.notify.notified();
+
+                if let Some(request) = self.take(method, pattern) {
+                    return request;
+                }
+
+                notified.await;
+            }
+        };
+
+        match tokio::time::timeout(TIMEOUT, wait).await {
+            Ok(request) => request,
+            Err(_) => panic!(
+                "Timed out waiting for {method} {pattern}. Requests made: {:#?}",
+                self.requests()
+                    .iter()
+                    .map(|request| format!("{} {}", request.method, request.path))
+                    .collect::<Vec<_>>()
+            ),
+        }
+    }
+
+    /// Waits for the initial response to the interaction with `token`.
+    pub async fn response(&self, token: &str) -> Request {
+        self.wait_for("POST", &format!("/interactions/*/{token}/callback"))
+            .await
+    }
+
+    /// Waits for the next edit of the original response to the interaction
+    /// with `token`, e.g. after deferring.
+    pub async fn edited_response(&self, token: &str) -> Request {
+        self.wait_for("PATCH", &format!("/webhooks/*/{token}/messages/@original"))
+            .await
+    }
+
+    fn take(&self, method: &str, pattern: &str) -> Option<Request> {
+        let requests = self.rest.requests.lock().unwrap();
+        let mut seen = self.seen.lock().unwrap();
+
+        let (index, request) = requests
+            .iter()
+            .enumerate()
+            .find(|(index, request)| !seen.contains(index) && request.is(method, pattern))?;
+
+        seen.insert(index);
+        Some(request.clone())
+    }
+}
+
+#[derive(Default)]
+pub struct TestDiscordBuilder {
+    type_map: Option<TypeMap>,
+    handler: Option<Arc<dyn RawEventHandler>>,
+}
+
+impl TestDiscordBuilder {
+    pub fn type_map(mut self, type_map: TypeMap) -> Self {
+        self.type_map = Some(type_map);
+        self
+    }
+
+    /// The handler that receives dispatched events, usually the bot's own.
+    pub fn raw_event_handler(mut self, handler: impl RawEventHandler + 'static) -> Self {
+        self.handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Starts the stand-in servers and waits for the client to be ready.
+    pub async fn start(self) -> TestDiscord {
+        let rest_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gateway_listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+
+        let rest_url = format!("http://{}", rest_listener.local_addr().unwrap());
+        let gateway_url = format!("ws://{}", gateway_listener.local_addr().unwrap());
+
+        let rest = Arc::new(rest::State::new(gateway_url));
+        tokio::spawn(rest::serve(rest_listener, rest.clone()));
+
+        let (events, events_rx) = mpsc::unbounded_channel();
+        tokio::spawn(gateway::serve(gateway_listener, events_rx));
+
+        let http = HttpBuilder::new("test-token")
+            .proxy(rest_url)
+            .ratelimiter_disabled(true)
+            .application_id(fixtures::APPLICATION_ID)
+            .build();
+
+        let (ready, ready_rx) = oneshot::channel();
+        let handler = ReadyHandler {
+            ready: Mutex::new(Some(ready)),
+            inner: self.handler,
+        };
+
+        let mut client = ClientBuilder::new_with_http(http, GatewayIntents::all())
+            .type_map(self.type_map.unwrap_or_default())
+            .raw_event_handler(handler)
+            .await
+            .unwrap();
+
+        tokio::spawn(async move { client.start().await });
+
+        let ctx = tokio::time::timeout(TIMEOUT, ready_rx)
+            .await
+            .expect("client did not connect to the test gateway")
+            .unwrap();
+
+        TestDiscord {
+            ctx,
+            rest,
+            events,
+            seen: Mutex::new(HashSet::new()),
+        }
+    }
+}
+
+/// Hands out the context once the client is ready, then forwards every event.
+struct ReadyHandler {
+    ready: Mutex<Option<oneshot::Sender<Context>>>,
+    inner: Option<Arc<dyn RawEventHandler>>,
+}
+
+#[async_trait]
+impl RawEventHandler for ReadyHandler {
+    async fn raw_event(&self, ctx: Context, ev: Event) {
+        if matches!(ev, Event::Ready(_)) {
+            let ready = self.ready.lock().unwrap().take();
+            if let Some(ready) = ready {
+                let _ = ready.send(ctx.clone());
+            }
+        }
+
+        if let Some(inner) = &self.inner {
+            inner.raw_event(ctx, ev).await;
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use std::sync::Mutex;
+
+use serde_json::{Value, json};
+use serenity::all::{ChannelId, MessageId, UserId};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+
+use super::fixtures::{self, BOT_ID, CHANNEL_ID, GUILD_ID};
+
+/// A request the code under test made to the Discord API.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    /// The route without the `/api/v10` prefix or query string.
+    pub path: String,
+    /// The JSON body, or `payload_json` for requests with attachments.
+    pub body: Value,
+    pub status: u16,
+    pub response: Value,
+}
+
+impl Request {
+    /// Whether this request matches `method` and `pattern`, where `*` in the
+    /// pattern matches any single path segment.
+    pub fn is(&self, method: &str, pattern: &str) -> bool {
+        self.method.eq_ignore_ascii_case(method) && matches(pattern, &self.path)
+    }
+}
+
+struct Stub {
+    method: String,
+    pattern: String,
+    status: u16,
+    body: Value,
+}
+
+pub(super) struct State {
+    gateway_url: String,
+    pub(super) requests: Mutex<Vec<Request>>,
+    stubs: Mutex<Vec<Stub>>,
+    pub(super) notify: Notify,
+}
+
+impl State {
+    pub(super) fn new(gateway_url: String) -> Self {
+        Self {
+            gateway_url,
+            requests: Mutex::new(Vec::new()),
+            stubs: Mutex::new(Vec::new()),
+            notify: Notify::new(),
+        }
+    }
+
+    pub(super) fn stub(&self, method: &str, pattern: &str, status: u16, body: Value) {
+        self.stubs.lock().unwrap().push(Stub {
+            method: method.to_string(),
+            pattern: pattern.to_string(),
+            status,
+            body,
+        });
+    }
+
+    fn respond(&self, method: &str, path: &str, body: &Value) -> (u16, Value) {
+        let stubs = self.stubs.lock().unwrap();
+
+        // Later stubs override earlier ones
+        if let Some(stub) = stubs
+            .iter()
+            .rev()
+            .find(|stub| stub.method.eq_ignore_ascii_case(method) && matches(&stub.pattern, path))
+        {
+            return (stub.status, stub.body.clone());
+        }
+
+        drop(stubs);
+
+        self.default_response(method, path, body)
+    }
+
+    /// Plausible responses for the routes the bot uses, so tests only need to
+    /// stub the ones they care about.
+    fn default_response(&self, method: &str, path: &str, body: &Value) -> (u16, Value) {
+        let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+
+        match (method, segments.as_slice()) {
+            ("GET", ["gateway"] | ["gateway", "bot"]) => (
+                200,
+                json!({
+                    "url": self.gateway_url,
+                    "shards": 1,
+                    "session_start_limit": {
+                        "total": 1000,
+                        "remaining": 1000,
+                        "reset_after": 0,
+                        "max_concurrency": 1,
+                    },
+                }),
+            ),
+            ("POST", ["interactions", _, _, "callback"]) => (204, Value::Null),
+            ("DELETE", _) => (204, Value::Null),
+            ("PUT", ["channels", _, "messages", _, "reactions", ..]) => (204, Value::Null),
+            ("PUT", ["applications", _, "commands"])
+            | ("PUT", ["applications", _, "guilds", _, "commands"]) => (200, json!([])),
+
+            ("POST", ["webhooks", _, _]) => (200, message(CHANNEL_ID, None, body)),
+            ("GET" | "PATCH", ["webhooks", _, _, "messages", id]) => {
+                (200, message(CHANNEL_ID, parse(id), body))
+            }
+            ("POST", ["channels", channel, "messages"]) => (
+                200,
+                message(parse(channel).unwrap_or(CHANNEL_ID), None, body),
+            ),
+            ("GET" | "PATCH", ["channels", channel, "messages", id]) => (
+                200,
+                message(parse(channel).unwrap_or(CHANNEL_ID), parse(id), body),
+            ),
+
+            ("GET", ["users", "@me"]) => (200, fixtures::user(BOT_ID)),
+            ("GET", ["users", id]) => (200, fixtures::user(parse(id).unwrap_or(BOT_ID))),
+            ("GET" | "PATCH", ["channels", id]) => {
+                let mut channel =
+                    fixtures::channel(GUILD_ID, parse::<ChannelId>(id).unwrap_or(CHANNEL_ID));
+                merge(&mut channel, body);
+                (200, channel)
+            }
+            ("GET", ["guilds", guild, "members", id]) => (
+                200,
+                fixtures::member(
+                    parse(guild).unwrap_or(GUILD_ID),
+                    parse::<UserId>(id).unwrap_or(BOT_ID),
+                ),
+            ),
+
+            _ => (
+                404,
+                json!({
+                    "code": 0,
+                    "message": format!("No test response for {method} {path}"),
+                }),
+            ),
+        }
+    }
+}
+
+pub(super) async fn serve(listener: TcpListener, state: std::sync::Arc<State>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(connection(stream, state.clone()));
+    }
+}
+
+async fn connection(stream: TcpStream, state: std::sync::Arc<State>) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).await? == 0 {
+            return Ok(());
+        }
+
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let target = parts.next().unwrap_or_default().to_string();
+
+        let mut content_length = 0;
+        let mut content_type = String::new();
+
+        loop {
+            let mut header = String::new();
+            if reader.read_line(&mut header).await? == 0 {
+                return Ok(());
+            }
+
+            let header = header.trim_end();
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = header.split_once(':') {
+                match name.trim().to_ascii_lowercase().as_str() {
+                    "content-length" => content_length = value.trim().parse().unwrap_or(0),
+                    "content-type" => content_type = value.trim().to_string(),
+                    _ => {}
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).await?;
+
+        let path = target.split('?').next().unwrap_or_default();
+        let path = path.strip_prefix("/api/v10").unwrap_or(path).to_string();
+        let body = parse_body(&content_type, &body);
+
+        let (status, response) = state.respond(&method, &path, &body);
+
+        state.requests.lock().unwrap().push(Request {
+            method,
+            path,
+            body,
+            status,
+            response: response.clone(),
+        });
+        state.notify.notify_waiters();
+
+        let response = if status == 204 {
+            format!("HTTP/1.1 {status} No Content\r\n\r\n")
+        } else {
+            let body = response.to_string();
+            format!(
+                "HTTP/1.1 {status} {}\r\ncontent-type: application/json\r\ncontent-length: {}\r\n\r\n{body}",
+                if status < 400 { "OK" } else { "Error" },
+                body.len()
+            )
+        };
+
+        reader.get_mut().write_all(response.as_bytes()).await?;
+    }
+}
+
+fn parse_body(content_type: &str, body: &[u8]) -> Value {
+    if !content_type.starts_with("multipart/form-data") {
+        return serde_json::from_slice(body).unwrap_or(Value::Null);
+    }
+
+    // Only the JSON part is interesting, attachments are ignored
+    let body = String::from_utf8_lossy(body);
+    body.split_once("name=\"payload_json\"")
+        .and_then(|(_, part)| part.split_once("\r\n\r\n"))
+        .and_then(|(_, part)| part.split("\r\n--").next())
+        .and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or(Value::Null)
+}
+
+/// A message as Discord would return it after creating or editing it with
+/// `body`.
+fn message(channel: ChannelId, id: Option<MessageId>, body: &Value) -> Value {
+    let id = id.unwrap_or_else(|| MessageId::new(fixtures::snowflake()));
+
+    let mut message = fixtures::message(channel, id, fixtures::user(BOT_ID));
+    merge(&mut message, body);
+    message
+}
+
+fn merge(target: &mut Value, body: &Value) {
+    let (Value::Object(target), Value::Object(body)) = (target, body) else {
+        return;
+    };
+
+    for (key, value) in body {
+        if target.contains_key(key) || key == "message_reference" {
+            target.insert(key.clone(), value.clone());
+        }
+    }
+}
+
+fn parse<T: From<u64>>(segment: &str) -> Option<T> {
+    segment
+        .parse::<u64>()
+        .ok()
+        .filter(|id| *id != 0)
+        .map(T::from)
+}
+
+fn matches(pattern: &str, path: &str) -> bool {
+    let mut pattern = pattern.trim_matches('/').split('/');
+    let mut path = path.trim_matches('/').split('/');
+
+    loop {
+        match (pattern.next(), path.next()) {
+            (None, None) => return true,
+            (Some("*"), Some(_)) => {}
+            (Some(expected), Some(segment)) if expected == segment => {}
+            _ => return false,
+        }
+    }
+}
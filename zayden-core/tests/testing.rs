@@ -0,0 +1,301 @@
+use async_trait::async_trait;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use serenity::all::{
+    CommandInteraction, Context, CreateButton, CreateCommand, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, EditInteractionResponse, Event,
+    Interaction, RawEventHandler, ResolvedOption, ResolvedValue,
+};
+use sqlx::{Pool, Sqlite, SqlitePool};
+use zayden_core::SlashCommand;
+use zayden_core::testing::{InteractionBuilder, TestDiscord, fixtures};
+
+#[derive(Debug)]
+struct Error;
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "error")
+    }
+}
+
+impl std::error::Error for Error {}
+
+struct Echo;
+
+#[async_trait]
+impl SlashCommand<Error, Sqlite> for Echo {
+    async fn run(
+        ctx: &Context,
+        interaction: &CommandInteraction,
+        options: Vec<ResolvedOption<'_>>,
+        _pool: &Pool<Sqlite>,
+    ) -> Result<(), Error> {
+        let text = match options.first().map(|option| &option.value) {
+            Some(ResolvedValue::String(text)) => text.to_string(),
+            _ => String::from("nothing"),
+        };
+
+        interaction
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new().content(text),
+                ),
+            )
+            .await
+            .unwrap();
+
+        let user = interaction.user.id.to_user(ctx).await.unwrap();
+        interaction
+            .channel_id
+            .say(ctx, format!("{} said something", user.name))
+            .await
+            .unwrap();
+
+        Ok(())
+    }
+
+    fn register(_ctx: &Context) -> Result<CreateCommand, Error> {
+        Ok(CreateCommand::new("echo"))
+    }
+}
+
+struct Handler;
+
+#[async_trait]
+impl RawEventHandler for Handler {
+    async fn raw_event(&self, ctx: Context, ev: Event) {
+        if let Event::InteractionCreate(event) = ev
+            && let Interaction::Command(interaction) = event.interaction
+        {
+            let pool = SqlitePool::connect_lazy("sqlite::memory:").unwrap();
+            Echo::run(&ctx, &interaction, interaction.data.options(), &pool)
+                .await
+                .unwrap();
+        }
+    }
+}
+
+#[tokio::test]
+async fn runs_command_against_stand_in() {
+    let discord = TestDiscord::start().await;
+    let pool = SqlitePool::connect_lazy("sqlite::memory:").unwrap();
+
+    let builder = InteractionBuilder::new();
+    let interaction = builder.command(
+        "echo",
+        json!([{ "name": "text", "type": 3, "value": "hi" }]),
+    );
+
+    Echo::run(
+        discord.ctx(),
+        &interaction,
+        interaction.data.options(),
+        &pool,
+    )
+    .await
+    .unwrap();
+
+    let response = discord.response(&builder.token()).await;
+    assert_eq!(response.body["type"], 4);
+    assert_eq!(response.body["data"]["content"], "hi");
+
+    let message = discord
+        .wait_for(
+            "POST",
+            &format!("/channels/{}/messages", fixtures::CHANNEL_ID),
+        )
+        .await;
+    assert_eq!(
+        message.body["content"],
+        format!("user{} said something", fixtures::USER_ID)
+    );
+}
+
+#[tokio::test]
+async fn dispatches_interactions_through_gateway() {
+    let discord = TestDiscord::builder()
+        .raw_event_handler(Handler)
+        .start()
+        .await;
+
+    let builder = InteractionBuilder::new().locale("de");
+    discord.interaction(builder.command_json("echo", json!([])));
+
+    let response = discord.response(&builder.token()).await;
+    assert_eq!(response.body["data"]["content"], "nothing");
+}
+
+#[tokio::test]
+async fn collects_component_clicks() {
+    let discord = TestDiscord::start().await;
+    let builder = InteractionBuilder::new();
+    let interaction = builder.command("counter", json!([]));
+
+    let ctx = discord.ctx().clone();
+    let game = tokio::spawn(async move {
+        interaction.defer(&ctx).await.unwrap();
+
+        let message = interaction
+            .edit_response(
+                &ctx,
+                EditInteractionResponse::new()
+                    .embed(
+                        CreateEmbed::new()
+                            .title("Counter")
+                            .field("Count", "0", true),
+                    )
+                    .button(CreateButton::new("increment").label("+1")),
+            )
+            .await
+            .unwrap();
+
+        let click = message
+            .await_component_interaction(&ctx.shard)
+            .timeout(Duration::from_secs(5))
+            .await
+            .unwrap();
+
+        click
+            .create_response(
+                &ctx,
+                CreateInteractionResponse::UpdateMessage(
+                    CreateInteractionResponseMessage::new().embed(
+                        CreateEmbed::new()
+                            .title("Counter")
+                            .field("Count", "1", true),
+                    ),
+                ),
+            )
+            .await
+            .unwrap();
+    });
+
+    discord.response(&builder.token()).await;
+    let board = discord.edited_response(&builder.token()).await;
+    assert_eq!(board.body["embeds"][0]["fields"][0]["value"], "0");
+
+    let message_id = board.response["id"]
+        .as_str()
+        .unwrap()
+        .parse::<u64>()
+        .unwrap();
+    let click = InteractionBuilder::new();
+    discord.interaction(click.component_json("increment", message_id));
+
+    let update = discord.response(&click.token()).await;
+    assert_eq!(update.body["type"], 7);
+    assert_eq!(update.body["data"]["embeds"][0]["fields"][0]["value"], "1");
+
+    game.await.unwrap();
+}
+
+#[test]
+fn builds_component_and_modal_interactions() {
+    let builder = InteractionBuilder::new();
+
+    let component = builder.component("lfg_join", 5u64);
+    assert_eq!(component.data.custom_id, "lfg_join");
+    assert_eq!(component.message.id.get(), 5);
+    assert_eq!(component.user.id, fixtures::USER_ID);
+
+    let modal = builder.modal("lfg_create", &[("activity", "Raid")]);
+    let data = zayden_core::parse_modal_data(&modal.data.components);
+    assert_eq!(data.get("activity"), Some(&"Raid"));
+}
+
+#[tokio::test]
+async fn syncs_missing_application_emojis() {
+    let discord = TestDiscord::start().await;
+    discord.stub(
+        "GET",
+        "applications/*/emojis",
+        200,
+        json!({ "items": [{ "id": "1", "name": "coin" }] }),
+    );
+    discord.stub(
+        "POST",
+        "applications/*/emojis",
+        201,
+        json!({ "id": "2", "name": "clubs_a" }),
+    );
+
+    let dir = std::env::temp_dir().join(format!("emojis-{}", fixtures::snowflake()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for file in ["coin.png", "clubs_a.png", "a.png", "notes.txt"] {
+        std::fs::write(dir.join(file), b"png").unwrap();
+    }
+
+    zayden_core::emojis::sync(&discord.ctx().http, &dir)
+        .await
+        .unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let uploads = discord
+        .requests()
+        .into_iter()
+        .filter(|request| request.is("POST", "applications/*/emojis"))
+        .collect::<Vec<_>>();
+    assert_eq!(uploads.len(), 1);
+    assert_eq!(uploads[0].body["name"], "clubs_a");
+
+    assert_eq!(zayden_core::emojis::get("coin").map(|id| id.get()), Some(1));
+    assert_eq!(
+        zayden_core::emojis::get("clubs_a").map(|id| id.get()),
+        Some(2)
+    );
+}
+
+#[tokio::test]
+async fn serves_metrics_and_health() {
+    let addr = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap();
+    let healthy = Arc::new(AtomicBool::new(true));
+
+    let refresh = healthy.clone();
+    tokio::spawn(zayden_core::metrics::serve(addr, move || {
+        let healthy = refresh.load(Ordering::SeqCst);
+        async move { healthy }
+    }));
+
+    let get = |path: &'static str| async move {
+        let mut stream = loop {
+            match TcpStream::connect(addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+        stream
+            .write_all(format!("GET {path} HTTP/1.1\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response
+    };
+
+    zayden_core::METRICS
+        .commands
+        .with_label_values(&["ping"])
+        .inc();
+
+    let metrics = get("/metrics").await;
+    assert!(metrics.starts_with("HTTP/1.1 200"));
+    assert!(metrics.contains(r#"zayden_commands_total{command="ping"} 1"#));
+
+    // A client that never sends its request doesn't hold up the next one
+    let _idle = TcpStream::connect(addr).await.unwrap();
+
+    assert!(get("/healthz").await.starts_with("HTTP/1.1 200"));
+    healthy.store(false, Ordering::SeqCst);
+    assert!(get("/healthz").await.starts_with("HTTP/1.1 503"));
+}